@@ -16,7 +16,13 @@ pub trait IntrospectionConnector: Send + Sync + 'static {
 
     async fn get_database_description(&self) -> ConnectorResult<String>;
 
-    async fn introspect(&self) -> ConnectorResult<IntrospectionResult>;
+    async fn introspect(&self, options: IntrospectionOptions) -> ConnectorResult<IntrospectionResult>;
+}
+
+#[derive(Debug, Default, Clone, Copy)]
+pub struct IntrospectionOptions {
+    /// Annotate the fields with the native type of their column, e.g. `@db.VarChar(255)`.
+    pub native_types: bool,
 }
 
 #[derive(Serialize, Deserialize, Debug)]
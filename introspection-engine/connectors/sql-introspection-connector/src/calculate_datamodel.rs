@@ -21,16 +21,18 @@ pub fn calculate_datamodel(schema: &SqlSchema, family: &SqlFamily) -> SqlIntrosp
         .tables
         .iter()
         .filter(|table| !is_migration_table(&table))
+        .filter(|table| !is_change_events_table(&table))
         .filter(|table| !is_prisma_1_point_1_or_2_join_table(&table))
         .filter(|table| !is_prisma_1_point_0_join_table(&table))
         .filter(|table| !is_relay_table(&table))
     {
         debug!("Calculating model: {}", table.name);
         let mut model = Model::new(table.name.clone(), None);
+        model.schema = table.schema.clone();
 
         for column in &table.columns {
             version_check.uses_non_prisma_type(&column.tpe);
-            let field = calculate_scalar_field(&table, &column);
+            let field = calculate_scalar_field(&table, &column, family);
             model.add_field(field);
         }
 
@@ -51,21 +53,29 @@ pub fn calculate_datamodel(schema: &SqlSchema, family: &SqlFamily) -> SqlIntrosp
         for index in table
             .indices
             .iter()
-            .filter(|i| !(i.columns.len() == 1 && i.is_unique()))
+            .filter(|i| !(i.columns.len() == 1 && i.is_unique() && !i.has_options()))
         {
             model.add_index(calculate_index(index));
         }
 
         if table.primary_key_columns().len() > 1 {
             model.id_fields = table.primary_key_columns();
+            model.id_db_name = calculate_primary_key_db_name(table);
         }
 
+        model.check_constraints = table.check_constraints.iter().map(calculate_check_constraint).collect();
+
         version_check.always_has_created_at_updated_at(table, &model);
         version_check.always_has_singular_id(table, &model);
 
         data_model.add_model(model);
     }
 
+    for view in schema.views.iter() {
+        debug!("Calculating view: {}", view.name);
+        data_model.add_model(calculate_view_model(view, family));
+    }
+
     for e in schema.enums.iter() {
         data_model.add_enum(dml::Enum {
             name: e.name.clone(),
@@ -140,11 +140,13 @@ pub fn commenting_out_guardrails(datamodel: &mut Datamodel) -> Vec<Warning> {
             && !model.indices.iter().any(|i| i.is_unique())
             && !models_with_one_to_one_relation.contains(&model.name)
         {
+            let kind = if model.is_view { "view" } else { "table" };
+
             model.is_commented_out = true;
-            model.documentation = Some(
-                "The underlying table does not contain a unique identifier and can therefore currently not be handled."
-                    .to_string(),
-            );
+            model.documentation = Some(format!(
+                "The underlying {} does not contain a unique identifier and can therefore currently not be handled.",
+                kind
+            ));
             models_without_identifiers.push(Model {
                 model: model.name.clone(),
             })
@@ -2,13 +2,15 @@ pub mod calculate_datamodel; // only exported to be able to unit test it
 mod commenting_out_guardrails;
 mod error;
 mod misc_helpers;
+mod native_types;
 mod prisma_1_defaults;
 mod sanitize_datamodel_names;
 mod schema_describer_loading;
 mod version_checker;
 
 use introspection_connector::{
-    ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionResult,
+    ConnectorError, ConnectorResult, DatabaseMetadata, IntrospectionConnector, IntrospectionOptions,
+    IntrospectionResult,
 };
 use quaint::prelude::ConnectionInfo;
 use sql_schema_describer::{SqlSchema, SqlSchemaDescriberBackend};
@@ -22,6 +24,7 @@ pub type SqlIntrospectionResult<T> = core::result::Result<T, SqlError>;
 pub struct SqlIntrospectionConnector {
     connection_info: ConnectionInfo,
     describer: Box<dyn SqlSchemaDescriberBackend>,
+    additional_schemas: Vec<String>,
 }
 
 impl SqlIntrospectionConnector {
@@ -40,9 +43,23 @@ impl SqlIntrospectionConnector {
         Ok(SqlIntrospectionConnector {
             describer,
             connection_info,
+            additional_schemas: Vec::new(),
         })
     }
 
+    /// Also introspect the tables of the given schemas, besides the one of the connection.
+    pub fn with_additional_schemas(mut self, schemas: &[String]) -> Self {
+        let schema_name = self.connection_info.schema_name();
+
+        self.additional_schemas = schemas
+            .iter()
+            .filter(|schema| schema.as_str() != schema_name)
+            .cloned()
+            .collect();
+
+        self
+    }
+
     async fn catch<O>(&self, fut: impl Future<Output = Result<O, SqlError>>) -> ConnectorResult<O> {
         fut.await
             .map_err(|sql_introspection_error| sql_introspection_error.into_connector_error(&self.connection_info))
@@ -62,7 +79,10 @@ impl SqlIntrospectionConnector {
     }
 
     async fn describe(&self) -> SqlIntrospectionResult<SqlSchema> {
-        Ok(self.describer.describe(self.connection_info.schema_name()).await?)
+        Ok(self
+            .describer
+            .describe_with_schemas(self.connection_info.schema_name(), &self.additional_schemas)
+            .await?)
     }
 }
 
@@ -83,13 +103,18 @@ impl IntrospectionConnector for SqlIntrospectionConnector {
         Ok(description)
     }
 
-    async fn introspect(&self) -> ConnectorResult<IntrospectionResult> {
+    async fn introspect(&self, options: IntrospectionOptions) -> ConnectorResult<IntrospectionResult> {
         let sql_schema = self.catch(self.describe()).await?;
         tracing::debug!("SQL Schema Describer is done: {:?}", sql_schema);
 
         let family = self.connection_info.sql_family();
 
-        let introspection_result = calculate_datamodel::calculate_datamodel(&sql_schema, &family).unwrap();
+        let mut introspection_result = calculate_datamodel::calculate_datamodel(&sql_schema, &family).unwrap();
+
+        if options.native_types {
+            native_types::add_native_types(&family, &mut introspection_result.datamodel, &sql_schema);
+        }
+
         tracing::debug!("Calculating datamodel is done: {:?}", sql_schema);
         Ok(introspection_result)
     }
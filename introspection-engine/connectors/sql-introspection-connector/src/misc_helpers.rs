@@ -2,8 +2,12 @@ use datamodel::{
     Datamodel, DefaultValue as DMLDef, Field, FieldArity, FieldType, IndexDefinition, Model, OnDeleteStrategy,
     RelationInfo, ScalarType, ValueGenerator as VG,
 };
+use prisma_value::PrismaValue;
+use quaint::connector::SqlFamily;
+use sql_migration_connector::normalize_database_expression;
 use sql_schema_describer::{
-    Column, ColumnArity, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, Index, IndexType, SqlSchema, Table,
+    CheckConstraint, Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue as SQLDef, ForeignKey, Index,
+    IndexAlgorithm, IndexColumnOptions, IndexType, SortOrder, SqlSchema, Table, View,
 };
 use tracing::debug;
 
@@ -13,6 +17,11 @@ pub fn is_migration_table(table: &Table) -> bool {
     table.name == "_Migration"
 }
 
+/// The log of record changes written by the triggers the migration engine installs on SQLite.
+pub(crate) fn is_change_events_table(table: &Table) -> bool {
+    table.name == "_prisma_change_events"
+}
+
 pub(crate) fn is_relay_table(table: &Table) -> bool {
     table.name == "_RelayId"
         && table.columns[0].name == "id"
@@ -78,6 +87,7 @@ pub fn calculate_many_to_many_field(foreign_key: &ForeignKey, relation_name: Str
         to: foreign_key.referenced_table.clone(),
         to_fields: foreign_key.referenced_columns.clone(),
         on_delete: OnDeleteStrategy::None,
+        fk_db_name: None,
     });
 
     let basename = foreign_key.referenced_table.clone();
@@ -95,6 +105,7 @@ pub fn calculate_many_to_many_field(foreign_key: &ForeignKey, relation_name: Str
         default_value: None,
         is_unique: false,
         is_id: false,
+        constraint_db_name: None,
         documentation: None,
         is_generated: false,
         is_updated_at: false,
@@ -112,28 +123,78 @@ pub(crate) fn calculate_index(index: &Index) -> IndexDefinition {
         name: Some(index.name.clone()),
         fields: index.columns.clone(),
         tpe,
+        db_name: None,
+        field_options: index.column_options.iter().map(calculate_index_field_options).collect(),
+        algorithm: index.algorithm.map(calculate_index_algorithm),
+        predicate: index.predicate.clone(),
     };
     index_definition
 }
 
-pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> Field {
+fn calculate_index_field_options(options: &IndexColumnOptions) -> datamodel::dml::IndexFieldOptions {
+    datamodel::dml::IndexFieldOptions {
+        sort_order: match options.sort_order {
+            SortOrder::Asc => datamodel::dml::SortOrder::Asc,
+            SortOrder::Desc => datamodel::dml::SortOrder::Desc,
+        },
+        length: options.length,
+    }
+}
+
+fn calculate_index_algorithm(algorithm: IndexAlgorithm) -> datamodel::dml::IndexAlgorithm {
+    match algorithm {
+        IndexAlgorithm::BTree => datamodel::dml::IndexAlgorithm::BTree,
+        IndexAlgorithm::Hash => datamodel::dml::IndexAlgorithm::Hash,
+        IndexAlgorithm::Gin => datamodel::dml::IndexAlgorithm::Gin,
+        IndexAlgorithm::Gist => datamodel::dml::IndexAlgorithm::Gist,
+        IndexAlgorithm::Brin => datamodel::dml::IndexAlgorithm::Brin,
+    }
+}
+
+pub(crate) fn calculate_check_constraint(check_constraint: &CheckConstraint) -> datamodel::dml::CheckConstraint {
+    debug!("Handling check constraint {:?}", check_constraint);
+    datamodel::dml::CheckConstraint {
+        name: Some(check_constraint.name.clone()),
+        expression: check_constraint.expression.clone(),
+    }
+}
+
+pub(crate) fn calculate_scalar_field(table: &Table, column: &Column, family: &SqlFamily) -> Field {
     debug!("Handling column {:?}", column);
-    let field_type = calculate_scalar_field_type(&column);
+    let field_type = calculate_scalar_field_type(&column, family);
     let (is_commented_out, documentation) = match field_type {
         FieldType::Unsupported(_) => (true, Some("This type is currently not supported.".to_string())),
         _ => (false, None),
     };
 
+    let is_integer = matches!(
+        field_type.scalar_type(),
+        Some(ScalarType::Int) | Some(ScalarType::BigInt)
+    );
     let arity = match column.tpe.arity {
-        _ if column.auto_increment && field_type == FieldType::Base(ScalarType::Int, None) => FieldArity::Required,
+        _ if column.auto_increment && is_integer => FieldArity::Required,
         ColumnArity::Required => FieldArity::Required,
         ColumnArity::Nullable => FieldArity::Optional,
         ColumnArity::List => FieldArity::List,
     };
 
     let is_id = is_id(&column, &table);
-    let default_value = calculate_default(table, &column, &arity);
+    // The describers read numeric defaults as `Int` and `Float` values.
+    let default_value = match (calculate_default(table, &column, &arity), field_type.scalar_type()) {
+        (Some(DMLDef::Single(PrismaValue::Int(value))), Some(ScalarType::BigInt)) => {
+            Some(DMLDef::Single(PrismaValue::BigInt(value)))
+        }
+        (Some(DMLDef::Single(PrismaValue::Float(value))), Some(ScalarType::Decimal)) => {
+            Some(DMLDef::Single(PrismaValue::Decimal(value)))
+        }
+        (default_value, _) => default_value,
+    };
     let is_unique = table.is_column_unique(&column.name) && !is_id;
+    let constraint_db_name = match (is_id, is_unique) {
+        (true, _) => calculate_primary_key_db_name(table),
+        (false, true) => calculate_unique_db_name(table, column),
+        (false, false) => None,
+    };
 
     Field {
         name: column.name.clone(),
@@ -143,6 +204,7 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> Field {
         default_value,
         is_unique,
         is_id,
+        constraint_db_name,
         documentation,
         is_generated: false,
         is_updated_at: false,
@@ -150,6 +212,28 @@ pub(crate) fn calculate_scalar_field(table: &Table, column: &Column) -> Field {
     }
 }
 
+/// Views have no keys, indexes or foreign keys, their columns are handled like the ones of an unconstrained table.
+pub(crate) fn calculate_view_model(view: &View, family: &SqlFamily) -> Model {
+    let table = Table {
+        name: view.name.clone(),
+        schema: None,
+        columns: view.columns.clone(),
+        indices: vec![],
+        primary_key: None,
+        foreign_keys: vec![],
+        check_constraints: vec![],
+    };
+
+    let mut model = Model::new(view.name.clone(), None);
+    model.is_view = true;
+
+    for column in &view.columns {
+        model.add_field(calculate_scalar_field(&table, column, family));
+    }
+
+    model
+}
+
 pub(crate) fn calculate_relation_field(schema: &SqlSchema, table: &Table, foreign_key: &ForeignKey) -> Field {
     debug!("Handling foreign key  {:?}", foreign_key);
 
@@ -159,6 +243,7 @@ pub(crate) fn calculate_relation_field(schema: &SqlSchema, table: &Table, foreig
         to: foreign_key.referenced_table.clone(),
         to_fields: foreign_key.referenced_columns.clone(),
         on_delete: OnDeleteStrategy::None,
+        fk_db_name: calculate_foreign_key_db_name(table, foreign_key),
     });
 
     let columns: Vec<&Column> = foreign_key
@@ -181,6 +266,7 @@ pub(crate) fn calculate_relation_field(schema: &SqlSchema, table: &Table, foreig
         default_value: None,
         is_unique: false,
         is_id: false,
+        constraint_db_name: None,
         documentation: None,
         is_generated: false,
         is_updated_at: false,
@@ -188,6 +274,56 @@ pub(crate) fn calculate_relation_field(schema: &SqlSchema, table: &Table, foreig
     }
 }
 
+/// The name of the primary key constraint, unless it is the default name Postgres gives it.
+pub(crate) fn calculate_primary_key_db_name(table: &Table) -> Option<String> {
+    let constraint_name = table.primary_key.as_ref()?.constraint_name.as_ref()?;
+
+    if *constraint_name == format!("{}_pkey", table.name) {
+        None
+    } else {
+        Some(constraint_name.clone())
+    }
+}
+
+/// The name of the unique index on a single column, unless it is a name chosen by the database or
+/// by the migration engine.
+fn calculate_unique_db_name(table: &Table, column: &Column) -> Option<String> {
+    let index = table.indices.iter().find(|index| {
+        index.tpe == IndexType::Unique
+            && index.columns.len() == 1
+            && index.columns[0] == column.name
+            && !index.has_options()
+    })?;
+
+    let is_default_name = index.name == format!("{}.{}", table.name, column.name)
+        || index.name == format!("{}_{}_key", table.name, column.name)
+        || index.name == column.name
+        || index.name.starts_with("sqlite_autoindex_");
+
+    if is_default_name {
+        None
+    } else {
+        Some(index.name.clone())
+    }
+}
+
+/// The name of the foreign key constraint, unless it is the default name Postgres or MySQL gives it.
+fn calculate_foreign_key_db_name(table: &Table, foreign_key: &ForeignKey) -> Option<String> {
+    let constraint_name = foreign_key.constraint_name.as_ref()?;
+    let postgres_default = format!("{}_{}_fkey", table.name, foreign_key.columns.join("_"));
+    let mysql_default_prefix = format!("{}_ibfk_", table.name);
+
+    let is_default_name = *constraint_name == postgres_default
+        || (constraint_name.starts_with(&mysql_default_prefix)
+            && constraint_name[mysql_default_prefix.len()..].parse::<u32>().is_ok());
+
+    if is_default_name {
+        None
+    } else {
+        Some(constraint_name.clone())
+    }
+}
+
 pub(crate) fn calculate_backrelation_field(
     schema: &SqlSchema,
     model: &Model,
@@ -203,6 +339,7 @@ pub(crate) fn calculate_backrelation_field(
         fields: vec![],
         to_fields: vec![],
         on_delete: OnDeleteStrategy::None,
+        fk_db_name: None,
     });
 
     let other_is_unique = || match &relation_info.fields.len() {
@@ -237,6 +374,7 @@ pub(crate) fn calculate_backrelation_field(
         default_value: None,
         is_unique: false,
         is_id: false,
+        constraint_db_name: None,
         documentation: None,
         is_generated: false,
         is_updated_at: false,
@@ -251,7 +389,10 @@ pub(crate) fn calculate_default(table: &Table, column: &Column, arity: &FieldAri
         (_, ColumnTypeFamily::Int) if is_sequence(column, table) => Some(DMLDef::Expression(VG::new_autoincrement())),
         (Some(SQLDef::SEQUENCE(_)), _) => Some(DMLDef::Expression(VG::new_autoincrement())),
         (Some(SQLDef::NOW), ColumnTypeFamily::DateTime) => Some(DMLDef::Expression(VG::new_now())),
-        (Some(SQLDef::DBGENERATED(_)), _) => Some(DMLDef::Expression(VG::new_dbgenerated())),
+        (Some(SQLDef::DBGENERATED(expression)), _) => Some(DMLDef::Expression(VG::from_database_expression(
+            expression,
+            normalize_database_expression,
+        ))),
         (Some(SQLDef::VALUE(val)), _) => Some(DMLDef::Single(val.clone())),
         _ => None,
     }
@@ -309,22 +450,62 @@ pub(crate) fn calculate_relation_name(schema: &SqlSchema, fk: &ForeignKey, table
     }
 }
 
-pub(crate) fn calculate_scalar_field_type(column: &Column) -> FieldType {
+pub(crate) fn calculate_scalar_field_type(column: &Column, family: &SqlFamily) -> FieldType {
     debug!("Calculating field type for '{}'", column.name);
 
     match &column.tpe.family {
         ColumnTypeFamily::Boolean => FieldType::Base(ScalarType::Boolean, None),
         ColumnTypeFamily::DateTime => FieldType::Base(ScalarType::DateTime, None),
+        ColumnTypeFamily::Float if is_decimal_type(&column.tpe, family) => FieldType::Base(ScalarType::Decimal, None),
         ColumnTypeFamily::Float => FieldType::Base(ScalarType::Float, None),
+        ColumnTypeFamily::Int if is_bigint_type(&column.tpe, family) => FieldType::Base(ScalarType::BigInt, None),
         ColumnTypeFamily::Int => FieldType::Base(ScalarType::Int, None),
         ColumnTypeFamily::String => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Enum(name) => FieldType::Enum(name.clone()),
         ColumnTypeFamily::Uuid => FieldType::Base(ScalarType::String, None),
         ColumnTypeFamily::Json => FieldType::Base(ScalarType::Json, None),
+        ColumnTypeFamily::Binary => FieldType::Base(ScalarType::Bytes, None),
         x => FieldType::Unsupported(x.to_string()),
     }
 }
 
+/// The name of a described type without its arguments, e.g. `decimal` for `decimal(10,2) unsigned`.
+fn base_type_name<'a>(tpe: &'a ColumnType, family: &SqlFamily) -> &'a str {
+    let full_data_type = match (family, &tpe.arity) {
+        // Postgres reports arrays with the `_` prefixed element type.
+        (SqlFamily::Postgres, ColumnArity::List) => tpe.full_data_type.trim_start_matches('_'),
+        _ => tpe.full_data_type.as_str(),
+    };
+
+    full_data_type
+        .split(|c: char| c == '(' || c.is_whitespace())
+        .next()
+        .unwrap_or(full_data_type)
+}
+
+fn is_bigint_type(tpe: &ColumnType, family: &SqlFamily) -> bool {
+    match family {
+        SqlFamily::Postgres => base_type_name(tpe, family) == "int8",
+        SqlFamily::Mysql => base_type_name(tpe, family) == "bigint",
+        SqlFamily::Sqlite => false,
+    }
+}
+
+/// The migration engine creates `Float` fields as `Decimal(65,30)` columns, these stay `Float` fields. SQLite has no
+/// exact decimal type: its `decimal` columns hold floating point numbers.
+fn is_decimal_type(tpe: &ColumnType, family: &SqlFamily) -> bool {
+    match family {
+        SqlFamily::Postgres => {
+            base_type_name(tpe, family) == "numeric"
+                && (tpe.numeric_precision, tpe.numeric_scale) != (Some(65), Some(30))
+        }
+        SqlFamily::Mysql => {
+            base_type_name(tpe, family) == "decimal" && !tpe.full_data_type.starts_with("decimal(65,30)")
+        }
+        SqlFamily::Sqlite => false,
+    }
+}
+
 // misc
 
 pub fn deduplicate_field_names(datamodel: &mut Datamodel) {
@@ -0,0 +1,71 @@
+use datamodel::{Datamodel, FieldType, WithDatabaseName};
+use datamodel_connector::{Connector, ExampleConnector};
+use quaint::connector::SqlFamily;
+use sql_schema_describer::{ColumnArity, ColumnType, SqlSchema};
+
+/// Annotates the scalar fields with the native type of their column, e.g. `@db.VarChar(255)`,
+/// so that migrating the introspected datamodel reproduces the column types exactly.
+pub fn add_native_types(family: &SqlFamily, data_model: &mut Datamodel, schema: &SqlSchema) {
+    let connector = match family {
+        SqlFamily::Postgres => ExampleConnector::postgres(),
+        SqlFamily::Mysql => ExampleConnector::mysql(),
+        SqlFamily::Sqlite => ExampleConnector::sqlite(),
+    };
+
+    for model in data_model.models_mut() {
+        let table = match schema.table(model.final_database_name()) {
+            Ok(table) => table,
+            Err(_) => continue,
+        };
+
+        for field in model.fields_mut() {
+            let scalar_type = match field.field_type {
+                FieldType::Base(scalar_type, _) => scalar_type,
+                _ => continue,
+            };
+
+            let column = match table.column(field.final_database_name()) {
+                Some(column) => column,
+                None => continue,
+            };
+
+            let datasource_type = described_type(family, &column.tpe);
+
+            if let Some(native_type) = connector
+                .parse_datasource_type(&datasource_type)
+                .filter(|native_type| native_type.prisma_type() == scalar_type)
+            {
+                field.field_type = FieldType::ConnectorSpecific(native_type);
+            }
+        }
+    }
+}
+
+fn described_type(family: &SqlFamily, tpe: &ColumnType) -> String {
+    let full_data_type = match (family, &tpe.arity) {
+        // Postgres reports arrays with the `_` prefixed element type.
+        (SqlFamily::Postgres, ColumnArity::List) => tpe.full_data_type.trim_start_matches('_'),
+        _ => tpe.full_data_type.as_str(),
+    };
+
+    // The other databases include the type arguments in the full data type.
+    let args: Vec<i64> = match family {
+        SqlFamily::Postgres => tpe
+            .character_maximum_length
+            .into_iter()
+            .chain(tpe.numeric_precision)
+            .chain(tpe.numeric_scale)
+            .chain(tpe.datetime_precision)
+            .collect(),
+        _ => Vec::new(),
+    };
+
+    match args.as_slice() {
+        [] => full_data_type.to_owned(),
+        args => {
+            let args: Vec<String> = args.iter().map(|arg| arg.to_string()).collect();
+
+            format!("{}({})", full_data_type, args.join(","))
+        }
+    }
+}
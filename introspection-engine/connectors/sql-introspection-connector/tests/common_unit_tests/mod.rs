@@ -34,10 +34,15 @@ fn a_data_model_can_be_generated_from_a_schema() {
                     .to_string(),
             ),
             is_embedded: false,
+            is_view: false,
+            view_definition: None,
+            schema: None,
+            check_constraints: vec![],
             is_generated: false,
             is_commented_out: true,
             indices: vec![],
             id_fields: vec![],
+            id_db_name: None,
             fields: col_types
                 .iter()
                 .map(|col_type| {
@@ -50,6 +55,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         ColumnTypeFamily::Enum(name) => (FieldType::Enum(name.clone()), false, None),
                         ColumnTypeFamily::Uuid => (FieldType::Base(ScalarType::String, None), false, None),
                         ColumnTypeFamily::Json => (FieldType::Base(ScalarType::Json, None), false, None),
+                        ColumnTypeFamily::Binary => (FieldType::Base(ScalarType::Bytes, None), false, None),
                         x => (
                             FieldType::Unsupported(x.to_string()),
                             true,
@@ -64,6 +70,7 @@ fn a_data_model_can_be_generated_from_a_schema() {
                         default_value: None,
                         is_unique: false,
                         is_id: false,
+                        constraint_db_name: None,
                         documentation,
                         is_generated: false,
                         is_updated_at: false,
@@ -73,11 +80,13 @@ fn a_data_model_can_be_generated_from_a_schema() {
                 .collect(),
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: col_types
                 .iter()
                 .map(|family| Column {
@@ -91,11 +100,14 @@ fn a_data_model_can_be_generated_from_a_schema() {
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
+                constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -110,6 +122,10 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            view_definition: None,
+            schema: None,
+            check_constraints: vec![],
             is_commented_out: false,
             fields: vec![
                 Field {
@@ -120,6 +136,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     default_value: None,
                     is_unique: false,
                     is_id: false,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -133,6 +150,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                     is_unique: false,
                     is_id: true,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -146,6 +164,7 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
                     default_value: None,
                     is_unique: false,
                     is_id: false,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -155,13 +174,16 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             is_generated: false,
             indices: vec![],
             id_fields: vec![],
+            id_db_name: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "optional".to_string(),
@@ -186,11 +208,14 @@ fn arity_is_preserved_when_generating_data_model_from_a_schema() {
             primary_key: Some(PrimaryKey {
                 columns: vec!["required".to_string()],
                 sequence: None,
+                constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -205,6 +230,10 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            view_definition: None,
+            schema: None,
+            check_constraints: vec![],
             is_commented_out: false,
             fields: vec![
                 Field {
@@ -215,6 +244,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     default_value: None,
                     is_unique: false,
                     is_id: false,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -228,6 +258,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     default_value: Some(dml::DefaultValue::Single(PrismaValue::Int(1))),
                     is_unique: false,
                     is_id: false,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -241,6 +272,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     default_value: Some(dml::DefaultValue::Single(PrismaValue::Boolean(true))),
                     is_unique: false,
                     is_id: false,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -254,6 +286,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     default_value: Some(dml::DefaultValue::Single(PrismaValue::Float(1.into()))),
                     is_unique: false,
                     is_id: false,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -267,6 +300,7 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                     default_value: Some(dml::DefaultValue::Single(PrismaValue::String("default".to_string()))),
                     is_unique: false,
                     is_id: false,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -278,15 +312,22 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                 name: Some("unique".into()),
                 fields: vec!["no_default".into(), "int_default".into()],
                 tpe: dml::IndexType::Unique,
+                db_name: None,
+                field_options: vec![],
+                algorithm: None,
+                predicate: None,
             }],
             id_fields: vec![],
+            id_db_name: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "no_default".to_string(),
@@ -323,12 +364,17 @@ fn defaults_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "unique".to_string(),
                 columns: vec!["no_default".into(), "int_default".into()],
                 tpe: IndexType::Unique,
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
             }],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -345,6 +391,10 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table1".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                view_definition: None,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
@@ -354,6 +404,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                     is_unique: false,
                     is_id: true,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -362,6 +413,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_db_name: None,
             },
             // Model with non-auto-incrementing primary key
             Model {
@@ -369,6 +421,10 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table2".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                view_definition: None,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
@@ -378,6 +434,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     default_value: None,
                     is_unique: false,
                     is_id: true,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -386,6 +443,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_db_name: None,
             },
             // Model with primary key seeded by sequence
             Model {
@@ -393,6 +451,10 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "Table3".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                view_definition: None,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
                 fields: vec![Field {
                     name: "primary".to_string(),
@@ -402,6 +464,7 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                     is_unique: false,
                     is_id: true,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -410,21 +473,28 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_db_name: None,
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![
             Table {
                 name: "Table1".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -435,17 +505,24 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 primary_key: Some(PrimaryKey {
                     columns: vec!["primary".to_string()],
                     sequence: None,
+                    constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
             Table {
                 name: "Table2".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -456,17 +533,24 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                 primary_key: Some(PrimaryKey {
                     columns: vec!["primary".to_string()],
                     sequence: None,
+                    constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
             Table {
                 name: "Table3".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "primary".to_string(),
                     tpe: ColumnType {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -479,15 +563,19 @@ fn primary_key_is_preserved_when_generating_data_model_from_a_schema() {
                     columns: vec!["primary".to_string()],
                     sequence: Some(Sequence {
                         name: "sequence".to_string(),
+                        schema: None,
                         initial_value: 1,
                         allocation_size: 1,
                     }),
+                    constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -502,6 +590,10 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             name: "Table1".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            view_definition: None,
+            schema: None,
+            check_constraints: vec![],
             is_commented_out: false,
             fields: vec![
                 Field {
@@ -512,6 +604,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     default_value: None,
                     is_unique: false,
                     is_id: false,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -525,6 +618,7 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                     default_value: None,
                     is_unique: true,
                     is_id: false,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -534,13 +628,16 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
             is_generated: false,
             indices: vec![],
             id_fields: vec![],
+            id_db_name: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "Table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "non_unique".to_string(),
@@ -559,12 +656,17 @@ fn uniqueness_is_preserved_when_generating_data_model_from_a_schema() {
                 name: "unique".to_string(),
                 columns: vec!["unique".to_string()],
                 tpe: IndexType::Unique,
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
             }],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -580,6 +682,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                view_definition: None,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
                 fields: vec![
                     Field {
@@ -590,6 +696,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                         is_unique: false,
                         is_id: true,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -603,6 +710,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         default_value: None,
                         is_unique: false,
                         is_id: false,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -616,12 +724,14 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             to_fields: vec![],
                             name: "CityToUser".to_string(),
                             on_delete: OnDeleteStrategy::None,
+                            fk_db_name: None,
                         }),
                         arity: FieldArity::List,
                         database_name: None,
                         default_value: None,
                         is_unique: false,
                         is_id: false,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -631,12 +741,17 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_db_name: None,
             },
             Model {
                 database_name: None,
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                view_definition: None,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
                 fields: vec![
                     Field {
@@ -647,6 +762,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                         is_unique: false,
                         is_id: true,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -660,6 +776,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         default_value: None,
                         is_unique: false,
                         is_id: false,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -673,6 +790,7 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                         default_value: None,
                         is_unique: false,
                         is_id: false,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -687,11 +805,13 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             fields: vec!["city_id".to_string(), "city_name".to_string()],
                             to_fields: vec!["id".to_string(), "name".to_string()],
                             on_delete: OnDeleteStrategy::None,
+                            fk_db_name: None,
                         }),
                         database_name: None,
                         default_value: None,
                         is_unique: false,
                         is_id: false,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -701,15 +821,18 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_db_name: None,
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![
             Table {
                 name: "City".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -717,6 +840,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -730,6 +857,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "text".to_string(),
                             full_data_type: "text".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Required,
@@ -742,11 +873,14 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 primary_key: Some(PrimaryKey {
                     columns: vec!["id".to_string()],
                     sequence: None,
+                    constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
             Table {
                 name: "User".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -754,6 +888,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -767,6 +905,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -780,6 +922,10 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                             data_type: "text".to_string(),
                             full_data_type: "text".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Required,
@@ -792,19 +938,23 @@ fn compound_foreign_keys_are_preserved_when_generating_data_model_from_a_schema(
                 primary_key: Some(PrimaryKey {
                     columns: vec!["id".to_string()],
                     sequence: None,
+                    constraint_name: None,
                 }),
                 foreign_keys: vec![ForeignKey {
                     // what does this mean? the from columns are not targeting a specific to column?
                     constraint_name: None,
                     columns: vec!["city-id".to_string(), "city-name".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string(), "name".to_string()],
                 }],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -819,6 +969,10 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
             name: "User".to_string(),
             documentation: None,
             is_embedded: false,
+            is_view: false,
+            view_definition: None,
+            schema: None,
+            check_constraints: vec![],
             is_commented_out: false,
             fields: vec![
                 Field {
@@ -829,6 +983,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                     is_unique: false,
                     is_id: true,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -842,6 +997,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     default_value: None,
                     is_unique: false,
                     is_id: false,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -855,6 +1011,7 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                     default_value: None,
                     is_unique: false,
                     is_id: false,
+                    constraint_db_name: None,
                     documentation: None,
                     is_generated: false,
                     is_updated_at: false,
@@ -866,15 +1023,22 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 name: Some("name_last_name_unique".to_string()),
                 fields: vec!["name".to_string(), "lastname".to_string()],
                 tpe: datamodel::dml::IndexType::Unique,
+                db_name: None,
+                field_options: vec![],
+                algorithm: None,
+                predicate: None,
             }],
             id_fields: vec![],
+            id_db_name: None,
         }],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "id".to_string(),
@@ -882,6 +1046,10 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -895,6 +1063,10 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                         data_type: "text".to_string(),
                         full_data_type: "text".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::String,
                         arity: ColumnArity::Required,
@@ -908,6 +1080,10 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                         data_type: "text".to_string(),
                         full_data_type: "text".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::String,
                         arity: ColumnArity::Required,
@@ -920,15 +1096,21 @@ fn multi_field_uniques_are_preserved_when_generating_data_model_from_a_schema()
                 name: "name_last_name_unique".to_string(),
                 columns: vec!["name".to_string(), "lastname".to_string()],
                 tpe: IndexType::Unique,
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
             }],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
                 sequence: None,
+                constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -944,6 +1126,10 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 name: "City".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                view_definition: None,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
                 fields: vec![
                     Field {
@@ -954,6 +1140,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                         is_unique: false,
                         is_id: true,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -967,6 +1154,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         default_value: None,
                         is_unique: false,
                         is_id: false,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -981,11 +1169,13 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             to_fields: vec![],
                             name: "CityToUser".to_string(),
                             on_delete: OnDeleteStrategy::None,
+                            fk_db_name: None,
                         }),
                         database_name: None,
                         default_value: None,
                         is_unique: false,
                         is_id: false,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -995,12 +1185,17 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_db_name: None,
             },
             Model {
                 database_name: None,
                 name: "User".to_string(),
                 documentation: None,
                 is_embedded: false,
+                is_view: false,
+                view_definition: None,
+                schema: None,
+                check_constraints: vec![],
                 is_commented_out: false,
                 fields: vec![
                     Field {
@@ -1011,6 +1206,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         default_value: Some(DMLDefault::Expression(ValueGenerator::new_autoincrement())),
                         is_unique: false,
                         is_id: true,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -1024,6 +1220,7 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                         default_value: None,
                         is_unique: false,
                         is_id: false,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -1038,11 +1235,13 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             fields: vec!["city_id".to_string()],
                             to_fields: vec!["id".to_string()],
                             on_delete: OnDeleteStrategy::None,
+                            fk_db_name: None,
                         }),
                         database_name: None,
                         default_value: None,
                         is_unique: false,
                         is_id: false,
+                        constraint_db_name: None,
                         documentation: None,
                         is_generated: false,
                         is_updated_at: false,
@@ -1052,15 +1251,18 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 is_generated: false,
                 indices: vec![],
                 id_fields: vec![],
+                id_db_name: None,
             },
         ],
         enums: vec![],
+        composite_types: vec![],
     };
 
     let schema = SqlSchema {
         tables: vec![
             Table {
                 name: "City".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -1068,6 +1270,10 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -1081,6 +1287,10 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "text".to_string(),
                             full_data_type: "text".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Required,
@@ -1093,11 +1303,14 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 primary_key: Some(PrimaryKey {
                     columns: vec!["id".to_string()],
                     sequence: None,
+                    constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
             Table {
                 name: "User".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "id".to_string(),
@@ -1105,6 +1318,10 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -1118,6 +1335,10 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -1130,18 +1351,22 @@ fn foreign_keys_are_preserved_when_generating_data_model_from_a_schema() {
                 primary_key: Some(PrimaryKey {
                     columns: vec!["id".to_string()],
                     sequence: None,
+                    constraint_name: None,
                 }),
                 foreign_keys: vec![ForeignKey {
                     constraint_name: None,
                     columns: vec!["city_id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                     referenced_columns: vec!["id".to_string()],
                 }],
+                check_constraints: vec![],
             },
         ],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
@@ -1171,6 +1396,7 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
                 },
             ],
         }],
+        composite_types: vec![],
     };
 
     let enum_values = vec!["a".to_string(), "b".to_string()];
@@ -1178,9 +1404,95 @@ fn enums_are_preserved_when_generating_data_model_from_a_schema() {
         tables: vec![],
         enums: vec![Enum {
             name: "Enum".to_string(),
+            schema: None,
             values: enum_values,
         }],
         sequences: vec![],
+        views: vec![],
+    };
+    let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
+
+    assert_eq!(introspection_result.datamodel, ref_data_model);
+}
+
+#[test]
+fn views_are_preserved_when_generating_data_model_from_a_schema() {
+    let ref_data_model = Datamodel {
+        models: vec![Model {
+            database_name: None,
+            name: "Report".to_string(),
+            // Views have no unique criteria, they stay commented out until one is added.
+            documentation: Some(
+                "The underlying view does not contain a unique identifier and can therefore currently not be handled."
+                    .to_string(),
+            ),
+            is_embedded: false,
+            is_view: true,
+            view_definition: None,
+            schema: None,
+            check_constraints: vec![],
+            is_generated: false,
+            is_commented_out: true,
+            indices: vec![],
+            id_fields: vec![],
+            id_db_name: None,
+            fields: vec![
+                Field {
+                    name: "id".to_string(),
+                    arity: FieldArity::Required,
+                    field_type: FieldType::Base(ScalarType::Int, None),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    is_id: false,
+                    constraint_db_name: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                    is_commented_out: false,
+                },
+                Field {
+                    name: "total".to_string(),
+                    arity: FieldArity::Optional,
+                    field_type: FieldType::Base(ScalarType::Float, None),
+                    database_name: None,
+                    default_value: None,
+                    is_unique: false,
+                    is_id: false,
+                    constraint_db_name: None,
+                    documentation: None,
+                    is_generated: false,
+                    is_updated_at: false,
+                    is_commented_out: false,
+                },
+            ],
+        }],
+        enums: vec![],
+        composite_types: vec![],
+    };
+
+    let schema = SqlSchema {
+        tables: vec![],
+        enums: vec![],
+        sequences: vec![],
+        views: vec![View {
+            name: "Report".to_string(),
+            definition: Some("SELECT id, total FROM orders".to_string()),
+            columns: vec![
+                Column {
+                    name: "id".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                    default: None,
+                    auto_increment: false,
+                },
+                Column {
+                    name: "total".to_string(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Nullable),
+                    default: None,
+                    auto_increment: false,
+                },
+            ],
+        }],
     };
     let introspection_result = calculate_datamodel(&schema, &SqlFamily::Postgres).expect("calculate data model");
 
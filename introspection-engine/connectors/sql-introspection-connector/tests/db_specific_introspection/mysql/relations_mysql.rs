@@ -83,7 +83,7 @@ async fn introspecting_two_one_to_one_relations_between_the_same_models_should_w
         model User {
             id                      Int   @default(autoincrement()) @id
             post_id                 Int   @unique
-            Post_PostToUser_post_id Post  @relation("PostToUser_post_id", fields: [post_id], references: [id])
+            Post_PostToUser_post_id Post  @relation("PostToUser_post_id", fields: [post_id], references: [id], map: "post_fk")
             Post_Post_user_idToUser Post? @relation("Post_user_idToUser")
         }
         "#;
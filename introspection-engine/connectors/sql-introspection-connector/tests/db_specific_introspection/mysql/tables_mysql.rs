@@ -64,6 +64,34 @@ async fn introspecting_a_table_with_json_type_must_work(api: &TestApi) {
     custom_assert(&result, dm);
 }
 
+#[test_each_connector(tags("mysql"))]
+async fn introspecting_a_table_with_bigint_and_decimal_columns_must_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute_with_schema(
+            |migration| {
+                migration.create_table("Account", |t| {
+                    t.add_column("id", types::primary());
+                    t.inject_custom("views bigint Not Null Default 9007199254740993");
+                    t.inject_custom("balance decimal(10, 2) Not Null Default 12.50");
+                    t.inject_custom("rating Decimal(65,30) Not Null");
+                });
+            },
+            api.db_name(),
+        )
+        .await;
+    let dm = r#"
+            model Account {
+                balance Decimal @default(12.50)
+                id      Int     @id @default(autoincrement())
+                rating  Float
+                views   BigInt  @default(9007199254740993)
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
+
 #[test_each_connector(tags("mysql"))]
 async fn introspecting_a_table_with_compound_primary_keys_must_work(api: &TestApi) {
     let barrel = api.barrel();
@@ -109,7 +137,7 @@ async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
 
     let dm = r#"
             model Blog {
-                authorId String @unique
+                authorId String @unique(map: "test")
                 id      Int @id @default(autoincrement())
             }
         "#;
@@ -750,3 +750,39 @@ async fn introspecting_a_relation_based_on_an_unsupported_type_should_drop_it(ap
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, "model Post {\n  id                  Int      @default(autoincrement()) @id\n  // This type is currently not supported.\n  // user_network_mac macaddr?\n}\n\nmodel User {\n  id             Int     @default(autoincrement()) @id\n  // This type is currently not supported.\n  // network_mac macaddr @unique\n}");
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_custom_constraint_names_should_work(api: &TestApi) {
+    let barrel = api.barrel();
+    barrel
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::integer());
+                t.add_column("email", types::text());
+                t.inject_custom("CONSTRAINT pk_users PRIMARY KEY (\"id\")");
+                t.inject_custom("CONSTRAINT uq_users_email UNIQUE (\"email\")");
+            });
+            migration.create_table("Post", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("author_id", types::integer());
+                t.inject_custom("CONSTRAINT fk_posts_author FOREIGN KEY (\"author_id\") REFERENCES \"User\"(\"id\")");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            model Post {
+                id        Int  @id @default(autoincrement())
+                author_id Int
+                User      User @relation(fields: [author_id], references: [id], map: "fk_posts_author")
+            }
+
+            model User {
+                id    Int    @id(map: "pk_users")
+                email String @unique(map: "uq_users_email")
+                Post  Post[]
+            }
+        "#;
+    let result = dbg!(api.introspect().await);
+    custom_assert(&result, dm);
+}
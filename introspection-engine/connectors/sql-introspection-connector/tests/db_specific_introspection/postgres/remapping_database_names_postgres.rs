@@ -124,7 +124,7 @@ async fn remapping_models_in_relations_should_work(api: &TestApi) {
     let dm = r#"
             model Post {
                 id              Int             @default(autoincrement()) @id
-                user_id         Int             @unique
+                user_id         Int             @unique(map: "post_user_unique")
                 User_with_Space User_with_Space @relation(fields: [user_id], references: [id])
             }
             
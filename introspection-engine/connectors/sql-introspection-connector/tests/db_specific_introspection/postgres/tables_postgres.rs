@@ -123,7 +123,7 @@ async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
 
     let dm = r#"
             model Blog {
-                authorId String @unique
+                authorId String @unique(map: "test")
                 id      Int @id @default(autoincrement())
             }
         "#;
@@ -366,16 +366,16 @@ async fn introspecting_default_values_should_work(api: &TestApi) {
             model Test {
                 boolean_boolean     Boolean?        @default(false)
                 id                  Int         @id @default(autoincrement())
-                numeric_decimal     Float?          @default(1234.1234)
+                numeric_decimal     Decimal?        @default(1234.1234)
                 numeric_float4      Float?          @default(123.1234)
                 numeric_float8      Float?          @default(123.1234)
                 numeric_int2        Int?            @default(2)
                 numeric_int4        Int?            @default(4)
-                numeric_int8        Int?            @default(8)
+                numeric_int8        BigInt?         @default(8)
                 string_char         String?         @default("abcdefgh")
                 string_text         String?         @default("abcdefgh")
                 string_varchar      String?         @default("abcd")
-                time_date           DateTime?       @default(dbgenerated())
+                time_date           DateTime?       @default(dbgenerated("CURRENT_DATE"))
                 time_time           DateTime?       @default(now())
                 time_timestamp      DateTime?       @default(now())
                 time_timestamptz    DateTime?       @default(now())
@@ -427,11 +427,11 @@ async fn introspecting_a_default_value_as_dbgenerated_should_work(api: &TestApi)
                 enum_static             color?      @default(black)
                 float_static            Float?      @default(1.43)
                 id                      Int         @default(autoincrement()) @id
-                int_function            Int?        @default(dbgenerated())
-                int_sequence            Int?        @default(dbgenerated())
+                int_function            Int?        @default(dbgenerated("date_part('year'::text, '2001-02-16 20:38:40'::timestamp without time zone)"))
+                int_sequence            Int?        @default(dbgenerated("nextval('test_seq'::regclass)"))
                 int_serial              Int        @default(autoincrement())
                 int_static              Int?        @default(2)
-                string_function         String?     @default(dbgenerated())
+                string_function         String?     @default(dbgenerated("('Concatenated'::text || '\n'::text)"))
                 string_static_char      String?     @default("test")
                 string_static_text      String?     @default("test")
                 string_static_text_null String?
@@ -587,3 +587,44 @@ async fn introspecting_a_table_with_only_an_unsupported_id_type_should_comment_i
     let result = dbg!(api.introspect().await);
     assert_eq!(&result, "// The underlying table does not contain a unique identifier and can therefore currently not be handled.\n// model Test {\n  // dummy       Int\n  // This type is currently not supported.\n  // network_mac macaddr @id\n// }");
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn introspecting_native_types_must_work(api: &TestApi) {
+    let barrel = api.barrel();
+    let _setup_schema = barrel
+        .execute(|migration| {
+            migration.create_table("Blog", |t| {
+                t.add_column("id", types::primary());
+                t.inject_custom("small  smallint Not Null");
+                t.inject_custom("title  varchar(120) Not Null");
+                t.inject_custom("code   char(3)");
+                t.inject_custom("body   text Not Null");
+                t.inject_custom("price  numeric(10, 2) Not Null");
+                t.inject_custom("amount numeric Not Null");
+                t.inject_custom("posted timestamp(3) Not Null");
+                t.inject_custom("opens  timetz Not Null");
+            });
+        })
+        .await;
+
+    let dm = r#"
+            datasource pg {
+                provider = "postgres"
+                url = "postgresql://localhost:5432"
+            }
+
+            model Blog {
+                amount  Decimal  @db.Decimal
+                body    String   @db.Text
+                code    String?  @db.Char(3)
+                id      Int      @id @default(autoincrement()) @db.Integer
+                opens   DateTime @db.Timetz(6)
+                posted  DateTime @db.Timestamp(3)
+                price   Decimal  @db.Decimal(10, 2)
+                small   Int      @db.SmallInt
+                title   String   @db.VarChar(120)
+            }
+        "#;
+    let result = dbg!(api.introspect_with_native_types().await);
+    custom_assert(&result, dm);
+}
@@ -74,7 +74,7 @@ async fn introspecting_a_table_with_unique_index_must_work(api: &TestApi) {
 
     let dm = r#"
             model Blog {
-                authorId String @unique
+                authorId String @unique(map: "test")
                 id Int @id @default(autoincrement())
             }
         "#;
@@ -1,5 +1,5 @@
 use super::misc_helpers::*;
-use introspection_connector::{DatabaseMetadata, IntrospectionConnector, Version};
+use introspection_connector::{DatabaseMetadata, IntrospectionConnector, IntrospectionOptions, Version};
 use quaint::{
     prelude::{Queryable, SqlFamily},
     single::Quaint,
@@ -26,17 +26,35 @@ impl TestApi {
     }
 
     pub async fn introspect(&self) -> String {
-        let introspection_result = self.introspection_connector.introspect().await.unwrap();
+        let introspection_result = self
+            .introspection_connector
+            .introspect(IntrospectionOptions::default())
+            .await
+            .unwrap();
+        datamodel::render_datamodel_to_string(&introspection_result.datamodel).expect("Datamodel rendering failed")
+    }
+
+    pub async fn introspect_with_native_types(&self) -> String {
+        let options = IntrospectionOptions { native_types: true };
+        let introspection_result = self.introspection_connector.introspect(options).await.unwrap();
         datamodel::render_datamodel_to_string(&introspection_result.datamodel).expect("Datamodel rendering failed")
     }
 
     pub async fn introspect_version(&self) -> Version {
-        let introspection_result = self.introspection_connector.introspect().await.unwrap();
+        let introspection_result = self
+            .introspection_connector
+            .introspect(IntrospectionOptions::default())
+            .await
+            .unwrap();
         introspection_result.version
     }
 
     pub async fn introspection_warnings(&self) -> String {
-        let introspection_result = self.introspection_connector.introspect().await.unwrap();
+        let introspection_result = self
+            .introspection_connector
+            .introspect(IntrospectionOptions::default())
+            .await
+            .unwrap();
         serde_json::to_string(&introspection_result.warnings).unwrap()
     }
 
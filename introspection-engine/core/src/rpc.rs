@@ -2,7 +2,9 @@ use crate::command_error::CommandError;
 use crate::error::Error;
 use crate::error_rendering::render_jsonrpc_error;
 use futures::{FutureExt, TryFutureExt};
-use introspection_connector::{DatabaseMetadata, IntrospectionConnector, IntrospectionResultOutput};
+use introspection_connector::{
+    DatabaseMetadata, IntrospectionConnector, IntrospectionOptions, IntrospectionResultOutput,
+};
 use jsonrpc_derive::rpc;
 use serde_derive::*;
 use sql_introspection_connector::SqlIntrospectionConnector;
@@ -42,7 +44,10 @@ impl Rpc for RpcImpl {
     }
 
     fn introspect(&self, input: IntrospectionInput) -> RpcFutureResult<IntrospectionResultOutput> {
-        Box::new(Self::introspect_internal(input.schema).boxed().compat())
+        let options = IntrospectionOptions {
+            native_types: input.native_types,
+        };
+        Box::new(Self::introspect_internal(input.schema, options).boxed().compat())
     }
 }
 
@@ -53,17 +58,22 @@ impl RpcImpl {
 
     async fn load_connector(schema: &String) -> Result<Box<dyn IntrospectionConnector>, Error> {
         let config = datamodel::parse_configuration(&schema)?;
-        let url = config
+        let source = config
             .datasources
             .first()
-            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?
-            .url()
-            .to_owned()
-            .value;
-        Ok(Box::new(SqlIntrospectionConnector::new(&url).await?))
+            .ok_or_else(|| CommandError::Generic(anyhow::anyhow!("There is no datasource in the schema.")))?;
+        let url = source.url().to_owned().value;
+        let connector = SqlIntrospectionConnector::new(&url)
+            .await?
+            .with_additional_schemas(source.schemas());
+
+        Ok(Box::new(connector))
     }
 
-    pub async fn introspect_internal(schema: String) -> RpcResult<IntrospectionResultOutput> {
+    pub async fn introspect_internal(
+        schema: String,
+        options: IntrospectionOptions,
+    ) -> RpcResult<IntrospectionResultOutput> {
         let config = datamodel::parse_configuration(&schema).map_err(Error::from)?;
         let url = config
             .datasources
@@ -74,7 +84,7 @@ impl RpcImpl {
             .to_owned()
             .value;
         let connector = RpcImpl::load_connector(&schema).await?;
-        let data_model = connector.introspect().await;
+        let data_model = connector.introspect(options).await;
 
         match data_model {
             Ok(introspection_result)
@@ -119,6 +129,10 @@ impl RpcImpl {
 }
 
 #[derive(Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct IntrospectionInput {
     pub(crate) schema: String,
+    /// Emit native type attributes, e.g. `@db.VarChar(255)`.
+    #[serde(default)]
+    pub(crate) native_types: bool,
 }
@@ -1,4 +1,4 @@
-use super::{Connector, ScalarFieldType, ScalarType};
+use super::{Connector, ConnectorError, ScalarFieldType, ScalarType};
 
 #[derive(Debug)]
 pub struct DeclarativeConnector {
@@ -8,22 +8,67 @@ pub struct DeclarativeConnector {
     pub supports_relations_over_non_unique_criteria: bool,
     pub supports_enums: bool,
     pub supports_json: bool,
+    pub supports_decimal: bool,
+    pub supports_index_column_length: bool,
+    pub supports_index_algorithms: bool,
+    pub supports_partial_indexes: bool,
+    pub supports_named_foreign_keys: bool,
 }
 
 impl Connector for DeclarativeConnector {
-    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Option<ScalarFieldType> {
-        match self.get_type_alias(name) {
-            Some(alias) => self.calculate_type(&alias.aliased_to, args),
-            None => self.get_field_type_constructor(&name).map(|constructor| {
-                let datasource_type = constructor.datasource_type(&args);
-
-                ScalarFieldType {
-                    name: name.to_string(),
-                    prisma_type: constructor.prisma_type,
-                    datasource_type,
-                }
-            }),
+    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Result<ScalarFieldType, ConnectorError> {
+        if let Some(alias) = self.get_type_alias(name) {
+            return self.calculate_type(&alias.aliased_to, args);
+        }
+
+        let constructor = self
+            .get_field_type_constructor(&name)
+            .ok_or_else(|| ConnectorError::UnknownNativeType {
+                native_type: name.to_string(),
+            })?;
+
+        if !constructor.accepts_number_of_args(args.len()) {
+            return Err(ConnectorError::InvalidNumberOfArguments {
+                native_type: name.to_string(),
+                expected: constructor.describe_number_of_args(),
+                given: args.len(),
+            });
         }
+
+        let datasource_type = constructor.datasource_type(&args);
+
+        Ok(ScalarFieldType::new(name, constructor.prisma_type, &datasource_type).with_args(args))
+    }
+
+    fn parse_datasource_type(&self, datasource_type: &str) -> Option<ScalarFieldType> {
+        let datasource_type = datasource_type.trim();
+
+        let (base, args) = match datasource_type.find('(') {
+            Some(start) => {
+                // Anything after the arguments, e.g. `unsigned`, has no native type equivalent.
+                if !datasource_type.ends_with(')') {
+                    return None;
+                }
+
+                let args: Result<Vec<i32>, _> = datasource_type[start + 1..datasource_type.len() - 1]
+                    .split(',')
+                    .map(|arg| arg.trim().parse::<i32>())
+                    .collect();
+
+                (&datasource_type[..start], args.ok()?)
+            }
+            None => (datasource_type, vec![]),
+        };
+
+        let constructor = self
+            .field_type_constructors
+            .iter()
+            .find(|constructor| constructor.datasource_type.eq_ignore_ascii_case(base.trim()))?;
+
+        // Display widths like `int(11)` do not change the type.
+        let args = if constructor.number_of_args == 0 { vec![] } else { args };
+
+        self.calculate_type(&constructor.name, args).ok()
     }
 
     fn supports_scalar_lists(&self) -> bool {
@@ -41,6 +86,26 @@ impl Connector for DeclarativeConnector {
     fn supports_json(&self) -> bool {
         self.supports_json
     }
+
+    fn supports_decimal(&self) -> bool {
+        self.supports_decimal
+    }
+
+    fn supports_index_column_length(&self) -> bool {
+        self.supports_index_column_length
+    }
+
+    fn supports_index_algorithms(&self) -> bool {
+        self.supports_index_algorithms
+    }
+
+    fn supports_partial_indexes(&self) -> bool {
+        self.supports_partial_indexes
+    }
+
+    fn supports_named_foreign_keys(&self) -> bool {
+        self.supports_named_foreign_keys
+    }
 }
 
 impl DeclarativeConnector {
@@ -72,6 +137,7 @@ pub struct FieldTypeConstructor {
     name: String,
     datasource_type: String,
     number_of_args: usize,
+    args_are_optional: bool,
     prisma_type: ScalarType,
 }
 
@@ -82,6 +148,7 @@ impl FieldTypeConstructor {
             datasource_type: datasource_type.to_string(),
             prisma_type,
             number_of_args: 0,
+            args_are_optional: false,
         }
     }
 
@@ -96,6 +163,23 @@ impl FieldTypeConstructor {
             datasource_type: datasource_type.to_string(),
             prisma_type,
             number_of_args,
+            args_are_optional: false,
+        }
+    }
+
+    /// A constructor taking up to `number_of_args` arguments, e.g. `numeric`, `numeric(10)` and `numeric(10,2)`.
+    pub fn with_optional_args(
+        name: &str,
+        datasource_type: &str,
+        prisma_type: ScalarType,
+        number_of_args: usize,
+    ) -> FieldTypeConstructor {
+        FieldTypeConstructor {
+            name: name.to_string(),
+            datasource_type: datasource_type.to_string(),
+            prisma_type,
+            number_of_args,
+            args_are_optional: true,
         }
     }
 
@@ -107,14 +191,23 @@ impl FieldTypeConstructor {
         self.number_of_args
     }
 
-    pub fn datasource_type(&self, args: &Vec<i32>) -> String {
-        if self.number_of_args != args.len() {
-            panic!(
-                "Did not provide the required number of arguments. {} were required, but were {} provided.",
-                self.number_of_args,
-                args.len()
-            )
+    pub fn accepts_number_of_args(&self, number_of_args: usize) -> bool {
+        if self.args_are_optional {
+            number_of_args <= self.number_of_args
+        } else {
+            number_of_args == self.number_of_args
         }
+    }
+
+    fn describe_number_of_args(&self) -> String {
+        if self.args_are_optional {
+            format!("up to {}", self.number_of_args)
+        } else {
+            self.number_of_args.to_string()
+        }
+    }
+
+    pub fn datasource_type(&self, args: &[i32]) -> String {
         if args.is_empty() {
             self.datasource_type.to_string()
         } else {
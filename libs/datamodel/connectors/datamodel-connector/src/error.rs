@@ -1,2 +1,39 @@
-#[allow(unused)]
-enum ConnectorError {}
+use std::fmt;
+
+/// Errors raised by a connector while resolving native types.
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConnectorError {
+    /// The connector does not know a native type of the given name.
+    UnknownNativeType { native_type: String },
+    /// The native type was given a number of arguments it does not accept.
+    InvalidNumberOfArguments {
+        native_type: String,
+        expected: String,
+        given: usize,
+    },
+}
+
+impl fmt::Display for ConnectorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConnectorError::UnknownNativeType { native_type } => {
+                write!(
+                    f,
+                    "Native type {} is not supported by the current connector.",
+                    native_type
+                )
+            }
+            ConnectorError::InvalidNumberOfArguments {
+                native_type,
+                expected,
+                given,
+            } => write!(
+                f,
+                "Native type {} takes {} arguments, but received {}.",
+                native_type, expected, given
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConnectorError {}
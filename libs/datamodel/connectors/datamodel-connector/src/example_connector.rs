@@ -4,80 +4,121 @@ pub struct ExampleConnector {}
 
 impl ExampleConnector {
     pub fn sqlite() -> DeclarativeConnector {
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("Integer", "INTEGER", ScalarType::Int),
+            FieldTypeConstructor::without_args("Real", "REAL", ScalarType::Float),
+            FieldTypeConstructor::without_args("Numeric", "NUMERIC", ScalarType::Float),
+            FieldTypeConstructor::without_args("Text", "TEXT", ScalarType::String),
+            FieldTypeConstructor::without_args("Boolean", "BOOLEAN", ScalarType::Boolean),
+            FieldTypeConstructor::without_args("DateTime", "DATETIME", ScalarType::DateTime),
+        ];
         DeclarativeConnector {
             type_aliases: vec![],
-            field_type_constructors: vec![],
+            field_type_constructors,
             supports_scalar_lists: false,
             supports_relations_over_non_unique_criteria: false,
             supports_enums: false,
             supports_json: false,
+            // SQLite stores decimals as floating point numbers.
+            supports_decimal: false,
+            supports_index_column_length: false,
+            supports_index_algorithms: false,
+            supports_partial_indexes: true,
+            // SQLite does not report foreign key names, so they could never be migrated.
+            supports_named_foreign_keys: false,
         }
     }
 
     pub fn mysql() -> DeclarativeConnector {
+        let type_aliases = vec![TypeAlias::new("Integer", "Int"), TypeAlias::new("Numeric", "Decimal")];
+        let field_type_constructors = vec![
+            FieldTypeConstructor::without_args("TinyInt", "tinyint", ScalarType::Int),
+            FieldTypeConstructor::without_args("SmallInt", "smallint", ScalarType::Int),
+            FieldTypeConstructor::without_args("MediumInt", "mediumint", ScalarType::Int),
+            FieldTypeConstructor::without_args("Int", "int", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::BigInt),
+            FieldTypeConstructor::with_optional_args("Decimal", "decimal", ScalarType::Decimal, 2),
+            FieldTypeConstructor::without_args("Float", "float", ScalarType::Float),
+            FieldTypeConstructor::without_args("Double", "double", ScalarType::Float),
+            FieldTypeConstructor::with_optional_args("Char", "char", ScalarType::String, 1),
+            FieldTypeConstructor::with_args("VarChar", "varchar", ScalarType::String, 1),
+            FieldTypeConstructor::without_args("TinyText", "tinytext", ScalarType::String),
+            FieldTypeConstructor::without_args("Text", "text", ScalarType::String),
+            FieldTypeConstructor::without_args("MediumText", "mediumtext", ScalarType::String),
+            FieldTypeConstructor::without_args("LongText", "longtext", ScalarType::String),
+            FieldTypeConstructor::without_args("Date", "date", ScalarType::DateTime),
+            FieldTypeConstructor::with_optional_args("DateTime", "datetime", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_optional_args("Timestamp", "timestamp", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_optional_args("Time", "time", ScalarType::DateTime, 1),
+            FieldTypeConstructor::without_args("Year", "year", ScalarType::Int),
+            FieldTypeConstructor::without_args("Json", "json", ScalarType::Json),
+        ];
         DeclarativeConnector {
-            type_aliases: vec![],
-            field_type_constructors: vec![],
+            type_aliases,
+            field_type_constructors,
             supports_scalar_lists: false,
             supports_relations_over_non_unique_criteria: true,
             supports_enums: true,
             supports_json: true,
+            supports_decimal: true,
+            supports_index_column_length: true,
+            supports_index_algorithms: false,
+            supports_partial_indexes: false,
+            supports_named_foreign_keys: true,
         }
     }
 
     pub fn postgres() -> DeclarativeConnector {
         let type_aliases = vec![
-            // standard types
-            TypeAlias::new("String", "Text"),
-            //            TypeAlias::new("Boolean", "Boolean"),
-            TypeAlias::new("Int", "Integer"),
-            TypeAlias::new("Float", "Real"),
-            TypeAlias::new("DateTime", "Timestamp"),
-            // custom types
             TypeAlias::new("Int8", "BigInt"),
             TypeAlias::new("Serial8", "BigSerial"),
             TypeAlias::new("Float8", "DoublePrecision"),
-            TypeAlias::new("Int", "Integer"),
             TypeAlias::new("Int4", "Integer"),
-            TypeAlias::new("Decimal", "Numeric"),
+            TypeAlias::new("Numeric", "Decimal"),
             TypeAlias::new("Float4", "Real"),
             TypeAlias::new("Int2", "SmallInt"),
             TypeAlias::new("Serial2", "SmallSerial"),
             TypeAlias::new("Serial4", "Serial"),
-            TypeAlias::new("Char", "Character"),
-            TypeAlias::new("VarChar", "CharacterVarying"),
-            TypeAlias::new("TimestampTZ", "TimestampWithTimeZone"),
+            TypeAlias::new("Character", "Char"),
+            TypeAlias::new("CharacterVarying", "VarChar"),
+            TypeAlias::new("TimestampWithTimeZone", "Timestamptz"),
             TypeAlias::new("Bool", "Boolean"),
-            TypeAlias::new("VarBit", "BitVarying"),
         ];
-        // missing because of interpolation:
-        // Numeric, Character, CharacterVarying, Timestamp, TimestampWithTimeZone, Time
-        // Bit, BitVarying
+        // The datasource types are the `udt_name`s postgres reports for a column, so that
+        // introspected columns can be mapped back onto a constructor.
         //
         // types for which photon types are unclear:
-        // ByteA, Date, TimeTZ
+        // Bit, BitVarying, ByteA
         // Point, Line, LSeg, Box, Path, Polygon, Circle
         // CIDR, INet, Macaddr
         // TSVector, TSQuery
-        // UUID
-        // XML, JSON, JSONB
         // Int4Range, Int8Range, NumRange, TSRange, TSTZRange, DateRange
         // TXIDSnapshot
         let field_type_constructors = vec![
-            FieldTypeConstructor::without_args("BigInt", "bigint", ScalarType::Int),
-            FieldTypeConstructor::without_args("BigSerial", "bigserial", ScalarType::Int),
-            FieldTypeConstructor::without_args("DoublePrecision", "double precision", ScalarType::Float),
-            FieldTypeConstructor::without_args("Integer", "integer", ScalarType::Int),
-            FieldTypeConstructor::without_args("Real", "real", ScalarType::Float),
-            FieldTypeConstructor::without_args("SmallInt", "smallint", ScalarType::Int),
+            FieldTypeConstructor::without_args("SmallInt", "int2", ScalarType::Int),
+            FieldTypeConstructor::without_args("Integer", "int4", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigInt", "int8", ScalarType::BigInt),
             FieldTypeConstructor::without_args("SmallSerial", "smallserial", ScalarType::Int),
             FieldTypeConstructor::without_args("Serial", "serial", ScalarType::Int),
+            FieldTypeConstructor::without_args("BigSerial", "bigserial", ScalarType::BigInt),
+            FieldTypeConstructor::with_optional_args("Decimal", "numeric", ScalarType::Decimal, 2),
+            FieldTypeConstructor::without_args("Real", "float4", ScalarType::Float),
+            FieldTypeConstructor::without_args("DoublePrecision", "float8", ScalarType::Float),
             FieldTypeConstructor::without_args("Money", "money", ScalarType::Float),
             FieldTypeConstructor::without_args("Text", "text", ScalarType::String),
-            FieldTypeConstructor::without_args("Char", "char", ScalarType::String),
+            FieldTypeConstructor::with_optional_args("Char", "bpchar", ScalarType::String, 1),
+            FieldTypeConstructor::with_optional_args("VarChar", "varchar", ScalarType::String, 1),
             FieldTypeConstructor::without_args("Name", "name", ScalarType::String),
-            FieldTypeConstructor::without_args("Boolean", "boolean", ScalarType::Boolean),
-            FieldTypeConstructor::without_args("Boolean", "boolean", ScalarType::Boolean),
+            FieldTypeConstructor::without_args("Uuid", "uuid", ScalarType::String),
+            FieldTypeConstructor::without_args("Xml", "xml", ScalarType::String),
+            FieldTypeConstructor::without_args("Boolean", "bool", ScalarType::Boolean),
+            FieldTypeConstructor::with_optional_args("Timestamp", "timestamp", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_optional_args("Timestamptz", "timestamptz", ScalarType::DateTime, 1),
+            FieldTypeConstructor::without_args("Date", "date", ScalarType::DateTime),
+            FieldTypeConstructor::with_optional_args("Time", "time", ScalarType::DateTime, 1),
+            FieldTypeConstructor::with_optional_args("Timetz", "timetz", ScalarType::DateTime, 1),
+            FieldTypeConstructor::without_args("Json", "json", ScalarType::Json),
+            FieldTypeConstructor::without_args("JsonB", "jsonb", ScalarType::Json),
             FieldTypeConstructor::without_args("PGLSN", "pg_lsn", ScalarType::Int),
         ];
         DeclarativeConnector {
@@ -87,6 +128,11 @@ impl ExampleConnector {
             supports_relations_over_non_unique_criteria: false,
             supports_enums: true,
             supports_json: true,
+            supports_decimal: true,
+            supports_index_column_length: false,
+            supports_index_algorithms: true,
+            supports_partial_indexes: true,
+            supports_named_foreign_keys: true,
         }
     }
 }
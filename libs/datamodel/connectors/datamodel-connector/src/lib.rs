@@ -7,10 +7,16 @@ mod declarative_connector;
 mod example_connector;
 
 pub use declarative_connector::DeclarativeConnector;
+pub use error::ConnectorError;
 pub use example_connector::ExampleConnector;
 
 pub trait Connector {
-    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Option<ScalarFieldType>;
+    /// Resolves a native type, e.g. `VarChar` with the arguments `[255]`.
+    fn calculate_type(&self, name: &str, args: Vec<i32>) -> Result<ScalarFieldType, ConnectorError>;
+
+    /// The inverse of `calculate_type`: resolves a type as reported by the database, e.g. `varchar(255)`.
+    /// Returns `None` if the type cannot be expressed through a native type of this connector.
+    fn parse_datasource_type(&self, datasource_type: &str) -> Option<ScalarFieldType>;
 
     fn supports_scalar_lists(&self) -> bool;
 
@@ -19,11 +25,27 @@ pub trait Connector {
     fn supports_enums(&self) -> bool;
 
     fn supports_json(&self) -> bool;
+
+    /// Whether `Decimal` fields can be stored without losing precision.
+    fn supports_decimal(&self) -> bool;
+
+    /// Whether index columns can be restricted to a prefix length, like on MySQL.
+    fn supports_index_column_length(&self) -> bool;
+
+    /// Whether an index method other than the default one can be chosen, like `Gin` on Postgres.
+    fn supports_index_algorithms(&self) -> bool;
+
+    /// Whether indexes can have a `where` clause.
+    fn supports_partial_indexes(&self) -> bool;
+
+    /// Whether foreign key constraints can be named, and renamed, through `@relation(map: ..)`.
+    fn supports_named_foreign_keys(&self) -> bool;
 }
 
 #[derive(Debug, Clone, PartialEq)]
 pub struct ScalarFieldType {
     name: String,
+    args: Vec<i32>,
     prisma_type: scalars::ScalarType,
     datasource_type: String,
 }
@@ -32,15 +54,32 @@ impl ScalarFieldType {
     pub fn new(name: &str, prisma_type: scalars::ScalarType, datasource_type: &str) -> Self {
         ScalarFieldType {
             name: name.to_string(),
+            args: vec![],
             prisma_type,
             datasource_type: datasource_type.to_string(),
         }
     }
 
+    pub fn with_args(mut self, args: Vec<i32>) -> Self {
+        self.args = args;
+        self
+    }
+
+    /// The name of the native type, e.g. `VarChar`.
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// The arguments the native type was given, e.g. `[255]`.
+    pub fn args(&self) -> &[i32] {
+        &self.args
+    }
+
     pub fn prisma_type(&self) -> scalars::ScalarType {
         self.prisma_type
     }
 
+    /// The type as the database spells it, e.g. `varchar(255)`.
     pub fn datasource_type(&self) -> &str {
         &self.datasource_type
     }
@@ -9,6 +9,9 @@ pub enum ScalarType {
     String,
     DateTime,
     Json,
+    Decimal,
+    BigInt,
+    Bytes,
 }
 
 impl ScalarType {
@@ -20,6 +23,9 @@ impl ScalarType {
             "String" => Ok(ScalarType::String),
             "DateTime" => Ok(ScalarType::DateTime),
             "Json" => Ok(ScalarType::Json),
+            "Decimal" => Ok(ScalarType::Decimal),
+            "BigInt" => Ok(ScalarType::BigInt),
+            "Bytes" => Ok(ScalarType::Bytes),
             _ => Err(format!("type {} is not a known scalar type.", s)),
         }
     }
@@ -34,6 +40,9 @@ impl ToString for ScalarType {
             ScalarType::String => String::from("String"),
             ScalarType::DateTime => String::from("DateTime"),
             ScalarType::Json => String::from("Json"),
+            ScalarType::Decimal => String::from("Decimal"),
+            ScalarType::BigInt => String::from("BigInt"),
+            ScalarType::Bytes => String::from("Bytes"),
         }
     }
 }
@@ -45,7 +45,11 @@ impl Argument {
     pub fn new_function(name: &str, fn_name: &str, value: Vec<Expression>) -> Argument {
         Argument {
             name: Identifier::new(name),
-            value: Expression::Function(fn_name.to_string(), value, Span::empty()),
+            value: Expression::Function(
+                fn_name.to_string(),
+                value.into_iter().map(Argument::new_unnamed).collect(),
+                Span::empty(),
+            ),
             span: Span::empty(),
         }
     }
@@ -15,6 +15,8 @@ pub struct Model {
     pub span: Span,
     /// Should this be commented out.
     pub commented_out: bool,
+    /// Is this a composite type, declared with the `type` keyword.
+    pub is_composite_type: bool,
 }
 
 impl WithIdentifier for Model {
@@ -70,11 +70,18 @@ pub fn parse_expression(token: &pest::iterators::Pair<'_, Rule>) -> Expression {
 
 fn parse_function(token: &pest::iterators::Pair<'_, Rule>) -> Expression {
     let mut name: Option<String> = None;
-    let mut arguments: Vec<Expression> = vec![];
+    let mut arguments: Vec<Argument> = vec![];
 
     match_children! { token, current,
         Rule::non_empty_identifier => name = Some(current.as_str().to_string()),
-        Rule::expression => arguments.push(parse_expression(&current)),
+        // This is a named arg.
+        Rule::argument => arguments.push(parse_directive_arg(&current)),
+        // This is a an unnamed arg.
+        Rule::argument_value => arguments.push(Argument {
+            name: Identifier::new(""),
+            value: parse_arg_value(&current),
+            span: Span::from_pest(current.as_span())
+        }),
         _ => unreachable!("Encountered impossible function during parsing: {:?}", current.tokens())
     };
 
@@ -278,13 +285,10 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
     let mut directives: Vec<Directive> = vec![];
     let mut fields: Vec<Field> = vec![];
     let mut comment: Option<Comment> = None;
+    let mut type_keyword_span: Option<Span> = None;
 
     match_children! { token, current,
-        Rule::TYPE_KEYWORD => { errors.push(
-            DatamodelError::new_legacy_parser_error(
-                "Model declarations have to be indicated with the `model` keyword.",
-                Span::from_pest(current.as_span()))
-        ) },
+        Rule::TYPE_KEYWORD => type_keyword_span = Some(Span::from_pest(current.as_span())),
         Rule::non_empty_identifier => name = Some(current.to_id()),
         Rule::directive => directives.push(parse_directive(&current)),
         Rule::field_declaration => {
@@ -304,6 +308,19 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
         _ => parsing_catch_all(&current)
     }
 
+    // Composite types have no identity. A `type` block with an id field is most likely a legacy model declaration.
+    if let Some(span) = type_keyword_span {
+        if fields
+            .iter()
+            .any(|field| field.directives.iter().any(|d| d.name.name == "id"))
+        {
+            errors.push(DatamodelError::new_legacy_parser_error(
+                "Model declarations have to be indicated with the `model` keyword.",
+                span,
+            ))
+        }
+    }
+
     errors.ok()?;
 
     match name {
@@ -314,6 +331,7 @@ fn parse_model(token: &pest::iterators::Pair<'_, Rule>) -> Result<Model, ErrorCo
             documentation: comment,
             span: Span::from_pest(token.as_span()),
             commented_out: false,
+            is_composite_type: type_keyword_span.is_some(),
         }),
         _ => panic!(
             "Encountered impossible model declaration during parsing: {:?}",
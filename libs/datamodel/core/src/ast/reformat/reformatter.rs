@@ -17,6 +17,13 @@ impl<'a> Reformatter<'a> {
         Reformatter { input, missing_fields }
     }
 
+    /// Creates a reformatter for one file of a schema made of multiple files. The missing fields
+    /// are found in the whole schema, as they can be implied by models of other files.
+    pub fn new_for_file(input: &'a str, schema: &str) -> Self {
+        let missing_fields = Self::find_all_missing_fields(schema);
+        Reformatter { input, missing_fields }
+    }
+
     // this finds all auto generated fields, that are added during auto generation AND are missing from the original input.
     fn find_all_missing_fields(schema_string: &str) -> Result<Vec<MissingField>, crate::error::ErrorCollection> {
         let schema_ast = crate::parse_schema_ast(&schema_string)?;
@@ -181,12 +188,19 @@ impl<'a> Reformatter<'a> {
     }
 
     fn reformat_model(&self, target: &mut Renderer, token: &Token) {
+        let block_type = if token.clone().into_inner().any(|t| t.as_rule() == Rule::TYPE_KEYWORD) {
+            "type"
+        } else {
+            "model"
+        };
+
         self.reformat_block_element_internal(
-            "model",
+            block_type,
             target,
             &token,
             Box::new(|table, renderer, token| {
                 match token.as_rule() {
+                    Rule::TYPE_KEYWORD => {}
                     Rule::directive => {
                         // model level Directives reset the table. -> .render() does that
                         table.render(renderer);
@@ -172,7 +172,9 @@ impl<'a> Renderer<'a> {
 
         Self::render_documentation(self, model);
 
-        self.write(format!("{}model ", comment_out).as_ref());
+        let keyword = if model.is_composite_type { "type" } else { "model" };
+
+        self.write(format!("{}{} ", comment_out, keyword).as_ref());
         self.write(&model.name.name);
         self.write(" {");
         self.end_line();
@@ -351,15 +353,20 @@ impl<'a> Renderer<'a> {
         };
     }
 
-    fn render_func(target: &mut dyn LineWriteable, name: &str, vals: &[ast::Expression]) {
+    fn render_func(target: &mut dyn LineWriteable, name: &str, args: &[ast::Argument]) {
         target.write(name);
         target.write("(");
-        for (idx, val) in vals.iter().enumerate() {
+        for (idx, arg) in args.iter().enumerate() {
             if idx > 0 {
                 target.write(", ");
             }
 
-            Self::render_value(target, val);
+            if !arg.is_unnamed() {
+                target.write(&arg.name.name);
+                target.write(": ");
+            }
+
+            Self::render_value(target, &arg.value);
         }
         target.write(")");
     }
@@ -59,6 +59,9 @@ impl ValueValidator {
             ScalarType::DateTime => self.as_date_time().map(PrismaValue::DateTime),
             ScalarType::String => self.as_str().map(PrismaValue::String),
             ScalarType::Json => self.as_str().map(PrismaValue::String),
+            ScalarType::Decimal => self.as_float().map(PrismaValue::Decimal),
+            ScalarType::BigInt => self.as_int().map(PrismaValue::BigInt),
+            ScalarType::Bytes => self.as_bytes().map(PrismaValue::Bytes),
         }
     }
 
@@ -124,6 +127,20 @@ impl ValueValidator {
         }
     }
 
+    /// Tries to convert the wrapped value to Prisma Bytes, given as a base64 encoded string.
+    pub fn as_bytes(&self) -> Result<Vec<u8>, DatamodelError> {
+        let value = self.as_str()?;
+
+        prisma_value::decode_bytes(&value).map_err(|_| {
+            DatamodelError::new_value_parser_error(
+                "base64 encoded bytes",
+                "Invalid base64 string.",
+                &self.raw(),
+                self.span(),
+            )
+        })
+    }
+
     /// Tries to convert the wrapped value to a Prisma Boolean.
     pub fn as_bool(&self) -> Result<bool, DatamodelError> {
         match &self.value {
@@ -179,6 +196,14 @@ impl ValueValidator {
         }
     }
 
+    /// Unwraps the wrapped value as a function call, returning its name and arguments.
+    pub fn as_function(&self) -> Result<(String, Vec<ast::Argument>), DatamodelError> {
+        match &self.value {
+            ast::Expression::Function(name, args, _) => Ok((name.clone(), args.clone())),
+            _ => Err(self.construct_type_mismatch_error("function")),
+        }
+    }
+
     /// Unwraps the wrapped value as a constant literal..
     pub fn as_array(&self) -> Vec<ValueValidator> {
         match &self.value {
@@ -199,8 +224,8 @@ impl ValueValidator {
 
     pub fn as_default_value(&self, scalar_type: ScalarType) -> Result<DefaultValue, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => {
-                let generator = self.get_value_generator(&name)?;
+            ast::Expression::Function(name, args, _) => {
+                let generator = self.get_value_generator(&name, &args)?;
                 Ok(DefaultValue::Expression(generator))
             }
             _ => {
@@ -212,15 +237,33 @@ impl ValueValidator {
 
     pub fn as_value_generator(&self) -> Result<ValueGenerator, DatamodelError> {
         match &self.value {
-            ast::Expression::Function(name, _, _) => self.get_value_generator(&name),
+            ast::Expression::Function(name, args, _) => self.get_value_generator(&name, &args),
             _ => Err(self.construct_type_mismatch_error("function")),
         }
     }
 
-    fn get_value_generator(&self, name: &str) -> Result<ValueGenerator, DatamodelError> {
-        ValueGenerator::new(name.to_string(), vec![])
+    fn get_value_generator(&self, name: &str, args: &[ast::Argument]) -> Result<ValueGenerator, DatamodelError> {
+        let args = args
+            .iter()
+            .map(|arg| ValueValidator::new(&arg.value).as_function_argument())
+            .collect::<Result<Vec<_>, _>>()?;
+
+        ValueGenerator::new(name.to_string(), args)
             .map_err(|err_msg| DatamodelError::new_functional_evaluation_error(&err_msg, self.span()))
     }
+
+    /// Tries to convert the wrapped value to the literal argument of a function.
+    fn as_function_argument(&self) -> Result<PrismaValue, DatamodelError> {
+        match &self.value {
+            ast::Expression::StringValue(value, _) => Ok(PrismaValue::String(value.clone())),
+            ast::Expression::BooleanValue(_, _) => self.as_bool().map(PrismaValue::Boolean),
+            ast::Expression::NumericValue(_, _) => self
+                .as_int()
+                .map(PrismaValue::Int)
+                .or_else(|_| self.as_float().map(PrismaValue::Float)),
+            _ => Err(self.construct_type_mismatch_error("literal")),
+        }
+    }
 }
 
 pub trait ValueListValidator {
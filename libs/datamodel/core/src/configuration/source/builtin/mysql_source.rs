@@ -6,6 +6,7 @@ pub const MYSQL_SOURCE_NAME: &str = "mysql";
 pub struct MySqlSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
+    pub(super) online_migrations: bool,
     pub(super) documentation: Option<String>,
 }
 
@@ -29,6 +30,10 @@ impl Source for MySqlSource {
         };
     }
 
+    fn online_migrations(&self) -> bool {
+        self.online_migrations
+    }
+
     fn documentation(&self) -> &Option<String> {
         &self.documentation
     }
@@ -18,11 +18,14 @@ impl SourceDefinition for MySqlSourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
+        _schemas: Vec<String>,
+        online_migrations: bool,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source + Send + Sync>, String> {
         Ok(Box::new(MySqlSource {
             name: String::from(name),
             url: validate_url(name, "mysql://", url)?,
+            online_migrations,
             documentation: documentation.clone(),
         }))
     }
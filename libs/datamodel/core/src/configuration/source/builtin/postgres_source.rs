@@ -6,6 +6,8 @@ pub const POSTGRES_SOURCE_NAME: &str = "postgresql";
 pub struct PostgresSource {
     pub(super) name: String,
     pub(super) url: StringFromEnvVar,
+    pub(super) schemas: Vec<String>,
+    pub(super) online_migrations: bool,
     pub(super) documentation: Option<String>,
 }
 
@@ -27,6 +29,14 @@ impl Source for PostgresSource {
         };
     }
 
+    fn schemas(&self) -> &[String] {
+        &self.schemas
+    }
+
+    fn online_migrations(&self) -> bool {
+        self.online_migrations
+    }
+
     fn documentation(&self) -> &Option<String> {
         &self.documentation
     }
@@ -18,6 +18,8 @@ impl SourceDefinition for PostgresSourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
+        schemas: Vec<String>,
+        online_migrations: bool,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source + Send + Sync>, String> {
         let high_prio_validation = validate_url(name, "postgresql://", url.clone());
@@ -25,6 +27,8 @@ impl SourceDefinition for PostgresSourceDefinition {
         Ok(Box::new(PostgresSource {
             name: String::from(name),
             url: low_prio_validation.or(high_prio_validation)?,
+            schemas,
+            online_migrations,
             documentation: documentation.clone(),
         }))
     }
@@ -18,6 +18,8 @@ impl SourceDefinition for SqliteSourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
+        _schemas: Vec<String>,
+        _online_migrations: bool,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source + Send + Sync>, String> {
         let validation_with_file_protocol = validate_url(name, "file:", url.clone());
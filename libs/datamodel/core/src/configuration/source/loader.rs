@@ -1,5 +1,8 @@
 use super::{
-    builtin::{MySqlSourceDefinition, PostgresSourceDefinition, SqliteSourceDefinition},
+    builtin::{
+        MySqlSourceDefinition, PostgresSourceDefinition, SqliteSourceDefinition, MYSQL_SOURCE_NAME,
+        POSTGRES_SOURCE_NAME,
+    },
     traits::{Source, SourceDefinition},
 };
 use crate::ast;
@@ -93,11 +96,47 @@ impl SourceLoader {
             ));
         }
 
+        let schemas_arg = args.optional_arg("schemas");
+        let schemas = match &schemas_arg {
+            Some(schemas_arg) => schemas_arg
+                .as_array()
+                .iter()
+                .map(|schema| schema.as_str())
+                .collect::<Result<Vec<String>, DatamodelError>>()?,
+            None => Vec::new(),
+        };
+
+        let online_migrations_arg = args.optional_arg("onlineMigrations");
+        let online_migrations = match &online_migrations_arg {
+            Some(online_migrations_arg) => online_migrations_arg.as_bool()?,
+            None => false,
+        };
+
         for decl in &self.source_declarations {
             // The provider given in the config block identifies the source type.
             // TODO: The second condition is a fallback to mitigate the postgres -> postgresql rename. It should be
             // renamed at some point.
             if provider == decl.connector_type() || (decl.connector_type() == "postgresql" && provider == "postgres") {
+                if let Some(schemas_arg) = &schemas_arg {
+                    if decl.connector_type() != POSTGRES_SOURCE_NAME {
+                        return Err(DatamodelError::new_source_validation_error(
+                            "The `schemas` property is only supported by the postgresql provider.",
+                            source_name,
+                            schemas_arg.span(),
+                        ));
+                    }
+                }
+
+                if let Some(online_migrations_arg) = &online_migrations_arg {
+                    if decl.connector_type() != POSTGRES_SOURCE_NAME && decl.connector_type() != MYSQL_SOURCE_NAME {
+                        return Err(DatamodelError::new_source_validation_error(
+                            "The `onlineMigrations` property is only supported by the postgresql and mysql providers.",
+                            source_name,
+                            online_migrations_arg.span(),
+                        ));
+                    }
+                }
+
                 let source = decl
                     .create(
                         source_name,
@@ -105,6 +144,8 @@ impl SourceLoader {
                             from_env_var: env_var_for_url,
                             value: url,
                         },
+                        schemas,
+                        online_migrations,
                         &ast_source.documentation.clone().map(|comment| comment.text),
                     )
                     .map_err(|err_msg| {
@@ -31,6 +31,22 @@ impl SourceSerializer {
             }
         }
 
+        if !source.schemas().is_empty() {
+            let values = source
+                .schemas()
+                .iter()
+                .map(|schema| ast::Expression::StringValue(schema.clone(), ast::Span::empty()))
+                .collect();
+            arguments.push(ast::Argument::new_array("schemas", values));
+        }
+
+        if source.online_migrations() {
+            arguments.push(ast::Argument::new(
+                "onlineMigrations",
+                ast::Expression::BooleanValue("true".to_owned(), ast::Span::empty()),
+            ));
+        }
+
         ast::SourceConfig {
             name: ast::Identifier::new(source.name()),
             properties: arguments,
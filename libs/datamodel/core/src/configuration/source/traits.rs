@@ -18,6 +18,16 @@ pub trait Source: Send + Sync {
 
     fn set_url(&mut self, url: &str);
 
+    /// Gets the additional database schemas the source spans, besides the one in the URL.
+    fn schemas(&self) -> &[String] {
+        &[]
+    }
+
+    /// Whether migrations should avoid locking tables, e.g. by creating indexes concurrently.
+    fn online_migrations(&self) -> bool {
+        false
+    }
+
     /// Documentation of this source.
     fn documentation(&self) -> &Option<String>;
 
@@ -35,6 +45,8 @@ pub trait SourceDefinition {
         &self,
         name: &str,
         url: StringFromEnvVar,
+        schemas: Vec<String>,
+        online_migrations: bool,
         documentation: &Option<String>,
     ) -> Result<Box<dyn Source + Send + Sync>, String>;
 }
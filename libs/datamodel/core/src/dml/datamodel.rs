@@ -7,6 +7,8 @@ pub struct Datamodel {
     pub enums: Vec<Enum>,
     /// All models.
     pub models: Vec<Model>,
+    /// All composite types.
+    pub composite_types: Vec<CompositeType>,
 }
 
 /// Type alias for (ModelName, FieldName)
@@ -18,6 +20,7 @@ impl Datamodel {
         Datamodel {
             enums: Vec::new(),
             models: Vec::new(),
+            composite_types: Vec::new(),
         }
     }
 
@@ -36,6 +39,11 @@ impl Datamodel {
         self.find_enum(name).is_some()
     }
 
+    /// Checks if a composite type with the given name exists.
+    pub fn has_composite_type(&self, name: &str) -> bool {
+        self.find_composite_type(name).is_some()
+    }
+
     /// Adds an enum to this datamodel.
     pub fn add_enum(&mut self, en: Enum) {
         self.enums.push(en);
@@ -56,6 +64,11 @@ impl Datamodel {
         self.models.retain(|m| m.name != name);
     }
 
+    /// Adds a composite type to this datamodel.
+    pub fn add_composite_type(&mut self, composite_type: CompositeType) {
+        self.composite_types.push(composite_type);
+    }
+
     /// Gets an iterator over all models.
     pub fn models(&self) -> std::slice::Iter<Model> {
         self.models.iter()
@@ -66,6 +79,11 @@ impl Datamodel {
         self.enums.iter()
     }
 
+    /// Gets an iterator over all composite types.
+    pub fn composite_types(&self) -> std::slice::Iter<CompositeType> {
+        self.composite_types.iter()
+    }
+
     /// Gets a mutable iterator over all models.
     pub fn models_mut(&mut self) -> std::slice::IterMut<Model> {
         self.models.iter_mut()
@@ -105,6 +123,11 @@ impl Datamodel {
         self.enums().find(|m| m.name == *name)
     }
 
+    /// Finds a composite type by name.
+    pub fn find_composite_type(&self, name: &str) -> Option<&CompositeType> {
+        self.composite_types().find(|t| t.name == *name)
+    }
+
     /// Finds a model by name and returns a mutable reference.
     pub fn find_model_mut(&mut self, name: &str) -> Option<&mut Model> {
         self.models_mut().find(|m| m.name == *name)
@@ -1,6 +1,10 @@
 use chrono::Utc;
+use once_cell::sync::Lazy;
 use prisma_value::PrismaValue;
-use std::fmt;
+use std::{
+    fmt,
+    sync::{PoisonError, RwLock, RwLockReadGuard},
+};
 use uuid::Uuid;
 
 #[derive(Clone, PartialEq)]
@@ -31,6 +35,7 @@ pub struct ValueGenerator {
 impl ValueGenerator {
     pub fn new(name: String, args: Vec<PrismaValue>) -> std::result::Result<Self, String> {
         let generator = ValueGeneratorFn::new(name.as_ref())?;
+        generator.validate_args(&args)?;
 
         Ok(ValueGenerator { name, args, generator })
     }
@@ -43,6 +48,32 @@ impl ValueGenerator {
         ValueGenerator::new("dbgenerated".to_owned(), vec![]).unwrap()
     }
 
+    /// A default generated by the database with the given SQL expression.
+    pub fn new_dbgenerated_expression(expression: String) -> Self {
+        ValueGenerator::new("dbgenerated".to_owned(), vec![PrismaValue::String(expression)]).unwrap()
+    }
+
+    /// The generator of a default expression found in the database: a registered function that
+    /// renders to the same expression, or `dbgenerated` with the expression. The databases rewrite
+    /// the expressions they store, the expressions are compared after `normalize`.
+    pub fn from_database_expression(expression: &str, normalize: impl Fn(&str) -> String) -> Self {
+        let normalized_expression = normalize(expression);
+        let registered = custom_generators()
+            .iter()
+            .find(|definition| {
+                definition
+                    .database_expression(&[])
+                    .map(|registered| normalize(&registered))
+                    == Some(normalized_expression.clone())
+            })
+            .map(|definition| definition.name());
+
+        match registered {
+            Some(name) => ValueGenerator::new(name.to_owned(), vec![]).unwrap(),
+            None => ValueGenerator::new_dbgenerated_expression(expression.to_owned()),
+        }
+    }
+
     pub fn new_now() -> Self {
         ValueGenerator::new("now".to_owned(), vec![]).unwrap()
     }
@@ -56,7 +87,17 @@ impl ValueGenerator {
     }
 
     pub fn generate(&self) -> Option<PrismaValue> {
-        self.generator.invoke()
+        self.generator.invoke(&self.args)
+    }
+
+    /// The SQL expression the database evaluates for this default, for generators that are not run
+    /// by the query engine.
+    pub fn database_expression(&self) -> Option<String> {
+        self.generator.database_expression(&self.args)
+    }
+
+    pub fn is_dbgenerated(&self) -> bool {
+        self.generator == ValueGeneratorFn::DbGenerated
     }
 
     fn name(&self) -> &str {
@@ -68,13 +109,54 @@ impl ValueGenerator {
     }
 }
 
-#[derive(Clone, Copy, PartialEq)]
+/// A function that can be used in `@default`, in addition to the builtin ones. Functions are made
+/// available with `register_value_generator`.
+pub trait ValueGeneratorDefinition: Send + Sync {
+    /// The name of the function in the schema.
+    fn name(&self) -> &'static str;
+
+    /// Validates the arguments the function is called with.
+    fn validate_args(&self, args: &[PrismaValue]) -> Result<(), String>;
+
+    /// Generates a value in the query engine before a record is created. Functions evaluated by
+    /// the database return `None`.
+    fn generate(&self, args: &[PrismaValue]) -> Option<PrismaValue>;
+
+    /// The SQL expression the migration engine renders as the column default, for functions
+    /// evaluated by the database.
+    fn database_expression(&self, _args: &[PrismaValue]) -> Option<String> {
+        None
+    }
+}
+
+static CUSTOM_GENERATORS: Lazy<RwLock<Vec<&'static dyn ValueGeneratorDefinition>>> =
+    Lazy::new(|| RwLock::new(Vec::new()));
+
+/// Makes a function available in `@default` for all schemas parsed afterwards. Builtin functions
+/// take precedence over registered functions with the same name.
+pub fn register_value_generator(definition: &'static dyn ValueGeneratorDefinition) {
+    CUSTOM_GENERATORS
+        .write()
+        .unwrap_or_else(PoisonError::into_inner)
+        .push(definition);
+}
+
+/// The registered functions. Registering only pushes to the list, so it is never left inconsistent and a
+/// poisoned lock can still be read.
+fn custom_generators() -> RwLockReadGuard<'static, Vec<&'static dyn ValueGeneratorDefinition>> {
+    CUSTOM_GENERATORS.read().unwrap_or_else(PoisonError::into_inner)
+}
+
+#[derive(Clone, Copy)]
 pub enum ValueGeneratorFn {
     UUID,
     CUID,
+    Nanoid,
+    Ulid,
     Now,
     Autoincrement,
     DbGenerated,
+    Custom(&'static dyn ValueGeneratorDefinition),
 }
 
 impl ValueGeneratorFn {
@@ -82,20 +164,53 @@ impl ValueGeneratorFn {
         match name {
             "cuid" => Ok(Self::CUID),
             "uuid" => Ok(Self::UUID),
+            "nanoid" => Ok(Self::Nanoid),
+            "ulid" => Ok(Self::Ulid),
             "now" => Ok(Self::Now),
             "autoincrement" => Ok(Self::Autoincrement),
             "dbgenerated" => Ok(Self::DbGenerated),
-            _ => Err(format!("The function {} is not a known function.", name)),
+            _ => custom_generators()
+                .iter()
+                .find(|definition| definition.name() == name)
+                .map(|definition| Self::Custom(*definition))
+                .ok_or_else(|| format!("The function {} is not a known function.", name)),
         }
     }
 
-    pub fn invoke(&self) -> Option<PrismaValue> {
+    fn validate_args(&self, args: &[PrismaValue]) -> std::result::Result<(), String> {
+        match (self, args) {
+            (Self::Custom(definition), args) => definition.validate_args(args),
+            (_, []) => Ok(()),
+            (Self::UUID, [PrismaValue::Int(4)]) | (Self::UUID, [PrismaValue::Int(7)]) => Ok(()),
+            (Self::UUID, _) => Err("The function uuid takes the UUID version, 4 or 7, as argument.".to_owned()),
+            (Self::Nanoid, [PrismaValue::Int(size)]) if *size >= 2 && *size <= 255 => Ok(()),
+            (Self::Nanoid, _) => Err("The function nanoid takes a size between 2 and 255 as argument.".to_owned()),
+            (Self::DbGenerated, [PrismaValue::String(_)]) => Ok(()),
+            (Self::DbGenerated, _) => {
+                Err("The function dbgenerated takes an SQL expression string as argument.".to_owned())
+            }
+            (_, _) => Err("The function does not take any arguments.".to_owned()),
+        }
+    }
+
+    pub fn invoke(&self, args: &[PrismaValue]) -> Option<PrismaValue> {
         match self {
-            Self::UUID => Self::generate_uuid(),
+            Self::UUID => Self::generate_uuid(args),
             Self::CUID => Self::generate_cuid(),
+            Self::Nanoid => Self::generate_nanoid(args),
+            Self::Ulid => Self::generate_ulid(),
             Self::Now => Self::generate_now(),
             Self::Autoincrement => None,
             Self::DbGenerated => None,
+            Self::Custom(definition) => definition.generate(args),
+        }
+    }
+
+    fn database_expression(&self, args: &[PrismaValue]) -> Option<String> {
+        match (self, args) {
+            (Self::DbGenerated, [PrismaValue::String(expression)]) => Some(expression.clone()),
+            (Self::Custom(definition), args) => definition.database_expression(args),
+            _ => None,
         }
     }
 
@@ -103,8 +218,59 @@ impl ValueGeneratorFn {
         Some(PrismaValue::String(cuid::cuid().unwrap()))
     }
 
-    fn generate_uuid() -> Option<PrismaValue> {
-        Some(PrismaValue::Uuid(Uuid::new_v4()))
+    fn generate_uuid(args: &[PrismaValue]) -> Option<PrismaValue> {
+        match args {
+            [PrismaValue::Int(7)] => Some(PrismaValue::Uuid(Self::new_uuid_v7())),
+            _ => Some(PrismaValue::Uuid(Uuid::new_v4())),
+        }
+    }
+
+    fn generate_nanoid(args: &[PrismaValue]) -> Option<PrismaValue> {
+        const ALPHABET: &[u8; 64] = b"_-0123456789abcdefghijklmnopqrstuvwxyzABCDEFGHIJKLMNOPQRSTUVWXYZ";
+
+        let size = match args {
+            [PrismaValue::Int(size)] => *size as usize,
+            _ => 21,
+        };
+
+        // The alphabet has 64 characters, so the lower 6 bits of a random byte pick one uniformly.
+        let id = random_bytes(size)
+            .into_iter()
+            .map(|byte| ALPHABET[(byte & 63) as usize] as char)
+            .collect();
+
+        Some(PrismaValue::String(id))
+    }
+
+    /// A ULID: a 48 bit millisecond timestamp followed by 80 random bits, in Crockford's base32.
+    fn generate_ulid() -> Option<PrismaValue> {
+        const ALPHABET: &[u8; 32] = b"0123456789ABCDEFGHJKMNPQRSTVWXYZ";
+
+        let mut value = (Utc::now().timestamp_millis() as u128) << 80;
+
+        for (i, byte) in random_bytes(10).into_iter().enumerate() {
+            value |= (byte as u128) << (8 * (9 - i));
+        }
+
+        let id = (0..26)
+            .rev()
+            .map(|i| ALPHABET[((value >> (5 * i)) & 31) as usize] as char)
+            .collect();
+
+        Some(PrismaValue::String(id))
+    }
+
+    /// A version 7 UUID: a 48 bit millisecond timestamp followed by random bits.
+    fn new_uuid_v7() -> Uuid {
+        let mut bytes = [0u8; 16];
+        let timestamp = Utc::now().timestamp_millis() as u64;
+
+        bytes[..6].copy_from_slice(&timestamp.to_be_bytes()[2..]);
+        bytes[6..].copy_from_slice(&random_bytes(10));
+        bytes[6] = (bytes[6] & 0x0f) | 0x70;
+        bytes[8] = (bytes[8] & 0x3f) | 0x80;
+
+        Uuid::from_bytes(bytes)
     }
 
     fn generate_now() -> Option<PrismaValue> {
@@ -112,6 +278,36 @@ impl ValueGeneratorFn {
     }
 }
 
+impl PartialEq for ValueGeneratorFn {
+    fn eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Custom(a), Self::Custom(b)) => a.name() == b.name(),
+            (a, b) => std::mem::discriminant(a) == std::mem::discriminant(b),
+        }
+    }
+}
+
+/// Random bytes, taken from the random bits of version 4 UUIDs.
+fn random_bytes(count: usize) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(count);
+
+    while bytes.len() < count {
+        let uuid = Uuid::new_v4();
+
+        // The version and variant are stored in the bytes 6 and 8, all other bytes are random.
+        bytes.extend(
+            uuid.as_bytes()
+                .iter()
+                .enumerate()
+                .filter(|(i, _)| *i != 6 && *i != 8)
+                .map(|(_, byte)| *byte),
+        );
+    }
+
+    bytes.truncate(count);
+    bytes
+}
+
 impl PartialEq for ValueGenerator {
     fn eq(&self, other: &Self) -> bool {
         self.name() == other.name() && self.args() == other.args()
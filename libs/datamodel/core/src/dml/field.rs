@@ -43,6 +43,8 @@ pub enum FieldType {
     Enum(String),
     /// This is a relation field.
     Relation(RelationInfo),
+    /// This is a field holding a value of the composite type with the given name.
+    Composite(String),
     /// Connector specific field type.
     ConnectorSpecific(ScalarFieldType),
     /// This is a field with an unsupported datatype.
@@ -59,9 +61,19 @@ impl FieldType {
         }
     }
 
+    pub fn is_composite(&self) -> bool {
+        match self {
+            Self::Composite(_) => true,
+            _ => false,
+        }
+    }
+
     pub fn is_compatible_with(&self, other: &Self) -> bool {
         match (self, other) {
             (Self::Base(a, _), Self::Base(b, _)) => a == b, // the name of the type alias is not important for the comparison
+            // a native type only refines the scalar type, it does not change it
+            (Self::ConnectorSpecific(a), b) => Some(a.prisma_type()) == b.scalar_type(),
+            (a, Self::ConnectorSpecific(b)) => a.scalar_type() == Some(b.prisma_type()),
             (a, b) => a == b,
         }
     }
@@ -100,6 +112,9 @@ pub struct Field {
     /// primary key.
     pub is_id: bool,
 
+    /// The database name of the primary key or unique constraint of this field, set with `map`.
+    pub constraint_db_name: Option<String>,
+
     /// Comments associated with this field.
     pub documentation: Option<String>,
 
@@ -157,6 +172,7 @@ impl Field {
             default_value: None,
             is_unique: false,
             is_id: false,
+            constraint_db_name: None,
             documentation: None,
             is_generated: false,
             is_updated_at: false,
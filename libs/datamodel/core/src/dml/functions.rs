@@ -38,7 +38,7 @@ impl EnvFunction {
             ));
         }
 
-        let var_wrapped = &args[0];
+        let var_wrapped = &args[0].value;
         let var_name = ValueValidator::new(var_wrapped).as_str()?;
         Ok(Self {
             var_name,
@@ -11,12 +11,22 @@ pub struct Model {
     pub documentation: Option<String>,
     /// The database internal name of this model.
     pub database_name: Option<String>,
+    /// The database schema this model lives in, if not the one of the datasource URL.
+    pub schema: Option<String>,
     /// Indicates if this model is embedded or not.
     pub is_embedded: bool,
+    /// Indicates if this model is backed by a database view.
+    pub is_view: bool,
+    /// The query defining the view. Only views with a definition are managed by migrations.
+    pub view_definition: Option<String>,
     /// Describes Composite Indexes
     pub indices: Vec<IndexDefinition>,
     /// Describes Composite Primary Keys
     pub id_fields: Vec<String>,
+    /// The database name of the primary key constraint, set with `map` on `@@id`.
+    pub id_db_name: Option<String>,
+    /// Describes check constraints
+    pub check_constraints: Vec<CheckConstraint>,
     /// Indicates if this model is generated.
     pub is_generated: bool,
     /// Indicates if this model has to be commented out.
@@ -28,6 +38,14 @@ pub struct IndexDefinition {
     pub name: Option<String>,
     pub fields: Vec<String>,
     pub tpe: IndexType,
+    /// The database name of the index, set with `map`. Falls back to `name` if not set.
+    pub db_name: Option<String>,
+    /// Per-field options, in the order of `fields`. Empty if all fields use the default options.
+    pub field_options: Vec<IndexFieldOptions>,
+    /// The index method, `None` for the default method of the database.
+    pub algorithm: Option<IndexAlgorithm>,
+    /// The condition of a partial index.
+    pub predicate: Option<String>,
 }
 
 impl IndexDefinition {
@@ -37,6 +55,103 @@ impl IndexDefinition {
             _ => false,
         }
     }
+
+    /// The options of the field at the given position in `fields`.
+    pub fn field_options(&self, position: usize) -> IndexFieldOptions {
+        self.field_options.get(position).cloned().unwrap_or_default()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Default)]
+pub struct IndexFieldOptions {
+    pub sort_order: SortOrder,
+    /// The length of the indexed prefix of the column.
+    pub length: Option<u32>,
+}
+
+impl IndexFieldOptions {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum SortOrder {
+    Asc,
+    Desc,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        SortOrder::Asc
+    }
+}
+
+impl Parsable for SortOrder {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "Asc" => Some(SortOrder::Asc),
+            "Desc" => Some(SortOrder::Desc),
+            _ => None,
+        }
+    }
+
+    fn descriptor() -> &'static str {
+        "sort order"
+    }
+}
+
+impl ToString for SortOrder {
+    fn to_string(&self) -> String {
+        match self {
+            SortOrder::Asc => String::from("Asc"),
+            SortOrder::Desc => String::from("Desc"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub enum IndexAlgorithm {
+    BTree,
+    Hash,
+    Gin,
+    Gist,
+    Brin,
+}
+
+impl Parsable for IndexAlgorithm {
+    fn parse(s: &str) -> Option<Self> {
+        match s {
+            "BTree" => Some(IndexAlgorithm::BTree),
+            "Hash" => Some(IndexAlgorithm::Hash),
+            "Gin" => Some(IndexAlgorithm::Gin),
+            "Gist" => Some(IndexAlgorithm::Gist),
+            "Brin" => Some(IndexAlgorithm::Brin),
+            _ => None,
+        }
+    }
+
+    fn descriptor() -> &'static str {
+        "index type"
+    }
+}
+
+impl ToString for IndexAlgorithm {
+    fn to_string(&self) -> String {
+        match self {
+            IndexAlgorithm::BTree => String::from("BTree"),
+            IndexAlgorithm::Hash => String::from("Hash"),
+            IndexAlgorithm::Gin => String::from("Gin"),
+            IndexAlgorithm::Gist => String::from("Gist"),
+            IndexAlgorithm::Brin => String::from("Brin"),
+        }
+    }
+}
+
+#[derive(Debug, PartialEq, Clone)]
+pub struct CheckConstraint {
+    pub name: Option<String>,
+    pub expression: String,
 }
 
 #[derive(Debug, PartialEq, Clone, Copy)]
@@ -64,9 +179,14 @@ impl Model {
             fields: vec![],
             indices: vec![],
             id_fields: vec![],
+            id_db_name: None,
+            check_constraints: vec![],
             documentation: None,
             database_name,
+            schema: None,
             is_embedded: false,
+            is_view: false,
+            view_definition: None,
             is_generated: false,
             is_commented_out: false,
         }
@@ -101,6 +221,15 @@ impl Model {
         self.find_field(name).is_some()
     }
 
+    /// The database name of the primary key constraint, set with `map` on either `@@id` or `@id`.
+    pub fn primary_key_db_name(&self) -> Option<&str> {
+        self.id_db_name.as_deref().or_else(|| {
+            self.fields()
+                .find(|field| field.is_id)
+                .and_then(|field| field.constraint_db_name.as_deref())
+        })
+    }
+
     /// Finds a field by name and returns a mutable reference.
     pub fn find_field_mut(&mut self, name: &str) -> Option<&mut Field> {
         self.fields_mut().find(|f| f.name == *name)
@@ -265,7 +394,7 @@ impl Model {
                 .find_field(name)
                 .or_else(|| model.find_field(name.to_lowercase().as_ref()))
             {
-                Some(f) => f.field_type == FieldType::Base(ScalarType::DateTime, None),
+                Some(f) => f.field_type.scalar_type() == Some(ScalarType::DateTime),
                 None => false,
             }
         }
@@ -14,6 +14,8 @@ pub struct RelationInfo {
     /// A strategy indicating what happens when
     /// a related node is deleted.
     pub on_delete: OnDeleteStrategy,
+    /// The database name of the foreign key constraint, set with `map`.
+    pub fk_db_name: Option<String>,
 }
 
 impl RelationInfo {
@@ -26,6 +28,7 @@ impl RelationInfo {
             to_fields: Vec::new(),
             name: String::new(),
             on_delete: OnDeleteStrategy::None,
+            fk_db_name: None,
         }
     }
 }
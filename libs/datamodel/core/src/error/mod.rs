@@ -379,15 +379,26 @@ impl DatamodelError {
     }
 
     pub fn pretty_print(&self, f: &mut dyn std::io::Write, file_name: &str, text: &str) -> std::io::Result<()> {
-        pretty_print_error(f, file_name, text, self)
+        pretty_print_error(f, file_name, text, self.span(), self)
+    }
+
+    /// Pretty prints the error at a span of `text` other than its own, e.g. within the schema
+    /// file the error originates from.
+    pub fn pretty_print_with_span(
+        &self,
+        f: &mut dyn std::io::Write,
+        file_name: &str,
+        text: &str,
+        span: Span,
+    ) -> std::io::Result<()> {
+        pretty_print_error(f, file_name, text, span, self)
     }
 }
 
 /// Given the datamodel text representation, pretty prints an error, including
 /// the offending portion of the source code, for human-friendly reading.
 #[rustfmt::skip]
-fn pretty_print_error(f: &mut dyn std::io::Write, file_name: &str, text: &str, error_obj: &DatamodelError) -> std::io::Result<()> {
-    let span = error_obj.span();
+fn pretty_print_error(f: &mut dyn std::io::Write, file_name: &str, text: &str, span: Span, error_obj: &DatamodelError) -> std::io::Result<()> {
     let error = error_obj.description();
 
     let start_line_number = text[..span.start].matches("\n").count();
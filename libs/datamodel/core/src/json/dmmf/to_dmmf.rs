@@ -17,6 +17,7 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
     let mut datamodel = Datamodel {
         models: vec![],
         enums: vec![],
+        types: vec![],
     };
 
     for enum_model in schema.enums() {
@@ -27,6 +28,10 @@ fn schema_to_dmmf(schema: &dml::Datamodel) -> Datamodel {
         datamodel.models.push(model_to_dmmf(&model));
     }
 
+    for composite_type in schema.composite_types() {
+        datamodel.types.push(composite_type_to_dmmf(&composite_type));
+    }
+
     datamodel
 }
 
@@ -75,6 +80,34 @@ fn model_to_dmmf(model: &dml::Model) -> Model {
     }
 }
 
+fn composite_type_to_dmmf(composite_type: &dml::CompositeType) -> CompositeType {
+    CompositeType {
+        name: composite_type.name.clone(),
+        fields: composite_type
+            .fields()
+            .map(|field| Field {
+                name: field.name.clone(),
+                kind: get_field_kind(field),
+                is_required: field.arity == dml::FieldArity::Required,
+                is_list: field.arity == dml::FieldArity::List,
+                is_id: false,
+                is_read_only: false,
+                has_default_value: false,
+                is_unique: false,
+                relation_name: None,
+                relation_from_fields: None,
+                relation_to_fields: None,
+                relation_on_delete: None,
+                field_type: get_field_type(field),
+                is_generated: None,
+                is_updated_at: None,
+                documentation: field.documentation.clone(),
+            })
+            .collect(),
+        documentation: composite_type.documentation.clone(),
+    }
+}
+
 fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
     let a_relation_field_is_based_on_this_field: bool = model.fields.iter().any(|f| match &f.field_type {
         dml::FieldType::Relation(rel_info) => rel_info.fields.contains(&field.name),
@@ -102,9 +135,9 @@ fn field_to_dmmf(model: &dml::Model, field: &dml::Field) -> Field {
 
 fn get_field_kind(field: &dml::Field) -> String {
     match field.field_type {
-        dml::FieldType::Relation(_) => String::from("object"),
+        dml::FieldType::Relation(_) | dml::FieldType::Composite(_) => String::from("object"),
         dml::FieldType::Enum(_) => String::from("enum"),
-        dml::FieldType::Base(_, _) => String::from("scalar"),
+        dml::FieldType::Base(_, _) | dml::FieldType::ConnectorSpecific(_) => String::from("scalar"),
         _ => unimplemented!("DMMF does not support field type {:?}", field.field_type),
     }
 }
@@ -112,6 +145,7 @@ fn get_field_kind(field: &dml::Field) -> String {
 fn get_field_type(field: &dml::Field) -> String {
     match &field.field_type {
         dml::FieldType::Relation(relation_info) => relation_info.to.clone(),
+        dml::FieldType::Composite(t) => t.clone(),
         dml::FieldType::Enum(t) => t.clone(),
         dml::FieldType::Unsupported(t) => t.clone(),
         dml::FieldType::Base(t, _) => type_to_string(t),
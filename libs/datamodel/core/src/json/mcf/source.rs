@@ -7,6 +7,10 @@ pub struct SourceConfig {
     pub name: String,
     pub connector_type: String,
     pub url: StringFromEnvVar,
+    #[serde(skip_serializing_if = "Vec::is_empty")]
+    pub schemas: Vec<String>,
+    #[serde(skip_serializing_if = "is_false")]
+    pub online_migrations: bool,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub documentation: Option<String>,
 }
@@ -36,6 +40,12 @@ fn source_to_json_struct(source: &dyn configuration::Source) -> SourceConfig {
         name: source.name().clone(),
         connector_type: String::from(source.connector_type()),
         url: source.url().clone(),
+        schemas: source.schemas().to_vec(),
+        online_migrations: source.online_migrations(),
         documentation: source.documentation().clone(),
     }
 }
+
+fn is_false(value: &bool) -> bool {
+    !value
+}
@@ -55,10 +55,12 @@ pub mod configuration;
 pub mod dml;
 pub mod error;
 pub mod json;
+pub mod schema_files;
 pub mod validator;
 
 pub use configuration::*;
 pub use dml::*;
+pub use schema_files::{SchemaFile, SchemaFiles};
 
 use crate::ast::SchemaAst;
 use std::io::Write;
@@ -0,0 +1,133 @@
+use crate::ast::Span;
+use crate::error::{DatamodelError, ErrorCollection};
+use std::path::Path;
+
+/// The file extension of Prisma schema files.
+const SCHEMA_FILE_EXTENSION: &str = "prisma";
+
+/// One file of a schema.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SchemaFile {
+    pub name: String,
+    pub content: String,
+}
+
+/// A schema made of one or more files.
+///
+/// The files are parsed and validated as one schema: their contents are joined in order, and the
+/// spans of the AST and of errors are offsets into the joined schema. `file_for_span` maps such a
+/// span back to the file it originates from.
+#[derive(Debug, Clone)]
+pub struct SchemaFiles {
+    files: Vec<SchemaFile>,
+    /// The offset in the joined schema and the index of each file. Empty files are left out of the
+    /// joined schema, no span can point into them.
+    offsets: Vec<(usize, usize)>,
+    schema: String,
+}
+
+impl SchemaFiles {
+    pub fn new(files: Vec<SchemaFile>) -> SchemaFiles {
+        let mut offsets = Vec::with_capacity(files.len());
+        let mut schema = String::new();
+
+        for (index, file) in files.iter().enumerate() {
+            if file.content.is_empty() {
+                continue;
+            }
+
+            offsets.push((schema.len(), index));
+            schema.push_str(&file.content);
+            // A comment on the last line of a file must not swallow the first line of the next one.
+            schema.push('\n');
+        }
+
+        SchemaFiles { files, offsets, schema }
+    }
+
+    /// A schema made of a single file.
+    pub fn single(name: &str, content: &str) -> SchemaFiles {
+        SchemaFiles::new(vec![SchemaFile {
+            name: name.to_owned(),
+            content: content.to_owned(),
+        }])
+    }
+
+    /// Loads a schema from a path. If the path is a directory, all `.prisma` files in it are
+    /// loaded, in the alphabetical order of their names.
+    pub fn load(path: &Path) -> std::io::Result<SchemaFiles> {
+        if !path.is_dir() {
+            let content = std::fs::read_to_string(path)?;
+
+            return Ok(SchemaFiles::single(&path.display().to_string(), &content));
+        }
+
+        let mut paths = Vec::new();
+
+        for entry in std::fs::read_dir(path)? {
+            let entry_path = entry?.path();
+
+            if entry_path.is_file() && entry_path.extension().map_or(false, |ext| ext == SCHEMA_FILE_EXTENSION) {
+                paths.push(entry_path);
+            }
+        }
+
+        paths.sort();
+
+        let mut files = Vec::with_capacity(paths.len());
+
+        for path in paths {
+            files.push(SchemaFile {
+                name: path.display().to_string(),
+                content: std::fs::read_to_string(&path)?,
+            });
+        }
+
+        Ok(SchemaFiles::new(files))
+    }
+
+    /// The joined schema, to be passed to the parser.
+    pub fn schema(&self) -> &str {
+        &self.schema
+    }
+
+    pub fn files(&self) -> &[SchemaFile] {
+        &self.files
+    }
+
+    /// Returns the file a span of the joined schema originates from, and the span within that file.
+    pub fn file_for_span(&self, span: Span) -> Option<(&SchemaFile, Span)> {
+        let position = match self.offsets.binary_search_by_key(&span.start, |(offset, _)| *offset) {
+            Ok(position) => position,
+            Err(0) => return None,
+            Err(position) => position - 1,
+        };
+
+        let (offset, index) = self.offsets[position];
+        let file = &self.files[index];
+        let start = span.start - offset;
+        let end = std::cmp::min(span.end - offset, file.content.len()).max(start);
+
+        Some((file, Span::new(start, end)))
+    }
+
+    /// Pretty prints an error, pointing at the file it originates from.
+    pub fn pretty_print_error(&self, f: &mut dyn std::io::Write, error: &DatamodelError) -> std::io::Result<()> {
+        match self.file_for_span(error.span()) {
+            Some((file, span)) => error.pretty_print_with_span(f, &file.name, &file.content, span),
+            None => error.pretty_print(f, "schema.prisma", &self.schema),
+        }
+    }
+
+    /// Renders errors in a pretty, colorful way, pointing at the files they originate from.
+    pub fn to_pretty_string(&self, errors: &ErrorCollection) -> String {
+        let mut message: Vec<u8> = Vec::new();
+
+        for err in errors.to_iter() {
+            self.pretty_print_error(&mut message, err)
+                .expect("printing datamodel error");
+        }
+
+        String::from_utf8_lossy(&message).into_owned()
+    }
+}
@@ -0,0 +1,56 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@check` directive.
+pub struct CheckDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for CheckDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"check"
+    }
+
+    fn is_duplicate_definition_allowed(&self) -> bool {
+        true
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let expression = args.default_arg("expression")?.as_str().map_err(|err| {
+            DatamodelError::new_directive_validation_error(&format!("{}", err), self.directive_name(), err.span())
+        })?;
+
+        let name = match args.optional_arg("name") {
+            Some(name_arg) => Some(name_arg.as_str()?),
+            None => None,
+        };
+
+        obj.check_constraints.push(dml::CheckConstraint { name, expression });
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        let directives = model
+            .check_constraints
+            .iter()
+            .map(|check_constraint| {
+                let mut args = vec![ast::Argument::new_unnamed(ast::Expression::StringValue(
+                    check_constraint.expression.clone(),
+                    ast::Span::empty(),
+                ))];
+
+                if let Some(name) = &check_constraint.name {
+                    args.push(ast::Argument::new_string("name", &name));
+                }
+
+                ast::Directive::new(self.directive_name(), args)
+            })
+            .collect();
+
+        Ok(directives)
+    }
+}
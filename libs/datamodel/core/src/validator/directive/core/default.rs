@@ -1,7 +1,7 @@
 use crate::error::DatamodelError;
 use crate::validator::directive::{Args, DirectiveValidator};
 use crate::validator::LowerDmlToAst;
-use crate::{ast, dml, ValueGenerator};
+use crate::{ast, dml};
 use prisma_value::PrismaValue;
 
 /// Prismas builtin `@default` directive.
@@ -18,7 +18,7 @@ impl DirectiveValidator<dml::Field> for DefaultDirectiveValidator {
             return self.new_directive_validation_error("Cannot set a default value on list field.", args.span());
         }
 
-        if let dml::FieldType::Base(scalar_type, _) = field.field_type {
+        if let Some(scalar_type) = field.field_type.scalar_type() {
             let dv = args
                 .default_arg("value")?
                 .as_default_value(scalar_type)
@@ -33,7 +33,7 @@ impl DirectiveValidator<dml::Field> for DefaultDirectiveValidator {
                 Ok(value) => field.default_value = Some(dml::DefaultValue::Single(PrismaValue::Enum(value))),
                 Err(err) => {
                     let generator = default_arg.as_value_generator()?;
-                    if generator == ValueGenerator::new_dbgenerated() {
+                    if generator.is_dbgenerated() {
                         field.default_value = Some(dml::DefaultValue::Expression(generator));
                     } else {
                         return Err(self.wrap_in_directive_validation_error(&err));
@@ -29,6 +29,10 @@ impl DirectiveValidator<dml::Field> for IdDirectiveValidator {
 
         obj.is_id = true;
 
+        if let Some(db_name) = args.optional_arg("map") {
+            obj.constraint_db_name = Some(db_name.as_str()?);
+        }
+
         Ok(())
     }
 
@@ -38,7 +42,13 @@ impl DirectiveValidator<dml::Field> for IdDirectiveValidator {
         _datamodel: &dml::Datamodel,
     ) -> Result<Vec<ast::Directive>, DatamodelError> {
         if field.is_id {
-            return Ok(vec![ast::Directive::new(self.directive_name(), Vec::new())]);
+            let mut args = Vec::new();
+
+            if let Some(db_name) = &field.constraint_db_name {
+                args.push(ast::Argument::new_string("map", db_name));
+            }
+
+            return Ok(vec![ast::Directive::new(self.directive_name(), args)]);
         }
 
         Ok(vec![])
@@ -61,6 +71,10 @@ impl DirectiveValidator<dml::Model> for ModelLevelIdDirectiveValidator {
             .collect();
         obj.id_fields = fields;
 
+        if let Some(db_name) = args.optional_arg("map") {
+            obj.id_db_name = Some(db_name.as_str()?);
+        }
+
         let undefined_fields: Vec<String> = obj
             .id_fields
             .iter()
@@ -125,6 +139,10 @@ impl DirectiveValidator<dml::Model> for ModelLevelIdDirectiveValidator {
                     .collect(),
             ));
 
+            if let Some(db_name) = &model.id_db_name {
+                args.push(ast::Argument::new_string("map", db_name));
+            }
+
             return Ok(vec![ast::Directive::new(self.directive_name(), args)]);
         }
 
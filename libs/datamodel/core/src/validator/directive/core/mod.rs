@@ -1,14 +1,17 @@
 use crate::dml;
 use crate::validator::directive::DirectiveListValidator;
 
+mod check;
 mod default;
 mod embedded;
 mod id;
 mod map;
 mod relation;
+mod schema;
 mod unique_and_index;
 mod updated_at;
 mod utils;
+mod view;
 
 /// Returns a directive list validator containing all builtin field directives.
 pub fn new_builtin_field_directives() -> DirectiveListValidator<dml::Field> {
@@ -33,6 +36,9 @@ pub fn new_builtin_model_directives() -> DirectiveListValidator<dml::Model> {
     validator.add(Box::new(unique_and_index::ModelLevelUniqueDirectiveValidator {}));
     validator.add(Box::new(unique_and_index::ModelLevelIndexDirectiveValidator {}));
     validator.add(Box::new(id::ModelLevelIdDirectiveValidator {}));
+    validator.add(Box::new(view::ViewDirectiveValidator {}));
+    validator.add(Box::new(schema::SchemaDirectiveValidator {}));
+    validator.add(Box::new(check::CheckDirectiveValidator {}));
 
     validator
 }
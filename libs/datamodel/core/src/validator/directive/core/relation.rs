@@ -32,6 +32,10 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 relation_info.fields = base_fields.as_array().to_literal_vec()?;
             }
 
+            if let Some(fk_db_name) = args.optional_arg("map") {
+                relation_info.fk_db_name = Some(fk_db_name.as_str()?);
+            }
+
             // TODO: bring `onDelete` back once `prisma migrate` is a thing
             //            if let Ok(on_delete) = args.arg("onDelete") {
             //                relation_info.on_delete = on_delete.parse_literal::<dml::OnDeleteStrategy>()?;
@@ -90,6 +94,10 @@ impl DirectiveValidator<dml::Field> for RelationDirectiveValidator {
                 args.push(ast::Argument::new_array("references", related_fields));
             }
 
+            if let Some(fk_db_name) = &relation_info.fk_db_name {
+                args.push(ast::Argument::new_string("map", fk_db_name));
+            }
+
             if relation_info.on_delete != dml::OnDeleteStrategy::None {
                 args.push(ast::Argument::new_constant(
                     "onDelete",
@@ -0,0 +1,39 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@schema` directive.
+pub struct SchemaDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for SchemaDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"schema"
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        let schema = args.default_arg("name")?.as_str().map_err(|err| {
+            DatamodelError::new_directive_validation_error(&format!("{}", err), self.directive_name(), err.span())
+        })?;
+
+        obj.schema = Some(schema);
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        match &model.schema {
+            Some(schema) => Ok(vec![ast::Directive::new(
+                self.directive_name(),
+                vec![ast::Argument::new_unnamed(ast::Expression::StringValue(
+                    schema.clone(),
+                    ast::Span::empty(),
+                ))],
+            )]),
+            None => Ok(vec![]),
+        }
+    }
+}
@@ -1,3 +1,4 @@
+use crate::common::value_validator::ValueValidator;
 use crate::error::DatamodelError;
 use crate::validator::directive::{Args, DirectiveValidator};
 use crate::{ast, dml, IndexDefinition, IndexType};
@@ -31,6 +32,10 @@ impl DirectiveValidator<dml::Field> for FieldLevelUniqueDirectiveValidator {
 
         obj.is_unique = true;
 
+        if let Some(db_name) = args.optional_arg("map") {
+            obj.constraint_db_name = Some(db_name.as_str()?);
+        }
+
         Ok(())
     }
 
@@ -40,7 +45,13 @@ impl DirectiveValidator<dml::Field> for FieldLevelUniqueDirectiveValidator {
         _datamodel: &dml::Datamodel,
     ) -> Result<Vec<ast::Directive>, DatamodelError> {
         if field.is_unique {
-            return Ok(vec![ast::Directive::new(self.directive_name(), vec![])]);
+            let mut args = Vec::new();
+
+            if let Some(db_name) = &field.constraint_db_name {
+                args.push(ast::Argument::new_string("map", db_name));
+            }
+
+            return Ok(vec![ast::Directive::new(self.directive_name(), args)]);
         }
 
         Ok(vec![])
@@ -117,6 +128,10 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
             name: None,
             fields: vec![],
             tpe: index_type,
+            db_name: None,
+            field_options: vec![],
+            algorithm: None,
+            predicate: None,
         };
         let name = match args.optional_arg("name") {
             Some(name_arg) => Some(name_arg.as_str()?),
@@ -124,13 +139,28 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
         };
         index_def.name = name;
 
-        let fields = args
-            .default_arg("fields")?
-            .as_array()
-            .iter()
-            .map(|f| f.as_constant_literal().unwrap())
-            .collect();
-        index_def.fields = fields;
+        if let Some(db_name) = args.optional_arg("map") {
+            index_def.db_name = Some(db_name.as_str()?);
+        }
+
+        for field in args.default_arg("fields")?.as_array() {
+            let (field_name, field_options) = self.validate_index_field(&field)?;
+            index_def.fields.push(field_name);
+            index_def.field_options.push(field_options);
+        }
+
+        // Only keep the per-field options if at least one of them differs from the defaults.
+        if index_def.field_options.iter().all(|options| options.is_default()) {
+            index_def.field_options.clear();
+        }
+
+        if let Some(algorithm) = args.optional_arg("type") {
+            index_def.algorithm = Some(algorithm.parse_literal::<dml::IndexAlgorithm>()?);
+        }
+
+        if let Some(predicate) = args.optional_arg("where") {
+            index_def.predicate = Some(predicate.as_str()?);
+        }
 
         let undefined_fields: Vec<String> = index_def
             .fields
@@ -181,6 +211,50 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
         Ok(index_def)
     }
 
+    /// Parses a single entry of the fields list, either `field` or `field(sort: Desc, length: 10)`.
+    fn validate_index_field(&self, field: &ValueValidator) -> Result<(String, dml::IndexFieldOptions), DatamodelError> {
+        let (field_name, field_args) = match field.as_function() {
+            Ok(function) => function,
+            Err(_) => return Ok((field.as_constant_literal()?, dml::IndexFieldOptions::default())),
+        };
+
+        let mut args = Args::new(&field_args, field.span());
+        let mut options = dml::IndexFieldOptions::default();
+
+        if let Some(sort_order) = args.optional_arg("sort") {
+            options.sort_order = sort_order.parse_literal::<dml::SortOrder>()?;
+        }
+
+        if let Some(length) = args.optional_arg("length") {
+            let value = length.as_int()?;
+
+            if value <= 0 || value > i64::from(u32::max_value()) {
+                return Err(DatamodelError::new_directive_validation_error(
+                    &format!(
+                        "The length of the indexed field `{}` must be a positive number.",
+                        field_name
+                    ),
+                    self.directive_name(),
+                    length.span(),
+                ));
+            }
+
+            options.length = Some(value as u32);
+        }
+
+        if let Some(unknown_arg) = field_args
+            .iter()
+            .find(|arg| arg.name.name != "sort" && arg.name.name != "length")
+        {
+            return Err(DatamodelError::new_unused_argument_error(
+                &unknown_arg.name.name,
+                unknown_arg.span,
+            ));
+        }
+
+        Ok((field_name, options))
+    }
+
     fn serialize_index_definitions(
         &self,
         model: &dml::Model,
@@ -198,12 +272,22 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
                     index_def
                         .fields
                         .iter()
-                        .map(|f| ast::Expression::ConstantValue(f.to_string(), ast::Span::empty()))
+                        .enumerate()
+                        .map(|(position, f)| serialize_index_field(f, &index_def.field_options(position)))
                         .collect(),
                 ));
                 if let Some(name) = &index_def.name {
                     args.push(ast::Argument::new_string("name", &name));
                 }
+                if let Some(db_name) = &index_def.db_name {
+                    args.push(ast::Argument::new_string("map", &db_name));
+                }
+                if let Some(algorithm) = &index_def.algorithm {
+                    args.push(ast::Argument::new_constant("type", &algorithm.to_string()));
+                }
+                if let Some(predicate) = &index_def.predicate {
+                    args.push(ast::Argument::new_string("where", &predicate));
+                }
 
                 ast::Directive::new(self.directive_name(), args)
             })
@@ -212,3 +296,24 @@ trait IndexDirectiveBase<T>: DirectiveValidator<T> {
         Ok(directives)
     }
 }
+
+fn serialize_index_field(field_name: &str, options: &dml::IndexFieldOptions) -> ast::Expression {
+    if options.is_default() {
+        return ast::Expression::ConstantValue(field_name.to_string(), ast::Span::empty());
+    }
+
+    let mut args = Vec::new();
+
+    if options.sort_order != dml::SortOrder::default() {
+        args.push(ast::Argument::new_constant("sort", &options.sort_order.to_string()));
+    }
+
+    if let Some(length) = options.length {
+        args.push(ast::Argument::new(
+            "length",
+            ast::Expression::NumericValue(length.to_string(), ast::Span::empty()),
+        ));
+    }
+
+    ast::Expression::Function(field_name.to_string(), args, ast::Span::empty())
+}
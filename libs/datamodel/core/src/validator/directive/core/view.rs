@@ -0,0 +1,46 @@
+use crate::error::DatamodelError;
+use crate::validator::directive::{Args, DirectiveValidator};
+use crate::{ast, dml};
+
+/// Prismas builtin `@@view` directive.
+pub struct ViewDirectiveValidator {}
+
+impl DirectiveValidator<dml::Model> for ViewDirectiveValidator {
+    fn directive_name(&self) -> &'static str {
+        &"view"
+    }
+
+    fn validate_and_apply(&self, args: &mut Args, obj: &mut dml::Model) -> Result<(), DatamodelError> {
+        obj.is_view = true;
+
+        if let Some(definition) = args.optional_arg("definition").or_else(|| args.optional_arg("")) {
+            let definition = definition.as_str().map_err(|err| {
+                DatamodelError::new_directive_validation_error(&format!("{}", err), self.directive_name(), err.span())
+            })?;
+
+            obj.view_definition = Some(definition);
+        }
+
+        Ok(())
+    }
+
+    fn serialize(
+        &self,
+        model: &dml::Model,
+        _datamodel: &dml::Datamodel,
+    ) -> Result<Vec<ast::Directive>, DatamodelError> {
+        if !model.is_view {
+            return Ok(vec![]);
+        }
+
+        let args = match &model.view_definition {
+            Some(definition) => vec![ast::Argument::new_unnamed(ast::Expression::StringValue(
+                definition.clone(),
+                ast::Span::empty(),
+            ))],
+            None => vec![],
+        };
+
+        Ok(vec![ast::Directive::new(self.directive_name(), args)])
+    }
+}
@@ -1,4 +1,4 @@
-use super::DirectiveBox;
+use super::{DirectiveBox, NATIVE_TYPE_NAMESPACE};
 use crate::{
     ast,
     common::value_validator::ValueValidator,
@@ -6,7 +6,6 @@ use crate::{
     configuration, dml,
     error::{DatamodelError, ErrorCollection},
 };
-use datamodel_connector::{Connector, ExampleConnector};
 
 /// Helper for lifting a datamodel.
 ///
@@ -18,8 +17,6 @@ pub struct LiftAstToDml<'a> {
     source: Option<&'a Box<dyn configuration::Source + Send + Sync>>,
 }
 
-const USE_CONNECTORS_FOR_CUSTOM_TYPES: bool = false; // FEATURE FLAG
-
 impl<'a> LiftAstToDml<'a> {
     /// Creates a new instance, with all builtin directives and
     /// the directives defined by the given sources registered.
@@ -42,6 +39,10 @@ impl<'a> LiftAstToDml<'a> {
                     Ok(en) => schema.add_enum(en),
                     Err(mut err) => errors.append(&mut err),
                 },
+                ast::Top::Model(ty) if ty.is_composite_type => match self.lift_composite_type(&ty, ast_schema) {
+                    Ok(ct) => schema.add_composite_type(ct),
+                    Err(mut err) => errors.append(&mut err),
+                },
                 ast::Top::Model(ty) => match self.lift_model(&ty, ast_schema) {
                     Ok(md) => schema.add_model(md),
                     Err(mut err) => errors.append(&mut err),
@@ -85,6 +86,54 @@ impl<'a> LiftAstToDml<'a> {
         Ok(model)
     }
 
+    /// Internal: Validates a composite type AST node and lifts it to a DML composite type.
+    fn lift_composite_type(
+        &self,
+        ast_model: &ast::Model,
+        ast_schema: &ast::SchemaAst,
+    ) -> Result<dml::CompositeType, ErrorCollection> {
+        let mut composite_type = dml::CompositeType::new(&ast_model.name.name);
+        composite_type.documentation = ast_model.documentation.clone().map(|comment| comment.text);
+
+        let mut errors = ErrorCollection::new();
+
+        if let Some(directive) = ast_model.directives.first() {
+            errors.push(DatamodelError::new_directive_validation_error(
+                "Composite types do not support block attributes.",
+                &directive.name.name,
+                directive.span,
+            ));
+        }
+
+        for ast_field in &ast_model.fields {
+            if let Some(directive) = ast_field.directives.first() {
+                errors.push(DatamodelError::new_directive_validation_error(
+                    "Fields of composite types do not support attributes.",
+                    &directive.name.name,
+                    directive.span,
+                ));
+                continue;
+            }
+
+            match self.lift_field(ast_field, ast_schema) {
+                Ok(field) if field.field_type.is_relation() => {
+                    errors.push(DatamodelError::new_validation_error(
+                        "Fields of composite types cannot refer to models.",
+                        ast_field.field_type.span,
+                    ));
+                }
+                Ok(field) => composite_type.add_field(field),
+                Err(mut err) => errors.append(&mut err),
+            }
+        }
+
+        if errors.has_errors() {
+            return Err(errors);
+        }
+
+        Ok(composite_type)
+    }
+
     /// Internal: Validates an enum AST node.
     fn lift_enum(&self, ast_enum: &ast::Enum) -> Result<dml::Enum, ErrorCollection> {
         let mut errors = ErrorCollection::new();
@@ -166,11 +215,29 @@ impl<'a> LiftAstToDml<'a> {
 
         // We merge arttributes so we can fail on duplicates.
         let attributes = [&extra_attributes[..], &ast_field.directives[..]].concat();
+        let (native_type_attributes, attributes): (Vec<_>, Vec<_>) =
+            attributes.into_iter().partition(Self::is_native_type_attribute);
 
         if let Err(mut err) = self.directives.field.validate_and_apply(&attributes, &mut field) {
             errors.append(&mut err);
         }
 
+        for (idx, attribute) in native_type_attributes.iter().enumerate() {
+            if idx > 0 {
+                errors.push(DatamodelError::new_directive_validation_error(
+                    "A field can only have a single native type attribute.",
+                    &attribute.name.name,
+                    attribute.span,
+                ));
+                continue;
+            }
+
+            match self.lift_native_type(attribute, &field_type) {
+                Ok(native_type) => field.field_type = native_type,
+                Err(err) => errors.push(err),
+            }
+        }
+
         if errors.has_errors() {
             Err(errors)
         } else {
@@ -178,6 +245,56 @@ impl<'a> LiftAstToDml<'a> {
         }
     }
 
+    fn is_native_type_attribute(attribute: &ast::Directive) -> bool {
+        attribute.name.name.starts_with(&format!("{}.", NATIVE_TYPE_NAMESPACE))
+    }
+
+    /// Internal: Resolves a native type attribute, e.g. `@db.VarChar(255)`, against the connector of the datasource.
+    fn lift_native_type(
+        &self,
+        attribute: &ast::Directive,
+        field_type: &dml::FieldType,
+    ) -> Result<dml::FieldType, DatamodelError> {
+        let attribute_name = &attribute.name.name;
+        let type_name = attribute_name.trim_start_matches(&format!("{}.", NATIVE_TYPE_NAMESPACE));
+        let error =
+            |message: &str| DatamodelError::new_directive_validation_error(message, attribute_name, attribute.span);
+
+        let source = self
+            .source
+            .ok_or_else(|| error("Native types can only be used with a datasource."))?;
+
+        let scalar_type = match field_type {
+            dml::FieldType::Base(scalar_type, _) => *scalar_type,
+            _ => return Err(error("Native types can only be used on scalar fields.")),
+        };
+
+        let mut args = Vec::with_capacity(attribute.arguments.len());
+
+        for argument in &attribute.arguments {
+            if !argument.name.name.is_empty() {
+                return Err(error("Native type arguments must not be named."));
+            }
+
+            args.push(ValueValidator::new(&argument.value).as_int()? as i32);
+        }
+
+        let native_type = source
+            .connector()
+            .calculate_type(type_name, args)
+            .map_err(|err| error(&err.to_string()))?;
+
+        if native_type.prisma_type() != scalar_type {
+            return Err(error(&format!(
+                "Native type {} is not compatible with the type {} of the field.",
+                native_type.name(),
+                scalar_type.to_string()
+            )));
+        }
+
+        Ok(dml::FieldType::ConnectorSpecific(native_type))
+    }
+
     /// Internal: Lift a field's arity.
     fn lift_field_arity(&self, ast_field: &ast::FieldArity) -> dml::FieldArity {
         match ast_field {
@@ -199,26 +316,13 @@ impl<'a> LiftAstToDml<'a> {
         let type_name = &ast_field.field_type.name;
 
         if let Ok(scalar_type) = ScalarType::from_str(type_name) {
-            if USE_CONNECTORS_FOR_CUSTOM_TYPES {
-                let pg_connector = ExampleConnector::postgres();
-                let args = vec![]; // TODO: figure out args
-                let pg_type_specification = ast_field
-                    .directives
-                    .iter()
-                    .find(|dir| dir.name.name.starts_with("pg.")) // we use find because there should be at max 1.
-                    .map(|dir| dir.name.name.trim_start_matches("pg."));
-
-                if let Some(x) = pg_type_specification.and_then(|ts| pg_connector.calculate_type(&ts, args)) {
-                    let field_type = dml::FieldType::ConnectorSpecific(x);
-                    Ok((field_type, vec![]))
-                } else {
-                    Ok((dml::FieldType::Base(scalar_type, custom_type_name), vec![]))
-                }
+            Ok((dml::FieldType::Base(scalar_type, custom_type_name), vec![]))
+        } else if let Some(ast_model) = ast_schema.find_model(type_name) {
+            if ast_model.is_composite_type {
+                Ok((dml::FieldType::Composite(type_name.clone()), vec![]))
             } else {
-                Ok((dml::FieldType::Base(scalar_type, custom_type_name), vec![]))
+                Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
             }
-        } else if ast_schema.find_model(type_name).is_some() {
-            Ok((dml::FieldType::Relation(dml::RelationInfo::new(type_name)), vec![]))
         } else if ast_schema.find_enum(type_name).is_some() {
             Ok((dml::FieldType::Enum(type_name.clone()), vec![]))
         } else {
@@ -251,7 +355,7 @@ impl<'a> LiftAstToDml<'a> {
             let (field_type, mut attrs) =
                 self.lift_field_type(custom_type, Some(type_name.to_owned()), ast_schema, checked_types)?;
 
-            if let dml::FieldType::Relation(_) = field_type {
+            if field_type.is_relation() || field_type.is_composite() {
                 return Err(DatamodelError::new_validation_error(
                     "Only scalar types can be used for defining custom types.",
                     custom_type.field_type.span,
@@ -260,19 +364,6 @@ impl<'a> LiftAstToDml<'a> {
 
             attrs.append(&mut custom_type.directives.clone());
             Ok((field_type, attrs))
-        } else if USE_CONNECTORS_FOR_CUSTOM_TYPES {
-            let pg_connector = ExampleConnector::postgres();
-            let args = vec![]; // TODO: figure out args
-
-            if let Some(x) = pg_connector.calculate_type(&ast_field.field_type.name, args) {
-                let field_type = dml::FieldType::ConnectorSpecific(x);
-                Ok((field_type, vec![]))
-            } else {
-                Err(DatamodelError::new_type_not_found_error(
-                    type_name,
-                    ast_field.field_type.span,
-                ))
-            }
         } else {
             Err(DatamodelError::new_type_not_found_error(
                 type_name,
@@ -1,6 +1,7 @@
-use super::DirectiveBox;
+use super::{DirectiveBox, NATIVE_TYPE_NAMESPACE};
 use crate::error::ErrorCollection;
 use crate::{ast, dml};
+use datamodel_connector::ScalarFieldType;
 use prisma_value::PrismaValue;
 
 pub struct LowerDmlToAst {
@@ -28,6 +29,13 @@ impl LowerDmlToAst {
             }
         }
 
+        for composite_type in datamodel.composite_types() {
+            match self.lower_composite_type(composite_type, datamodel) {
+                Ok(res) => tops.push(ast::Top::Model(res)),
+                Err(mut err) => errors.append(&mut err),
+            }
+        }
+
         for enm in datamodel.enums() {
             match self.lower_enum(enm, datamodel) {
                 Ok(res) => tops.push(ast::Top::Enum(res)),
@@ -60,6 +68,37 @@ impl LowerDmlToAst {
             documentation: model.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
             commented_out: model.is_commented_out,
+            is_composite_type: false,
+        })
+    }
+
+    fn lower_composite_type(
+        &self,
+        composite_type: &dml::CompositeType,
+        datamodel: &dml::Datamodel,
+    ) -> Result<ast::Model, ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+        let mut fields: Vec<ast::Field> = Vec::new();
+
+        for field in composite_type.fields() {
+            match self.lower_field(field, datamodel) {
+                Ok(ast_field) => fields.push(ast_field),
+                Err(mut err) => errors.append(&mut err),
+            };
+        }
+
+        if errors.has_errors() {
+            return Err(errors);
+        }
+
+        Ok(ast::Model {
+            name: ast::Identifier::new(&composite_type.name),
+            fields,
+            directives: vec![],
+            documentation: composite_type.documentation.clone().map(|text| ast::Comment { text }),
+            span: ast::Span::empty(),
+            commented_out: false,
+            is_composite_type: true,
         })
     }
 
@@ -84,11 +123,17 @@ impl LowerDmlToAst {
     }
 
     pub fn lower_field(&self, field: &dml::Field, datamodel: &dml::Datamodel) -> Result<ast::Field, ErrorCollection> {
+        let mut directives = self.directives.field.serialize(field, datamodel)?;
+
+        if let dml::FieldType::ConnectorSpecific(native_type) = &field.field_type {
+            directives.push(self.lower_native_type(native_type));
+        }
+
         Ok(ast::Field {
             name: ast::Identifier::new(&field.name),
             arity: self.lower_field_arity(field.arity),
             default_value: field.default_value.clone().map(|dv| Self::lower_default_value(dv)),
-            directives: self.directives.field.serialize(field, datamodel)?,
+            directives,
             field_type: self.lower_type(&field.field_type),
             documentation: field.documentation.clone().map(|text| ast::Comment { text }),
             span: ast::Span::empty(),
@@ -109,8 +154,12 @@ impl LowerDmlToAst {
         match dv {
             dml::DefaultValue::Single(v) => Self::lower_prisma_value(&v),
             dml::DefaultValue::Expression(e) => {
-                let exprs = e.args.iter().map(Self::lower_prisma_value).collect();
-                ast::Expression::Function(e.name, exprs, ast::Span::empty())
+                let args = e
+                    .args
+                    .iter()
+                    .map(|arg| ast::Argument::new_unnamed(Self::lower_prisma_value(arg)))
+                    .collect();
+                ast::Expression::Function(e.name, args, ast::Span::empty())
             }
         }
     }
@@ -124,6 +173,11 @@ impl LowerDmlToAst {
             PrismaValue::DateTime(value) => ast::Expression::StringValue(value.to_rfc3339(), ast::Span::empty()),
             PrismaValue::Float(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
             PrismaValue::Int(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
+            PrismaValue::Decimal(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
+            PrismaValue::BigInt(value) => ast::Expression::NumericValue(value.to_string(), ast::Span::empty()),
+            PrismaValue::Bytes(value) => {
+                ast::Expression::StringValue(prisma_value::encode_bytes(value), ast::Span::empty())
+            }
             PrismaValue::Null => ast::Expression::ConstantValue("null".to_string(), ast::Span::empty()),
             PrismaValue::Uuid(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
             PrismaValue::Json(val) => ast::Expression::StringValue(val.to_string(), ast::Span::empty()),
@@ -143,7 +197,21 @@ impl LowerDmlToAst {
             dml::FieldType::Enum(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Unsupported(tpe) => ast::Identifier::new(&tpe.to_string()),
             dml::FieldType::Relation(rel) => ast::Identifier::new(&rel.to),
-            _ => unimplemented!("Connector specific types are not supported atm."),
+            dml::FieldType::Composite(name) => ast::Identifier::new(name),
+            dml::FieldType::ConnectorSpecific(native_type) => {
+                ast::Identifier::new(&native_type.prisma_type().to_string())
+            }
         }
     }
+
+    /// Internal: Lowers a native type to its attribute, e.g. `@db.VarChar(255)`.
+    fn lower_native_type(&self, native_type: &ScalarFieldType) -> ast::Directive {
+        let arguments = native_type
+            .args()
+            .iter()
+            .map(|arg| ast::Argument::new_unnamed(ast::Expression::NumericValue(arg.to_string(), ast::Span::empty())))
+            .collect();
+
+        ast::Directive::new(&format!("{}.{}", NATIVE_TYPE_NAMESPACE, native_type.name()), arguments)
+    }
 }
@@ -12,6 +12,9 @@ pub mod directive;
 
 use directive_box::*;
 
+/// The namespace of native type attributes, e.g. `@db.VarChar(255)`.
+const NATIVE_TYPE_NAMESPACE: &str = "db";
+
 use lift::*;
 pub use lower::*;
 use standardise::*;
@@ -185,6 +185,7 @@ impl Standardiser {
                             to_fields: vec![],
                             name: rel.name.clone(),
                             on_delete: OnDeleteStrategy::None,
+                            fk_db_name: None,
                         };
                         let mut back_relation_field =
                             dml::Field::new_generated(&model.name, dml::FieldType::Relation(relation_info));
@@ -257,6 +258,7 @@ impl Standardiser {
                             to_fields: unique_criteria_field_names,
                             name: rel.name.clone(),
                             on_delete: OnDeleteStrategy::None,
+                            fk_db_name: None,
                         };
 
                         let mut back_relation_field =
@@ -48,6 +48,13 @@ impl<'a> Validator<'a> {
             if let Err(err) = self.validate_embedded_types_have_no_back_relation(ast_schema, schema, model) {
                 errors_for_model.push(err);
             }
+            if let Err(err) = self.validate_views_have_no_relations(ast_schema, schema, model) {
+                errors_for_model.push(err);
+            }
+            if let Err(err) = self.validate_model_schema(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.push(err);
+            }
 
             if let Err(ref mut the_errors) =
                 self.validate_field_arities(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
@@ -61,6 +68,18 @@ impl<'a> Validator<'a> {
                 errors_for_model.append(the_errors);
             }
 
+            if let Err(ref mut the_errors) =
+                self.validate_index_options(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
+            if let Err(ref mut the_errors) =
+                self.validate_foreign_key_names(ast_schema.find_model(&model.name).expect(STATE_ERROR), model)
+            {
+                errors_for_model.append(the_errors);
+            }
+
             if let Err(ref mut the_errors) = self.validate_base_fields_for_relation(
                 schema,
                 ast_schema.find_model(&model.name).expect(STATE_ERROR),
@@ -131,7 +150,12 @@ impl<'a> Validator<'a> {
         let mut errors = ErrorCollection::new();
 
         for model in ast_schema.models() {
-            errors.push_opt(model.name.validate("Model").err());
+            let kind = if model.is_composite_type {
+                "Composite type"
+            } else {
+                "Model"
+            };
+            errors.push_opt(model.name.validate(kind).err());
             errors.append(&mut model.validate_directives());
 
             for field in model.fields.iter() {
@@ -210,6 +234,124 @@ impl<'a> Validator<'a> {
                     ));
                 }
             }
+
+            if let Some(dml::ScalarType::Decimal) = field.field_type.scalar_type() {
+                let supports_decimal_type = match self.source {
+                    Some(source) => source.connector().supports_decimal(),
+                    None => true,
+                };
+                if !supports_decimal_type {
+                    errors.push(DatamodelError::new_field_validation_error(
+                        &format!("Field `{}` in model `{}` can't be of type Decimal. The current connector does not support the Decimal type.", &field.name, &model.name),
+                        &model.name,
+                        &field.name,
+                        ast_field.span.clone(),
+                    ));
+                }
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_index_options(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        let (supports_column_length, supports_algorithms, supports_partial_indexes) = match self.source {
+            Some(source) => (
+                source.connector().supports_index_column_length(),
+                source.connector().supports_index_algorithms(),
+                source.connector().supports_partial_indexes(),
+            ),
+            None => (false, false, false),
+        };
+
+        for index_type in &[dml::IndexType::Unique, dml::IndexType::Normal] {
+            let directive_name = match index_type {
+                dml::IndexType::Unique => "unique",
+                dml::IndexType::Normal => "index",
+            };
+
+            // The indexes of a type are lifted in the order of their directives.
+            let ast_directives = ast_model
+                .directives
+                .iter()
+                .filter(|directive| directive.name.name == directive_name);
+            let indexes = model.indices.iter().filter(|index| index.tpe == *index_type);
+
+            for (index, ast_directive) in indexes.zip(ast_directives) {
+                let mut unsupported_options = Vec::new();
+
+                if !supports_column_length && index.field_options.iter().any(|options| options.length.is_some()) {
+                    unsupported_options.push("length");
+                }
+                if !supports_algorithms && index.algorithm.is_some() {
+                    unsupported_options.push("type");
+                }
+                if !supports_partial_indexes && index.predicate.is_some() {
+                    unsupported_options.push("where");
+                }
+
+                for option in unsupported_options {
+                    errors.push(DatamodelError::new_directive_validation_error(
+                        &format!("The `{}` option is not supported by the current connector.", option),
+                        directive_name,
+                        ast_directive.span,
+                    ));
+                }
+            }
+        }
+
+        if errors.has_errors() {
+            Err(errors)
+        } else {
+            Ok(())
+        }
+    }
+
+    fn validate_foreign_key_names(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), ErrorCollection> {
+        let mut errors = ErrorCollection::new();
+
+        let supports_named_foreign_keys = match self.source {
+            Some(source) => source.connector().supports_named_foreign_keys(),
+            None => true,
+        };
+
+        if supports_named_foreign_keys {
+            return Ok(());
+        }
+
+        for field in model.fields() {
+            let has_fk_db_name = match &field.field_type {
+                dml::FieldType::Relation(rel_info) => rel_info.fk_db_name.is_some(),
+                _ => false,
+            };
+
+            if !has_fk_db_name {
+                continue;
+            }
+
+            let ast_field = ast_model
+                .fields
+                .iter()
+                .find(|ast_field| ast_field.name.name == field.name)
+                .expect(STATE_ERROR);
+            let span = ast_field
+                .directives
+                .iter()
+                .find(|directive| directive.name.name == "relation")
+                .map(|directive| directive.span)
+                .unwrap_or(ast_field.span);
+
+            errors.push(DatamodelError::new_directive_validation_error(
+                "The `map` argument is not supported by the current connector, foreign keys cannot be named.",
+                "relation",
+                span,
+            ));
         }
 
         if errors.has_errors() {
@@ -306,6 +448,63 @@ impl<'a> Validator<'a> {
         Ok(())
     }
 
+    /// Ensures that views are not part of relations. They are read-only and can not be referenced
+    /// by foreign keys.
+    fn validate_views_have_no_relations(
+        &self,
+        ast_schema: &ast::SchemaAst,
+        datamodel: &dml::Datamodel,
+        model: &dml::Model,
+    ) -> Result<(), DatamodelError> {
+        for field in model.fields() {
+            if let dml::FieldType::Relation(rel) = &field.field_type {
+                let related_is_view = datamodel.find_model(&rel.to).map(|m| m.is_view).unwrap_or(false);
+
+                if model.is_view || related_is_view {
+                    return Err(DatamodelError::new_model_validation_error(
+                        "Views cannot be part of relations.",
+                        &model.name,
+                        ast_schema.find_field(&model.name, &field.name).expect(STATE_ERROR).span,
+                    ));
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    fn validate_model_schema(&self, ast_model: &ast::Model, model: &dml::Model) -> Result<(), DatamodelError> {
+        let schema = match &model.schema {
+            Some(schema) => schema,
+            None => return Ok(()),
+        };
+
+        let schema_is_declared = match self.source {
+            Some(source) => source.schemas().iter().any(|declared| declared == schema),
+            None => false,
+        };
+
+        if schema_is_declared {
+            return Ok(());
+        }
+
+        let span = ast_model
+            .directives
+            .iter()
+            .find(|directive| directive.name.name == "schema")
+            .map(|directive| directive.span)
+            .unwrap_or(ast_model.span);
+
+        Err(DatamodelError::new_model_validation_error(
+            &format!(
+                "The schema `{}` is not declared in the `schemas` property of the datasource.",
+                schema
+            ),
+            &model.name,
+            span,
+        ))
+    }
+
     fn validate_base_fields_for_relation(
         &self,
         _datamodel: &dml::Datamodel,
@@ -12,6 +12,9 @@ fn parse_scalar_types() {
         age          Int
         isPro        Boolean
         averageGrade Float
+        balance      Decimal
+        views        BigInt
+        avatar       Bytes
     }
     "#;
 
@@ -27,6 +30,15 @@ fn parse_scalar_types() {
     user_model
         .assert_has_field("averageGrade")
         .assert_base_type(&ScalarType::Float);
+    user_model
+        .assert_has_field("balance")
+        .assert_base_type(&ScalarType::Decimal);
+    user_model
+        .assert_has_field("views")
+        .assert_base_type(&ScalarType::BigInt);
+    user_model
+        .assert_has_field("avatar")
+        .assert_base_type(&ScalarType::Bytes);
 }
 
 #[test]
@@ -187,3 +199,25 @@ fn json_type_must_work_for_some_connectors() {
         .assert_has_field("json")
         .assert_base_type(&ScalarType::Json);
 }
+
+#[test]
+fn decimal_type_must_not_work_on_sqlite() {
+    let dml = r#"
+    model User {
+        id      Int     @id
+        balance Decimal
+    }
+    "#;
+
+    parse_error(&format!("{}\n{}", SQLITE_SOURCE, dml)).assert_is(DatamodelError::new_field_validation_error(
+        "Field `balance` in model `User` can\'t be of type Decimal. The current connector does not support the Decimal type.",
+        "User",
+        "balance",
+        Span::new(143, 159),
+    ));
+
+    parse(&format!("{}\n{}", POSTGRES_SOURCE, dml))
+        .assert_has_model("User")
+        .assert_has_field("balance")
+        .assert_base_type(&ScalarType::Decimal);
+}
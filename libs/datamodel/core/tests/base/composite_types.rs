@@ -0,0 +1,94 @@
+use crate::common::*;
+use datamodel::ast::Span;
+use datamodel::common::ScalarType;
+use datamodel::{dml, error::DatamodelError};
+
+#[test]
+fn parse_composite_type() {
+    let dml = r#"
+    model User {
+        id        Int       @id
+        address   Address
+        locations Address[]
+    }
+
+    type Address {
+        street String
+        zip    Int?
+        geo    Geo?
+    }
+
+    type Geo {
+        lat Float
+        lng Float
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model
+        .assert_has_field("address")
+        .assert_composite_type("Address")
+        .assert_arity(&dml::FieldArity::Required);
+    user_model
+        .assert_has_field("locations")
+        .assert_composite_type("Address")
+        .assert_arity(&dml::FieldArity::List);
+
+    assert!(!schema.has_model("Address"));
+
+    let address = schema.assert_has_composite_type("Address");
+    address
+        .find_field("street")
+        .unwrap()
+        .assert_base_type(&ScalarType::String);
+    address
+        .find_field("zip")
+        .unwrap()
+        .assert_base_type(&ScalarType::Int)
+        .assert_arity(&dml::FieldArity::Optional);
+    address.find_field("geo").unwrap().assert_composite_type("Geo");
+}
+
+#[test]
+fn composite_type_fields_must_not_have_attributes() {
+    let dml = r#"
+    model User {
+        id      Int     @id
+        address Address
+    }
+
+    type Address {
+        street String @unique
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "Fields of composite types do not support attributes.",
+        "unique",
+        Span::new(119, 125),
+    ));
+}
+
+#[test]
+fn composite_type_fields_must_not_refer_to_models() {
+    let dml = r#"
+    model User {
+        id      Int     @id
+        address Address
+    }
+
+    type Address {
+        owner User
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_validation_error(
+        "Fields of composite types cannot refer to models.",
+        Span::new(110, 114),
+    ));
+}
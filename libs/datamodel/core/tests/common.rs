@@ -8,6 +8,7 @@ use pretty_assertions::assert_eq;
 pub trait FieldAsserts {
     fn assert_base_type(&self, t: &ScalarType) -> &Self;
     fn assert_enum_type(&self, en: &str) -> &Self;
+    fn assert_composite_type(&self, t: &str) -> &Self;
     fn assert_connector_type(&self, sft: &ScalarFieldType) -> &Self;
     fn assert_relation_name(&self, t: &str) -> &Self;
     fn assert_relation_to(&self, t: &str) -> &Self;
@@ -27,9 +28,13 @@ pub trait FieldAsserts {
 pub trait ModelAsserts {
     fn assert_has_field(&self, t: &str) -> &dml::Field;
     fn assert_is_embedded(&self, t: bool) -> &Self;
+    fn assert_is_view(&self, t: bool) -> &Self;
+    fn assert_with_view_definition(&self, t: Option<&str>) -> &Self;
+    fn assert_with_schema(&self, t: Option<&str>) -> &Self;
     fn assert_with_db_name(&self, t: &str) -> &Self;
     fn assert_with_documentation(&self, t: &str) -> &Self;
     fn assert_has_index(&self, def: IndexDefinition) -> &Self;
+    fn assert_has_check_constraint(&self, def: dml::CheckConstraint) -> &Self;
     fn assert_has_id_fields(&self, fields: &[&str]) -> &Self;
 }
 
@@ -46,6 +51,7 @@ pub trait EnumValueAsserts {
 pub trait DatamodelAsserts {
     fn assert_has_model(&self, t: &str) -> &dml::Model;
     fn assert_has_enum(&self, t: &str) -> &dml::Enum;
+    fn assert_has_composite_type(&self, t: &str) -> &dml::CompositeType;
 }
 
 pub trait ErrorAsserts {
@@ -85,6 +91,16 @@ impl FieldAsserts for dml::Field {
         self
     }
 
+    fn assert_composite_type(&self, t: &str) -> &Self {
+        if let dml::FieldType::Composite(composite_type) = &self.field_type {
+            assert_eq!(composite_type, t);
+        } else {
+            panic!("Composite type expected, but found {:?}", self.field_type);
+        }
+
+        self
+    }
+
     fn assert_relation_to(&self, t: &str) -> &Self {
         if let dml::FieldType::Relation(info) = &self.field_type {
             assert_eq!(info.to, t);
@@ -193,6 +209,10 @@ impl DatamodelAsserts for dml::Datamodel {
         self.find_enum(&t.to_owned())
             .expect(format!("Enum {} not found", t).as_str())
     }
+    fn assert_has_composite_type(&self, t: &str) -> &dml::CompositeType {
+        self.find_composite_type(&t.to_owned())
+            .expect(format!("Composite type {} not found", t).as_str())
+    }
 }
 
 impl ModelAsserts for dml::Model {
@@ -207,6 +227,24 @@ impl ModelAsserts for dml::Model {
         self
     }
 
+    fn assert_is_view(&self, t: bool) -> &Self {
+        assert_eq!(self.is_view, t);
+
+        self
+    }
+
+    fn assert_with_view_definition(&self, t: Option<&str>) -> &Self {
+        assert_eq!(self.view_definition.as_deref(), t);
+
+        self
+    }
+
+    fn assert_with_schema(&self, t: Option<&str>) -> &Self {
+        assert_eq!(self.schema.as_deref(), t);
+
+        self
+    }
+
     fn assert_with_db_name(&self, t: &str) -> &Self {
         assert_eq!(self.database_name, Some(t.to_owned()));
 
@@ -229,6 +267,16 @@ impl ModelAsserts for dml::Model {
         self
     }
 
+    fn assert_has_check_constraint(&self, def: dml::CheckConstraint) -> &Self {
+        assert!(
+            self.check_constraints.contains(&def),
+            "could not find check constraint {:?} in the check constraints of this model \n {:?}",
+            def,
+            self.check_constraints
+        );
+        self
+    }
+
     fn assert_has_id_fields(&self, fields: &[&str]) -> &Self {
         assert_eq!(self.id_fields, fields);
         self
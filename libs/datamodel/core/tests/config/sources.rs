@@ -220,6 +220,105 @@ fn new_lines_in_source_must_work() {
     assert_eq_json(&rendered, expected);
 }
 
+#[test]
+fn schemas_must_be_loaded_and_serialized() {
+    let schema = r#"
+        datasource ds {
+            provider = "postgresql"
+            url = "postgresql://localhost"
+            schemas = ["auth", "billing", "public"]
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "connectorType": "postgresql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "postgresql://localhost"
+          },
+          "schemas": ["auth", "billing", "public"]
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+}
+
+#[test]
+fn must_error_if_schemas_are_used_with_mysql() {
+    let schema = r#"
+        datasource myds {
+            provider = "mysql"
+            url = "mysql://localhost"
+            schemas = ["auth", "billing"]
+        }
+    "#;
+    let config = datamodel::parse_configuration(schema);
+    assert!(config.is_err());
+    let errors = config.err().expect("This must error");
+    errors.assert_is(DatamodelError::new_source_validation_error(
+        "The `schemas` property is only supported by the postgresql provider.",
+        "myds",
+        Span::new(118, 137),
+    ));
+}
+
+#[test]
+fn online_migrations_must_be_loaded_and_serialized() {
+    let schema = r#"
+        datasource ds {
+            provider = "mysql"
+            url = "mysql://localhost"
+            onlineMigrations = true
+        }
+    "#;
+
+    let config = datamodel::parse_configuration(schema).unwrap();
+    let rendered = datamodel::json::mcf::render_sources_to_json(&config.datasources);
+
+    let expected = r#"[
+        {
+          "name": "ds",
+          "connectorType": "mysql",
+          "url": {
+              "fromEnvVar": null,
+              "value": "mysql://localhost"
+          },
+          "onlineMigrations": true
+        }
+    ]"#;
+
+    assert_eq_json(&rendered, expected);
+    assert!(
+        datamodel::render_datamodel_and_config_to_string(&datamodel::dml::Datamodel::new(), &config)
+            .unwrap()
+            .contains("onlineMigrations = true")
+    );
+}
+
+#[test]
+fn must_error_if_online_migrations_are_used_with_sqlite() {
+    let schema = r#"
+        datasource myds {
+            provider = "sqlite"
+            url = "file:dev.db"
+            onlineMigrations = true
+        }
+    "#;
+    let config = datamodel::parse_configuration(schema);
+    assert!(config.is_err());
+    let errors = config.err().expect("This must error");
+    errors.assert_is(DatamodelError::new_source_validation_error(
+        "The `onlineMigrations` property is only supported by the postgresql and mysql providers.",
+        "myds",
+        Span::new(122, 126),
+    ));
+}
+
 fn assert_eq_json(a: &str, b: &str) {
     let json_a: serde_json::Value = serde_json::from_str(a).expect("The String a was not valid JSON.");
     let json_b: serde_json::Value = serde_json::from_str(b).expect("The String b was not valid JSON.");
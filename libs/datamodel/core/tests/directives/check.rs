@@ -0,0 +1,46 @@
+use crate::common::*;
+use datamodel::dml;
+
+#[test]
+fn check_directive() {
+    let dml = r#"
+    model Product {
+        id       Int @id
+        price    Int
+        discount Int
+
+        @@check("price >= 0")
+        @@check("discount <= price", name: "discount_below_price")
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema
+        .assert_has_model("Product")
+        .assert_has_check_constraint(dml::CheckConstraint {
+            name: None,
+            expression: "price >= 0".to_owned(),
+        })
+        .assert_has_check_constraint(dml::CheckConstraint {
+            name: Some("discount_below_price".to_owned()),
+            expression: "discount <= price".to_owned(),
+        });
+}
+
+#[test]
+fn check_directive_must_be_rendered() {
+    let input = r#"model Product {
+  id       Int @id
+  price    Int
+  discount Int
+
+  @@check("price >= 0")
+  @@check("discount <= price", name: "discount_below_price")
+}"#;
+
+    let dml = parse(input);
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    assert_eq!(rendered, input);
+}
@@ -0,0 +1,87 @@
+use crate::common::*;
+use datamodel::{ast::Span, error::DatamodelError, render_datamodel_to_string, IndexDefinition, IndexType};
+
+const DATAMODEL: &str = r#"
+    model User {
+        id    Int    @id(map: "pk_users")
+        email String @unique(map: "uq_users_email")
+        posts Post[]
+    }
+
+    model Post {
+        id       Int
+        authorId Int
+        title    String
+        author   User   @relation(fields: [authorId], references: [id], map: "fk_posts_author")
+
+        @@id([id, authorId], map: "pk_posts")
+        @@index([title], name: "titleIndex", map: "idx_posts_title")
+    }
+"#;
+
+#[test]
+fn map_arguments_must_set_the_database_names_of_constraints() {
+    let schema = parse(DATAMODEL);
+
+    let user = schema.assert_has_model("User");
+    assert_eq!(
+        user.find_field("id").unwrap().constraint_db_name.as_deref(),
+        Some("pk_users")
+    );
+    assert_eq!(
+        user.find_field("email").unwrap().constraint_db_name.as_deref(),
+        Some("uq_users_email")
+    );
+    assert_eq!(user.primary_key_db_name(), Some("pk_users"));
+
+    let post = schema.assert_has_model("Post");
+    assert_eq!(post.primary_key_db_name(), Some("pk_posts"));
+    post.assert_has_index(IndexDefinition {
+        name: Some("titleIndex".to_owned()),
+        fields: vec!["title".to_owned()],
+        tpe: IndexType::Normal,
+        db_name: Some("idx_posts_title".to_owned()),
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
+    });
+
+    match &post.find_field("author").unwrap().field_type {
+        datamodel::FieldType::Relation(relation_info) => {
+            assert_eq!(relation_info.fk_db_name.as_deref(), Some("fk_posts_author"))
+        }
+        _ => panic!("Expected a relation field."),
+    }
+}
+
+#[test]
+fn map_arguments_must_be_rendered() {
+    let schema = parse(DATAMODEL);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert_eq!(parse(&rendered), schema);
+}
+
+#[test]
+fn foreign_key_names_must_error_on_sqlite() {
+    let dml = r#"
+    model User {
+        id    Int    @id
+        posts Post[]
+    }
+
+    model Post {
+        id       Int    @id
+        authorId Int
+        author   User   @relation(fields: [authorId], references: [id], map: "fk_posts_author")
+    }
+    "#;
+
+    parse_error(&format!("{}\n{}", SQLITE_SOURCE, dml)).assert_is(DatamodelError::new_directive_validation_error(
+        "The `map` argument is not supported by the current connector, foreign keys cannot be named.",
+        "relation",
+        Span::new(251, 321),
+    ));
+
+    parse(&format!("{}\n{}", POSTGRES_SOURCE, dml)).assert_has_model("Post");
+}
@@ -94,3 +94,37 @@ fn should_error_if_unknown_function_is_used() {
         Span::new(68, 86),
     ));
 }
+
+#[test]
+fn should_error_if_nanoid_size_is_out_of_range() {
+    let dml = r#"
+    model Model {
+        id String @id @default(nanoid(1))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The function nanoid takes a size between 2 and 255 as argument.",
+        "default",
+        Span::new(50, 59),
+    ));
+}
+
+#[test]
+fn should_error_if_uuid_version_is_not_supported() {
+    let dml = r#"
+    model Model {
+        id String @id @default(uuid(5))
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The function uuid takes the UUID version, 4 or 7, as argument.",
+        "default",
+        Span::new(50, 57),
+    ));
+}
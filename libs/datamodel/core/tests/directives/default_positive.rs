@@ -1,6 +1,6 @@
 use crate::common::*;
 use chrono::{DateTime, Utc};
-use datamodel::{common::ScalarType, DefaultValue, ValueGenerator};
+use datamodel::{common::ScalarType, register_value_generator, DefaultValue, ValueGenerator, ValueGeneratorDefinition};
 use prisma_value::PrismaValue;
 use rust_decimal::prelude::FromPrimitive;
 use rust_decimal::Decimal;
@@ -47,6 +47,33 @@ fn should_set_default_for_all_scalar_types() {
         )));
 }
 
+#[test]
+fn should_set_default_for_exact_and_binary_scalar_types() {
+    let dml = r#"
+    model Model {
+        id Int @id
+        decimal Decimal @default(1234.5678)
+        bigInt BigInt @default(9007199254740993)
+        bytes Bytes @default("aGVsbG8=")
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_field("decimal")
+        .assert_base_type(&ScalarType::Decimal)
+        .assert_default_value(DefaultValue::Single(PrismaValue::Decimal("1234.5678".parse().unwrap())));
+    user_model
+        .assert_has_field("bigInt")
+        .assert_base_type(&ScalarType::BigInt)
+        .assert_default_value(DefaultValue::Single(PrismaValue::BigInt(9007199254740993)));
+    user_model
+        .assert_has_field("bytes")
+        .assert_base_type(&ScalarType::Bytes)
+        .assert_default_value(DefaultValue::Single(PrismaValue::Bytes(b"hello".to_vec())));
+}
+
 #[test]
 fn should_set_default_an_enum_type() {
     let dml = r#"
@@ -118,3 +145,68 @@ fn db_generated_function_must_work_for_enum_fields() {
         .assert_enum_type("Role")
         .assert_default_value(DefaultValue::Expression(ValueGenerator::new_dbgenerated()));
 }
+
+#[test]
+fn should_set_generator_functions_with_arguments() {
+    let dml = r#"
+    model Model {
+        id        String @id @default(nanoid(10))
+        ulid      String @default(ulid())
+        uuid      String @default(uuid(7))
+        generated String @default(dbgenerated("gen_random_uuid()"))
+    }
+    "#;
+
+    let datamodel = parse(dml);
+    let user_model = datamodel.assert_has_model("Model");
+    user_model
+        .assert_has_field("id")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("nanoid".to_owned(), vec![PrismaValue::Int(10)]).unwrap(),
+        ));
+    user_model
+        .assert_has_field("ulid")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("ulid".to_owned(), vec![]).unwrap(),
+        ));
+    user_model
+        .assert_has_field("uuid")
+        .assert_default_value(DefaultValue::Expression(
+            ValueGenerator::new("uuid".to_owned(), vec![PrismaValue::Int(7)]).unwrap(),
+        ));
+    user_model
+        .assert_has_field("generated")
+        .assert_default_value(DefaultValue::Expression(ValueGenerator::new_dbgenerated_expression(
+            "gen_random_uuid()".to_owned(),
+        )));
+}
+
+struct RandomMd5;
+
+impl ValueGeneratorDefinition for RandomMd5 {
+    fn name(&self) -> &'static str {
+        "random_md5"
+    }
+
+    fn validate_args(&self, _args: &[PrismaValue]) -> Result<(), String> {
+        Ok(())
+    }
+
+    fn generate(&self, _args: &[PrismaValue]) -> Option<PrismaValue> {
+        None
+    }
+
+    fn database_expression(&self, _args: &[PrismaValue]) -> Option<String> {
+        Some("md5(random()::text)".to_owned())
+    }
+}
+
+#[test]
+fn registered_functions_match_normalized_database_expressions() {
+    register_value_generator(&RandomMd5);
+
+    let without_parentheses = |expression: &str| expression.replace(|c| c == '(' || c == ')', "");
+    let generator = ValueGenerator::from_database_expression("md5((random())::text)", without_parentheses);
+
+    assert_eq!("random_md5", generator.name);
+}
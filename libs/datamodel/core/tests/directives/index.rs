@@ -1,4 +1,7 @@
-use datamodel::{ast::Span, error::*, render_datamodel_to_string, IndexDefinition, IndexType};
+use datamodel::{
+    ast::Span, error::*, render_datamodel_to_string, IndexAlgorithm, IndexDefinition, IndexFieldOptions, IndexType,
+    SortOrder,
+};
 
 use crate::common::*;
 
@@ -20,6 +23,10 @@ fn basic_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
     });
 }
 
@@ -45,6 +52,10 @@ fn indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
     });
 }
 
@@ -91,6 +102,10 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
     });
 }
 
@@ -114,12 +129,20 @@ fn multiple_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Normal,
+        db_name: None,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
     });
 }
 
@@ -157,3 +180,98 @@ fn index_directives_must_serialize_to_valid_dml() {
 
     assert!(datamodel::parse_datamodel(&render_datamodel_to_string(&schema).unwrap()).is_ok());
 }
+
+#[test]
+fn index_options_must_work() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost:5432"
+    }
+
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String
+        tags      Json
+
+        @@index([firstName, lastName(sort: Desc)], where: "id > 10")
+        @@index([tags], type: Gin)
+    }
+    "#;
+
+    let schema = parse(dml);
+    let user_model = schema.assert_has_model("User");
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["firstName".to_string(), "lastName".to_string()],
+        tpe: IndexType::Normal,
+        db_name: None,
+        field_options: vec![
+            IndexFieldOptions::default(),
+            IndexFieldOptions {
+                sort_order: SortOrder::Desc,
+                length: None,
+            },
+        ],
+        algorithm: None,
+        predicate: Some("id > 10".to_string()),
+    });
+    user_model.assert_has_index(IndexDefinition {
+        name: None,
+        fields: vec!["tags".to_string()],
+        tpe: IndexType::Normal,
+        db_name: None,
+        field_options: vec![],
+        algorithm: Some(IndexAlgorithm::Gin),
+        predicate: None,
+    });
+}
+
+#[test]
+fn index_options_must_serialize_to_valid_dml() {
+    let dml = r#"
+    datasource db {
+        provider = "mysql"
+        url      = "mysql://localhost:3306"
+    }
+
+    model User {
+        id        Int    @id
+        firstName String
+        lastName  String
+
+        @@unique([firstName(length: 10), lastName(sort: Desc, length: 20)], name: "names")
+    }
+    "#;
+
+    let schema = parse(dml);
+    let rendered = render_datamodel_to_string(&schema).unwrap();
+
+    assert!(rendered.contains("@@unique([firstName(length: 10), lastName(sort: Desc, length: 20)], name: \"names\")"));
+}
+
+#[test]
+fn unsupported_index_options_must_error() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost:5432"
+    }
+
+    model User {
+        id        Int    @id
+        firstName String
+
+        @@index([firstName(length: 10)])
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_directive_validation_error(
+        "The `length` option is not supported by the current connector.",
+        "index",
+        Span::new(191, 221),
+    ));
+}
@@ -1,4 +1,6 @@
 pub mod builtin_directives;
+pub mod check;
+pub mod constraint_names;
 pub mod default_negative;
 pub mod default_positive;
 pub mod id_negative;
@@ -11,9 +13,11 @@ pub mod relations_legacy;
 pub mod relations_negative;
 pub mod relations_new;
 pub mod relations_positive;
+pub mod schema;
 pub mod unique;
 pub mod unique_criteria;
 pub mod updated_at_negative;
 pub mod updated_at_positive;
+pub mod view;
 
 pub mod arg_parsing;
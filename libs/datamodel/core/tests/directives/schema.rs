@@ -0,0 +1,61 @@
+use crate::common::*;
+use datamodel::ast::Span;
+use datamodel::error::DatamodelError;
+
+#[test]
+fn schema_directive() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost:5432"
+        schemas  = ["auth", "billing"]
+    }
+
+    model User {
+        id Int @id
+    }
+
+    model Account {
+        id Int @id
+
+        @@schema("auth")
+    }
+
+    model Invoice {
+        id Int @id
+
+        @@schema(name: "billing")
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema.assert_has_model("User").assert_with_schema(None);
+    schema.assert_has_model("Account").assert_with_schema(Some("auth"));
+    schema.assert_has_model("Invoice").assert_with_schema(Some("billing"));
+}
+
+#[test]
+fn schema_directive_must_reference_a_declared_schema() {
+    let dml = r#"
+    datasource db {
+        provider = "postgresql"
+        url      = "postgresql://localhost:5432"
+        schemas  = ["auth", "billing"]
+    }
+
+    model Invoice {
+        id Int @id
+
+        @@schema("accounting")
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "The schema `accounting` is not declared in the `schemas` property of the datasource.",
+        "Invoice",
+        Span::new(198, 218),
+    ));
+}
@@ -20,6 +20,10 @@ fn basic_unique_index_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        db_name: None,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
     });
 }
 
@@ -118,6 +122,10 @@ fn the_name_argument_must_work() {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        db_name: None,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
     });
 }
 
@@ -141,12 +149,20 @@ fn multiple_unique_must_work() {
         name: None,
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        db_name: None,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
     });
 
     user_model.assert_has_index(IndexDefinition {
         name: Some("MyIndexName".to_string()),
         fields: vec!["firstName".to_string(), "lastName".to_string()],
         tpe: IndexType::Unique,
+        db_name: None,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
     });
 }
 
@@ -172,6 +188,10 @@ fn multi_field_unique_indexes_on_enum_fields_must_work() {
         name: None,
         fields: vec!["role".to_string()],
         tpe: IndexType::Unique,
+        db_name: None,
+        field_options: vec![],
+        algorithm: None,
+        predicate: None,
     });
 }
 
@@ -0,0 +1,72 @@
+use crate::common::*;
+use datamodel::ast::Span;
+use datamodel::error::DatamodelError;
+
+#[test]
+fn view_directive() {
+    let dml = r#"
+    model User {
+        id Int @id
+    }
+
+    model UserReport {
+        id    Int @id
+        count Int
+
+        @@view
+    }
+
+    model ManagedReport {
+        id Int @id
+
+        @@view("SELECT id FROM User")
+    }
+
+    model NamedReport {
+        id Int @id
+
+        @@view(definition: "SELECT id FROM User")
+    }
+    "#;
+
+    let schema = parse(dml);
+
+    schema.assert_has_model("User").assert_is_view(false);
+    schema
+        .assert_has_model("UserReport")
+        .assert_is_view(true)
+        .assert_with_view_definition(None);
+    schema
+        .assert_has_model("ManagedReport")
+        .assert_is_view(true)
+        .assert_with_view_definition(Some("SELECT id FROM User"));
+    schema
+        .assert_has_model("NamedReport")
+        .assert_is_view(true)
+        .assert_with_view_definition(Some("SELECT id FROM User"));
+}
+
+#[test]
+fn views_must_not_be_part_of_relations() {
+    let dml = r#"
+    model User {
+        id       Int    @id
+        reportId Int
+        report   Report @relation(fields: [reportId], references: [id])
+    }
+
+    model Report {
+        id Int @id
+
+        @@view
+    }
+    "#;
+
+    let errors = parse_error(dml);
+
+    errors.assert_is(DatamodelError::new_model_validation_error(
+        "Views cannot be part of relations.",
+        "User",
+        Span::new(75, 139),
+    ));
+}
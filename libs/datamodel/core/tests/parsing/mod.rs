@@ -1,2 +1,3 @@
 pub mod literals;
 pub mod nice_errors;
+pub mod schema_files;
@@ -0,0 +1,88 @@
+use crate::common::*;
+use datamodel::{ast::Span, dml, SchemaFile, SchemaFiles};
+
+fn schema_files(files: &[(&str, &str)]) -> SchemaFiles {
+    SchemaFiles::new(
+        files
+            .iter()
+            .map(|(name, content)| SchemaFile {
+                name: name.to_string(),
+                content: content.to_string(),
+            })
+            .collect(),
+    )
+}
+
+#[test]
+fn models_can_reference_types_from_other_files() {
+    let enums = r#"
+        enum Role {
+            Admin
+            User
+        }
+    "#;
+
+    let users = r#"
+        model User {
+            id    Int    @id
+            role  Role
+            posts Post[]
+        }
+    "#;
+
+    let posts = r#"
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id])
+        }
+    "#;
+
+    let files = schema_files(&[
+        ("enums.prisma", enums),
+        ("users.prisma", users),
+        ("posts.prisma", posts),
+    ]);
+
+    let schema = parse(files.schema());
+
+    schema
+        .assert_has_model("User")
+        .assert_has_field("role")
+        .assert_enum_type("Role");
+    schema
+        .assert_has_model("Post")
+        .assert_has_field("author")
+        .assert_relation_to("User")
+        .assert_arity(&dml::FieldArity::Required);
+}
+
+#[test]
+fn errors_must_point_at_the_file_they_originate_from() {
+    let files = schema_files(&[
+        ("a.prisma", "model User {\n  id Int @id\n}"),
+        ("b.prisma", "model User {\n  id Int @id\n}"),
+    ]);
+
+    let errors = parse_error(files.schema());
+    let (file, span) = files.file_for_span(errors.errors[0].span()).unwrap();
+
+    assert_eq!(file.name, "b.prisma");
+    assert_eq!(span, Span::new(6, 10));
+    assert!(files.to_pretty_string(&errors).contains("b.prisma:1"));
+}
+
+#[test]
+fn empty_files_must_not_shift_the_spans_of_the_next_files() {
+    let files = schema_files(&[
+        ("a.prisma", "model User {\n  id Int @id\n}"),
+        ("b.prisma", ""),
+        ("c.prisma", "model User {\n  id Int @id\n}"),
+    ]);
+
+    let errors = parse_error(files.schema());
+    let (file, span) = files.file_for_span(errors.errors[0].span()).unwrap();
+
+    assert_eq!(file.name, "c.prisma");
+    assert_eq!(span, Span::new(6, 10));
+}
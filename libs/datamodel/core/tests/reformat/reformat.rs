@@ -614,6 +614,24 @@ model Bl
     assert_reformat(input, expected);
 }
 
+#[test]
+fn test_reformat_composite_type() {
+    let input = r#"
+        type Address { 
+            street               String 
+            zip Int?
+        }
+    "#;
+
+    let expected = r#"type Address {
+  street String
+  zip    Int?
+}
+"#;
+
+    assert_reformat(input, expected);
+}
+
 fn assert_reformat(schema: &str, expected_result: &str) {
     println!("schema: {:?}", schema);
     let result = datamodel::ast::reformat::Reformatter::new(&schema).reformat_to_string();
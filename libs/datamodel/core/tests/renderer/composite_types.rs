@@ -0,0 +1,25 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn composite_types_roundtrip() {
+    let input = indoc!(
+        r#"
+        model User {
+          id      Int       @id
+          address Address?
+          history Address[]
+        }
+
+        /// A postal address.
+        type Address {
+          street String
+          zip    Int?
+        }"#
+    );
+
+    let dml = datamodel::parse_datamodel(input).unwrap();
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    assert_eq!(input, rendered);
+}
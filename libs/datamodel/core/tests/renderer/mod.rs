@@ -1,2 +1,4 @@
+pub mod composite_types;
 pub mod literals;
+pub mod native_types;
 pub mod simplification;
@@ -0,0 +1,30 @@
+use indoc::indoc;
+use pretty_assertions::assert_eq;
+
+#[test]
+fn native_types_roundtrip() {
+    let datasource = indoc!(
+        r#"
+        datasource pg {
+          provider = "postgres"
+          url      = "postgresql://"
+        }
+
+        "#
+    );
+
+    let input = indoc!(
+        r#"
+        model Blog {
+          id     Int      @id @db.SmallInt
+          title  String   @db.VarChar(191)
+          rating Decimal  @db.Decimal(10, 2)
+          posted DateTime @db.Timestamptz(6)
+        }"#
+    );
+
+    let dml = datamodel::parse_datamodel(&format!("{}{}", datasource, input)).unwrap();
+    let rendered = datamodel::render_datamodel_to_string(&dml).unwrap();
+
+    assert_eq!(input, rendered);
+}
@@ -108,3 +108,90 @@ fn should_fail_on_custom_related_types() {
         ast::Span::new(25, 29),
     ));
 }
+
+#[test]
+fn should_fail_on_unknown_native_type() {
+    let dml = r#"
+    datasource pg {
+        provider = "postgres"
+        url = "postgresql://"
+    }
+
+    model Blog {
+        id   Int    @id
+        name String @db.Varchar2(10)
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type Varchar2 is not supported by the current connector.",
+        "db.Varchar2",
+        ast::Span::new(150, 165),
+    ));
+}
+
+#[test]
+fn should_fail_on_native_type_with_wrong_number_of_arguments() {
+    let dml = r#"
+    datasource pg {
+        provider = "postgres"
+        url = "postgresql://"
+    }
+
+    model Blog {
+        id   Int    @id
+        name String @db.VarChar(10, 2)
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type VarChar takes up to 1 arguments, but received 2.",
+        "db.VarChar",
+        ast::Span::new(150, 167),
+    ));
+}
+
+#[test]
+fn should_fail_on_native_type_for_a_different_scalar_type() {
+    let dml = r#"
+    datasource pg {
+        provider = "postgres"
+        url = "postgresql://"
+    }
+
+    model Blog {
+        id   Int    @id
+        name String @db.SmallInt
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native type SmallInt is not compatible with the type String of the field.",
+        "db.SmallInt",
+        ast::Span::new(150, 161),
+    ));
+}
+
+#[test]
+fn should_fail_on_native_type_without_datasource() {
+    let dml = r#"
+    model Blog {
+        id   Int    @id
+        name String @db.VarChar(10)
+    }
+    "#;
+
+    let error = parse_error(dml);
+
+    error.assert_is(DatamodelError::new_directive_validation_error(
+        "Native types can only be used with a datasource.",
+        "db.VarChar",
+        ast::Span::new(63, 77),
+    ));
+}
@@ -123,7 +123,6 @@ fn should_handle_type_mappings() {
 }
 
 #[test]
-#[ignore]
 fn should_handle_type_specifications() {
     let dml = r#"
         datasource pg {
@@ -133,7 +132,7 @@ fn should_handle_type_specifications() {
 
         model Blog {
             id     Int @id
-            bigInt Int @pg.BigInt
+            bigInt BigInt @db.BigInt
         }
     "#;
 
@@ -143,5 +142,38 @@ fn should_handle_type_specifications() {
 
     user_model
         .assert_has_field("bigInt")
-        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::Int, "bigint"));
+        .assert_connector_type(&ScalarFieldType::new("BigInt", ScalarType::BigInt, "int8"));
+}
+
+#[test]
+fn should_handle_type_specifications_with_arguments() {
+    let dml = r#"
+        datasource mysql {
+          provider = "mysql"
+          url = "mysql://"
+        }
+
+        type Title = String @db.VarChar(191)
+
+        model Blog {
+            id     Int    @id
+            title  Title
+            rating Decimal @db.Decimal(10, 2)
+            posted DateTime @db.DateTime(3)
+        }
+    "#;
+
+    let datamodel = parse(dml);
+
+    let user_model = datamodel.assert_has_model("Blog");
+
+    user_model.assert_has_field("title").assert_connector_type(
+        &ScalarFieldType::new("VarChar", ScalarType::String, "varchar(191)").with_args(vec![191]),
+    );
+    user_model.assert_has_field("rating").assert_connector_type(
+        &ScalarFieldType::new("Decimal", ScalarType::Decimal, "decimal(10,2)").with_args(vec![10, 2]),
+    );
+    user_model.assert_has_field("posted").assert_connector_type(
+        &ScalarFieldType::new("DateTime", ScalarType::DateTime, "datetime(3)").with_args(vec![3]),
+    );
 }
@@ -0,0 +1,25 @@
+use crate::prelude::*;
+
+/// A composite type. Values of composite types are stored as JSON documents inside a field of a model.
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct CompositeType {
+    pub name: String,
+    pub fields: Vec<CompositeTypeField>,
+}
+
+#[derive(Debug, PartialEq, Eq, Hash, Clone)]
+pub struct CompositeTypeField {
+    pub name: String,
+    pub type_identifier: TypeIdentifier,
+    pub is_required: bool,
+    pub is_list: bool,
+    pub internal_enum: Option<InternalEnum>,
+    /// The name of the composite type if this field holds a nested composite value.
+    pub composite_type: Option<String>,
+}
+
+impl CompositeType {
+    pub fn find_field(&self, name: &str) -> Option<&CompositeTypeField> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+}
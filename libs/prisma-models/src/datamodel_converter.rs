@@ -29,6 +29,7 @@ impl<'a> DatamodelConverter<'a> {
             models: self.convert_models(),
             relations: self.convert_relations(),
             enums: self.convert_enums(),
+            composite_types: self.convert_composite_types(),
             version: Some("v2".to_string()),
         }
     }
@@ -52,12 +53,34 @@ impl<'a> DatamodelConverter<'a> {
             .collect()
     }
 
+    fn convert_composite_types(&self) -> Vec<CompositeType> {
+        self.datamodel
+            .composite_types()
+            .map(|composite_type| CompositeType {
+                name: composite_type.name.clone(),
+                fields: composite_type
+                    .fields()
+                    .map(|field| CompositeTypeField {
+                        name: field.name.clone(),
+                        type_identifier: field.type_identifier(),
+                        is_required: field.is_required(),
+                        is_list: field.is_list(),
+                        internal_enum: field.internal_enum(self.datamodel),
+                        composite_type: field.composite_type(),
+                    })
+                    .collect(),
+            })
+            .collect()
+    }
+
     fn convert_models(&self) -> Vec<ModelTemplate> {
         self.datamodel
             .models()
             .map(|model| ModelTemplate {
                 name: model.name.clone(),
                 is_embedded: model.is_embedded,
+                is_view: model.is_view,
+                schema: model.schema.clone(),
                 fields: self.convert_fields(&model),
                 manifestation: model.database_name().map(|s| s.to_owned()),
                 id_field_names: model.id_fields.clone(),
@@ -104,6 +127,7 @@ impl<'a> DatamodelConverter<'a> {
                     is_auto_generated_int_id: field.is_auto_generated_int_id(),
                     behaviour: field.behaviour(),
                     internal_enum: field.internal_enum(self.datamodel),
+                    composite_type: field.composite_type(),
                     db_name: field.database_name.clone(),
                     arity: field.arity,
                     default_value: field.default_value.clone(),
@@ -366,28 +390,37 @@ trait DatamodelFieldExtensions {
     fn is_auto_generated_int_id(&self) -> bool;
     fn behaviour(&self) -> Option<FieldBehaviour>;
     fn final_db_name(&self) -> String;
+    fn composite_type(&self) -> Option<String>;
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum>;
     fn internal_enum_value(&self, enum_value: &dml::EnumValue) -> InternalEnumValue;
     // fn default_value(&self) -> Option<dml::DefaultValue>; todo this is not applicable anymore
 }
 
+fn scalar_type_identifier(scalar: dml::ScalarType) -> TypeIdentifier {
+    match scalar {
+        dml::ScalarType::Boolean => TypeIdentifier::Boolean,
+        dml::ScalarType::DateTime => TypeIdentifier::DateTime,
+        dml::ScalarType::Float => TypeIdentifier::Float,
+        dml::ScalarType::Int => TypeIdentifier::Int,
+        dml::ScalarType::String => TypeIdentifier::String,
+        dml::ScalarType::Json => TypeIdentifier::Json,
+        dml::ScalarType::Decimal => TypeIdentifier::Decimal,
+        dml::ScalarType::BigInt => TypeIdentifier::BigInt,
+        dml::ScalarType::Bytes => TypeIdentifier::Bytes,
+    }
+}
+
 impl DatamodelFieldExtensions for dml::Field {
     fn type_identifier(&self) -> TypeIdentifier {
         match &self.field_type {
             dml::FieldType::Enum(x) => TypeIdentifier::Enum(x.clone()),
             dml::FieldType::Relation(_) => TypeIdentifier::String, // Todo: Unused
-            dml::FieldType::Base(scalar, _) => match scalar {
-                dml::ScalarType::Boolean => TypeIdentifier::Boolean,
-                dml::ScalarType::DateTime => TypeIdentifier::DateTime,
-                dml::ScalarType::Float => TypeIdentifier::Float,
-                dml::ScalarType::Int => TypeIdentifier::Int,
-                dml::ScalarType::String => TypeIdentifier::String,
-                dml::ScalarType::Json => TypeIdentifier::Json,
-            },
+            // Composite values are stored as JSON documents.
+            dml::FieldType::Composite(_) => TypeIdentifier::Json,
+            dml::FieldType::Base(scalar, _) => scalar_type_identifier(*scalar),
             dml::FieldType::Unsupported(_) => panic!("These should always be commented out"),
-            dml::FieldType::ConnectorSpecific { .. } => {
-                unimplemented!("Connector Specific types are not supported here yet")
-            }
+            // The native type only matters to the database, the query engine treats it like its scalar type.
+            dml::FieldType::ConnectorSpecific(native_type) => scalar_type_identifier(native_type.prisma_type()),
         }
     }
 
@@ -421,7 +454,7 @@ impl DatamodelFieldExtensions for dml::Field {
             _ => false,
         };
 
-        let is_an_int = self.type_identifier() == TypeIdentifier::Int;
+        let is_an_int = matches!(self.type_identifier(), TypeIdentifier::Int | TypeIdentifier::BigInt);
 
         is_autogenerated_id && is_an_int
     }
@@ -438,6 +471,13 @@ impl DatamodelFieldExtensions for dml::Field {
         self.final_database_name().to_owned()
     }
 
+    fn composite_type(&self) -> Option<String> {
+        match &self.field_type {
+            dml::FieldType::Composite(name) => Some(name.clone()),
+            _ => None,
+        }
+    }
+
     fn internal_enum(&self, datamodel: &dml::Datamodel) -> Option<InternalEnum> {
         match self.field_type {
             dml::FieldType::Enum(ref name) => {
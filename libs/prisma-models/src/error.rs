@@ -12,6 +12,9 @@ pub enum DomainError {
     #[fail(display = "Relation `{}` not found", name)]
     RelationNotFound { name: String },
 
+    #[fail(display = "Composite type `{}` not found", name)]
+    CompositeTypeNotFound { name: String },
+
     #[fail(display = "ScalarField `{}` on model `{}` not found", name, model)]
     ScalarFieldNotFound { name: String, model: String },
 
@@ -66,6 +66,9 @@ pub enum TypeIdentifier {
     DateTime,
     UUID,
     Int,
+    Decimal,
+    BigInt,
+    Bytes,
 }
 
 impl Field {
@@ -170,6 +173,9 @@ impl From<ScalarType> for TypeIdentifier {
             ScalarType::Boolean => Self::Boolean,
             ScalarType::DateTime => Self::DateTime,
             ScalarType::Json => Self::Json,
+            ScalarType::Decimal => Self::Decimal,
+            ScalarType::BigInt => Self::BigInt,
+            ScalarType::Bytes => Self::Bytes,
         }
     }
 }
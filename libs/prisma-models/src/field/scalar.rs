@@ -25,6 +25,7 @@ pub struct ScalarFieldTemplate {
     pub is_auto_generated_int_id: bool,
     pub behaviour: Option<FieldBehaviour>,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<String>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
     pub default_value: Option<DefaultValue>,
@@ -39,6 +40,7 @@ pub struct ScalarField {
     pub is_id: bool,
     pub is_auto_generated_int_id: bool,
     pub internal_enum: Option<InternalEnum>,
+    pub composite_type: Option<String>,
     pub behaviour: Option<FieldBehaviour>,
     pub arity: FieldArity,
     pub db_name: Option<String>,
@@ -61,6 +63,7 @@ impl Hash for ScalarField {
         self.is_id.hash(state);
         self.is_auto_generated_int_id.hash(state);
         self.internal_enum.hash(state);
+        self.composite_type.hash(state);
         self.behaviour.hash(state);
         self.is_unique.hash(state);
         self.model().hash(state);
@@ -78,6 +81,7 @@ impl PartialEq for ScalarField {
             && self.is_id == other.is_id
             && self.is_auto_generated_int_id == other.is_auto_generated_int_id
             && self.internal_enum == other.internal_enum
+            && self.composite_type == other.composite_type
             && self.behaviour == other.behaviour
             && self.default_value == other.default_value
             && self.is_unique == other.is_unique
@@ -112,6 +116,7 @@ impl ScalarFieldTemplate {
             read_only: OnceCell::new(),
             is_unique: self.is_unique,
             internal_enum: self.internal_enum,
+            composite_type: self.composite_type,
             behaviour: self.behaviour,
             arity: self.arity,
             db_name: self.db_name,
@@ -174,8 +179,24 @@ impl ScalarField {
         &self.db_name.as_ref().unwrap_or(&self.name)
     }
 
+    /// The composite type of the values stored in this field, if any.
+    pub fn composite_type(&self) -> Option<CompositeType> {
+        let name = self.composite_type.as_ref()?;
+        let internal_data_model = self.internal_data_model();
+
+        internal_data_model.find_composite_type(name).ok().cloned()
+    }
+
+    pub fn is_composite(&self) -> bool {
+        self.composite_type.is_some()
+    }
+
     pub fn type_identifier_with_arity(&self) -> (TypeIdentifier, FieldArity) {
-        (self.type_identifier.clone(), self.arity)
+        match self.arity {
+            // Lists of composite values are stored as a single JSON array, not as a scalar list.
+            FieldArity::List if self.is_composite() => (self.type_identifier.clone(), FieldArity::Required),
+            arity => (self.type_identifier.clone(), arity),
+        }
     }
 
     pub fn is_read_only(&self) -> bool {
@@ -10,12 +10,14 @@ pub struct InternalDataModelTemplate {
     pub models: Vec<ModelTemplate>,
     pub relations: Vec<RelationTemplate>,
     pub enums: Vec<InternalEnum>,
+    pub composite_types: Vec<CompositeType>,
     pub version: Option<String>,
 }
 
 #[derive(DebugStub)]
 pub struct InternalDataModel {
     pub enums: Vec<InternalEnum>,
+    pub composite_types: Vec<CompositeType>,
     version: Option<String>,
 
     /// Todo clarify / rename.
@@ -96,6 +98,7 @@ impl InternalDataModelTemplate {
             models: OnceCell::new(),
             relations: OnceCell::new(),
             enums: self.enums,
+            composite_types: self.composite_types,
             version: self.version,
             db_name,
             relation_fields: OnceCell::new(),
@@ -142,6 +145,13 @@ impl InternalDataModel {
             .ok_or_else(|| DomainError::ModelNotFound { name: name.to_string() })
     }
 
+    pub fn find_composite_type(&self, name: &str) -> crate::Result<&CompositeType> {
+        self.composite_types
+            .iter()
+            .find(|composite_type| composite_type.name == name)
+            .ok_or_else(|| DomainError::CompositeTypeNotFound { name: name.to_string() })
+    }
+
     pub fn find_relation(&self, name: &str) -> crate::Result<RelationWeakRef> {
         self.relations
             .get()
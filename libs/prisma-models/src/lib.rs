@@ -3,6 +3,7 @@
 #[macro_use]
 extern crate debug_stub_derive;
 
+mod composite_type;
 mod datamodel_converter;
 mod error;
 mod field;
@@ -21,6 +22,7 @@ pub mod sql_ext;
 
 pub mod prelude;
 
+pub use composite_type::*;
 pub use datamodel::dml;
 pub use datamodel_converter::*;
 pub use error::*;
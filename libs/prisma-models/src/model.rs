@@ -12,6 +12,8 @@ pub type ModelWeakRef = Weak<Model>;
 pub struct ModelTemplate {
     pub name: String,
     pub is_embedded: bool,
+    pub is_view: bool,
+    pub schema: Option<String>,
     pub fields: Vec<FieldTemplate>,
     pub manifestation: Option<String>,
     pub id_field_names: Vec<String>,
@@ -22,6 +24,8 @@ pub struct ModelTemplate {
 pub struct Model {
     pub name: String,
     pub is_embedded: bool,
+    pub is_view: bool,
+    pub schema: Option<String>,
 
     manifestation: Option<String>,
     fields: OnceCell<Fields>,
@@ -36,6 +40,8 @@ impl ModelTemplate {
         let model = Arc::new(Model {
             name: self.name,
             is_embedded: self.is_embedded,
+            is_view: self.is_view,
+            schema: self.schema,
             fields: OnceCell::new(),
             indexes: OnceCell::new(),
             manifestation: self.manifestation,
@@ -148,6 +154,14 @@ impl Model {
         self.manifestation.as_ref().map(|m| m.as_ref())
     }
 
+    /// The database schema the model's table lives in.
+    pub fn db_schema(&self) -> String {
+        match &self.schema {
+            Some(schema) => schema.clone(),
+            None => self.internal_data_model().db_name.clone(),
+        }
+    }
+
     pub fn internal_data_model(&self) -> InternalDataModelRef {
         self.internal_data_model
             .upgrade()
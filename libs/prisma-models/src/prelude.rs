@@ -1,3 +1,4 @@
+pub use crate::composite_type::*;
 pub use crate::datamodel_converter::*;
 pub use crate::error::*;
 pub use crate::field::*;
@@ -21,6 +21,9 @@ impl PrismaValueExtensions for PrismaValue {
             (val @ PrismaValue::DateTime(_), TypeIdentifier::DateTime) => val,
             (val @ PrismaValue::Enum(_), TypeIdentifier::Enum(_)) => val,
             (val @ PrismaValue::Uuid(_), TypeIdentifier::UUID) => val,
+            (val @ PrismaValue::Decimal(_), TypeIdentifier::Decimal) => val,
+            (val @ PrismaValue::BigInt(_), TypeIdentifier::BigInt) => val,
+            (val @ PrismaValue::Bytes(_), TypeIdentifier::Bytes) => val,
 
             // Valid String coercions
             (PrismaValue::Int(i), TypeIdentifier::String) => PrismaValue::String(format!("{}", i)),
@@ -31,6 +34,8 @@ impl PrismaValueExtensions for PrismaValue {
             }
             (PrismaValue::Enum(e), TypeIdentifier::String) => PrismaValue::String(e),
             (PrismaValue::Uuid(u), TypeIdentifier::String) => PrismaValue::String(u.to_string()),
+            (PrismaValue::Decimal(d), TypeIdentifier::String) => PrismaValue::String(d.to_string()),
+            (PrismaValue::BigInt(i), TypeIdentifier::String) => PrismaValue::String(i.to_string()),
 
             // Valid Int coersions
             (PrismaValue::String(s), TypeIdentifier::Int) => match s.parse() {
@@ -43,6 +48,12 @@ impl PrismaValueExtensions for PrismaValue {
                 }
             },
             (PrismaValue::Float(f), TypeIdentifier::Int) => PrismaValue::Int(f.trunc().to_i64().unwrap()),
+            (PrismaValue::BigInt(i), TypeIdentifier::Int) => PrismaValue::Int(i),
+
+            // Valid exact number coercions
+            (PrismaValue::Int(i), TypeIdentifier::BigInt) => PrismaValue::BigInt(i),
+            (PrismaValue::Int(i), TypeIdentifier::Decimal) => PrismaValue::Decimal(i.into()),
+            (PrismaValue::Float(f), TypeIdentifier::Decimal) => PrismaValue::Decimal(f),
 
             // Todo other coercions here
 
@@ -33,12 +33,32 @@ pub enum PrismaValue {
 
     #[serde(serialize_with = "serialize_decimal")]
     Float(Decimal),
+
+    /// An exact decimal, serialized as a string to preserve its precision.
+    #[serde(serialize_with = "serialize_exact_decimal")]
+    Decimal(Decimal),
+
+    /// A 64 bit integer, serialized as a string because it can exceed the safe integer range of JavaScript.
+    #[serde(serialize_with = "serialize_bigint")]
+    BigInt(i64),
+
+    /// Raw bytes, base64-encoded on the wire.
+    #[serde(serialize_with = "serialize_bytes")]
+    Bytes(Vec<u8>),
 }
 
 pub fn stringify_date(date: &DateTime<Utc>) -> String {
     format!("{}", date.format("%Y-%m-%dT%H:%M:%S%.3fZ"))
 }
 
+pub fn encode_bytes(bytes: &[u8]) -> String {
+    base64::encode(bytes)
+}
+
+pub fn decode_bytes(s: &str) -> PrismaValueResult<Vec<u8>> {
+    base64::decode(s).map_err(|_| ConversionFailure::new("base64 encoded bytes", "PrismaValue::Bytes"))
+}
+
 impl TryFrom<serde_json::Value> for PrismaValue {
     type Error = crate::error::ConversionFailure;
 
@@ -83,6 +103,27 @@ where
     decimal.to_f64().expect("Decimal is not a f64.").serialize(serializer)
 }
 
+fn serialize_exact_decimal<S>(decimal: &Decimal, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    decimal.to_string().serialize(serializer)
+}
+
+fn serialize_bigint<S>(int: &i64, serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    int.to_string().serialize(serializer)
+}
+
+fn serialize_bytes<S>(bytes: &[u8], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+{
+    encode_bytes(bytes).serialize(serializer)
+}
+
 impl PrismaValue {
     pub fn is_null(&self) -> bool {
         match self {
@@ -119,6 +160,9 @@ impl fmt::Display for PrismaValue {
         match self {
             PrismaValue::String(x) => x.fmt(f),
             PrismaValue::Float(x) => x.fmt(f),
+            PrismaValue::Decimal(x) => x.fmt(f),
+            PrismaValue::BigInt(x) => x.fmt(f),
+            PrismaValue::Bytes(x) => encode_bytes(x).fmt(f),
             PrismaValue::Boolean(x) => x.fmt(f),
             PrismaValue::DateTime(x) => x.fmt(f),
             PrismaValue::Enum(x) => x.fmt(f),
@@ -29,6 +29,9 @@ impl<'a> From<PrismaValue> for Value<'a> {
         match pv {
             PrismaValue::String(s) => s.into(),
             PrismaValue::Float(f) => f.into(),
+            PrismaValue::Decimal(d) => d.into(),
+            PrismaValue::BigInt(i) => i.into(),
+            PrismaValue::Bytes(b) => Value::Bytes(b.into()),
             PrismaValue::Boolean(b) => b.into(),
             PrismaValue::DateTime(d) => d.into(),
             PrismaValue::Enum(e) => Value::Enum(e.into()),
@@ -33,6 +33,15 @@ pub trait SqlSchemaDescriberBackend: Send + Sync + 'static {
     async fn get_metadata(&self, schema: &str) -> SqlSchemaDescriberResult<SQLMetadata>;
     /// Describe a database schema.
     async fn describe(&self, schema: &str) -> SqlSchemaDescriberResult<SqlSchema>;
+    /// Describe a database schema, together with the tables of additional schemas. Only Postgres
+    /// supports additional schemas, the other backends describe `schema` alone.
+    async fn describe_with_schemas(
+        &self,
+        schema: &str,
+        _additional_schemas: &[String],
+    ) -> SqlSchemaDescriberResult<SqlSchema> {
+        self.describe(schema).await
+    }
 }
 
 #[derive(Serialize, Deserialize)]
@@ -51,6 +60,9 @@ pub struct SqlSchema {
     pub enums: Vec<Enum>,
     /// The schema's sequences, unique to Postgres.
     pub sequences: Vec<Sequence>,
+    /// The schema's views.
+    #[serde(default)]
+    pub views: Vec<View>,
 }
 
 impl SqlSchema {
@@ -63,9 +75,16 @@ impl SqlSchema {
         self.tables.iter().find(|x| x.name == name)
     }
 
-    /// Get an enum.
+    /// Get a table by schema and name. Tables in the described schema have no schema.
+    pub fn get_table_in_schema(&self, schema: Option<&str>, name: &str) -> Option<&Table> {
+        self.tables
+            .iter()
+            .find(|table| table.name == name && table.schema.as_deref() == schema)
+    }
+
+    /// Get an enum of the described schema.
     pub fn get_enum(&self, name: &str) -> Option<&Enum> {
-        self.enums.iter().find(|x| x.name == name)
+        self.enums.iter().find(|x| x.name == name && x.schema.is_none())
     }
 
     pub fn table(&self, name: &str) -> core::result::Result<&Table, String> {
@@ -79,9 +98,14 @@ impl SqlSchema {
         self.table(&name).unwrap()
     }
 
-    /// Get a sequence.
+    /// Get a view.
+    pub fn get_view(&self, name: &str) -> Option<&View> {
+        self.views.iter().find(|x| x.name == name)
+    }
+
+    /// Get a sequence of the described schema.
     pub fn get_sequence(&self, name: &str) -> Option<&Sequence> {
-        self.sequences.iter().find(|x| x.name == name)
+        self.sequences.iter().find(|x| x.name == name && x.schema.is_none())
     }
 
     pub fn empty() -> SqlSchema {
@@ -89,6 +113,7 @@ impl SqlSchema {
             tables: Vec::new(),
             enums: Vec::new(),
             sequences: Vec::new(),
+            views: Vec::new(),
         }
     }
 }
@@ -99,6 +124,9 @@ impl SqlSchema {
 pub struct Table {
     /// The table's name.
     pub name: String,
+    /// The schema the table lives in, when it is not the described schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     /// The table's columns.
     pub columns: Vec<Column>,
     /// The table's indices.
@@ -107,6 +135,9 @@ pub struct Table {
     pub primary_key: Option<PrimaryKey>,
     /// The table's foreign keys.
     pub foreign_keys: Vec<ForeignKey>,
+    /// The table's check constraints.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub check_constraints: Vec<CheckConstraint>,
 }
 
 impl Table {
@@ -152,9 +183,29 @@ impl Table {
             index.tpe == IndexType::Unique
                 && index.columns.len() == 1
                 && index.columns.contains(&column_name.to_owned())
+                && !index.has_options()
         })
     }
 }
+
+/// A view found in a schema.
+#[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct View {
+    /// The view's name.
+    pub name: String,
+    /// The SQL query defining the view, if the database exposes it.
+    pub definition: Option<String>,
+    /// The view's columns.
+    pub columns: Vec<Column>,
+}
+
+impl View {
+    pub fn column(&self, name: &str) -> Option<&Column> {
+        self.columns.iter().find(|c| c.name == name)
+    }
+}
+
 /// The type of an index.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
@@ -184,22 +235,103 @@ pub struct Index {
     pub columns: Vec<String>,
     /// Type of index.
     pub tpe: IndexType,
+    /// Per-column options, in the order of `columns`. Empty if all columns use the default options.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub column_options: Vec<IndexColumnOptions>,
+    /// The index method, `None` for the default method of the database.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub algorithm: Option<IndexAlgorithm>,
+    /// The condition of a partial index.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub predicate: Option<String>,
 }
 
 impl Index {
     pub fn is_unique(&self) -> bool {
         self.tpe == IndexType::Unique
     }
+
+    /// Whether any column options, a non-default method or a predicate are set on the index.
+    pub fn has_options(&self) -> bool {
+        !self.column_options.is_empty() || self.algorithm.is_some() || self.predicate.is_some()
+    }
+
+    /// The options of the column at the given position in `columns`.
+    pub fn column_options(&self, position: usize) -> IndexColumnOptions {
+        self.column_options.get(position).cloned().unwrap_or_default()
+    }
 }
 
-/// The primary key of a table.
+/// Options of a single column in an index.
+#[derive(PartialEq, Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct IndexColumnOptions {
+    /// The sort order of the column in the index.
+    pub sort_order: SortOrder,
+    /// The length of the indexed prefix of the column.
+    pub length: Option<u32>,
+}
+
+impl IndexColumnOptions {
+    pub fn is_default(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+/// The sort order of an index column.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum SortOrder {
+    /// Ascending.
+    Asc,
+    /// Descending.
+    Desc,
+}
+
+impl Default for SortOrder {
+    fn default() -> Self {
+        SortOrder::Asc
+    }
+}
+
+/// The method of an index, when it is not the default of the database.
+#[derive(PartialEq, Debug, Clone, Copy, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub enum IndexAlgorithm {
+    BTree,
+    Hash,
+    Gin,
+    Gist,
+    Brin,
+}
+
+/// A check constraint of a table.
 #[derive(PartialEq, Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
+pub struct CheckConstraint {
+    /// The constraint's name.
+    pub name: String,
+    /// The SQL boolean expression checked by the constraint, as returned by the database.
+    pub expression: String,
+}
+
+/// The primary key of a table.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
 pub struct PrimaryKey {
     /// Columns.
     pub columns: Vec<String>,
     /// The sequence optionally seeding this primary key.
     pub sequence: Option<Sequence>,
+    /// The name of the primary key constraint, when available. Only Postgres lets us name it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub constraint_name: Option<String>,
+}
+
+impl PartialEq for PrimaryKey {
+    fn eq(&self, other: &Self) -> bool {
+        self.columns == other.columns && self.sequence == other.sequence
+    }
 }
 
 impl PrimaryKey {
@@ -238,6 +370,20 @@ pub struct ColumnType {
     pub full_data_type: String,
     /// The maximum length for character or string bit types if specified.
     pub character_maximum_length: Option<i64>,
+    /// The precision of numeric types, when the database reports it separately from the full data type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub numeric_precision: Option<i64>,
+    /// The scale of numeric types, when the database reports it separately from the full data type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub numeric_scale: Option<i64>,
+    /// The fractional seconds precision of time and timestamp types, when the database reports it
+    /// separately from the full data type.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub datetime_precision: Option<i64>,
+    /// The native type the column was declared with in the datamodel, e.g. `varchar(255)`.
+    /// Only set on calculated schemas, never by the describers.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub native_type: Option<String>,
     /// The family of the raw type.
     pub family: ColumnTypeFamily,
     /// The arity of the column.
@@ -250,6 +396,10 @@ impl ColumnType {
             data_type: "".to_string(),
             full_data_type: "".to_string(),
             character_maximum_length: None,
+            numeric_precision: None,
+            numeric_scale: None,
+            datetime_precision: None,
+            native_type: None,
             family,
             arity,
         }
@@ -367,6 +517,9 @@ pub struct ForeignKey {
     pub columns: Vec<String>,
     /// Referenced table.
     pub referenced_table: String,
+    /// The schema of the referenced table, when it is not the described schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub referenced_schema: Option<String>,
     /// Referenced columns.
     pub referenced_columns: Vec<String>,
     /// Action on deletion.
@@ -377,6 +530,7 @@ impl PartialEq for ForeignKey {
     fn eq(&self, other: &Self) -> bool {
         self.columns == other.columns
             && self.referenced_table == other.referenced_table
+            && self.referenced_schema == other.referenced_schema
             && self.referenced_columns == other.referenced_columns
     }
 }
@@ -387,6 +541,9 @@ impl PartialEq for ForeignKey {
 pub struct Enum {
     /// Enum name.
     pub name: String,
+    /// The schema the enum lives in, when it is not the described schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     /// Possible enum values.
     pub values: Vec<String>,
 }
@@ -397,6 +554,9 @@ pub struct Enum {
 pub struct Sequence {
     /// Sequence name.
     pub name: String,
+    /// The schema the sequence lives in, when it is not the described schema.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     /// Sequence initial value.
     pub initial_value: u32,
     /// Sequence allocation size.
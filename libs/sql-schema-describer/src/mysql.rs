@@ -56,18 +56,27 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = get_all_columns(self.conn.as_ref(), schema, &flavour).await;
         let mut indexes = get_all_indexes(self.conn.as_ref(), schema).await;
         let mut fks = get_foreign_keys(self.conn.as_ref(), schema).await;
+        let mut check_constraints = get_check_constraints(self.conn.as_ref(), schema, &flavour).await;
 
         let mut enums = vec![];
         for table_name in &table_names {
-            let (table, enms) = self.get_table(table_name, &mut columns, &mut indexes, &mut fks);
+            let (table, enms) =
+                self.get_table(table_name, &mut columns, &mut indexes, &mut fks, &mut check_constraints);
             tables.push(table);
             enums.extend(enms.iter().cloned());
         }
 
+        let mut views = vec![];
+        for (view, enms) in self.get_views(schema, &mut columns).await {
+            views.push(view);
+            enums.extend(enms);
+        }
+
         Ok(SqlSchema {
             tables,
             enums,
             sequences: vec![],
+            views,
         })
     }
 }
@@ -99,7 +108,7 @@ impl SqlSchemaDescriber {
         debug!("Getting table names");
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = ?
-            -- Views are not supported yet
+            -- Views are described separately
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self
@@ -120,6 +129,39 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(
+        &self,
+        schema: &str,
+        columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
+    ) -> Vec<(View, Vec<Enum>)> {
+        debug!("Getting views");
+        let sql = "SELECT table_name AS view_name, view_definition AS view_definition
+            FROM information_schema.views
+            WHERE table_schema = ?
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+        let views = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+                let definition = row.get("view_definition").and_then(|x| x.to_string());
+                let (columns, enums) = columns.remove(&name).unwrap_or_default();
+
+                (
+                    View {
+                        name,
+                        definition,
+                        columns,
+                    },
+                    enums,
+                )
+            })
+            .collect();
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         use rust_decimal::prelude::*;
 
@@ -150,19 +192,23 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, (Vec<Column>, Vec<Enum>)>,
         indexes: &mut HashMap<String, (BTreeMap<String, Index>, Option<PrimaryKey>)>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> (Table, Vec<Enum>) {
         debug!("Getting table '{}'", name);
         let (columns, enums) = columns.remove(name).expect("table columns not found");
         let (indices, primary_key) = indexes.remove(name).unwrap_or_else(|| (BTreeMap::new(), None));
 
         let foreign_keys = foreign_keys.remove(name).unwrap_or_default();
+        let check_constraints = check_constraints.remove(name).unwrap_or_default();
         (
             Table {
                 name: name.to_string(),
+                schema: None,
                 columns,
                 foreign_keys,
                 indices: indices.into_iter().map(|(_k, v)| v).collect(),
                 primary_key,
+                check_constraints,
             },
             enums,
         )
@@ -332,7 +378,9 @@ async fn get_all_indexes(
                 non_unique AS non_unique,
                 column_name AS column_name,
                 seq_in_index AS seq_in_index,
-                table_name AS table_name
+                table_name AS table_name,
+                collation AS collation,
+                sub_part AS sub_part
             FROM INFORMATION_SCHEMA.STATISTICS
             WHERE table_schema = ?
             ORDER BY index_name, seq_in_index
@@ -351,6 +399,14 @@ async fn get_all_indexes(
         let index_name = row.get("index_name").and_then(|x| x.to_string()).expect("index_name");
         let is_unique = !row.get("non_unique").and_then(|x| x.as_bool()).expect("non_unique");
         let column_name = row.get("column_name").and_then(|x| x.to_string()).expect("column_name");
+        // The collation is `D` for descending columns, `A` for ascending and NULL for unsorted.
+        let sort_order = match row.get("collation").and_then(|x| x.to_string()).as_deref() {
+            Some("D") => SortOrder::Desc,
+            _ => SortOrder::Asc,
+        };
+        // The number of indexed characters if only a prefix of the column is indexed.
+        let length = row.get("sub_part").and_then(|x| x.as_i64()).map(|x| x as u32);
+        let column_options = IndexColumnOptions { sort_order, length };
 
         // Multi-column indices will return more than one row (with different column_name values).
         // We cannot assume that one row corresponds to one index.
@@ -378,6 +434,7 @@ async fn get_all_indexes(
                         Some(PrimaryKey {
                             columns: vec![column_name],
                             sequence: None,
+                            constraint_name: None,
                         }),
                     );
                 }
@@ -386,6 +443,7 @@ async fn get_all_indexes(
             if indexes_map.contains_key(&index_name) {
                 indexes_map.get_mut(&index_name).map(|index: &mut Index| {
                     index.columns.push(column_name);
+                    index.column_options.push(column_options);
                 });
             } else {
                 indexes_map.insert(
@@ -397,12 +455,23 @@ async fn get_all_indexes(
                             true => IndexType::Unique,
                             false => IndexType::Normal,
                         },
+                        column_options: vec![column_options],
+                        algorithm: None,
+                        predicate: None,
                     },
                 );
             }
         }
     }
 
+    for (indexes_map, _) in map.values_mut() {
+        for index in indexes_map.values_mut() {
+            if index.column_options.iter().all(|options| options.is_default()) {
+                index.column_options.clear();
+            }
+        }
+    }
+
     map
 }
 
@@ -505,6 +574,7 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
                     constraint_name: Some(constraint_name.clone()),
                     columns: vec![column],
                     referenced_table,
+                    referenced_schema: None,
                     referenced_columns: vec![referenced_column],
                     on_delete_action,
                 };
@@ -524,6 +594,98 @@ async fn get_foreign_keys(conn: &dyn Queryable, schema_name: &str) -> HashMap<St
         .collect()
 }
 
+/// Returns a map from table name to check constraints. Check constraints are only exposed by MySQL
+/// 8.0.16+ and MariaDB 10.2+, older versions return no constraints.
+async fn get_check_constraints(
+    conn: &dyn Queryable,
+    schema_name: &str,
+    flavour: &Flavour,
+) -> HashMap<String, Vec<CheckConstraint>> {
+    let mut map: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+    let table_exists_sql = "
+        SELECT COUNT(*) AS count
+        FROM information_schema.tables
+        WHERE table_schema = 'information_schema' AND table_name = 'CHECK_CONSTRAINTS'
+    ";
+    let table_exists = conn
+        .query_raw(table_exists_sql, &[])
+        .await
+        .expect("checking for the check constraints table")
+        .into_iter()
+        .next()
+        .and_then(|row| row.get("count").and_then(|x| x.as_i64()))
+        .unwrap_or(0)
+        > 0;
+
+    if !table_exists {
+        return map;
+    }
+
+    // MariaDB check constraints are scoped to their table, MySQL ones to the schema.
+    let sql = match flavour {
+        Flavour::MariaDb => {
+            "
+            SELECT
+                cc.table_name table_name,
+                cc.constraint_name constraint_name,
+                cc.check_clause check_clause
+            FROM information_schema.check_constraints AS cc
+            WHERE cc.constraint_schema = ?
+            ORDER BY table_name, constraint_name
+            "
+        }
+        Flavour::Mysql => {
+            "
+            SELECT
+                tc.table_name table_name,
+                cc.constraint_name constraint_name,
+                cc.check_clause check_clause
+            FROM information_schema.check_constraints AS cc
+            INNER JOIN information_schema.table_constraints AS tc ON
+                tc.constraint_schema = cc.constraint_schema
+                AND tc.constraint_name = cc.constraint_name
+            WHERE cc.constraint_schema = ? AND tc.constraint_type = 'CHECK'
+            ORDER BY table_name, constraint_name
+            "
+        }
+    };
+
+    debug!("describing check constraints, SQL: '{}'", sql);
+
+    let result_set = conn
+        .query_raw(sql, &[schema_name.into()])
+        .await
+        .expect("querying for check constraints");
+
+    for row in result_set.into_iter() {
+        debug!("Got check constraint row {:#?}", row);
+        let table_name = row
+            .get("table_name")
+            .and_then(|x| x.to_string())
+            .expect("get table_name");
+        let name = row
+            .get("constraint_name")
+            .and_then(|x| x.to_string())
+            .expect("get constraint_name");
+        let expression = row
+            .get("check_clause")
+            .and_then(|x| x.to_string())
+            .expect("get check_clause");
+
+        // MariaDB implements JSON columns as LONGTEXT with a `json_valid` check named after the column.
+        if matches!(flavour, Flavour::MariaDb) && expression == format!("json_valid(`{}`)", name) {
+            continue;
+        }
+
+        map.entry(table_name)
+            .or_default()
+            .push(CheckConstraint { name, expression });
+    }
+
+    map
+}
+
 fn get_column_type_and_enum(
     table: &str,
     column_name: &str,
@@ -580,6 +742,10 @@ fn get_column_type_and_enum(
         data_type: data_type.to_owned(),
         full_data_type: full_data_type.to_owned(),
         character_maximum_length,
+        numeric_precision: None,
+        numeric_scale: None,
+        datetime_precision: None,
+        native_type: None,
         family: family.clone(),
         arity,
     };
@@ -589,6 +755,7 @@ fn get_column_type_and_enum(
             tpe,
             Some(Enum {
                 name: name.clone(),
+                schema: None,
                 values: extract_enum_values(&full_data_type),
             }),
         ),
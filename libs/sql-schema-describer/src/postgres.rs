@@ -32,20 +32,83 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
         let mut columns = self.get_columns(schema, &enums).await;
         let mut foreign_keys = self.get_foreign_keys(schema).await;
         let mut indexes = self.get_indices(schema, &sequences).await;
+        let mut check_constraints = self.get_check_constraints(schema).await;
 
         let table_names = self.get_table_names(schema).await;
         let mut tables = Vec::with_capacity(table_names.len());
 
         for table_name in &table_names {
-            tables.push(self.get_table(&table_name, &mut columns, &mut foreign_keys, &mut indexes));
+            tables.push(self.get_table(
+                &table_name,
+                &mut columns,
+                &mut foreign_keys,
+                &mut indexes,
+                &mut check_constraints,
+            ));
         }
 
+        let views = self.get_views(schema, &mut columns).await;
+
         Ok(SqlSchema {
             enums,
             sequences,
             tables,
+            views,
         })
     }
+
+    async fn describe_with_schemas(
+        &self,
+        schema: &str,
+        additional_schemas: &[String],
+    ) -> SqlSchemaDescriberResult<SqlSchema> {
+        let mut sql_schema = self.describe(schema).await?;
+
+        for additional_schema in additional_schemas.iter().filter(|name| name.as_str() != schema) {
+            debug!("describing additional schema '{}'", additional_schema);
+            let sequences = self.get_sequences(additional_schema).await?;
+            let enums = self.get_enums(additional_schema).await?;
+            let mut columns = self.get_columns(additional_schema, &enums).await;
+            let mut foreign_keys = self.get_foreign_keys(additional_schema).await;
+            let mut indexes = self.get_indices(additional_schema, &sequences).await;
+            let mut check_constraints = self.get_check_constraints(additional_schema).await;
+
+            for table_name in self.get_table_names(additional_schema).await {
+                let mut table = self.get_table(
+                    &table_name,
+                    &mut columns,
+                    &mut foreign_keys,
+                    &mut indexes,
+                    &mut check_constraints,
+                );
+
+                // Qualify the foreign keys relative to the main schema.
+                for fk in table.foreign_keys.iter_mut() {
+                    fk.referenced_schema = match fk.referenced_schema.take() {
+                        None => Some(additional_schema.clone()),
+                        Some(referenced_schema) if referenced_schema == schema => None,
+                        Some(referenced_schema) => Some(referenced_schema),
+                    };
+                }
+
+                table.schema = Some(additional_schema.clone());
+                sql_schema.tables.push(table);
+            }
+
+            sql_schema.enums.extend(enums.into_iter().map(|r#enum| Enum {
+                schema: Some(additional_schema.clone()),
+                ..r#enum
+            }));
+            sql_schema
+                .sequences
+                .extend(sequences.into_iter().map(|sequence| Sequence {
+                    schema: Some(additional_schema.clone()),
+                    ..sequence
+                }));
+        }
+
+        Ok(sql_schema)
+    }
 }
 
 impl SqlSchemaDescriber {
@@ -75,7 +138,7 @@ impl SqlSchemaDescriber {
         debug!("Getting table names");
         let sql = "SELECT table_name as table_name FROM information_schema.tables
             WHERE table_schema = $1
-            -- Views are not supported yet
+            -- Views are described separately
             AND table_type = 'BASE TABLE'
             ORDER BY table_name";
         let rows = self
@@ -96,6 +159,32 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(&self, schema: &str, columns: &mut HashMap<String, Vec<Column>>) -> Vec<View> {
+        debug!("Getting views");
+        let sql = "SELECT table_name AS view_name, view_definition AS view_definition
+            FROM information_schema.views
+            WHERE table_schema = $1
+            ORDER BY table_name";
+        let rows = self.conn.query_raw(sql, &[schema.into()]).await.expect("get views");
+        let views = rows
+            .into_iter()
+            .map(|row| {
+                let name = row.get("view_name").and_then(|x| x.to_string()).expect("get view name");
+                let definition = row.get("view_definition").and_then(|x| x.to_string());
+                let columns = columns.remove(&name).unwrap_or_else(Vec::new);
+
+                View {
+                    name,
+                    definition,
+                    columns,
+                }
+            })
+            .collect();
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, schema: &str) -> usize {
         debug!("Getting db size");
         let sql =
@@ -118,17 +207,21 @@ impl SqlSchemaDescriber {
         columns: &mut HashMap<String, Vec<Column>>,
         foreign_keys: &mut HashMap<String, Vec<ForeignKey>>,
         indices: &mut HashMap<String, (Vec<Index>, Option<PrimaryKey>)>,
+        check_constraints: &mut HashMap<String, Vec<CheckConstraint>>,
     ) -> Table {
         debug!("Getting table '{}'", name);
         let (indices, primary_key) = indices.remove(name).unwrap_or_else(|| (Vec::new(), None));
         let foreign_keys = foreign_keys.remove(name).unwrap_or_else(Vec::new);
+        let check_constraints = check_constraints.remove(name).unwrap_or_else(Vec::new);
         let columns = columns.remove(name).expect("could not get columns");
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             foreign_keys,
             indices,
             primary_key,
+            check_constraints,
         }
     }
 
@@ -142,6 +235,9 @@ impl SqlSchemaDescriber {
                 data_type,
                 udt_name as full_data_type,
                 character_maximum_length,
+                numeric_precision,
+                numeric_scale,
+                datetime_precision,
                 column_default,
                 is_nullable,
                 is_identity,
@@ -174,6 +270,9 @@ impl SqlSchemaDescriber {
                 .and_then(|x| x.to_string())
                 .expect("get full_data_type aka udt_name");
             let character_maximum_length = col.get("character_maximum_length").and_then(|x| x.as_i64());
+            let numeric_precision = col.get("numeric_precision").and_then(|x| x.as_i64());
+            let numeric_scale = col.get("numeric_scale").and_then(|x| x.as_i64());
+            let datetime_precision = col.get("datetime_precision").and_then(|x| x.as_i64());
             let is_identity_str = col
                 .get("is_identity")
                 .and_then(|x| x.to_string())
@@ -207,6 +306,9 @@ impl SqlSchemaDescriber {
                 data_type.as_ref(),
                 &full_data_type,
                 character_maximum_length,
+                numeric_precision,
+                numeric_scale,
+                datetime_precision,
                 arity,
                 enums,
             );
@@ -299,6 +401,7 @@ impl SqlSchemaDescriber {
                 con.oid as "con_id",
                 att2.attname as "child_column",
                 cl.relname as "parent_table",
+                parent_ns.nspname as "parent_schema",
                 att.attname as "parent_column",
                 con.confdeltype,
                 conname as constraint_name,
@@ -329,6 +432,8 @@ impl SqlSchemaDescriber {
                 att.attrelid = con.confrelid and att.attnum = con.child
             JOIN pg_class cl on
                 cl.oid = con.confrelid
+            JOIN pg_namespace parent_ns on
+                parent_ns.oid = cl.relnamespace
             JOIN pg_attribute att2 on
                 att2.attrelid = con.conrelid and att2.attnum = con.parent
             ORDER BY con_id, con.colidx"#;
@@ -354,6 +459,10 @@ impl SqlSchemaDescriber {
                 .get("parent_table")
                 .and_then(|x| x.to_string())
                 .expect("get parent_table");
+            let referenced_schema = row
+                .get("parent_schema")
+                .and_then(|x| x.to_string())
+                .expect("get parent_schema");
             let referenced_column = row
                 .get("parent_column")
                 .and_then(|x| x.to_string())
@@ -388,6 +497,12 @@ impl SqlSchemaDescriber {
                         constraint_name: Some(constraint_name),
                         columns: vec![column],
                         referenced_table,
+                        // Foreign keys into the described schema are not qualified.
+                        referenced_schema: if referenced_schema == schema {
+                            None
+                        } else {
+                            Some(referenced_schema)
+                        },
                         referenced_columns: vec![referenced_column],
                         on_delete_action,
                     };
@@ -416,6 +531,51 @@ impl SqlSchemaDescriber {
         fks
     }
 
+    /// Returns a map from table name to check constraints.
+    async fn get_check_constraints(&self, schema: &str) -> HashMap<String, Vec<CheckConstraint>> {
+        let sql = r#"
+            SELECT
+                cl.relname AS table_name,
+                con.conname AS constraint_name,
+                pg_get_expr(con.conbin, con.conrelid) AS expression
+            FROM pg_constraint con
+            JOIN pg_class cl ON cl.oid = con.conrelid
+            JOIN pg_namespace ns ON ns.oid = cl.relnamespace
+            WHERE ns.nspname = $1 AND con.contype = 'c'
+            ORDER BY cl.relname, con.conname"#;
+        debug!("describing check constraints, SQL: '{}'", sql);
+
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[schema.into()])
+            .await
+            .expect("querying for check constraints");
+        let mut check_constraints: HashMap<String, Vec<CheckConstraint>> = HashMap::new();
+
+        for row in result_set.into_iter() {
+            debug!("Got check constraint row {:?}", row);
+            let table_name = row
+                .get("table_name")
+                .and_then(|x| x.to_string())
+                .expect("get table_name");
+            let name = row
+                .get("constraint_name")
+                .and_then(|x| x.to_string())
+                .expect("get constraint_name");
+            let expression = row
+                .get("expression")
+                .and_then(|x| x.to_string())
+                .expect("get expression");
+
+            check_constraints
+                .entry(table_name)
+                .or_insert_with(Vec::new)
+                .push(CheckConstraint { name, expression });
+        }
+
+        check_constraints
+    }
+
     /// Returns a map from table name to indexes and (optional) primary key.
     async fn get_indices(
         &self,
@@ -432,6 +592,10 @@ impl SqlSchemaDescriber {
             rawIndex.indisprimary AS is_primary_key,
             tableInfos.relname AS table_name,
             rawIndex.indkeyidx,
+            -- the first bit of indoption is set for descending columns
+            (indexMeta.indoption[rawIndex.indkeyidx] & 1) = 1 AS is_descending,
+            accessMethod.amname AS algorithm,
+            pg_get_expr(indexMeta.indpred, indexMeta.indrelid) AS predicate,
             pg_get_serial_sequence('"' || $1 || '"."' || tableInfos.relname || '"', columnInfos.attname) AS sequence_name
         FROM
             -- pg_class stores infos about tables, indices etc: https://www.postgresql.org/docs/current/catalog-pg-class.html
@@ -450,6 +614,9 @@ impl SqlSchemaDescriber {
                 GROUP BY indrelid, indexrelid, indisunique, indisprimary, indkeyidx, indkey
                 ORDER BY indrelid, indexrelid, indkeyidx
             ) rawIndex,
+            pg_index indexMeta,
+            -- pg_am stores index access methods: https://www.postgresql.org/docs/current/catalog-pg-am.html
+            pg_am accessMethod,
             -- pg_attribute stores infos about columns: https://www.postgresql.org/docs/current/catalog-pg-attribute.html
             pg_attribute columnInfos,
             -- pg_namespace stores info about the schema
@@ -459,6 +626,8 @@ impl SqlSchemaDescriber {
             tableInfos.oid = rawIndex.indrelid
             -- find index info
             AND indexInfos.oid = rawIndex.indexrelid
+            AND indexMeta.indexrelid = rawIndex.indexrelid
+            AND accessMethod.oid = indexInfos.relam
             -- find table columns
             AND columnInfos.attrelid = tableInfos.oid
             AND columnInfos.attnum = rawIndex.indkey[rawIndex.indkeyidx]
@@ -467,7 +636,8 @@ impl SqlSchemaDescriber {
             -- we only consider stuff out of one specific schema
             AND tableInfos.relnamespace = schemaInfo.oid
             AND schemaInfo.nspname = $1
-        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx
+        GROUP BY tableInfos.relname, indexInfos.relname, rawIndex.indisunique, rawIndex.indisprimary, columnInfos.attname, rawIndex.indkeyidx,
+            is_descending, algorithm, predicate
         ORDER BY rawIndex.indkeyidx
         "#;
         debug!("Getting indices: {}", sql);
@@ -486,6 +656,9 @@ impl SqlSchemaDescriber {
                 name,
                 sequence_name,
                 table_name,
+                is_descending,
+                algorithm,
+                predicate,
             } = quaint::serde::from_row::<IndexRow>(index).unwrap();
 
             if is_primary_key {
@@ -506,17 +679,25 @@ impl SqlSchemaDescriber {
                             })
                         });
 
+                        // The constraint backing a primary key has the name of its index.
                         entry.1 = Some(PrimaryKey {
                             columns: vec![column_name],
                             sequence,
+                            constraint_name: Some(name),
                         });
                     }
                 }
             } else {
                 let entry: &mut (Vec<Index>, _) = indexes_map.entry(table_name).or_insert_with(|| (Vec::new(), None));
 
+                let column_options = IndexColumnOptions {
+                    sort_order: if is_descending { SortOrder::Desc } else { SortOrder::Asc },
+                    length: None,
+                };
+
                 if let Some(existing_index) = entry.0.iter_mut().find(|idx| idx.name == name) {
                     existing_index.columns.push(column_name);
+                    existing_index.column_options.push(column_options);
                 } else {
                     entry.0.push(Index {
                         name: name,
@@ -525,11 +706,22 @@ impl SqlSchemaDescriber {
                             true => IndexType::Unique,
                             false => IndexType::Normal,
                         },
+                        column_options: vec![column_options],
+                        algorithm: get_index_algorithm(&algorithm),
+                        predicate,
                     })
                 }
             }
         }
 
+        for (indexes, _) in indexes_map.values_mut() {
+            for index in indexes.iter_mut() {
+                if index.column_options.iter().all(|options| options.is_default()) {
+                    index.column_options.clear();
+                }
+            }
+        }
+
         indexes_map
     }
 
@@ -557,6 +749,7 @@ impl SqlSchemaDescriber {
                     // hardcodes this as 1
                     allocation_size: 1,
                     initial_value,
+                    schema: None,
                     name: seq
                         .get("sequence_name")
                         .and_then(|x| x.to_string())
@@ -590,7 +783,11 @@ impl SqlSchemaDescriber {
 
         let mut enums: Vec<Enum> = enum_values
             .into_iter()
-            .map(|(k, v)| Enum { name: k, values: v })
+            .map(|(k, v)| Enum {
+                name: k,
+                schema: None,
+                values: v,
+            })
             .collect();
 
         enums.sort_by(|a, b| Ord::cmp(&a.name, &b.name));
@@ -608,12 +805,29 @@ struct IndexRow {
     is_primary_key: bool,
     table_name: String,
     sequence_name: Option<String>,
+    is_descending: bool,
+    algorithm: String,
+    predicate: Option<String>,
+}
+
+/// BTree is the default index method, so it is described as `None`.
+fn get_index_algorithm(amname: &str) -> Option<IndexAlgorithm> {
+    match amname {
+        "hash" => Some(IndexAlgorithm::Hash),
+        "gin" => Some(IndexAlgorithm::Gin),
+        "gist" => Some(IndexAlgorithm::Gist),
+        "brin" => Some(IndexAlgorithm::Brin),
+        _ => None,
+    }
 }
 
 fn get_column_type<'a>(
     data_type: &str,
     full_data_type: &'a str,
     character_maximum_length: Option<i64>,
+    numeric_precision: Option<i64>,
+    numeric_scale: Option<i64>,
+    datetime_precision: Option<i64>,
     arity: ColumnArity,
     enums: &Vec<Enum>,
 ) -> ColumnType {
@@ -663,10 +877,25 @@ fn get_column_type<'a>(
         "inet" | "_inet" => String,
         data_type => Unsupported(data_type.into()),
     };
+
+    // Postgres reports a precision for all numeric and datetime types. Only these types take it as arguments.
+    let (numeric_precision, numeric_scale) = match full_data_type {
+        "numeric" => (numeric_precision, numeric_scale),
+        _ => (None, None),
+    };
+    let datetime_precision = match full_data_type {
+        "time" | "timetz" | "timestamp" | "timestamptz" => datetime_precision,
+        _ => None,
+    };
+
     ColumnType {
         data_type: data_type.to_owned(),
         full_data_type: full_data_type.to_owned(),
         character_maximum_length,
+        numeric_precision,
+        numeric_scale,
+        datetime_precision,
+        native_type: None,
         family,
         arity,
     }
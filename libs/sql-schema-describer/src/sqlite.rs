@@ -56,6 +56,7 @@ impl super::SqlSchemaDescriberBackend for SqlSchemaDescriber {
             // There are no sequences in SQLite.
             sequences: vec![],
             tables: tables,
+            views: self.get_views(schema).await,
         })
     }
 }
@@ -97,6 +98,31 @@ impl SqlSchemaDescriber {
         names
     }
 
+    async fn get_views(&self, schema: &str) -> Vec<View> {
+        let sql = format!(r#"SELECT name, sql FROM "{}".sqlite_master WHERE type='view'"#, schema);
+        debug!("describing views with query: '{}'", sql);
+        let result_set = self.conn.query_raw(&sql, &[]).await.expect("get views");
+        let mut views = Vec::new();
+
+        for row in result_set.into_iter() {
+            let name = row.get("name").and_then(|x| x.to_string()).unwrap();
+            let definition = row
+                .get("sql")
+                .and_then(|x| x.to_string())
+                .map(|sql| extract_view_definition(&sql));
+            let (columns, _) = self.get_columns(schema, &name).await;
+
+            views.push(View {
+                name,
+                definition,
+                columns,
+            });
+        }
+
+        debug!("Found views: {:?}", views);
+        views
+    }
+
     async fn get_size(&self, _schema: &str) -> usize {
         debug!("Getting db size");
         let sql = format!(r#"SELECT page_count * page_size as size FROM pragma_page_count(), pragma_page_size();"#);
@@ -114,12 +140,15 @@ impl SqlSchemaDescriber {
         let (columns, primary_key) = self.get_columns(schema, name).await;
         let foreign_keys = self.get_foreign_keys(schema, name).await;
         let indices = self.get_indices(schema, name).await;
+        let check_constraints = self.get_check_constraints(schema, name).await;
         Table {
             name: name.to_string(),
+            schema: None,
             columns,
             indices,
             primary_key,
             foreign_keys,
+            check_constraints,
         }
     }
 
@@ -248,6 +277,7 @@ impl SqlSchemaDescriber {
                 Some(PrimaryKey {
                     columns,
                     sequence: None,
+                    constraint_name: None,
                 })
             }
         };
@@ -342,6 +372,7 @@ impl SqlSchemaDescriber {
                 let fk = ForeignKey {
                     columns,
                     referenced_table: intermediate_fk.referenced_table.to_owned(),
+                    referenced_schema: None,
                     referenced_columns,
                     on_delete_action: intermediate_fk.on_delete_action.to_owned(),
 
@@ -374,26 +405,48 @@ impl SqlSchemaDescriber {
         for row in filtered_rows {
             let is_unique = row.get("unique").and_then(|x| x.as_bool()).expect("get unique");
             let name = row.get("name").and_then(|x| x.to_string()).expect("get name");
+            let is_partial = row.get("partial").and_then(|x| x.as_bool()).expect("get partial");
             let mut index = Index {
                 name: name.clone(),
                 tpe: match is_unique {
                     true => IndexType::Unique,
                     false => IndexType::Normal,
                 },
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
                 columns: vec![],
             };
 
-            let sql = format!(r#"PRAGMA "{}".index_info("{}");"#, schema, name);
+            // index_xinfo also describes the sort order, and the auxiliary columns we skip.
+            let sql = format!(r#"PRAGMA "{}".index_xinfo("{}");"#, schema, name);
             debug!("describing table index '{}', SQL: '{}'", name, sql);
             let result_set = self.conn.query_raw(&sql, &[]).await.expect("querying for index info");
             debug!("Got index description results: {:?}", result_set);
             for row in result_set.into_iter() {
+                if !row.get("key").and_then(|x| x.as_bool()).expect("get key") {
+                    continue;
+                }
+
                 let pos = row.get("seqno").and_then(|x| x.as_i64()).expect("get seqno") as usize;
                 let col_name = row.get("name").and_then(|x| x.to_string()).expect("get name");
+                let is_descending = row.get("desc").and_then(|x| x.as_bool()).expect("get desc");
                 if index.columns.len() <= pos {
                     index.columns.resize(pos + 1, "".to_string());
+                    index.column_options.resize(pos + 1, IndexColumnOptions::default());
                 }
                 index.columns[pos] = col_name;
+                if is_descending {
+                    index.column_options[pos].sort_order = SortOrder::Desc;
+                }
+            }
+
+            if index.column_options.iter().all(|options| options.is_default()) {
+                index.column_options.clear();
+            }
+
+            if is_partial {
+                index.predicate = self.get_index_predicate(schema, &name).await;
             }
 
             indices.push(index)
@@ -401,6 +454,47 @@ impl SqlSchemaDescriber {
 
         indices
     }
+
+    /// SQLite only stores check constraints as part of the `CREATE TABLE` statement.
+    async fn get_check_constraints(&self, schema: &str, table: &str) -> Vec<CheckConstraint> {
+        let sql = format!(
+            r#"SELECT sql FROM "{}".sqlite_master WHERE type='table' AND name = ?"#,
+            schema
+        );
+        debug!("describing check constraints, SQL: '{}'", sql);
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[table.into()])
+            .await
+            .expect("querying for table sql");
+
+        result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .map(|sql| extract_check_constraints(table, &sql))
+            .unwrap_or_default()
+    }
+
+    /// SQLite only stores the predicate of a partial index as part of its `CREATE INDEX` statement.
+    async fn get_index_predicate(&self, schema: &str, index_name: &str) -> Option<String> {
+        let sql = format!(
+            r#"SELECT sql FROM "{}".sqlite_master WHERE type='index' AND name = ?"#,
+            schema
+        );
+        debug!("describing index predicate, SQL: '{}'", sql);
+        let result_set = self
+            .conn
+            .query_raw(&sql, &[index_name.into()])
+            .await
+            .expect("querying for index sql");
+
+        result_set
+            .into_iter()
+            .next()
+            .and_then(|row| row.get("sql").and_then(|x| x.to_string()))
+            .and_then(|sql| extract_index_predicate(&sql))
+    }
 }
 
 fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
@@ -423,6 +517,7 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         "datetime" => ColumnTypeFamily::DateTime,
         "timestamp" => ColumnTypeFamily::DateTime,
         "binary" => ColumnTypeFamily::Binary,
+        "blob" => ColumnTypeFamily::Binary,
         "double" => ColumnTypeFamily::Float,
         "binary[]" => ColumnTypeFamily::Binary,
         "boolean[]" => ColumnTypeFamily::Boolean,
@@ -440,6 +535,10 @@ fn get_column_type(tpe: &str, arity: ColumnArity) -> ColumnType {
         data_type: tpe.to_string(),
         full_data_type: tpe.to_string(),
         character_maximum_length: None,
+        numeric_precision: None,
+        numeric_scale: None,
+        datetime_precision: None,
+        native_type: None,
         family,
         arity,
     }
@@ -460,6 +559,101 @@ fn unquote_sqlite_string_default(s: &str) -> Cow<'_, str> {
     }
 }
 
+/// SQLite stores the whole `CREATE VIEW` statement, we only keep the query.
+fn extract_view_definition(create_view: &str) -> String {
+    static RE: Lazy<Regex> =
+        Lazy::new(|| Regex::new(r"(?is)^\s*CREATE\s+(?:TEMP\s+|TEMPORARY\s+)?VIEW\s+.*?\s+AS\s+(.*)$").unwrap());
+
+    RE.captures(create_view)
+        .and_then(|captures| captures.get(1))
+        .map(|query| query.as_str().trim().to_owned())
+        .unwrap_or_else(|| create_view.to_owned())
+}
+
+/// Extracts the predicate from the `CREATE INDEX ... WHERE ...` statement of a partial index.
+fn extract_index_predicate(create_index: &str) -> Option<String> {
+    static RE: Lazy<Regex> = Lazy::new(|| Regex::new(r"(?is)\)\s*WHERE\s+(.*)$").unwrap());
+
+    RE.captures(create_index)
+        .and_then(|captures| captures.get(1))
+        .map(|predicate| predicate.as_str().trim().to_owned())
+}
+
+/// Extracts the check constraints from a `CREATE TABLE` statement. Unnamed check constraints are named like the
+/// migration engine names the unnamed check constraints of the datamodel.
+fn extract_check_constraints(table: &str, create_table: &str) -> Vec<CheckConstraint> {
+    static RE: Lazy<Regex> = Lazy::new(|| {
+        Regex::new(r#"(?i)(?:\bCONSTRAINT\s+(?:"([^"]+)"|`([^`]+)`|\[([^\]]+)\]|(\w+))\s+)?\bCHECK\s*\("#).unwrap()
+    });
+
+    // The string literals are blanked out, so that their contents are not taken for constraints or parentheses.
+    let masked = mask_string_literals(create_table);
+
+    RE.captures_iter(&masked)
+        .enumerate()
+        .filter_map(|(idx, captures)| {
+            let start = captures.get(0)?.end();
+            let end = start + closing_parenthesis_position(&masked[start..])?;
+            let name = (1..=4)
+                .filter_map(|group| captures.get(group))
+                .map(|name| name.as_str().to_owned())
+                .next()
+                .unwrap_or_else(|| match idx {
+                    0 => format!("{}_check", table),
+                    idx => format!("{}_check{}", table, idx),
+                });
+
+            Some(CheckConstraint {
+                name,
+                expression: create_table[start..end].trim().to_owned(),
+            })
+        })
+        .collect()
+}
+
+/// Replaces the contents of the string literals with spaces, keeping the positions of all other characters.
+fn mask_string_literals(sql: &str) -> String {
+    let mut in_literal = false;
+
+    sql.chars()
+        .map(|c| match c {
+            '\'' => {
+                // Escaped quotes close and reopen the literal.
+                in_literal = !in_literal;
+                c.to_string()
+            }
+            c if in_literal => " ".repeat(c.len_utf8()),
+            c => c.to_string(),
+        })
+        .collect()
+}
+
+/// The position of the parenthesis closing an already opened one, skipping quoted identifiers.
+fn closing_parenthesis_position(s: &str) -> Option<usize> {
+    let mut depth = 1;
+    let mut quote = None;
+
+    for (idx, c) in s.char_indices() {
+        match (quote, c) {
+            (Some(q), c) if c == q => quote = None,
+            (Some(_), _) => (),
+            (None, '"') | (None, '`') => quote = Some(c),
+            (None, '[') => quote = Some(']'),
+            (None, '(') => depth += 1,
+            (None, ')') => {
+                depth -= 1;
+
+                if depth == 0 {
+                    return Some(idx);
+                }
+            }
+            (None, _) => (),
+        }
+    }
+
+    None
+}
+
 /// Returns whether a table is one of the SQLite system tables.
 fn is_system_table(table_name: &str) -> bool {
     SQLITE_SYSTEM_TABLES
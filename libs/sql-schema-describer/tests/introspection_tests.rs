@@ -70,6 +70,10 @@ async fn is_required_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -82,6 +86,10 @@ async fn is_required_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Nullable,
@@ -124,6 +132,10 @@ async fn foreign_keys_must_work(api: &TestApi) {
             data_type: int_data_type(api),
             full_data_type: int_full_data_type(api),
             character_maximum_length: None,
+            numeric_precision: None,
+            numeric_scale: None,
+            datetime_precision: None,
+            native_type: None,
 
             family: ColumnTypeFamily::Int,
             arity: ColumnArity::Required,
@@ -141,6 +153,9 @@ async fn foreign_keys_must_work(api: &TestApi) {
             name: "city".to_owned(),
             columns: vec!["city".to_owned()],
             tpe: IndexType::Normal,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }]
     } else {
         vec![]
@@ -150,6 +165,7 @@ async fn foreign_keys_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: expected_indexes,
             primary_key: None,
@@ -162,8 +178,10 @@ async fn foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city".to_string()],
                 referenced_columns: vec!["id".to_string()],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
             }],
+            check_constraints: vec![],
         }
     );
 }
@@ -209,6 +227,10 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -226,6 +248,10 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 } else {
                     Some(255)
                 },
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -239,6 +265,9 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
             name: "city_name".to_owned(),
             columns: vec!["city_name".to_owned(), "city".to_owned()],
             tpe: IndexType::Normal,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }]
     } else {
         vec![]
@@ -253,6 +282,7 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: expected_indexes,
             primary_key: None,
@@ -266,8 +296,10 @@ async fn multi_column_foreign_keys_must_work(api: &TestApi) {
                 columns: vec!["city_name".to_string(), "city".to_string()],
                 referenced_columns: vec!["name".to_string(), "id".to_string(),],
                 referenced_table: "City".to_string(),
+                referenced_schema: None,
                 on_delete_action,
             },],
+            check_constraints: vec![],
         }
     );
 }
@@ -289,6 +321,10 @@ async fn names_with_hyphens_must_work(api: &TestApi) {
             data_type: int_data_type(api),
             full_data_type: int_full_data_type(api),
             character_maximum_length: None,
+            numeric_precision: None,
+            numeric_scale: None,
+            datetime_precision: None,
+            native_type: None,
 
             family: ColumnTypeFamily::Int,
             arity: ColumnArity::Required,
@@ -331,6 +367,10 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -348,6 +388,10 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
                 } else {
                     Some(255)
                 },
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -361,13 +405,16 @@ async fn composite_primary_keys_must_work(api: &TestApi) {
         table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string(), "name".to_string()],
                 sequence: None,
+                constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -396,6 +443,10 @@ async fn indices_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -409,6 +460,10 @@ async fn indices_must_work(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -421,6 +476,7 @@ async fn indices_must_work(api: &TestApi) {
     let pk_sequence = match api.sql_family() {
         SqlFamily::Postgres => Some(Sequence {
             name: "User_id_seq".to_string(),
+            schema: None,
             allocation_size: 1,
             initial_value: 1,
         }),
@@ -430,17 +486,23 @@ async fn indices_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![Index {
                 name: "count".to_string(),
                 columns: vec!["count".to_string()],
                 tpe: IndexType::Normal,
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
                 sequence: pk_sequence,
+                constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -466,6 +528,10 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -479,6 +545,10 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 data_type: int_data_type(api),
                 full_data_type: int_full_data_type(api),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -492,12 +562,18 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
         name: "uniq".to_string(),
         columns: vec!["uniq2".to_string()],
         tpe: IndexType::Unique,
+        column_options: vec![],
+        algorithm: None,
+        predicate: None,
     }];
     match api.sql_family() {
         SqlFamily::Mysql => expected_indices.push(Index {
             name: "uniq1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }),
         SqlFamily::Postgres => expected_indices.insert(
             0,
@@ -505,22 +581,30 @@ async fn column_uniqueness_must_be_detected(api: &TestApi) {
                 name: "User_uniq1_key".to_string(),
                 columns: vec!["uniq1".to_string()],
                 tpe: IndexType::Unique,
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
             },
         ),
         SqlFamily::Sqlite => expected_indices.push(Index {
             name: "sqlite_autoindex_User_1".to_string(),
             columns: vec!["uniq1".to_string()],
             tpe: IndexType::Unique,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }),
     };
     assert_eq!(
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: expected_indices,
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
     assert!(
@@ -552,6 +636,10 @@ async fn defaults_must_work(api: &TestApi) {
             data_type: int_data_type(api),
             full_data_type: int_full_data_type(api),
             character_maximum_length: None,
+            numeric_precision: None,
+            numeric_scale: None,
+            datetime_precision: None,
+            native_type: None,
 
             family: ColumnTypeFamily::Int,
             arity: ColumnArity::Nullable,
@@ -564,10 +652,52 @@ async fn defaults_must_work(api: &TestApi) {
         user_table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
+
+#[test_each_connector]
+async fn views_must_work(api: &TestApi) {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("User", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::varchar(255));
+            });
+        })
+        .await;
+
+    let sql = match api.sql_family() {
+        SqlFamily::Mysql => format!(
+            "CREATE VIEW `{0}`.`UserName` AS SELECT id, name FROM `{0}`.`User`",
+            api.db_name()
+        ),
+        SqlFamily::Postgres => format!(
+            "CREATE VIEW \"{0}\".\"UserName\" AS SELECT id, name FROM \"{0}\".\"User\"",
+            api.schema_name()
+        ),
+        SqlFamily::Sqlite => format!(
+            "CREATE VIEW \"{0}\".\"UserName\" AS SELECT id, name FROM \"User\"",
+            api.schema_name()
+        ),
+    };
+
+    api.database().query_raw(&sql, &[]).await.unwrap();
+
+    let schema = api.describe().await.expect("describe failed");
+
+    assert!(schema.get_table("UserName").is_none());
+    assert_eq!(schema.tables.len(), 1);
+
+    let view = schema.get_view("UserName").expect("couldn't get UserName view");
+    let column_names: Vec<&str> = view.columns.iter().map(|c| c.name.as_str()).collect();
+
+    assert_eq!(column_names, vec!["id", "name"]);
+    assert!(view.definition.as_ref().unwrap().to_lowercase().contains("select"));
+}
@@ -68,6 +68,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "int".to_string(),
                 full_data_type: "int(11)".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -81,6 +85,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "int".to_string(),
                 full_data_type: "int(11)".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -94,6 +102,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "smallint".to_string(),
                 full_data_type: "smallint(6)".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -107,6 +119,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinyint".to_string(),
                 full_data_type: "tinyint(4)".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -119,6 +135,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinyint".to_string(),
                 full_data_type: "tinyint(1)".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Boolean,
                 arity: ColumnArity::Required,
             },
@@ -132,6 +152,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "mediumint".to_string(),
                 full_data_type: "mediumint(9)".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -145,6 +169,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "bigint".to_string(),
                 full_data_type: "bigint(20)".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -158,6 +186,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "decimal".to_string(),
                 full_data_type: "decimal(10,0)".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -171,6 +203,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "decimal".to_string(),
                 full_data_type: "decimal(10,0)".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -184,6 +220,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "float".to_string(),
                 full_data_type: "float".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -197,6 +237,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "double".to_string(),
                 full_data_type: "double".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -210,6 +254,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "date".to_string(),
                 full_data_type: "date".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -223,6 +271,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "time".to_string(),
                 full_data_type: "time".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -236,6 +288,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "datetime".to_string(),
                 full_data_type: "datetime".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -249,6 +305,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "timestamp".to_string(),
                 full_data_type: "timestamp".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -262,6 +322,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "year".to_string(),
                 full_data_type: "year(4)".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -275,6 +339,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "char".to_string(),
                 full_data_type: "char(1)".to_string(),
                 character_maximum_length: Some(1),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -288,6 +356,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "varchar".to_string(),
                 full_data_type: "varchar(255)".to_string(),
                 character_maximum_length: Some(255),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -301,6 +373,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "text".to_string(),
                 full_data_type: "text".to_string(),
                 character_maximum_length: Some(65535),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -314,6 +390,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinytext".to_string(),
                 full_data_type: "tinytext".to_string(),
                 character_maximum_length: Some(255),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -327,6 +407,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "mediumtext".to_string(),
                 full_data_type: "mediumtext".to_string(),
                 character_maximum_length: Some(16777215),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -340,6 +424,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "longtext".to_string(),
                 full_data_type: "longtext".to_string(),
                 character_maximum_length: Some(4294967295),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -353,6 +441,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "enum".to_string(),
                 full_data_type: "enum(\'a\',\'b\')".to_string(),
                 character_maximum_length: Some(1),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Enum("User_enum_col".into()),
                 arity: ColumnArity::Required,
             },
@@ -366,6 +458,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "set".to_string(),
                 full_data_type: "set(\'a\',\'b\')".to_string(),
                 character_maximum_length: Some(3),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -379,6 +475,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "binary".to_string(),
                 full_data_type: "binary(1)".to_string(),
                 character_maximum_length: Some(1),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -392,6 +492,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "varbinary".to_string(),
                 full_data_type: "varbinary(255)".to_string(),
                 character_maximum_length: Some(255),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -405,6 +509,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "blob".to_string(),
                 full_data_type: "blob".to_string(),
                 character_maximum_length: Some(65535),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -418,6 +526,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "tinyblob".to_string(),
                 full_data_type: "tinyblob".to_string(),
                 character_maximum_length: Some(255),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
@@ -432,6 +544,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "mediumblob".to_string(),
                 full_data_type: "mediumblob".to_string(),
                 character_maximum_length: Some(16777215),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -445,6 +561,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "longblob".to_string(),
                 full_data_type: "longblob".to_string(),
                 character_maximum_length: Some(4294967295),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
             },
@@ -458,6 +578,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "geometry".to_string(),
                 full_data_type: "geometry".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -471,6 +595,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "point".to_string(),
                 full_data_type: "point".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -484,6 +612,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "linestring".to_string(),
                 full_data_type: "linestring".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -497,6 +629,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "polygon".to_string(),
                 full_data_type: "polygon".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -510,6 +646,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "multipoint".to_string(),
                 full_data_type: "multipoint".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -523,6 +663,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "multilinestring".to_string(),
                 full_data_type: "multilinestring".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -536,6 +680,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "multipolygon".to_string(),
                 full_data_type: "multipolygon".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -549,6 +697,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "geometrycollection".to_string(),
                 full_data_type: "geometrycollection".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -562,6 +714,10 @@ async fn all_mysql_column_types_must_work() {
                 data_type: "json".to_string(),
                 full_data_type: "json".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Json,
                 arity: ColumnArity::Required,
             },
@@ -576,13 +732,16 @@ async fn all_mysql_column_types_must_work() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
+                constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -614,6 +773,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "city".to_string(),
@@ -621,6 +781,10 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -633,6 +797,10 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -645,6 +813,10 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -657,6 +829,10 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -669,6 +845,10 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                         data_type: "int".to_string(),
                         full_data_type: "int(11)".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -681,27 +861,40 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                 Index {
                     name: "city".to_owned(),
                     columns: vec!["city".to_owned(),],
-                    tpe: IndexType::Normal
+                    tpe: IndexType::Normal,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 },
                 Index {
                     name: "city_cascade".to_owned(),
                     columns: vec!["city_cascade".to_owned(),],
-                    tpe: IndexType::Normal
+                    tpe: IndexType::Normal,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 },
                 Index {
                     name: "city_restrict".to_owned(),
                     columns: vec!["city_restrict".to_owned(),],
-                    tpe: IndexType::Normal
+                    tpe: IndexType::Normal,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 },
                 Index {
                     name: "city_set_null".to_owned(),
                     columns: vec!["city_set_null".to_owned(),],
-                    tpe: IndexType::Normal
+                    tpe: IndexType::Normal,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 }
             ],
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
                 sequence: None,
+                constraint_name: None,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -709,6 +902,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -716,6 +910,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -723,6 +918,7 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -730,9 +926,11 @@ async fn mysql_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
         }
     );
 }
@@ -759,7 +957,10 @@ async fn mysql_multi_field_indexes_must_be_inferred() {
         &[Index {
             name: "age_and_name_index".into(),
             columns: vec!["name".to_owned(), "age".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }]
     );
 }
@@ -797,7 +998,10 @@ async fn mysql_join_table_unique_indexes_must_be_inferred() {
         &[Index {
             name: "cat_and_human_index".into(),
             columns: vec!["cat".to_owned(), "human".to_owned()],
-            tpe: IndexType::Unique
+            tpe: IndexType::Unique,
+            column_options: vec![],
+            algorithm: None,
+            predicate: None,
         }]
     );
 }
@@ -835,6 +1039,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -866,6 +1071,7 @@ async fn constraints_from_other_databases_should_not_be_introspected() {
             constraint_name: Some("Post_ibfk_1".into()),
             columns: vec!["user_id".into()],
             referenced_table: "User".into(),
+            referenced_schema: None,
             referenced_columns: vec!["id".into()],
             on_delete_action: ForeignKeyAction::Restrict,
         }]
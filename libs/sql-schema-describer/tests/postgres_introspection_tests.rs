@@ -73,6 +73,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_bytea".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::List,
@@ -86,6 +90,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_bool".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Boolean,
                 arity: ColumnArity::List,
@@ -99,6 +107,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_date".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::List,
@@ -112,6 +124,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_float8".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::List,
@@ -125,6 +141,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_float8".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::List,
@@ -138,6 +158,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_int4".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::List,
@@ -151,6 +175,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_text".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::List,
@@ -164,6 +192,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "ARRAY".into(),
                 full_data_type: "_varchar".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::List,
@@ -177,6 +209,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bytea".into(),
                 full_data_type: "bytea".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Binary,
                 arity: ColumnArity::Required,
@@ -190,6 +226,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "boolean".into(),
                 full_data_type: "bool".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Boolean,
                 arity: ColumnArity::Required,
@@ -203,6 +243,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "date".into(),
                 full_data_type: "date".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -217,6 +261,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "double precision".into(),
                 full_data_type: "float8".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
@@ -231,6 +279,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "double precision".into(),
                 full_data_type: "float8".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
@@ -245,6 +297,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "integer".into(),
                 full_data_type: "int4".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -259,6 +315,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "integer".into(),
                 full_data_type: "int4".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -276,6 +336,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "text".into(),
                 full_data_type: "text".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
@@ -290,6 +354,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "character varying".into(),
                 full_data_type: "varchar".into(),
                 character_maximum_length: Some(1),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -303,6 +371,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bigint".into(),
                 full_data_type: "int8".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
@@ -317,6 +389,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bigint".into(),
                 full_data_type: "int8".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -333,6 +409,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bit".into(),
                 full_data_type: "bit".into(),
                 character_maximum_length: Some(1),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -346,6 +426,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "bit varying".into(),
                 full_data_type: "varbit".into(),
                 character_maximum_length: Some(1),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -359,6 +443,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "box".into(),
                 full_data_type: "box".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -372,6 +460,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "character".into(),
                 full_data_type: "bpchar".into(),
                 character_maximum_length: Some(1),
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -385,6 +477,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "circle".into(),
                 full_data_type: "circle".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -398,6 +494,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "interval".into(),
                 full_data_type: "interval".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -411,6 +511,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "line".into(),
                 full_data_type: "line".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -424,6 +528,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "lseg".into(),
                 full_data_type: "lseg".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -437,6 +545,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "numeric".into(),
                 full_data_type: "numeric".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -450,6 +562,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "path".into(),
                 full_data_type: "path".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -463,6 +579,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "pg_lsn".into(),
                 full_data_type: "pg_lsn".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::LogSequenceNumber,
                 arity: ColumnArity::Required,
             },
@@ -476,6 +596,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "polygon".into(),
                 full_data_type: "polygon".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Geometric,
                 arity: ColumnArity::Required,
             },
@@ -489,6 +613,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "smallint".into(),
                 full_data_type: "int2".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -502,6 +630,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "smallint".into(),
                 full_data_type: "int2".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -518,6 +650,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "integer".into(),
                 full_data_type: "int4".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -534,6 +670,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "time without time zone".into(),
                 full_data_type: "time".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: Some(6),
+                native_type: None,
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
             },
@@ -547,6 +687,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "time with time zone".into(),
                 full_data_type: "timetz".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: Some(6),
+                native_type: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -561,6 +705,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "timestamp without time zone".into(),
                 full_data_type: "timestamp".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: Some(6),
+                native_type: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -575,6 +723,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "timestamp with time zone".into(),
                 full_data_type: "timestamptz".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: Some(6),
+                native_type: None,
 
                 family: ColumnTypeFamily::DateTime,
                 arity: ColumnArity::Required,
@@ -589,6 +741,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "tsquery".into(),
                 full_data_type: "tsquery".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::TextSearch,
                 arity: ColumnArity::Required,
@@ -603,6 +759,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "tsvector".into(),
                 full_data_type: "tsvector".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::TextSearch,
                 arity: ColumnArity::Required,
@@ -617,6 +777,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "txid_snapshot".into(),
                 full_data_type: "txid_snapshot".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::TransactionId,
                 arity: ColumnArity::Required,
@@ -631,6 +795,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "json".into(),
                 full_data_type: "json".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Json,
                 arity: ColumnArity::Required,
@@ -645,6 +813,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "jsonb".into(),
                 full_data_type: "jsonb".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Json,
                 arity: ColumnArity::Required,
@@ -659,6 +831,10 @@ async fn all_postgres_column_types_must_work() {
                 data_type: "uuid".into(),
                 full_data_type: "uuid".into(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Uuid,
                 arity: ColumnArity::Required,
@@ -674,21 +850,28 @@ async fn all_postgres_column_types_must_work() {
         table,
         Table {
             name: "User".into(),
+            schema: None,
             columns: expected_columns,
             indices: vec![Index {
                 name: "User_uuid_col_key".into(),
                 columns: vec!["uuid_col".into(),],
                 tpe: IndexType::Unique,
+                column_options: vec![],
+                algorithm: None,
+                predicate: None,
             },],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".into()],
                 sequence: Some(Sequence {
                     name: "User_primary_col_seq".into(),
+                    schema: None,
                     initial_value: 1,
                     allocation_size: 1,
                 },),
+                constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -718,6 +901,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".into(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "city".into(),
@@ -725,6 +909,10 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -738,6 +926,10 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -751,6 +943,10 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -764,6 +960,10 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -777,6 +977,10 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -790,6 +994,10 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                         data_type: "integer".into(),
                         full_data_type: "int4".into(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -803,6 +1011,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".into()],
                 sequence: None,
+                constraint_name: None,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -810,6 +1019,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -817,6 +1027,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -824,6 +1035,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -831,6 +1043,7 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
                 ForeignKey {
@@ -838,9 +1051,11 @@ async fn postgres_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".into()],
                     referenced_columns: vec!["id".into()],
                     referenced_table: "City".into(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
         }
     );
 }
@@ -861,6 +1076,7 @@ async fn postgres_enums_must_work() {
         got_enum,
         &Enum {
             name: "mood".into(),
+            schema: None,
             values,
         }
     );
@@ -881,6 +1097,7 @@ async fn postgres_sequences_must_work() {
         got_seq,
         &Sequence {
             name: "test".into(),
+            schema: None,
             initial_value: 1,
             allocation_size: 1,
         },
@@ -918,6 +1135,194 @@ async fn postgres_multi_field_indexes_must_be_inferred_in_the_right_order() {
     assert_eq!(&index.columns, &["age", "name"]);
 }
 
+#[tokio::test]
+async fn postgres_tables_and_foreign_keys_across_schemas_must_work() {
+    let sql = format!(
+        r##"
+            DROP SCHEMA IF EXISTS "billing" CASCADE;
+            CREATE SCHEMA "billing";
+
+            CREATE TYPE "billing"."status" AS ENUM ('open', 'paid');
+            CREATE SEQUENCE "billing"."invoice_number";
+
+            CREATE TABLE "{schema_name}"."User" (id INT PRIMARY KEY);
+            CREATE TABLE "billing"."Invoice" (
+                id INT PRIMARY KEY,
+                user_id INT NOT NULL REFERENCES "{schema_name}"."User" (id),
+                status "billing"."status" NOT NULL
+            );
+            CREATE TABLE "{schema_name}"."Payment" (
+                id INT PRIMARY KEY,
+                invoice_id INT NOT NULL REFERENCES "billing"."Invoice" (id)
+            );
+        "##,
+        schema_name = SCHEMA
+    );
+
+    let inspector = get_postgres_describer(&sql, "postgres_tables_across_schemas").await;
+    let schema = inspector
+        .describe_with_schemas(SCHEMA, &["billing".to_owned()])
+        .await
+        .unwrap();
+
+    let user = schema.table_bang("User");
+    assert_eq!(user.schema, None);
+
+    let invoice = schema.table_bang("Invoice");
+    assert_eq!(invoice.schema.as_deref(), Some("billing"));
+    assert_eq!(invoice.foreign_keys[0].referenced_table, "User");
+    assert_eq!(invoice.foreign_keys[0].referenced_schema, None);
+
+    let payment = schema.table_bang("Payment");
+    assert_eq!(payment.foreign_keys[0].referenced_table, "Invoice");
+    assert_eq!(payment.foreign_keys[0].referenced_schema.as_deref(), Some("billing"));
+
+    assert_eq!(
+        invoice.column_bang("status").tpe.family,
+        ColumnTypeFamily::Enum("status".to_owned())
+    );
+    assert_eq!(
+        schema.enums,
+        &[Enum {
+            name: "status".into(),
+            schema: Some("billing".into()),
+            values: vec!["open".into(), "paid".into()],
+        }]
+    );
+    assert!(schema.get_enum("status").is_none());
+    assert_eq!(
+        schema.sequences,
+        &[Sequence {
+            name: "invoice_number".into(),
+            schema: Some("billing".into()),
+            initial_value: 1,
+            allocation_size: 1,
+        }]
+    );
+}
+
+#[tokio::test]
+async fn postgres_check_constraints_must_work() {
+    let sql = format!(
+        r##"
+            CREATE TABLE "{schema_name}"."Product" (
+                id INT PRIMARY KEY,
+                price INT NOT NULL CHECK (price >= 0),
+                discount INT NOT NULL,
+                CONSTRAINT "discount_below_price" CHECK (discount <= price)
+            );
+        "##,
+        schema_name = SCHEMA
+    );
+
+    let inspector = get_postgres_describer(&sql, "postgres_check_constraints").await;
+    let schema = inspector.describe(SCHEMA).await.unwrap();
+    let mut check_constraints = schema.table_bang("Product").check_constraints.clone();
+    check_constraints.sort_by(|a, b| a.name.cmp(&b.name));
+
+    assert_eq!(
+        check_constraints,
+        &[
+            CheckConstraint {
+                name: "Product_price_check".into(),
+                expression: "(price >= 0)".into(),
+            },
+            CheckConstraint {
+                name: "discount_below_price".into(),
+                expression: "(discount <= price)".into(),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn postgres_index_options_must_work() {
+    let sql = format!(
+        r##"
+            CREATE TABLE "{schema_name}"."Post" (
+                id INT PRIMARY KEY,
+                title TEXT NOT NULL,
+                published BOOLEAN NOT NULL,
+                tags JSONB NOT NULL
+            );
+
+            CREATE INDEX "Post_title_published" ON "{schema_name}"."Post" (title DESC, published) WHERE published;
+            CREATE INDEX "Post_tags" ON "{schema_name}"."Post" USING gin (tags);
+        "##,
+        schema_name = SCHEMA
+    );
+
+    let inspector = get_postgres_describer(&sql, "postgres_index_options").await;
+    let schema = inspector.describe(SCHEMA).await.unwrap();
+    let mut indices = schema.table_bang("Post").indices.clone();
+    indices.sort_by(|a, b| a.name.cmp(&b.name));
+
+    assert_eq!(
+        indices,
+        &[
+            Index {
+                name: "Post_tags".into(),
+                columns: vec!["tags".into()],
+                tpe: IndexType::Normal,
+                column_options: vec![],
+                algorithm: Some(IndexAlgorithm::Gin),
+                predicate: None,
+            },
+            Index {
+                name: "Post_title_published".into(),
+                columns: vec!["title".into(), "published".into()],
+                tpe: IndexType::Normal,
+                column_options: vec![
+                    IndexColumnOptions {
+                        sort_order: SortOrder::Desc,
+                        length: None,
+                    },
+                    IndexColumnOptions::default(),
+                ],
+                algorithm: None,
+                predicate: Some("published".into()),
+            },
+        ]
+    );
+}
+
+#[tokio::test]
+async fn postgres_constraint_names_must_be_described() {
+    let sql = format!(
+        r##"
+            CREATE TABLE "{schema_name}"."User" (
+                id INT,
+                CONSTRAINT "pk_users" PRIMARY KEY (id)
+            );
+
+            CREATE TABLE "{schema_name}"."Post" (
+                id INT PRIMARY KEY,
+                author_id INT NOT NULL,
+                CONSTRAINT "fk_posts_author" FOREIGN KEY (author_id) REFERENCES "{schema_name}"."User" (id)
+            );
+        "##,
+        schema_name = SCHEMA
+    );
+
+    let inspector = get_postgres_describer(&sql, "postgres_constraint_names").await;
+    let schema = inspector.describe(SCHEMA).await.unwrap();
+    let user_table = schema.table_bang("User");
+    let post_table = schema.table_bang("Post");
+
+    assert_eq!(
+        user_table.primary_key.as_ref().unwrap().constraint_name.as_deref(),
+        Some("pk_users")
+    );
+    assert_eq!(
+        post_table.primary_key.as_ref().unwrap().constraint_name.as_deref(),
+        Some("Post_pkey")
+    );
+    assert_eq!(
+        post_table.foreign_keys[0].constraint_name.as_deref(),
+        Some("fk_posts_author")
+    );
+}
+
 #[test_each_connector(tags("postgres"))]
 async fn escaped_quotes_in_string_defaults_must_be_unescaped(api: &TestApi) -> TestResult {
     let create_table = format!(
@@ -995,3 +1400,36 @@ async fn escaped_backslashes_in_string_literals_must_be_unescaped(api: &TestApi)
 
     Ok(())
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn postgres_numeric_and_datetime_precisions_must_be_described(api: &TestApi) -> TestResult {
+    let create_table = r#"
+        CREATE TABLE "precisions" (
+            "price" NUMERIC(10, 2) NOT NULL,
+            "amount" NUMERIC NOT NULL,
+            "created_at" TIMESTAMP(3) NOT NULL,
+            "updated_at" TIMESTAMPTZ NOT NULL,
+            "opens_at" TIME(0) NOT NULL,
+            "count" INTEGER NOT NULL
+        )
+    "#;
+
+    api.database().query_raw(&create_table, &[]).await?;
+
+    let schema = api.describe().await?;
+    let table = schema.table_bang("precisions");
+    let precisions = |column: &str| {
+        let tpe = &table.column_bang(column).tpe;
+
+        (tpe.numeric_precision, tpe.numeric_scale, tpe.datetime_precision)
+    };
+
+    assert_eq!(precisions("price"), (Some(10), Some(2), None));
+    assert_eq!(precisions("amount"), (None, None, None));
+    assert_eq!(precisions("created_at"), (None, None, Some(3)));
+    assert_eq!(precisions("updated_at"), (None, None, Some(6)));
+    assert_eq!(precisions("opens_at"), (None, None, Some(0)));
+    assert_eq!(precisions("count"), (None, None, None));
+
+    Ok(())
+}
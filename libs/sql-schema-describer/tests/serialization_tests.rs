@@ -23,6 +23,7 @@ fn database_schema_is_serializable() {
         tables: vec![
             Table {
                 name: "table1".to_string(),
+                schema: None,
                 columns: vec![
                     Column {
                         name: "column1".to_string(),
@@ -30,6 +31,10 @@ fn database_schema_is_serializable() {
                             data_type: "integer".to_string(),
                             full_data_type: "int".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -43,6 +48,10 @@ fn database_schema_is_serializable() {
                             data_type: "varchar(255)".to_string(),
                             full_data_type: "varchar(255)".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::String,
                             arity: ColumnArity::Nullable,
@@ -56,6 +65,10 @@ fn database_schema_is_serializable() {
                             data_type: "integer".to_string(),
                             full_data_type: "integer".to_string(),
                             character_maximum_length: None,
+                            numeric_precision: None,
+                            numeric_scale: None,
+                            datetime_precision: None,
+                            native_type: None,
 
                             family: ColumnTypeFamily::Int,
                             arity: ColumnArity::Required,
@@ -68,27 +81,38 @@ fn database_schema_is_serializable() {
                     name: "column2".to_string(),
                     columns: vec!["column2".to_string()],
                     tpe: IndexType::Normal,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 }],
                 primary_key: Some(PrimaryKey {
                     columns: vec!["column1".to_string()],
                     sequence: None,
+                    constraint_name: None,
                 }),
                 foreign_keys: vec![ForeignKey {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
                 }],
+                check_constraints: vec![],
             },
             Table {
                 name: "table2".to_string(),
+                schema: None,
                 columns: vec![Column {
                     name: "id".to_string(),
                     tpe: ColumnType {
                         data_type: "integer".to_string(),
                         full_data_type: "integer".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
@@ -100,19 +124,24 @@ fn database_schema_is_serializable() {
                 primary_key: Some(PrimaryKey {
                     columns: vec!["id".to_string()],
                     sequence: None,
+                    constraint_name: None,
                 }),
                 foreign_keys: vec![],
+                check_constraints: vec![],
             },
         ],
         enums: vec![Enum {
             name: "enum1".to_string(),
+            schema: None,
             values: enum_values,
         }],
         sequences: vec![Sequence {
             name: "sequence1".to_string(),
+            schema: None,
             initial_value: 1,
             allocation_size: 32,
         }],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -131,12 +160,17 @@ fn database_schema_without_primary_key_is_serializable() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns: vec![Column {
                 name: "column1".to_string(),
                 tpe: ColumnType {
                     data_type: "integer".to_string(),
                     full_data_type: "int".to_string(),
                     character_maximum_length: None,
+                    numeric_precision: None,
+                    numeric_scale: None,
+                    datetime_precision: None,
+                    native_type: None,
 
                     family: ColumnTypeFamily::Int,
                     arity: ColumnArity::Nullable,
@@ -147,9 +181,11 @@ fn database_schema_without_primary_key_is_serializable() {
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-without-primary-key.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -188,6 +224,10 @@ fn database_schema_is_serializable_for_every_column_type_family() {
             data_type: "raw type".to_string(),
             full_data_type: "full raw type".to_string(),
             character_maximum_length: None,
+            numeric_precision: None,
+            numeric_scale: None,
+            datetime_precision: None,
+            native_type: None,
 
             family: family.to_owned(),
             arity: ColumnArity::Nullable,
@@ -199,13 +239,16 @@ fn database_schema_is_serializable_for_every_column_type_family() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns,
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-type-families.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -231,6 +274,10 @@ fn database_schema_is_serializable_for_every_column_arity() {
                 data_type: "integer".to_string(),
                 full_data_type: "int".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
 
                 family: ColumnTypeFamily::Int,
                 arity: arity.to_owned(),
@@ -242,13 +289,16 @@ fn database_schema_is_serializable_for_every_column_arity() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns,
             indices: vec![],
             primary_key: None,
             foreign_keys: vec![],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-column-arities.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -268,6 +318,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
     let schema = SqlSchema {
         tables: vec![Table {
             name: "table1".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "column1".to_string(),
@@ -275,6 +326,10 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -288,6 +343,10 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -301,6 +360,10 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -314,6 +377,10 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -327,6 +394,10 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                         data_type: "integer".to_string(),
                         full_data_type: "int".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -342,6 +413,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column1".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
@@ -349,6 +421,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column2".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
@@ -356,6 +429,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column3".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
@@ -363,6 +437,7 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column4".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
@@ -370,13 +445,16 @@ fn database_schema_is_serializable_for_every_foreign_key_action() {
                     constraint_name: None,
                     columns: vec!["column5".to_string()],
                     referenced_table: "table2".to_string(),
+                    referenced_schema: None,
                     referenced_columns: vec!["id".to_string()],
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
             ],
+            check_constraints: vec![],
         }],
         enums: vec![],
         sequences: vec![],
+        views: vec![],
     };
     let ref_schema_json = include_str!("./resources/schema-all-foreign-key-actions.json");
     let ref_schema: SqlSchema = serde_json::from_str(ref_schema_json).expect("deserialize reference schema");
@@ -33,6 +33,10 @@ async fn sqlite_column_types_must_work() {
                 data_type: "int".to_string(),
                 full_data_type: "int".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -45,6 +49,10 @@ async fn sqlite_column_types_must_work() {
                 data_type: "INTEGER".to_string(),
                 full_data_type: "INTEGER".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -57,6 +65,10 @@ async fn sqlite_column_types_must_work() {
                 data_type: "TEXT".to_string(),
                 full_data_type: "TEXT".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::String,
                 arity: ColumnArity::Required,
             },
@@ -69,6 +81,10 @@ async fn sqlite_column_types_must_work() {
                 data_type: "REAL".to_string(),
                 full_data_type: "REAL".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -81,6 +97,10 @@ async fn sqlite_column_types_must_work() {
                 data_type: "INTEGER".to_string(),
                 full_data_type: "INTEGER".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Int,
                 arity: ColumnArity::Required,
             },
@@ -93,6 +113,10 @@ async fn sqlite_column_types_must_work() {
                 data_type: "decimal (5, 3)".to_string(),
                 full_data_type: "decimal (5, 3)".to_string(),
                 character_maximum_length: None,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
                 family: ColumnTypeFamily::Float,
                 arity: ColumnArity::Required,
             },
@@ -106,13 +130,16 @@ async fn sqlite_column_types_must_work() {
         table,
         &Table {
             name: "User".to_string(),
+            schema: None,
             columns: expected_columns,
             indices: vec![],
             primary_key: Some(PrimaryKey {
                 columns: vec!["primary_col".to_string()],
                 sequence: None,
+                constraint_name: None,
             }),
             foreign_keys: vec![],
+            check_constraints: vec![],
         }
     );
 }
@@ -141,6 +168,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
         table,
         Table {
             name: "User".to_string(),
+            schema: None,
             columns: vec![
                 Column {
                     name: "city".to_string(),
@@ -148,6 +176,10 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -160,6 +192,10 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -172,6 +208,10 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -184,6 +224,10 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
                     },
@@ -196,6 +240,10 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
 
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Nullable,
@@ -209,6 +257,10 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                         data_type: "INTEGER".to_string(),
                         full_data_type: "INTEGER".to_string(),
                         character_maximum_length: None,
+                        numeric_precision: None,
+                        numeric_scale: None,
+                        datetime_precision: None,
+                        native_type: None,
                         family: ColumnTypeFamily::Int,
                         arity: ColumnArity::Required,
                     },
@@ -220,6 +272,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
             primary_key: Some(PrimaryKey {
                 columns: vec!["id".to_string()],
                 sequence: None,
+                constraint_name: None,
             }),
             foreign_keys: vec![
                 ForeignKey {
@@ -227,6 +280,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::NoAction,
                 },
                 ForeignKey {
@@ -234,6 +288,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_cascade".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Cascade,
                 },
                 ForeignKey {
@@ -241,6 +296,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_restrict".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::Restrict,
                 },
                 ForeignKey {
@@ -248,6 +304,7 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_default".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetDefault,
                 },
                 ForeignKey {
@@ -255,9 +312,11 @@ async fn sqlite_foreign_key_on_delete_must_be_handled() {
                     columns: vec!["city_set_null".to_string()],
                     referenced_columns: vec!["id".to_string()],
                     referenced_table: "City".to_string(),
+                    referenced_schema: None,
                     on_delete_action: ForeignKeyAction::SetNull,
                 },
             ],
+            check_constraints: vec![],
         }
     );
 }
@@ -291,11 +350,42 @@ async fn sqlite_text_primary_keys_must_be_inferred_on_table_and_not_as_separate_
         table.primary_key.as_ref().unwrap(),
         &PrimaryKey {
             columns: vec!["primary_col".to_owned()],
-            sequence: None
+            sequence: None,
+            constraint_name: None,
         }
     );
 }
 
+#[tokio::test]
+async fn sqlite_check_constraints_must_work() {
+    let full_sql = r#"
+        CREATE TABLE "Product" (
+            id INTEGER PRIMARY KEY,
+            price INTEGER NOT NULL CHECK (price >= 0),
+            discount INTEGER NOT NULL,
+            label TEXT NOT NULL DEFAULT 'CHECK (',
+            CONSTRAINT "discount_below_price" CHECK (discount <= price AND (discount >= 0))
+        );
+    "#;
+
+    let inspector = get_sqlite_describer(full_sql, "sqlite_check_constraints_must_work").await;
+    let schema = inspector.describe(SCHEMA).await.expect("describing");
+
+    assert_eq!(
+        schema.table_bang("Product").check_constraints,
+        &[
+            CheckConstraint {
+                name: "Product_check".into(),
+                expression: "price >= 0".into(),
+            },
+            CheckConstraint {
+                name: "discount_below_price".into(),
+                expression: "discount <= price AND (discount >= 0)".into(),
+            },
+        ]
+    );
+}
+
 #[test_each_connector(tags("sqlite"))]
 async fn escaped_quotes_in_string_defaults_must_be_unescaped(api: &TestApi) -> TestResult {
     let create_table = format!(
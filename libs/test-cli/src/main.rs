@@ -64,7 +64,7 @@ async fn main() -> anyhow::Result<()> {
                 unreachable!()
             };
 
-            let introspected = introspection_core::RpcImpl::introspect_internal(schema)
+            let introspected = introspection_core::RpcImpl::introspect_internal(schema, Default::default())
                 .await
                 .map_err(|err| anyhow::anyhow!("{:?}", err.data))?;
 
@@ -93,6 +93,7 @@ async fn main() -> anyhow::Result<()> {
                 assume_to_be_applied: Some(Vec::new()),
                 datamodel: datamodel_string.clone(),
                 migration_id: migration_id.clone(),
+                accepted_renames: Vec::new(),
             };
 
             api.reset(&serde_json::Value::Null).await?;
@@ -103,6 +104,9 @@ async fn main() -> anyhow::Result<()> {
                 force,
                 migration_id,
                 steps: result.datamodel_steps,
+                data_migration_steps: Vec::new(),
+                accepted_renames: Vec::new(),
+                dry_run: false,
             };
 
             let result = api.apply_migration(&apply_input).await?;
@@ -176,7 +180,7 @@ async fn generate_dmmf(cmd: &DmmfCommand) -> anyhow::Result<()> {
         if let Some(url) = cmd.url.as_ref() {
             let skeleton = minimal_schema_from_url(url)?;
 
-            let introspected = introspection_core::RpcImpl::introspect_internal(skeleton)
+            let introspected = introspection_core::RpcImpl::introspect_internal(skeleton, Default::default())
                 .await
                 .map_err(|err| anyhow::anyhow!("{:?}", err.data))?;
 
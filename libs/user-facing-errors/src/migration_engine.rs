@@ -34,6 +34,15 @@ struct MigrationRollback {
 )]
 pub struct DatabaseMigrationFormatChanged;
 
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P3004",
+    message = "Timed out after ${timeout_seconds} seconds waiting for the migration lock. Another migration engine is changing the database, try again once it is done."
+)]
+pub struct MigrationLockTimeout {
+    pub timeout_seconds: u64,
+}
+
 // Tests
 
 #[cfg(test)]
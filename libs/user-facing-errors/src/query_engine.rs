@@ -226,3 +226,12 @@ pub struct InputError {
 pub struct ValueOutOfRange {
     pub details: String,
 }
+
+#[derive(Debug, UserFacingError, Serialize)]
+#[user_facing(
+    code = "P2021",
+    message = "The persisted query with hash `${sha256_hash}` is not registered. Send the query text along with its hash to register it."
+)]
+pub struct PersistedQueryNotFound {
+    pub sha256_hash: String,
+}
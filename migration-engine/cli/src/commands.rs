@@ -6,7 +6,7 @@ use anyhow::Context;
 use error::CliError;
 use futures::FutureExt;
 use migration_connector::{ConnectorError, ErrorKind, MigrationConnector};
-use migration_core::CoreResult;
+use migration_core::{api::MigrationApi, ApplyMigrationInput, CoreResult, GenericApi, InferMigrationStepsInput};
 use quaint::prelude::SqlFamily;
 use sql_migration_connector::SqlMigrationConnector;
 use std::collections::HashMap;
@@ -26,7 +26,12 @@ impl Cli {
     pub(crate) async fn run(&self) -> ! {
         match std::panic::AssertUnwindSafe(self.run_inner()).catch_unwind().await {
             Ok(Ok(msg)) => {
-                tracing::info!("{}", msg);
+                match self.command {
+                    // The script is the output of the command.
+                    CliCommand::DryRunMigration { .. } => println!("{}", msg),
+                    _ => tracing::info!("{}", msg),
+                }
+
                 std::process::exit(0);
             }
             Ok(Err(error)) => {
@@ -50,8 +55,9 @@ impl Cli {
     }
 
     pub(crate) async fn run_inner(&self) -> Result<String, CliError> {
-        match self.command {
+        match &self.command {
             CliCommand::CreateDatabase => create_database(&self.datasource).await,
+            CliCommand::DryRunMigration { datamodel } => dry_run_migration(&self.datasource, datamodel).await,
             CliCommand::CanConnectToDatabase => {
                 create_conn(&self.datasource, false).await?;
                 Ok("Connection successful".to_owned())
@@ -66,6 +72,12 @@ enum CliCommand {
     CreateDatabase,
     /// Does the database connection string work?
     CanConnectToDatabase,
+    /// Print the SQL script migrating the database to the datamodel, without applying it.
+    DryRunMigration {
+        /// Path to the datamodel file, or to a directory of `.prisma` files making up the datamodel
+        #[structopt(long, short = "m")]
+        datamodel: String,
+    },
 }
 
 async fn create_conn(datasource: &str, admin_mode: bool) -> CoreResult<(String, Box<SqlMigrationConnector>)> {
@@ -146,6 +158,42 @@ async fn create_database(datasource: &str) -> Result<String, CliError> {
     }
 }
 
+/// Renders the migration `applyMigration` would run for the datamodel. Like all the engine
+/// commands, it creates the migrations table if it is missing, but the migration itself is not
+/// applied nor saved.
+async fn dry_run_migration(datasource: &str, datamodel_location: &str) -> Result<String, CliError> {
+    let schema_files = datamodel::SchemaFiles::load(std::path::Path::new(datamodel_location))
+        .context("Reading the datamodel files.")
+        .map_err(CliError::Other)?;
+    let (_, connector) = create_conn(datasource, false).await?;
+    // The dry run must not create the migration table.
+    let api = MigrationApi::uninitialized(*connector);
+    let migration_id = "dry-run".to_owned();
+
+    let infer_input = InferMigrationStepsInput {
+        migration_id: migration_id.clone(),
+        datamodel: schema_files.schema().to_owned(),
+        assume_to_be_applied: None,
+        assume_applied_migrations: None,
+        accepted_renames: Vec::new(),
+    };
+
+    let steps = api.infer_migration_steps(&infer_input).await?.datamodel_steps;
+
+    let apply_input = ApplyMigrationInput {
+        migration_id,
+        steps,
+        force: None,
+        data_migration_steps: Vec::new(),
+        accepted_renames: Vec::new(),
+        dry_run: true,
+    };
+
+    let output = api.apply_migration(&apply_input).await?;
+
+    Ok(output.script.unwrap_or_default())
+}
+
 /// Try to connect as an admin to a postgres database. We try to pick a default database from which
 /// we can create another database.
 async fn create_postgres_admin_conn(mut url: Url) -> CoreResult<SqlMigrationConnector> {
@@ -14,7 +14,7 @@ struct MigrationEngineCli {
     /// Run only a single command, then exit
     #[structopt(short = "s", long)]
     single_cmd: bool,
-    /// Path to the datamodel
+    /// Path to the datamodel file, or to a directory of `.prisma` files making up the datamodel
     #[structopt(short = "d", long, name = "FILE")]
     datamodel: Option<String>,
     #[structopt(subcommand)]
@@ -60,28 +60,25 @@ async fn main() {
 }
 
 async fn start_engine(datamodel_location: &str, single_cmd: bool) -> ! {
-    use std::io::Read as _;
-
     tracing::info!(git_hash = env!("GIT_HASH"), "Starting migration engine RPC server",);
-    let mut file = std::fs::File::open(datamodel_location).expect("error opening datamodel file");
-
-    let mut datamodel = String::new();
-    file.read_to_string(&mut datamodel).unwrap();
+    let schema_files =
+        datamodel::SchemaFiles::load(std::path::Path::new(datamodel_location)).expect("error reading datamodel files");
+    let datamodel = schema_files.schema();
 
     if single_cmd {
-        let api = RpcApi::new(&datamodel).await.unwrap();
+        let api = RpcApi::new(datamodel).await.unwrap();
         let response = api.handle().unwrap();
 
         println!("{}", response);
     } else {
-        match RpcApi::new(&datamodel).await {
+        match RpcApi::new(datamodel).await {
             // Block the thread and handle IO in async until EOF.
             Ok(api) => json_rpc_stdio::run(api.io_handler()).await.unwrap(),
             Err(err) => {
                 let (error, exit_code) = match &err {
                     CoreError::DatamodelError(errors) => {
                         let error = user_facing_errors::UnknownError {
-                            message: migration_core::api::pretty_print_datamodel_errors(errors, &datamodel)
+                            message: migration_core::api::pretty_print_datamodel_errors(errors, &schema_files)
                                 .expect("rendering error"),
                             backtrace: Some(format!("{:?}", user_facing_errors::new_backtrace())),
                         };
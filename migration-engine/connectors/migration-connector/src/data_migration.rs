@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+/// A change to the data, written by the user and added to an inferred migration. For example, the
+/// null values of a column are filled in before the column is made required.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum DataMigrationStep {
+    /// SQL statements run as they are, after all the schema changes unless a position is given.
+    /// The destructive changes checker does not look into them.
+    #[serde(rename_all = "camelCase")]
+    RawSql {
+        sql: String,
+        #[serde(default)]
+        position: Option<DataMigrationPosition>,
+    },
+    /// Sets the column to `value` on the rows where it is null, before the first change to its table
+    /// unless a position is given.
+    Backfill {
+        table: String,
+        /// The schema of the table, when it is not the schema of the connection.
+        #[serde(default, skip_serializing_if = "Option::is_none")]
+        schema: Option<String>,
+        column: String,
+        value: serde_json::Value,
+        #[serde(default)]
+        position: Option<DataMigrationPosition>,
+    },
+}
+
+impl DataMigrationStep {
+    /// Where the step runs among the inferred steps.
+    pub fn position(&self) -> DataMigrationPosition {
+        match self {
+            DataMigrationStep::RawSql { position, .. } => position.clone().unwrap_or(DataMigrationPosition::End),
+            DataMigrationStep::Backfill { table, position, .. } => position
+                .clone()
+                .unwrap_or_else(|| DataMigrationPosition::BeforeTable { table: table.clone() }),
+        }
+    }
+}
+
+/// Where a [DataMigrationStep](enum.DataMigrationStep.html) runs among the inferred steps. The
+/// positions relative to a table the migration does not change are the end of the migration.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum DataMigrationPosition {
+    /// Before all the schema changes.
+    Start,
+    /// Before the first change to the table.
+    BeforeTable { table: String },
+    /// After the last change to the table.
+    AfterTable { table: String },
+    /// After all the schema changes.
+    End,
+}
@@ -1,4 +1,4 @@
-use crate::{ConnectorResult, MigrationStep};
+use crate::{ConnectorError, ConnectorResult, DataMigrationStep, ErrorKind, MigrationStep, Rename};
 use datamodel::Datamodel;
 
 /// The component responsible for generating a [DatabaseMigration](trait.MigrationConnector.html#associatedtype.DatabaseMigration)
@@ -9,6 +9,44 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync {
     /// Infer the database migration steps. The previous datamodel is provided, but the implementor can ignore it.
     async fn infer(&self, previous: &Datamodel, next: &Datamodel, steps: &[MigrationStep]) -> ConnectorResult<T>;
 
+    /// Like `infer`, with the [DataMigrationStep](enum.DataMigrationStep.html)s added to the
+    /// inferred migration. Connectors without data migrations only accept an empty list.
+    async fn infer_with_data_migration_steps(
+        &self,
+        previous: &Datamodel,
+        next: &Datamodel,
+        steps: &[MigrationStep],
+        data_migration_steps: &[DataMigrationStep],
+    ) -> ConnectorResult<T> {
+        if !data_migration_steps.is_empty() {
+            return Err(ConnectorError::from_kind(ErrorKind::Generic(anyhow::anyhow!(
+                "Data migration steps are not supported by this connector."
+            ))));
+        }
+
+        self.infer(previous, next, steps).await
+    }
+
+    /// Like `infer_with_data_migration_steps`, with the [Rename](enum.Rename.html)s accepted among
+    /// the proposed ones. Connectors without renames only accept an empty list.
+    async fn infer_with_accepted_renames(
+        &self,
+        previous: &Datamodel,
+        next: &Datamodel,
+        steps: &[MigrationStep],
+        data_migration_steps: &[DataMigrationStep],
+        accepted_renames: &[Rename],
+    ) -> ConnectorResult<T> {
+        if !accepted_renames.is_empty() {
+            return Err(ConnectorError::from_kind(ErrorKind::Generic(anyhow::anyhow!(
+                "Renames are not supported by this connector."
+            ))));
+        }
+
+        self.infer_with_data_migration_steps(previous, next, steps, data_migration_steps)
+            .await
+    }
+
     /// Infer a database migration based on the previous and next datamodels. The method signature is identical to `infer`,
     /// but it is expected that this method is implemented based on the provided previous datamodel, and does not rely
     /// on the current state of the database.
@@ -18,4 +56,8 @@ pub trait DatabaseMigrationInferrer<T>: Send + Sync {
         next: &Datamodel,
         steps: &[MigrationStep],
     ) -> ConnectorResult<T>;
+
+    /// Infer a database migration from the schema the `previous` migration led to, to the schema the `next`
+    /// migration leads to. The inferred migration has no steps if both migrations lead to the same schema.
+    fn infer_between_migrations(&self, previous: &T, next: &T) -> ConnectorResult<T>;
 }
@@ -14,4 +14,27 @@ pub trait DatabaseMigrationStepApplier<T>: Send + Sync {
 
     /// Render steps for the CLI. Each step will contain the raw field.
     fn render_steps_pretty(&self, database_migration: &T) -> ConnectorResult<Vec<serde_json::Value>>;
+
+    /// Render the migration as a script, to be written to a migrations directory. The migrations
+    /// applied from a script render the script they were applied from.
+    fn render_script(&self, database_migration: &T) -> ConnectorResult<String>;
+
+    /// Render the statements `apply_step` would run for the whole migration, in order, with the
+    /// transactions they run in. Nothing is run against the database.
+    fn render_dry_run_script(&self, database_migration: &T) -> ConnectorResult<String>;
+
+    /// Render the rollback of the migration as a script, to be written next to the migration script.
+    fn render_rollback_script(&self, database_migration: &T) -> ConnectorResult<String>;
+
+    /// Applies a script read from a migrations directory. The script may have been edited by hand,
+    /// so it is run as is. Returns a database migration without steps, recording the script and the
+    /// schemas before and after it.
+    ///
+    /// The script runs in a transaction on databases with transactional DDL. Elsewhere, e.g. on
+    /// MySQL, a failing script leaves the statements before the failing one applied.
+    async fn apply_script(&self, script: &str) -> ConnectorResult<T>;
+
+    /// Applies a down script read from a migrations directory. The script runs in a transaction on
+    /// databases with transactional DDL, so a failing script leaves the database untouched there.
+    async fn apply_rollback_script(&self, script: &str) -> ConnectorResult<T>;
 }
@@ -0,0 +1,88 @@
+use serde::Serialize;
+use std::fmt;
+
+/// A difference between the schema the applied migrations lead to and the schema found in the
+/// database, e.g. a column added by hand.
+#[derive(Debug, Clone, PartialEq, Serialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Drift {
+    AddedTable { table: String },
+    RemovedTable { table: String },
+    RenamedTable { from: String, to: String },
+    AddedColumn { table: String, column: String },
+    RemovedColumn { table: String, column: String },
+    ChangedColumn { table: String, column: String },
+    RenamedColumn { table: String, from: String, to: String },
+    AddedIndex { table: String, index: String },
+    RemovedIndex { table: String, index: String },
+    ChangedIndex { table: String, index: String },
+    AddedForeignKey { table: String, columns: Vec<String> },
+    RemovedForeignKey { table: String, constraint: String },
+    AddedConstraint { table: String, constraint: String },
+    RemovedConstraint { table: String, constraint: String },
+    ChangedConstraint { table: String, constraint: String },
+    AddedEnum { name: String },
+    RemovedEnum { name: String },
+    ChangedEnum { name: String },
+    AddedView { name: String },
+    RemovedView { name: String },
+}
+
+impl fmt::Display for Drift {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Drift::AddedTable { table } => write!(f, "Added table `{}`", table),
+            Drift::RemovedTable { table } => write!(f, "Removed table `{}`", table),
+            Drift::RenamedTable { from, to } => write!(f, "Renamed table `{}` to `{}`", from, to),
+            Drift::AddedColumn { table, column } => write!(f, "Added column `{}` on table `{}`", column, table),
+            Drift::RemovedColumn { table, column } => write!(f, "Removed column `{}` on table `{}`", column, table),
+            Drift::ChangedColumn { table, column } => write!(f, "Changed column `{}` on table `{}`", column, table),
+            Drift::RenamedColumn { table, from, to } => {
+                write!(f, "Renamed column `{}` to `{}` on table `{}`", from, to, table)
+            }
+            Drift::AddedIndex { table, index } => write!(f, "Added index `{}` on table `{}`", index, table),
+            Drift::RemovedIndex { table, index } => write!(f, "Removed index `{}` on table `{}`", index, table),
+            Drift::ChangedIndex { table, index } => write!(f, "Changed index `{}` on table `{}`", index, table),
+            Drift::AddedForeignKey { table, columns } => write!(
+                f,
+                "Added foreign key on columns ({}) of table `{}`",
+                columns.join(", "),
+                table
+            ),
+            Drift::RemovedForeignKey { table, constraint } => {
+                write!(f, "Removed foreign key `{}` on table `{}`", constraint, table)
+            }
+            Drift::AddedConstraint { table, constraint } => {
+                write!(f, "Added constraint `{}` on table `{}`", constraint, table)
+            }
+            Drift::RemovedConstraint { table, constraint } => {
+                write!(f, "Removed constraint `{}` on table `{}`", constraint, table)
+            }
+            Drift::ChangedConstraint { table, constraint } => {
+                write!(f, "Changed constraint `{}` on table `{}`", constraint, table)
+            }
+            Drift::AddedEnum { name } => write!(f, "Added enum `{}`", name),
+            Drift::RemovedEnum { name } => write!(f, "Removed enum `{}`", name),
+            Drift::ChangedEnum { name } => write!(f, "Changed enum `{}`", name),
+            Drift::AddedView { name } => write!(f, "Added view `{}`", name),
+            Drift::RemovedView { name } => write!(f, "Removed view `{}`", name),
+        }
+    }
+}
+
+/// Renders drifts for humans, one per line.
+pub fn render_drifts(drifts: &[Drift]) -> String {
+    if drifts.is_empty() {
+        return "The database schema is in sync with the migration history.".to_owned();
+    }
+
+    let mut rendered = String::from("The database schema drifted from the migration history:\n");
+
+    for drift in drifts {
+        rendered.push_str("  - ");
+        rendered.push_str(&drift.to_string());
+        rendered.push('\n');
+    }
+
+    rendered
+}
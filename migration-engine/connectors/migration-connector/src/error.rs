@@ -1,8 +1,8 @@
 use anyhow::format_err;
-use std::fmt::Display;
+use std::{fmt::Display, time::Duration};
 use thiserror::Error;
 use tracing_error::SpanTrace;
-use user_facing_errors::KnownError;
+use user_facing_errors::{migration_engine::MigrationLockTimeout, KnownError};
 
 #[derive(Debug, Error)]
 #[error("{}\n{}", kind, context)]
@@ -35,6 +35,16 @@ impl ConnectorError {
             context: SpanTrace::capture(),
         }
     }
+
+    pub fn migration_lock_timeout(timeout: Duration) -> Self {
+        let timeout_seconds = timeout.as_secs();
+
+        ConnectorError {
+            user_facing_error: KnownError::new(MigrationLockTimeout { timeout_seconds }).ok(),
+            kind: ErrorKind::MigrationLockTimeout { timeout_seconds },
+            context: SpanTrace::capture(),
+        }
+    }
 }
 
 #[derive(Debug, Error)]
@@ -76,6 +86,9 @@ pub enum ErrorKind {
     #[error("Operation timed out")]
     Timeout,
 
+    #[error("Timed out after {} seconds waiting for the migration lock", timeout_seconds)]
+    MigrationLockTimeout { timeout_seconds: u64 },
+
     #[error("Error opening a TLS connection. {}", message)]
     TlsError { message: String },
 
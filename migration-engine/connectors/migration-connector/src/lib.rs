@@ -1,23 +1,33 @@
 //! This crate defines the API exposed by the connectors to the migration engine core. The entry point for this API is the [MigrationConnector](trait.MigrationConnector.html) trait.
 
+mod data_migration;
 mod database_migration_inferrer;
 mod database_migration_step_applier;
 mod destructive_changes_checker;
+mod drift;
 mod error;
 mod migration_applier;
+mod migration_lock;
 mod migration_persistence;
+mod migrations_directory;
+mod rename;
 
 pub mod steps;
 
+pub use data_migration::*;
 pub use database_migration_inferrer::*;
 pub use database_migration_step_applier::*;
 pub use destructive_changes_checker::*;
+pub use drift::*;
 pub use error::*;
 pub use migration_applier::*;
+pub use migration_lock::*;
 pub use migration_persistence::*;
+pub use migrations_directory::*;
+pub use rename::*;
 pub use steps::MigrationStep;
 
-use std::fmt::Debug;
+use std::{fmt::Debug, time::Duration};
 use user_facing_errors::migration_engine::DatabaseMigrationFormatChanged;
 
 /// The top-level trait for connectors. This is the abstraction the migration engine core relies on to
@@ -43,6 +53,24 @@ pub trait MigrationConnector: Send + Sync + 'static {
     /// Drop all database state.
     async fn reset(&self) -> ConnectorResult<()>;
 
+    /// Install the database triggers producing record change events on the given tables, or on all
+    /// tables if none are given. The query engine streams these events to subscriptions.
+    /// Returns the names of the tables producing change events.
+    async fn enable_change_events(&self, tables: &[String]) -> ConnectorResult<Vec<String>>;
+
+    /// Replay the scripts of the applied migrations, in order, in a temporary shadow database and
+    /// compare the database with the schema they lead to. Returns the differences, i.e. the changes
+    /// made to the database outside of migrations.
+    async fn diagnose_drift(&self, applied_migration_scripts: &[String]) -> ConnectorResult<Vec<Drift>>;
+
+    /// Describe the database to start its migration history from its current schema. The returned
+    /// database migration creates the current schema of the database from an empty database.
+    async fn baseline(&self) -> ConnectorResult<Self::DatabaseMigration>;
+
+    /// Take the lock serializing the migration engines that change the database, waiting for it
+    /// at most `timeout`. See [MigrationLock](trait.MigrationLock.html).
+    async fn acquire_migration_lock<'a>(&'a self, timeout: Duration) -> ConnectorResult<Box<dyn MigrationLock + 'a>>;
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(
@@ -85,6 +113,12 @@ pub trait MigrationConnector: Send + Sync + 'static {
 
 pub trait DatabaseMigrationMarker: Debug + Send + Sync {
     fn serialize(&self) -> serde_json::Value;
+
+    /// The renames the migration could make instead of dropping and adding tables or columns. See
+    /// [Rename](enum.Rename.html).
+    fn proposed_renames(&self) -> &[Rename] {
+        &[]
+    }
 }
 
 /// Shorthand for a [Result](https://doc.rust-lang.org/std/result/enum.Result.html) where the error
@@ -0,0 +1,10 @@
+use crate::ConnectorResult;
+
+/// A lock on the database held while migrations are applied, so migration engines started at the
+/// same time, e.g. by two deployments, do not apply the same migrations concurrently.
+#[async_trait::async_trait]
+pub trait MigrationLock: Send + Sync {
+    /// Release the lock. A lock that is dropped without being released is only released when the
+    /// connection to the database is closed.
+    async fn release(self: Box<Self>) -> ConnectorResult<()>;
+}
@@ -45,6 +45,10 @@ pub trait MigrationPersistence: Send + Sync {
     /// into the database.
     async fn update(&self, params: &MigrationUpdateParams) -> Result<(), ConnectorError>;
 
+    /// Replace the squashed migrations with the provided migration. The migration takes the revision of the
+    /// last squashed migration, so it keeps its place before the migrations applied after the squashed ones.
+    async fn squash(&self, squashed: &[Migration], migration: Migration) -> Result<Migration, ConnectorError>;
+
     /// Returns whether the migration with the provided migration id has already been successfully applied.
     ///
     /// The default impl will load all migrations and scan for the provided migration id. Implementors are encouraged to implement this more efficiently.
@@ -70,6 +74,8 @@ pub struct Migration {
     pub datamodel_string: String,
     pub datamodel_steps: Vec<MigrationStep>,
     pub database_migration: serde_json::Value,
+    /// The checksum of the script of migrations applied from a migrations directory.
+    pub checksum: Option<String>,
     pub errors: Vec<String>,
     pub started_at: DateTime<Utc>,
     pub finished_at: Option<DateTime<Utc>>,
@@ -84,6 +90,7 @@ pub struct MigrationUpdateParams {
     pub status: MigrationStatus,
     pub applied: usize,
     pub rolled_back: usize,
+    pub database_migration: serde_json::Value,
     pub errors: Vec<String>,
     pub finished_at: Option<DateTime<Utc>>,
 }
@@ -110,6 +117,7 @@ impl Migration {
             rolled_back: 0,
             datamodel_steps: Vec::new(),
             database_migration: serde_json::to_value("{}").unwrap(),
+            checksum: None,
             errors: Vec::new(),
             started_at: Self::timestamp_without_nanos(),
             finished_at: None,
@@ -124,6 +132,7 @@ impl Migration {
             status: self.status.clone(),
             applied: self.applied,
             rolled_back: self.rolled_back,
+            database_migration: self.database_migration.clone(),
             errors: self.errors.clone(),
             finished_at: self.finished_at.clone(),
         }
@@ -238,4 +247,8 @@ impl MigrationPersistence for EmptyMigrationPersistence {
     async fn update(&self, _params: &MigrationUpdateParams) -> Result<(), ConnectorError> {
         unimplemented!("Not allowed on a EmptyMigrationPersistence")
     }
+
+    async fn squash(&self, _squashed: &[Migration], _migration: Migration) -> Result<Migration, ConnectorError> {
+        unimplemented!("Not allowed on a EmptyMigrationPersistence")
+    }
 }
@@ -0,0 +1,146 @@
+//! Migrations directory interactions. A migrations directory contains one directory per migration,
+//! named `{timestamp}_{migration_name}`, holding the script of the migration and the down script
+//! rolling it back. The directories are applied in the order of their names. A lock file at the root of the migrations directory records
+//! the provider the migrations were written for.
+
+use sha2::{Digest, Sha256};
+use std::{
+    fs::{read_dir, DirEntry},
+    io::{self, Write},
+    path::{Path, PathBuf},
+};
+
+/// The file name of the script in a migration directory.
+pub const MIGRATION_SCRIPT_FILENAME: &str = "migration.sql";
+
+/// The file name of the script rolling back the migration in a migration directory.
+pub const DOWN_SCRIPT_FILENAME: &str = "down.sql";
+
+/// The file name of the lock file at the root of a migrations directory.
+pub const MIGRATION_LOCK_FILENAME: &str = "migration_lock.toml";
+
+/// Creates a directory for a new migration, named after the current timestamp and the migration name.
+pub fn create_migration_directory(
+    migrations_directory_path: &Path,
+    migration_name: &str,
+) -> io::Result<MigrationDirectory> {
+    let timestamp = chrono::Utc::now().format("%Y%m%d%H%M%S");
+    let directory_name = format!(
+        "{timestamp}_{migration_name}",
+        timestamp = timestamp,
+        migration_name = migration_name
+    );
+    let directory_path = migrations_directory_path.join(directory_name);
+
+    if directory_path.exists() {
+        return Err(io::Error::new(
+            io::ErrorKind::AlreadyExists,
+            format!("The migration directory `{}` already exists.", directory_path.display()),
+        ));
+    }
+
+    std::fs::create_dir_all(&directory_path)?;
+
+    Ok(MigrationDirectory { path: directory_path })
+}
+
+/// Lists the migration directories of a migrations directory, in the order they are applied. A
+/// migrations directory that does not exist is empty.
+pub fn list_migrations(migrations_directory_path: &Path) -> io::Result<Vec<MigrationDirectory>> {
+    if !migrations_directory_path.exists() {
+        return Ok(Vec::new());
+    }
+
+    let mut entries: Vec<DirEntry> = read_dir(migrations_directory_path)?.collect::<Result<_, _>>()?;
+
+    entries.retain(|entry| entry.path().is_dir());
+    entries.sort_by_key(|entry| entry.file_name());
+
+    Ok(entries
+        .into_iter()
+        .map(|entry| MigrationDirectory { path: entry.path() })
+        .collect())
+}
+
+/// Writes the lock file of a migrations directory, recording the provider of the migrations.
+pub fn write_migration_lock_file(migrations_directory_path: &Path, provider: &str) -> io::Result<()> {
+    std::fs::create_dir_all(migrations_directory_path)?;
+
+    let mut file = std::fs::File::create(migrations_directory_path.join(MIGRATION_LOCK_FILENAME))?;
+
+    writeln!(file, "# Please do not edit this file manually")?;
+    writeln!(file, "provider = \"{}\"", provider)?;
+
+    Ok(())
+}
+
+/// Reads the provider recorded in the lock file of a migrations directory, if there is one.
+pub fn read_provider_from_lock_file(migrations_directory_path: &Path) -> io::Result<Option<String>> {
+    let lock_file_path = migrations_directory_path.join(MIGRATION_LOCK_FILENAME);
+
+    if !lock_file_path.exists() {
+        return Ok(None);
+    }
+
+    let content = std::fs::read_to_string(lock_file_path)?;
+    let provider = content
+        .lines()
+        .map(|line| line.trim())
+        .filter(|line| line.starts_with("provider"))
+        .filter_map(|line| line.splitn(2, '=').nth(1))
+        .map(|value| value.trim().trim_matches('"').to_owned())
+        .next();
+
+    Ok(provider)
+}
+
+/// The checksum of a migration script, recorded when the migration is applied to detect later
+/// edits of the script.
+pub fn script_checksum(script: &str) -> String {
+    format!("{:x}", Sha256::digest(script.as_bytes()))
+}
+
+/// A directory containing one migration.
+#[derive(Debug, Clone)]
+pub struct MigrationDirectory {
+    path: PathBuf,
+}
+
+impl MigrationDirectory {
+    /// The name of the migration, i.e. the name of the directory.
+    pub fn migration_name(&self) -> &str {
+        self.path
+            .file_name()
+            .expect("MigrationDirectory::migration_name")
+            .to_str()
+            .expect("Migration directory name is not valid UTF-8.")
+    }
+
+    pub fn path(&self) -> &Path {
+        &self.path
+    }
+
+    /// Reads the script of the migration, as it was rendered or edited by hand.
+    pub fn read_migration_script(&self) -> io::Result<String> {
+        std::fs::read_to_string(self.path.join(MIGRATION_SCRIPT_FILENAME))
+    }
+
+    pub fn write_migration_script(&self, script: &str) -> io::Result<()> {
+        std::fs::write(self.path.join(MIGRATION_SCRIPT_FILENAME), script)
+    }
+
+    /// Reads the down script of the migration. `None` for migrations written without one.
+    pub fn read_down_script(&self) -> io::Result<Option<String>> {
+        let down_script_path = self.path.join(DOWN_SCRIPT_FILENAME);
+
+        if !down_script_path.exists() {
+            return Ok(None);
+        }
+
+        std::fs::read_to_string(down_script_path).map(Some)
+    }
+
+    pub fn write_down_script(&self, script: &str) -> io::Result<()> {
+        std::fs::write(self.path.join(DOWN_SCRIPT_FILENAME), script)
+    }
+}
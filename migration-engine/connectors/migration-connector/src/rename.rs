@@ -0,0 +1,19 @@
+use serde::{Deserialize, Serialize};
+
+/// A table or column rename. The connectors propose renames when a dropped and an added table or
+/// column are compatible, but only the renames accepted in the migration input are migrated as
+/// renames. The others are a drop and an addition, and the data is lost.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "kind", rename_all = "camelCase")]
+pub enum Rename {
+    Table {
+        from: String,
+        to: String,
+    },
+    /// `table` is the name of the table after the migration.
+    Column {
+        table: String,
+        from: String,
+        to: String,
+    },
+}
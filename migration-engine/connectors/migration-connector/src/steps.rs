@@ -32,6 +32,10 @@ pub enum MigrationStep {
 #[serde(rename_all = "camelCase", deny_unknown_fields)]
 pub struct CreateModel {
     pub model: String,
+
+    /// Whether the model is a composite type, declared with the `type` keyword.
+    #[serde(skip_serializing_if = "std::ops::Not::not", default)]
+    pub is_composite_type: bool,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Hash, Eq)]
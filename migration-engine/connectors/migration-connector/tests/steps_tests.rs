@@ -7,6 +7,17 @@ fn full_CreateModel_must_work() {
     let json = r#"{"tag":"CreateModel","model":"Blog"}"#;
     let expected_struct = MigrationStep::CreateModel(CreateModel {
         model: "Blog".to_string(),
+        is_composite_type: false,
+    });
+    assert_symmetric_serde(json, expected_struct);
+}
+
+#[test]
+fn CreateModel_for_composite_type_must_work() {
+    let json = r#"{"tag":"CreateModel","model":"Address","isCompositeType":true}"#;
+    let expected_struct = MigrationStep::CreateModel(CreateModel {
+        model: "Address".to_string(),
+        is_composite_type: true,
     });
     assert_symmetric_serde(json, expected_struct);
 }
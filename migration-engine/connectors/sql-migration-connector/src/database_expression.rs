@@ -0,0 +1,325 @@
+use std::{iter::Peekable, str::Chars};
+
+/// Normalizes an SQL expression, to compare it with the expressions databases return for column defaults and
+/// check constraints. Postgres for example returns `md5(random()::text)` as `md5((random())::text)`, `'a'` as
+/// `'a'::text` and `a > 0 AND b > 0` as `((a > 0) AND (b > 0))`.
+///
+/// Whitespace and redundant parentheses are removed, keywords and unquoted identifiers are lowercased and the
+/// casts of string literals to text types are dropped. Quoted identifiers are kept as written, without their
+/// quotes, as are string literals and the other casts: `'1'::integer` is not the same expression as `'1'`.
+pub fn normalize_database_expression(expression: &str) -> String {
+    let mut tokens = expression_tokens(expression);
+
+    while let Some((open, close)) = redundant_parentheses(&tokens) {
+        remove_parenthesis(&mut tokens, close);
+        remove_parenthesis(&mut tokens, open);
+    }
+
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            ExpressionToken::Char(c) => c.to_string(),
+            ExpressionToken::Literal(literal) => literal,
+        })
+        .collect()
+}
+
+/// Removes a parenthesis, keeping the words around it apart.
+fn remove_parenthesis(tokens: &mut Vec<ExpressionToken>, position: usize) {
+    let is_word_char = |token: Option<&ExpressionToken>| match token {
+        Some(ExpressionToken::Char(c)) => is_identifier_char(*c),
+        _ => false,
+    };
+
+    if position > 0 && is_word_char(tokens.get(position - 1)) && is_word_char(tokens.get(position + 1)) {
+        tokens[position] = ExpressionToken::Char(' ');
+    } else {
+        tokens.remove(position);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum ExpressionToken {
+    Char(char),
+    Literal(String),
+}
+
+/// The characters of the expression, with the string literals as single tokens. Whitespace is kept only to
+/// separate words.
+fn expression_tokens(expression: &str) -> Vec<ExpressionToken> {
+    let mut tokens = Vec::new();
+    let mut chars = expression.chars().peekable();
+
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                let mut literal = c.to_string();
+
+                while let Some(c) = chars.next() {
+                    literal.push(c);
+
+                    // Quotes are escaped by doubling them.
+                    if c == '\'' {
+                        match chars.peek() {
+                            Some('\'') => literal.push(chars.next().unwrap()),
+                            _ => break,
+                        }
+                    }
+                }
+
+                tokens.push(ExpressionToken::Literal(literal));
+                skip_cast(&mut chars);
+            }
+            quote @ '"' | quote @ '`' => {
+                // Quotes are escaped by doubling them.
+                while let Some(c) = chars.next() {
+                    if c == quote {
+                        match chars.peek() {
+                            Some(next) if *next == quote => chars.next(),
+                            _ => break,
+                        };
+                    }
+
+                    tokens.push(ExpressionToken::Char(c));
+                }
+            }
+            c if c.is_whitespace() => {
+                let follows_word = match tokens.last() {
+                    Some(ExpressionToken::Char(c)) => is_identifier_char(*c),
+                    _ => false,
+                };
+
+                while chars.peek().map(|c| c.is_whitespace()).unwrap_or(false) {
+                    chars.next();
+                }
+
+                let precedes_word = chars
+                    .peek()
+                    .map(|c| is_identifier_char(*c) || *c == '"' || *c == '`')
+                    .unwrap_or(false);
+
+                if follows_word && precedes_word {
+                    tokens.push(ExpressionToken::Char(' '));
+                }
+            }
+            c => tokens.extend(c.to_lowercase().map(ExpressionToken::Char)),
+        }
+    }
+
+    tokens
+}
+
+/// Skips a `::type` cast to a text type, which the databases add to string literals. The casts to other types
+/// change the expression and are kept.
+fn skip_cast(chars: &mut Peekable<Chars<'_>>) {
+    let mut lookahead = chars.clone();
+
+    if lookahead.next() != Some(':') || lookahead.next() != Some(':') {
+        return;
+    }
+
+    match identifier(&mut lookahead).to_lowercase().as_str() {
+        "text" | "varchar" | "bpchar" => *chars = lookahead,
+        "character" | "char" => {
+            *chars = lookahead.clone();
+
+            while lookahead.peek().map(|c| c.is_whitespace()).unwrap_or(false) {
+                lookahead.next();
+            }
+
+            if identifier(&mut lookahead).to_lowercase() == "varying" {
+                *chars = lookahead;
+            }
+        }
+        _ => (),
+    }
+}
+
+fn identifier(chars: &mut Peekable<Chars<'_>>) -> String {
+    let mut identifier = String::new();
+
+    while let Some(c) = chars.peek().copied().filter(|c| is_identifier_char(*c)) {
+        identifier.push(c);
+        chars.next();
+    }
+
+    identifier
+}
+
+/// The positions of the first pair of parentheses that can be removed without changing the expression: doubled
+/// parentheses, parentheses around the whole expression, parentheses around a single operand that are not the
+/// parentheses of a function call, and parentheses around the operands of `AND`, `OR` and `NOT`.
+fn redundant_parentheses(tokens: &[ExpressionToken]) -> Option<(usize, usize)> {
+    const BOOLEAN_OPERATORS: &[&str] = &["and", "or", "not"];
+
+    let mut open_parentheses = Vec::new();
+
+    for (close, token) in tokens.iter().enumerate() {
+        match token {
+            ExpressionToken::Char('(') => open_parentheses.push(close),
+            ExpressionToken::Char(')') => {
+                let open = open_parentheses.pop()?;
+                let content = &tokens[open + 1..close];
+                let previous = open.checked_sub(1).map(|idx| &tokens[idx]);
+                let next = tokens.get(close + 1);
+                let word_before = word_before(tokens, open);
+                let word_after = word_after(tokens, close + 1);
+
+                let is_doubled =
+                    previous == Some(&ExpressionToken::Char('(')) && next == Some(&ExpressionToken::Char(')'));
+                let is_whole_expression = open == 0 && close == tokens.len() - 1;
+                let is_call = !word_before.is_empty() && !BOOLEAN_OPERATORS.contains(&word_before.as_str());
+
+                let opens_operand = match previous {
+                    None | Some(ExpressionToken::Char('(')) | Some(ExpressionToken::Char(',')) => true,
+                    _ => BOOLEAN_OPERATORS.contains(&word_before.as_str()),
+                };
+                let closes_operand = match next {
+                    None | Some(ExpressionToken::Char(')')) | Some(ExpressionToken::Char(',')) => true,
+                    _ => word_after == "and" || word_after == "or",
+                };
+                let is_boolean_operand = opens_operand
+                    && closes_operand
+                    && !top_level_words(content)
+                        .iter()
+                        .any(|word| word == "and" || word == "or");
+
+                if is_doubled || is_whole_expression || is_boolean_operand || (!is_call && is_single_operand(content)) {
+                    return Some((open, close));
+                }
+            }
+            _ => (),
+        }
+    }
+
+    None
+}
+
+/// The word right before the position, skipping a separating space.
+fn word_before(tokens: &[ExpressionToken], position: usize) -> String {
+    let mut chars = leading_word(tokens[..position].iter().rev());
+    chars.reverse();
+
+    chars.into_iter().collect()
+}
+
+/// The word starting at the position, skipping a separating space.
+fn word_after(tokens: &[ExpressionToken], position: usize) -> String {
+    leading_word(tokens[position.min(tokens.len())..].iter())
+        .into_iter()
+        .collect()
+}
+
+fn leading_word<'a>(tokens: impl Iterator<Item = &'a ExpressionToken>) -> Vec<char> {
+    tokens
+        .skip_while(|token| **token == ExpressionToken::Char(' '))
+        .take_while(|token| match token {
+            ExpressionToken::Char(c) => is_identifier_char(*c),
+            _ => false,
+        })
+        .filter_map(|token| match token {
+            ExpressionToken::Char(c) => Some(*c),
+            _ => None,
+        })
+        .collect()
+}
+
+/// The words outside of parentheses.
+fn top_level_words(tokens: &[ExpressionToken]) -> Vec<String> {
+    let mut words = vec![String::new()];
+    let mut depth = 0;
+
+    for token in tokens {
+        match token {
+            ExpressionToken::Char('(') => depth += 1,
+            ExpressionToken::Char(')') => depth -= 1,
+            ExpressionToken::Char(c) if depth == 0 && is_identifier_char(*c) => words.last_mut().unwrap().push(*c),
+            _ => words.push(String::new()),
+        }
+    }
+
+    words.into_iter().filter(|word| !word.is_empty()).collect()
+}
+
+/// Whether the tokens are a single operand: identifiers, literals, function calls and casts, without operators.
+fn is_single_operand(tokens: &[ExpressionToken]) -> bool {
+    let mut depth = 0;
+
+    !tokens.is_empty()
+        && tokens.iter().all(|token| match token {
+            ExpressionToken::Char('(') => {
+                depth += 1;
+                true
+            }
+            ExpressionToken::Char(')') => {
+                depth -= 1;
+                true
+            }
+            _ if depth > 0 => true,
+            ExpressionToken::Char(c) => is_identifier_char(*c) || *c == '.' || *c == ':',
+            ExpressionToken::Literal(_) => true,
+        })
+}
+
+fn is_identifier_char(c: char) -> bool {
+    c.is_alphanumeric() || c == '_'
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn database_expressions_are_normalized() {
+        let pairs = &[
+            ("md5(random()::text)", "md5((random())::text)"),
+            ("uuid()", "(UUID())"),
+            ("'Concatenated' || 'value'", "('Concatenated'::text || 'value'::text)"),
+            ("'It''s'", "'It''s'::character varying"),
+            ("a > 0 AND b > 0", "((a > 0) AND (b > 0))"),
+            ("\"name\" <> ''", "(name <> ''::text)"),
+        ];
+
+        for (written, described) in pairs {
+            assert_eq!(
+                normalize_database_expression(written),
+                normalize_database_expression(described)
+            );
+        }
+
+        assert_ne!(
+            normalize_database_expression("a * (b + c)"),
+            normalize_database_expression("a * b + c")
+        );
+        assert_ne!(
+            normalize_database_expression("'Value'"),
+            normalize_database_expression("'value'")
+        );
+    }
+
+    #[test]
+    fn quoted_identifiers_are_kept_as_written() {
+        assert_eq!(normalize_database_expression("\"Name\" <> ''"), "Name<>''");
+        assert_ne!(
+            normalize_database_expression("\"Name\" <> ''"),
+            normalize_database_expression("Name <> ''")
+        );
+        assert_eq!(normalize_database_expression("`odd``name` > 0"), "odd`name>0");
+    }
+
+    #[test]
+    fn only_the_casts_to_text_types_are_dropped() {
+        assert_ne!(
+            normalize_database_expression("'1'::integer"),
+            normalize_database_expression("'1'")
+        );
+        assert_ne!(
+            normalize_database_expression("'2001-02-16'::date"),
+            normalize_database_expression("'2001-02-16'")
+        );
+        assert_eq!(
+            normalize_database_expression("'a'::character varying || 'b'::bpchar"),
+            normalize_database_expression("'a' || 'b'")
+        );
+    }
+}
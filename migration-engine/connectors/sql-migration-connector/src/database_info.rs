@@ -10,6 +10,7 @@ use quaint::{
 pub struct DatabaseInfo {
     connection_info: ConnectionInfo,
     database_version: Option<String>,
+    online_migrations: bool,
 }
 
 impl DatabaseInfo {
@@ -19,6 +20,7 @@ impl DatabaseInfo {
         Ok(DatabaseInfo {
             connection_info,
             database_version,
+            online_migrations: false,
         })
     }
 
@@ -40,6 +42,41 @@ impl DatabaseInfo {
                 .unwrap_or(false)
     }
 
+    /// SQLite can rename columns in place since 3.25. Older versions redefine the table instead.
+    pub(crate) fn sqlite_supports_rename_column(&self) -> bool {
+        let version = match self.database_version.as_ref() {
+            Some(version) if self.connection_info.sql_family() == SqlFamily::Sqlite => version,
+            _ => return false,
+        };
+
+        let mut parts = version.split('.').map(|part| part.parse::<u32>().unwrap_or(0));
+        let major = parts.next().unwrap_or(0);
+        let minor = parts.next().unwrap_or(0);
+
+        (major, minor) >= (3, 25)
+    }
+
+    /// Postgres adds columns with constant defaults without rewriting the table since version 11.
+    pub(crate) fn postgres_has_fast_column_defaults(&self) -> bool {
+        self.connection_info.sql_family() == SqlFamily::Postgres
+            && self
+                .database_version
+                .as_ref()
+                .and_then(|version| version.parse::<u32>().ok())
+                .map(|version_num| version_num >= 110_000)
+                .unwrap_or(false)
+    }
+
+    /// Online migrations avoid locking the tables they change, so the application can keep using
+    /// them. SQLite locks the whole database to write, so it ignores the setting.
+    pub(crate) fn online_migrations(&self) -> bool {
+        self.online_migrations && self.connection_info.sql_family() != SqlFamily::Sqlite
+    }
+
+    pub(crate) fn set_online_migrations(&mut self, online_migrations: bool) {
+        self.online_migrations = online_migrations;
+    }
+
     pub(crate) fn sql_family(&self) -> SqlFamily {
         self.connection_info.sql_family()
     }
@@ -72,7 +109,29 @@ async fn get_database_version(connection: &Quaint, connection_info: &ConnectionI
 
             Ok(version_string)
         }
-        _ => Ok(None),
+        SqlFamily::Sqlite => {
+            let query = r#"SELECT sqlite_version() version"#;
+
+            let rows = connection.query_raw(query, &[]).await?;
+
+            let version_string = rows
+                .get(0)
+                .and_then(|row| row.get("version").and_then(|version| version.to_string()));
+
+            Ok(version_string)
+        }
+        SqlFamily::Postgres => {
+            // The version as a number, e.g. `110005` for 11.5.
+            let query = r#"SELECT current_setting('server_version_num') version"#;
+
+            let rows = connection.query_raw(query, &[]).await?;
+
+            let version_string = rows
+                .get(0)
+                .and_then(|row| row.get("version").and_then(|version| version.to_string()));
+
+            Ok(version_string)
+        }
     }
 }
 
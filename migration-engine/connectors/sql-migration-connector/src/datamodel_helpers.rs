@@ -1,24 +1,33 @@
 use datamodel::{
     dml::{
-        Datamodel, DefaultValue, Enum, Field, FieldArity, FieldType, IndexDefinition, Model, ScalarType,
-        WithDatabaseName,
+        CheckConstraint, Datamodel, DefaultValue, Enum, Field, FieldArity, FieldType, IndexDefinition, Model,
+        ScalarType, WithDatabaseName,
     },
     RelationInfo,
 };
 
+/// Iterator to walk all the models backed by a table. Views are skipped.
 pub(crate) fn walk_models<'a>(datamodel: &'a Datamodel) -> impl Iterator<Item = ModelRef<'a>> + 'a {
-    datamodel.models.iter().map(move |model| ModelRef { datamodel, model })
+    datamodel
+        .models
+        .iter()
+        .filter(|model| !model.is_view)
+        .map(move |model| ModelRef { datamodel, model })
 }
 
 /// Iterator to walk all the fields in the schema, associating them with their parent model.
+/// Fields of views are skipped.
 pub(super) fn walk_fields<'a>(datamodel: &'a Datamodel) -> impl Iterator<Item = FieldRef<'a>> + 'a {
-    datamodel.models().flat_map(move |model| {
-        model.fields().map(move |field| FieldRef {
-            datamodel,
-            model,
-            field,
+    datamodel
+        .models()
+        .filter(|model| !model.is_view)
+        .flat_map(move |model| {
+            model.fields().map(move |field| FieldRef {
+                datamodel,
+                model,
+                field,
+            })
         })
-    })
 }
 
 #[derive(Debug, Copy, Clone)]
@@ -40,6 +49,10 @@ impl<'a> ModelRef<'a> {
         self.model.final_database_name()
     }
 
+    pub(super) fn schema(&self) -> Option<&'a str> {
+        self.model.schema.as_deref()
+    }
+
     pub(super) fn fields<'b>(&'b self) -> impl Iterator<Item = FieldRef<'a>> + 'b {
         self.model.fields().map(move |field| FieldRef {
             datamodel: self.datamodel,
@@ -64,10 +77,18 @@ impl<'a> ModelRef<'a> {
         self.model.indices.iter()
     }
 
+    pub(super) fn check_constraints<'b>(&'b self) -> impl Iterator<Item = &'a CheckConstraint> + 'b {
+        self.model.check_constraints.iter()
+    }
+
     pub(super) fn model(&self) -> &'a Model {
         self.model
     }
 
+    pub(super) fn primary_key_db_name(&self) -> Option<&'a str> {
+        self.model.primary_key_db_name()
+    }
+
     pub(super) fn name(&self) -> &'a str {
         &self.model.name
     }
@@ -113,13 +134,22 @@ pub(super) struct FieldRef<'a> {
 
 impl<'a> FieldRef<'a> {
     pub(super) fn arity(&self) -> FieldArity {
-        self.field.arity
+        match (&self.field.field_type, self.field.arity) {
+            // Lists of composite values are stored as a single JSON array.
+            (FieldType::Composite(_), FieldArity::List) => FieldArity::Required,
+            (_, arity) => arity,
+        }
     }
 
     pub(super) fn db_name(&self) -> &'a str {
         self.field.final_database_name()
     }
 
+    /// The database name of the unique constraint of the field, if set with `map`.
+    pub(super) fn constraint_db_name(&self) -> Option<&'a str> {
+        self.field.constraint_db_name.as_deref()
+    }
+
     pub(super) fn default_value(&self) -> Option<&'a DefaultValue> {
         self.field.default_value.as_ref()
     }
@@ -131,10 +161,21 @@ impl<'a> FieldRef<'a> {
                 r#enum: self.datamodel.find_enum(name).unwrap(),
             }),
             FieldType::Base(scalar_type, _) => TypeRef::Base(*scalar_type),
+            FieldType::ConnectorSpecific(native_type) => TypeRef::Base(native_type.prisma_type()),
+            // Composite values are stored as JSON documents.
+            FieldType::Composite(_) => TypeRef::Base(ScalarType::Json),
             _ => TypeRef::Other,
         }
     }
 
+    /// The native database type of the field, e.g. `varchar(255)` for `@db.VarChar(255)`.
+    pub(super) fn native_type(&self) -> Option<&'a str> {
+        match &self.field.field_type {
+            FieldType::ConnectorSpecific(native_type) => Some(native_type.datasource_type()),
+            _ => None,
+        }
+    }
+
     pub(super) fn as_relation_field(&self) -> Option<RelationFieldRef<'a>> {
         match &self.field.field_type {
             FieldType::Relation(relation_info) => Some(RelationFieldRef {
@@ -260,10 +301,18 @@ impl<'a> RelationFieldRef<'a> {
         self.relation_info.name.as_ref()
     }
 
+    pub(crate) fn fk_db_name(&self) -> Option<&'a str> {
+        self.relation_info.fk_db_name.as_deref()
+    }
+
     pub(crate) fn referenced_table_name(&self) -> &'a str {
         self.referenced_model().final_database_name()
     }
 
+    pub(crate) fn referenced_schema(&self) -> Option<&'a str> {
+        self.referenced_model().schema.as_deref()
+    }
+
     fn referenced_model(&self) -> &'a Model {
         self.field
             .datamodel
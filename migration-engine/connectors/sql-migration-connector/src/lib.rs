@@ -1,18 +1,25 @@
 mod component;
+mod database_expression;
 mod database_info;
 mod datamodel_helpers;
 mod error;
+mod sql_baseline;
+mod sql_change_events;
 mod sql_database_migration_inferrer;
 mod sql_database_step_applier;
 mod sql_destructive_changes_checker;
+mod sql_drift_detector;
 mod sql_migration;
+mod sql_migration_lock;
 mod sql_migration_persistence;
 mod sql_renderer;
 mod sql_schema_calculator;
 mod sql_schema_differ;
 mod sql_schema_helpers;
 
+pub use database_expression::normalize_database_expression;
 pub use error::*;
+pub use sql_change_events::{CHANGE_EVENTS_CHANNEL, CHANGE_EVENTS_TABLE_NAME};
 pub use sql_migration::*;
 pub use sql_migration_persistence::MIGRATION_TABLE_NAME;
 
@@ -24,9 +31,13 @@ use quaint::{
     prelude::{ConnectionInfo, Queryable, SqlFamily},
     single::Quaint,
 };
+use sql_baseline::SqlBaseline;
+use sql_change_events::SqlChangeEvents;
 use sql_database_migration_inferrer::*;
 use sql_database_step_applier::*;
 use sql_destructive_changes_checker::*;
+use sql_drift_detector::SqlDriftDetector;
+use sql_migration_lock::SqlMigrationLock;
 use sql_migration_persistence::*;
 use sql_schema_describer::SqlSchemaDescriberBackend;
 use std::{fs, path::PathBuf, sync::Arc, time::Duration};
@@ -37,9 +48,13 @@ const CONNECTION_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub struct SqlMigrationConnector {
     pub schema_name: String,
+    /// The schemas managed besides `schema_name`, from the `schemas` property of the datasource.
+    pub additional_schemas: Vec<String>,
     pub database: Arc<dyn Queryable + Send + Sync + 'static>,
     pub database_info: DatabaseInfo,
     pub database_describer: Arc<dyn SqlSchemaDescriberBackend + Send + Sync + 'static>,
+    /// The connection string, to connect to the shadow databases of drift detection.
+    pub url: String,
 }
 
 impl SqlMigrationConnector {
@@ -89,11 +104,30 @@ impl SqlMigrationConnector {
         Ok(Self {
             database_info,
             schema_name,
+            additional_schemas: Vec::new(),
             database: conn,
             database_describer: Arc::clone(&describer),
+            url: database_str.to_owned(),
         })
     }
 
+    /// Manage the given schemas besides the one of the connection. Only Postgres supports this.
+    pub fn with_additional_schemas(mut self, schemas: &[String]) -> Self {
+        self.additional_schemas = schemas
+            .iter()
+            .filter(|schema| **schema != self.schema_name)
+            .cloned()
+            .collect();
+        self
+    }
+
+    /// Render the migrations so they do not lock the tables they change, from the `onlineMigrations`
+    /// property of the datasource. Only Postgres and MySQL support this.
+    pub fn with_online_migrations(mut self, online_migrations: bool) -> Self {
+        self.database_info.set_online_migrations(online_migrations);
+        self
+    }
+
     async fn create_database_impl(&self, db_name: &str) -> SqlResult<()> {
         match self.database_info.sql_family() {
             SqlFamily::Postgres => {
@@ -124,11 +158,13 @@ impl SqlMigrationConnector {
                 }
             }
             ConnectionInfo::Postgres(_) => {
-                let schema_sql = format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", &self.schema_name);
+                for schema_name in std::iter::once(&self.schema_name).chain(self.additional_schemas.iter()) {
+                    let schema_sql = format!("CREATE SCHEMA IF NOT EXISTS \"{}\";", schema_name);
 
-                debug!("{}", schema_sql);
+                    debug!("{}", schema_sql);
 
-                self.database.query_raw(&schema_sql, &[]).await?;
+                    self.database.query_raw(&schema_sql, &[]).await?;
+                }
             }
             ConnectionInfo::Mysql(_) => {
                 let schema_sql = format!(
@@ -155,10 +191,12 @@ impl SqlMigrationConnector {
         catch(self.connection_info(), async {
             match &self.connection_info() {
                 ConnectionInfo::Postgres(_) => {
-                    let sql_str = format!(r#"DROP SCHEMA "{}" CASCADE;"#, self.schema_name());
-                    debug!("{}", sql_str);
+                    for schema_name in std::iter::once(&self.schema_name).chain(self.additional_schemas.iter()) {
+                        let sql_str = format!(r#"DROP SCHEMA "{}" CASCADE;"#, schema_name);
+                        debug!("{}", sql_str);
 
-                    self.conn().query_raw(&sql_str, &[]).await.ok();
+                        self.conn().query_raw(&sql_str, &[]).await.ok();
+                    }
                 }
                 ConnectionInfo::Sqlite { file_path, .. } => {
                     self.conn()
@@ -215,6 +253,30 @@ impl MigrationConnector for SqlMigrationConnector {
         Ok(())
     }
 
+    async fn enable_change_events(&self, tables: &[String]) -> ConnectorResult<Vec<String>> {
+        let change_events = SqlChangeEvents { connector: self };
+
+        catch(self.connection_info(), change_events.enable(tables)).await
+    }
+
+    async fn diagnose_drift(&self, applied_migration_scripts: &[String]) -> ConnectorResult<Vec<Drift>> {
+        let drift_detector = SqlDriftDetector { connector: self };
+
+        drift_detector.diagnose(applied_migration_scripts).await
+    }
+
+    async fn baseline(&self) -> ConnectorResult<SqlMigration> {
+        let baseline = SqlBaseline { connector: self };
+
+        catch(self.connection_info(), baseline.describe_baseline()).await
+    }
+
+    async fn acquire_migration_lock<'a>(&'a self, timeout: Duration) -> ConnectorResult<Box<dyn MigrationLock + 'a>> {
+        let lock = SqlMigrationLock::acquire(self, timeout).await?;
+
+        Ok(Box::new(lock))
+    }
+
     /// Optionally check that the features implied by the provided datamodel are all compatible with
     /// the specific database version being used.
     fn check_database_version_compatibility(&self, datamodel: &datamodel::dml::Datamodel) -> Vec<MigrationError> {
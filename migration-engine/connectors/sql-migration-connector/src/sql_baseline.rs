@@ -0,0 +1,30 @@
+use crate::{sql_database_migration_inferrer::infer, Component, SqlMigration, SqlMigrationConnector, SqlResult};
+use sql_schema_describer::SqlSchema;
+
+/// Describes a database created outside of migrations. The baseline migration creates the
+/// described schema from an empty database, so its script matches the live schema.
+pub(crate) struct SqlBaseline<'a> {
+    pub connector: &'a SqlMigrationConnector,
+}
+
+impl Component for SqlBaseline<'_> {
+    fn connector(&self) -> &SqlMigrationConnector {
+        self.connector
+    }
+}
+
+impl SqlBaseline<'_> {
+    pub(crate) async fn describe_baseline(&self) -> SqlResult<SqlMigration> {
+        let database_schema = self.describe().await?;
+
+        infer(
+            &SqlSchema::empty(),
+            &database_schema,
+            self.schema_name(),
+            self.sql_family(),
+            self.database_info(),
+            &[],
+            &[],
+        )
+    }
+}
@@ -0,0 +1,180 @@
+use crate::{
+    sql_renderer::{IteratorJoin, Quoted, QuotedWithSchema},
+    Component, SqlError, SqlMigrationConnector, SqlResult, MIGRATION_TABLE_NAME,
+};
+use quaint::prelude::SqlFamily;
+use sql_schema_describer::Table;
+use tracing::debug;
+
+/// The channel the Postgres triggers notify. The query engine listens on the same channel.
+pub const CHANGE_EVENTS_CHANNEL: &str = "prisma_change_events";
+
+/// The table the SQLite triggers log changes into. The query engine polls the same table.
+pub const CHANGE_EVENTS_TABLE_NAME: &str = "_prisma_change_events";
+
+const TRIGGER_NAME: &str = "_prisma_change_events";
+const NOTIFY_FUNCTION_NAME: &str = "_prisma_notify_change";
+
+/// Installs the triggers producing record change events, which the query engine streams to
+/// subscriptions. Every event carries the table, the operation and the primary key of the changed
+/// row. Postgres notifies listeners directly, SQLite logs the events into a table.
+pub(crate) struct SqlChangeEvents<'a> {
+    pub connector: &'a SqlMigrationConnector,
+}
+
+impl Component for SqlChangeEvents<'_> {
+    fn connector(&self) -> &SqlMigrationConnector {
+        self.connector
+    }
+}
+
+impl SqlChangeEvents<'_> {
+    /// Installs the triggers on the given tables, or on all tables if none are given.
+    /// Tables without a primary key are skipped. Returns the names of the tables covered.
+    pub(crate) async fn enable(&self, table_names: &[String]) -> SqlResult<Vec<String>> {
+        let schema = self.describe().await?;
+
+        let tables: Vec<&Table> = schema
+            .tables
+            .iter()
+            .filter(|table| table.name != MIGRATION_TABLE_NAME && table.name != CHANGE_EVENTS_TABLE_NAME)
+            .filter(|table| table_names.is_empty() || table_names.contains(&table.name))
+            .filter(|table| table.primary_key.is_some())
+            .collect();
+
+        let statements = match self.sql_family() {
+            SqlFamily::Postgres => self.postgres_statements(&tables),
+            SqlFamily::Sqlite => self.sqlite_statements(&tables),
+            SqlFamily::Mysql => {
+                return Err(SqlError::Generic(anyhow::anyhow!(
+                    "Record change events are not supported on MySQL."
+                )))
+            }
+        };
+
+        for statement in statements {
+            debug!("{}", statement);
+            self.conn().query_raw(&statement, &[]).await?;
+        }
+
+        Ok(tables.into_iter().map(|table| table.name.clone()).collect())
+    }
+
+    fn postgres_statements(&self, tables: &[&Table]) -> Vec<String> {
+        let function_name = QuotedWithSchema {
+            schema_name: self.schema_name(),
+            name: Quoted::postgres_ident(NOTIFY_FUNCTION_NAME),
+        };
+
+        let mut statements = vec![format!(
+            r#"CREATE OR REPLACE FUNCTION {function_name}() RETURNS trigger AS $$
+DECLARE
+    row_data jsonb;
+    identifier jsonb := '{{}}'::jsonb;
+    key_column text;
+BEGIN
+    IF TG_OP = 'DELETE' THEN
+        row_data := to_jsonb(OLD);
+    ELSE
+        row_data := to_jsonb(NEW);
+    END IF;
+
+    FOREACH key_column IN ARRAY TG_ARGV LOOP
+        identifier := identifier || jsonb_build_object(key_column, row_data -> key_column);
+    END LOOP;
+
+    PERFORM pg_notify({channel}, jsonb_build_object(
+        'schema', TG_TABLE_SCHEMA,
+        'table', TG_TABLE_NAME,
+        'operation', TG_OP,
+        'record', identifier
+    )::text);
+
+    RETURN NULL;
+END;
+$$ LANGUAGE plpgsql"#,
+            function_name = function_name,
+            channel = Quoted::postgres_string(CHANGE_EVENTS_CHANNEL),
+        )];
+
+        for table in tables {
+            let table_name = QuotedWithSchema {
+                schema_name: self.schema_name(),
+                name: Quoted::postgres_ident(&table.name),
+            };
+
+            // The primary key columns are passed as trigger arguments, they are the only data sent.
+            let key_columns = table
+                .primary_key
+                .iter()
+                .flat_map(|pk| pk.columns.iter())
+                .map(Quoted::postgres_string)
+                .join(", ");
+
+            statements.push(format!(
+                "DROP TRIGGER IF EXISTS {trigger} ON {table}",
+                trigger = Quoted::postgres_ident(TRIGGER_NAME),
+                table = table_name,
+            ));
+
+            statements.push(format!(
+                "CREATE TRIGGER {trigger} AFTER INSERT OR UPDATE OR DELETE ON {table} FOR EACH ROW EXECUTE PROCEDURE {function}({key_columns})",
+                trigger = Quoted::postgres_ident(TRIGGER_NAME),
+                table = table_name,
+                function = function_name,
+                key_columns = key_columns,
+            ));
+        }
+
+        statements
+    }
+
+    fn sqlite_statements(&self, tables: &[&Table]) -> Vec<String> {
+        let mut statements = vec![format!(
+            r#"CREATE TABLE IF NOT EXISTS {table} (
+    "id" INTEGER PRIMARY KEY AUTOINCREMENT,
+    "table_name" TEXT NOT NULL,
+    "operation" TEXT NOT NULL,
+    "record" TEXT NOT NULL
+)"#,
+            table = QuotedWithSchema {
+                schema_name: self.schema_name(),
+                name: Quoted::sqlite_ident(CHANGE_EVENTS_TABLE_NAME),
+            },
+        )];
+
+        for table in tables {
+            for (operation, row) in &[("INSERT", "NEW"), ("UPDATE", "NEW"), ("DELETE", "OLD")] {
+                let trigger_name = format!("{}_{}_{}", TRIGGER_NAME, table.name, operation.to_lowercase());
+                let trigger_name = QuotedWithSchema {
+                    schema_name: self.schema_name(),
+                    name: Quoted::sqlite_ident(trigger_name.as_str()),
+                };
+
+                let identifier = table
+                    .primary_key
+                    .iter()
+                    .flat_map(|pk| pk.columns.iter())
+                    .map(|column| format!("'{}', {}.{}", column, row, Quoted::sqlite_ident(column)))
+                    .join(", ");
+
+                statements.push(format!("DROP TRIGGER IF EXISTS {}", trigger_name));
+
+                // Triggers can only reference tables of their own schema, hence the unqualified names.
+                statements.push(format!(
+                    r#"CREATE TRIGGER {trigger} AFTER {operation} ON {table} FOR EACH ROW BEGIN
+    INSERT INTO {events_table} ("table_name", "operation", "record") VALUES ('{table_name}', '{operation}', json_object({identifier}));
+END"#,
+                    trigger = trigger_name,
+                    operation = operation,
+                    table = Quoted::sqlite_ident(&table.name),
+                    events_table = Quoted::sqlite_ident(CHANGE_EVENTS_TABLE_NAME),
+                    table_name = table.name,
+                    identifier = identifier,
+                ));
+            }
+        }
+
+        statements
+    }
+}
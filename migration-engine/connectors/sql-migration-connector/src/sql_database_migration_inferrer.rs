@@ -23,12 +23,36 @@ impl Component for SqlDatabaseMigrationInferrer<'_> {
 impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_> {
     async fn infer(
         &self,
-        _previous: &Datamodel,
+        previous: &Datamodel,
+        next: &Datamodel,
+        steps: &[MigrationStep],
+    ) -> ConnectorResult<SqlMigration> {
+        self.infer_with_accepted_renames(previous, next, steps, &[], &[]).await
+    }
+
+    async fn infer_with_data_migration_steps(
+        &self,
+        previous: &Datamodel,
+        next: &Datamodel,
+        steps: &[MigrationStep],
+        data_migration_steps: &[DataMigrationStep],
+    ) -> ConnectorResult<SqlMigration> {
+        self.infer_with_accepted_renames(previous, next, steps, data_migration_steps, &[])
+            .await
+    }
+
+    async fn infer_with_accepted_renames(
+        &self,
+        previous: &Datamodel,
         next: &Datamodel,
         _steps: &[MigrationStep],
+        data_migration_steps: &[DataMigrationStep],
+        accepted_renames: &[Rename],
     ) -> ConnectorResult<SqlMigration> {
         let fut = async {
-            let current_database_schema: SqlSchema = self.describe().await?;
+            let previous_database_schema = SqlSchemaCalculator::calculate(previous, self.database_info())?;
+            let current_database_schema =
+                with_managed_view_definitions(self.describe().await?, &previous_database_schema);
             let expected_database_schema = SqlSchemaCalculator::calculate(next, self.database_info())?;
             infer(
                 &current_database_schema,
@@ -36,6 +60,8 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
                 self.schema_name(),
                 self.sql_family(),
                 self.database_info(),
+                data_migration_steps,
+                accepted_renames,
             )
         };
 
@@ -57,33 +83,70 @@ impl DatabaseMigrationInferrer<SqlMigration> for SqlDatabaseMigrationInferrer<'_
                 self.schema_name(),
                 self.sql_family(),
                 self.database_info(),
+                &[],
+                &[],
             )
         })();
 
         result.map_err(|sql_error| sql_error.into_connector_error(self.connection_info()))
     }
+
+    fn infer_between_migrations(&self, previous: &SqlMigration, next: &SqlMigration) -> ConnectorResult<SqlMigration> {
+        let previous_database_schema = with_managed_view_definitions(previous.after.clone(), &next.after);
+
+        infer(
+            &previous_database_schema,
+            &next.after,
+            self.schema_name(),
+            self.sql_family(),
+            self.database_info(),
+            &[],
+            &[],
+        )
+        .map_err(|sql_error| sql_error.into_connector_error(self.connection_info()))
+    }
+}
+
+/// The databases normalize the view definitions they return, so these can not be compared with the
+/// definitions in the datamodel. The described views take the definitions of the previous datamodel
+/// instead, views it does not manage are left without definition.
+pub(crate) fn with_managed_view_definitions(mut described: SqlSchema, previous: &SqlSchema) -> SqlSchema {
+    for view in described.views.iter_mut() {
+        view.definition = previous
+            .get_view(&view.name)
+            .and_then(|previous_view| previous_view.definition.clone());
+    }
+
+    described
 }
 
-fn infer(
+pub(crate) fn infer(
     current_database_schema: &SqlSchema,
     expected_database_schema: &SqlSchema,
     schema_name: &str,
     sql_family: SqlFamily,
     database_info: &DatabaseInfo,
+    data_migration_steps: &[DataMigrationStep],
+    accepted_renames: &[Rename],
 ) -> SqlResult<SqlMigration> {
-    let (original_steps, corrected_steps) = infer_database_migration_steps_and_fix(
+    let (original_steps, corrected_steps, proposed_renames) = infer_database_migration_steps_and_fix(
         &current_database_schema,
         &expected_database_schema,
         &schema_name,
         sql_family,
+        &DiffingOptions::from_database_info(database_info).with_accepted_renames(accepted_renames),
         database_info,
+        data_migration_steps,
     )?;
-    let (_, rollback) = infer_database_migration_steps_and_fix(
+    // The data migrations cannot be reverted.
+    let (_, rollback, _) = infer_database_migration_steps_and_fix(
         &expected_database_schema,
         &current_database_schema,
         &schema_name,
         sql_family,
+        &DiffingOptions::from_database_info(database_info).with_accepted_renames(&reversed_renames(accepted_renames)),
         database_info,
+        &[],
     )?;
     Ok(SqlMigration {
         before: current_database_schema.clone(),
@@ -91,41 +154,155 @@ fn infer(
         original_steps,
         corrected_steps,
         rollback,
+        proposed_renames,
+        script: None,
     })
 }
 
+/// The renames rolling back the accepted renames. The renamed columns are on the table under its
+/// previous name.
+fn reversed_renames(renames: &[Rename]) -> Vec<Rename> {
+    let previous_table_name = |table_name: &str| {
+        renames
+            .iter()
+            .find_map(|rename| match rename {
+                Rename::Table { from, to } if to == table_name => Some(from.clone()),
+                _ => None,
+            })
+            .unwrap_or_else(|| table_name.to_owned())
+    };
+
+    renames
+        .iter()
+        .map(|rename| match rename {
+            Rename::Table { from, to } => Rename::Table {
+                from: to.clone(),
+                to: from.clone(),
+            },
+            Rename::Column { table, from, to } => Rename::Column {
+                table: previous_table_name(table),
+                from: to.clone(),
+                to: from.clone(),
+            },
+        })
+        .collect()
+}
+
 fn infer_database_migration_steps_and_fix(
     from: &SqlSchema,
     to: &SqlSchema,
     schema_name: &str,
     sql_family: SqlFamily,
+    diffing_options: &DiffingOptions,
     database_info: &DatabaseInfo,
-) -> SqlResult<(Vec<SqlMigrationStep>, Vec<SqlMigrationStep>)> {
-    let diff: SqlSchemaDiff = SqlSchemaDiffer::diff(
-        &from,
-        &to,
-        sql_family,
-        &DiffingOptions::from_database_info(database_info),
-    );
+    data_migration_steps: &[DataMigrationStep],
+) -> SqlResult<(Vec<SqlMigrationStep>, Vec<SqlMigrationStep>, Vec<Rename>)> {
+    let diff: SqlSchemaDiff = SqlSchemaDiffer::diff(&from, &to, sql_family, diffing_options);
+    let proposed_renames = diff.proposed_renames.clone();
 
     let corrected_steps = if sql_family.is_sqlite() {
-        sqlite::fix(diff, &from, &to, &schema_name, database_info)?
+        sqlite::fix(diff, &from, &to, &schema_name, database_info, data_migration_steps)?
     } else {
-        fix_id_column_type_change(&from, &to, schema_name, diff.into_steps(), sql_family, database_info)?
+        let steps = with_data_migration_steps(diff.into_steps(), data_migration_steps);
+
+        fix_id_column_type_change(&from, &to, schema_name, steps, sql_family, database_info)?
     };
 
+    let original_steps = SqlSchemaDiffer::diff(&from, &to, sql_family, diffing_options).into_steps();
+
     Ok((
-        SqlSchemaDiffer::diff(
-            &from,
-            &to,
-            sql_family,
-            &DiffingOptions::from_database_info(database_info),
-        )
-        .into_steps(),
+        with_data_migration_steps(original_steps, data_migration_steps),
         corrected_steps,
+        proposed_renames,
     ))
 }
 
+/// Adds the data migration steps at their positions, and marks the altered columns they run on
+/// before. The data migration steps at the same position keep their order.
+fn with_data_migration_steps(
+    steps: Vec<SqlMigrationStep>,
+    data_migration_steps: &[DataMigrationStep],
+) -> Vec<SqlMigrationStep> {
+    let positioned_data_migration_steps: Vec<(usize, &DataMigrationStep)> = data_migration_steps
+        .iter()
+        .map(|data_migration_step| {
+            let idx = data_migration_step_index(&steps, &data_migration_step.position());
+
+            (idx, data_migration_step)
+        })
+        .collect();
+
+    let steps_count = steps.len();
+    let mut result = Vec::with_capacity(steps_count + data_migration_steps.len());
+    let mut previous_data_migration_steps: Vec<&DataMigrationStep> = Vec::new();
+
+    for (idx, mut step) in steps.into_iter().enumerate() {
+        for &(_, data_migration_step) in positioned_data_migration_steps
+            .iter()
+            .filter(|(position, _)| *position == idx)
+        {
+            result.push(SqlMigrationStep::DataMigration(data_migration_step.clone()));
+            previous_data_migration_steps.push(data_migration_step);
+        }
+
+        if let SqlMigrationStep::AlterTable(alter_table) = &mut step {
+            let table = &alter_table.table;
+
+            for change in alter_table.changes.iter_mut() {
+                if let TableChange::AlterColumn(alter_column) = change {
+                    alter_column.is_backfilled = previous_data_migration_steps.iter().any(|data_migration_step| {
+                        data_migration_step_runs_on(data_migration_step, table, &alter_column.name)
+                    });
+                }
+            }
+        }
+
+        result.push(step);
+    }
+
+    for &(_, data_migration_step) in positioned_data_migration_steps
+        .iter()
+        .filter(|(position, _)| *position == steps_count)
+    {
+        result.push(SqlMigrationStep::DataMigration(data_migration_step.clone()));
+    }
+
+    result
+}
+
+/// The index in the schema steps the data migration step is inserted at. The positions relative to
+/// a table the migration does not change are the end of the migration.
+fn data_migration_step_index(steps: &[SqlMigrationStep], position: &DataMigrationPosition) -> usize {
+    let index = match position {
+        DataMigrationPosition::Start => Some(0),
+        DataMigrationPosition::BeforeTable { table } => steps.iter().position(|step| step.changes_table(table)),
+        DataMigrationPosition::AfterTable { table } => steps
+            .iter()
+            .rposition(|step| step.changes_table(table))
+            .map(|idx| idx + 1),
+        DataMigrationPosition::End => None,
+    };
+
+    index.unwrap_or_else(|| steps.len())
+}
+
+/// Backfills run on their column, raw SQL on the columns of the table it is positioned around.
+fn data_migration_step_runs_on(data_migration_step: &DataMigrationStep, table: &Table, column_name: &str) -> bool {
+    match data_migration_step {
+        DataMigrationStep::Backfill {
+            table: table_name,
+            schema,
+            column,
+            ..
+        } => *table_name == table.name && *schema == table.schema && column == column_name,
+        DataMigrationStep::RawSql { .. } => match data_migration_step.position() {
+            DataMigrationPosition::BeforeTable { table: table_name }
+            | DataMigrationPosition::AfterTable { table: table_name } => table_name == table.name,
+            DataMigrationPosition::Start | DataMigrationPosition::End => false,
+        },
+    }
+}
+
 fn fix_id_column_type_change(
     from: &SqlSchema,
     to: &SqlSchema,
@@ -165,19 +342,17 @@ fn fix_id_column_type_change(
 
     // TODO: There's probably a much more graceful way to handle this. But this would also involve a lot of data loss probably. Let's tackle that after P Day
     if has_id_type_change {
-        let tables_to_drop: Vec<String> = from
+        let tables_to_drop: Vec<DropTable> = from
             .tables
             .iter()
-            .filter(|t| t.name != MIGRATION_TABLE_NAME)
-            .map(|t| t.name.clone())
+            .filter(|t| t.name != MIGRATION_TABLE_NAME && t.name != CHANGE_EVENTS_TABLE_NAME)
+            .map(|t| DropTable {
+                name: t.name.clone(),
+                schema: t.schema.clone(),
+            })
             .collect();
         let mut radical_steps = Vec::with_capacity(tables_to_drop.len());
-        radical_steps.extend(
-            tables_to_drop
-                .into_iter()
-                .map(|name| DropTable { name })
-                .map(SqlMigrationStep::DropTable),
-        );
+        radical_steps.extend(tables_to_drop.into_iter().map(SqlMigrationStep::DropTable));
         let diff_from_empty: SqlSchemaDiff = SqlSchemaDiffer::diff(
             &SqlSchema::empty(),
             &to,
@@ -5,6 +5,7 @@ use crate::{
     sql_schema_differ::{ColumnDiffer, DiffingOptions, SqlSchemaDiff, TableDiffer},
     SqlFamily, SqlResult,
 };
+use migration_connector::DataMigrationStep;
 use sql_schema_describer::{ColumnArity, SqlSchema, Table};
 
 pub(super) fn fix(
@@ -13,8 +14,43 @@ pub(super) fn fix(
     next_database_schema: &SqlSchema,
     schema_name: &str,
     database_info: &DatabaseInfo,
+    data_migration_steps: &[DataMigrationStep],
 ) -> SqlResult<Vec<SqlMigrationStep>> {
-    let steps = diff.into_steps();
+    // The changes to renamed tables refer to them by their new name, the current schema by the
+    // previous one.
+    let renamed_tables: Vec<RenameTable> = diff.rename_tables.clone();
+    let previous_table_name = |table_name: &str| -> String {
+        renamed_tables
+            .iter()
+            .find(|rename| rename.new_name == table_name)
+            .map(|rename| rename.name.clone())
+            .unwrap_or_else(|| table_name.to_owned())
+    };
+    // The data migrations run on the tables before they are redefined.
+    let steps = super::with_data_migration_steps(diff.into_steps(), data_migration_steps);
+    // The redefined tables copy the renamed columns from their previous name.
+    let renamed_columns: Vec<(String, RenameColumn)> = steps
+        .iter()
+        .filter_map(|step| match step {
+            SqlMigrationStep::AlterTable(alter_table) => Some(alter_table),
+            _ => None,
+        })
+        .flat_map(|alter_table| {
+            alter_table.changes.iter().filter_map(move |change| match change {
+                TableChange::RenameColumn(rename_column) => {
+                    Some((alter_table.table.name.clone(), rename_column.clone()))
+                }
+                _ => None,
+            })
+        })
+        .collect();
+    let renamed_columns_of = |table_name: &str| -> Vec<RenameColumn> {
+        renamed_columns
+            .iter()
+            .filter(|(table, _)| table == table_name)
+            .map(|(_, rename_column)| rename_column.clone())
+            .collect()
+    };
 
     let mut result = Vec::new();
     let mut fixed_tables = Vec::new();
@@ -26,12 +62,15 @@ pub(super) fn fix(
     for step in steps {
         match step {
             SqlMigrationStep::AlterTable(ref alter_table)
-                if needs_fix(&alter_table) && current_database_schema.has_table(&alter_table.table.name) =>
+                if needs_fix(&alter_table, database_info)
+                    && current_database_schema.has_table(&previous_table_name(&alter_table.table.name)) =>
             {
                 result.extend(sqlite_fix_table(
                     current_database_schema,
                     next_database_schema,
+                    &previous_table_name(&alter_table.table.name),
                     &alter_table.table.name,
+                    &renamed_columns_of(&alter_table.table.name),
                     schema_name,
                     database_info,
                 )?);
@@ -47,7 +86,9 @@ pub(super) fn fix(
                 result.extend(sqlite_fix_table(
                     current_database_schema,
                     next_database_schema,
+                    &previous_table_name(&table),
                     &table,
+                    &renamed_columns_of(&table),
                     schema_name,
                     database_info,
                 )?);
@@ -73,7 +114,7 @@ pub(super) fn fix(
     Ok(result)
 }
 
-fn needs_fix(alter_table: &AlterTable) -> bool {
+fn needs_fix(alter_table: &AlterTable, database_info: &DatabaseInfo) -> bool {
     let change_that_does_not_work_on_sqlite = alter_table.changes.iter().find(|change| match change {
         TableChange::AddColumn(add_column) => {
             // sqlite does not allow adding not null columns without a default value even if the table is empty
@@ -83,25 +124,46 @@ fn needs_fix(alter_table: &AlterTable) -> bool {
         }
         TableChange::DropColumn(_) => true,
         TableChange::AlterColumn(_) => true,
+        TableChange::RenameColumn(_) => !database_info.sqlite_supports_rename_column(),
         TableChange::DropForeignKey(_) => true,
+        TableChange::AddCheckConstraint(_) => true,
+        TableChange::DropCheckConstraint(_) => true,
     });
 
     change_that_does_not_work_on_sqlite.is_some()
 }
 
+/// The table has already been renamed to `table_name` when it is redefined, so the current table is
+/// redefined under that name.
 fn sqlite_fix_table(
     current_database_schema: &SqlSchema,
     next_database_schema: &SqlSchema,
+    previous_table_name: &str,
     table_name: &str,
+    renamed_columns: &[RenameColumn],
     schema_name: &str,
     database_info: &DatabaseInfo,
 ) -> SqlResult<impl Iterator<Item = SqlMigrationStep>> {
-    let current_table = current_database_schema.table(table_name)?;
+    let mut current_table = current_database_schema.table(previous_table_name)?.clone();
+    current_table.name = table_name.to_owned();
     let next_table = next_database_schema.table(table_name)?;
-    Ok(fix_table(&current_table, &next_table, &schema_name, database_info).into_iter())
+    Ok(fix_table(
+        &current_table,
+        &next_table,
+        renamed_columns,
+        &schema_name,
+        database_info,
+    )
+    .into_iter())
 }
 
-fn fix_table(current: &Table, next: &Table, schema_name: &str, database_info: &DatabaseInfo) -> Vec<SqlMigrationStep> {
+fn fix_table(
+    current: &Table,
+    next: &Table,
+    renamed_columns: &[RenameColumn],
+    schema_name: &str,
+    database_info: &DatabaseInfo,
+) -> Vec<SqlMigrationStep> {
     // based on 'Making Other Kinds Of Table Schema Changes' from https://www.sqlite.org/lang_altertable.html
     let name_of_temporary_table = format!("new_{}", &next.name);
     let mut temporary_table = next.clone();
@@ -123,23 +185,27 @@ fn fix_table(current: &Table, next: &Table, schema_name: &str, database_info: &D
             previous: current,
             next: &temporary_table,
         },
+        renamed_columns,
         schema_name,
     )
     .unwrap();
 
     result.push(SqlMigrationStep::DropTable(DropTable {
         name: current.name.clone(),
+        schema: None,
     }));
 
-    result.push(SqlMigrationStep::RenameTable {
+    result.push(SqlMigrationStep::RenameTable(RenameTable {
         name: name_of_temporary_table,
         new_name: next.name.clone(),
-    });
+        schema: None,
+    }));
 
     // Recreate the indices
     result.extend(next.indices.iter().map(|index| {
         SqlMigrationStep::CreateIndex(CreateIndex {
             table: next.name.clone(),
+            schema: None,
             index: index.clone(),
         })
     }));
@@ -151,10 +217,12 @@ fn fix_table(current: &Table, next: &Table, schema_name: &str, database_info: &D
 ///
 /// The process is complicated by the migrations that add make an optional column required with a
 /// default value. In this case, we need to treat them differently and `coalesce`ing them with the
-/// default value, since SQLite does not have the `DEFAULT` keyword.
+/// default value, since SQLite does not have the `DEFAULT` keyword. Renamed columns are copied
+/// from their previous name.
 fn copy_current_table_into_new_table(
     steps: &mut Vec<SqlMigrationStep>,
     differ: TableDiffer<'_>,
+    renamed_columns: &[RenameColumn],
     schema_name: &str,
 ) -> std::fmt::Result {
     use std::fmt::Write as _;
@@ -193,6 +261,11 @@ fn copy_current_table_into_new_table(
                 .iter()
                 .map(|columns| columns.name()),
         )
+        .chain(
+            renamed_columns
+                .iter()
+                .map(|rename_column| rename_column.column.name.as_str()),
+        )
         .peekable();
 
     while let Some(destination_column) = destination_columns.next() {
@@ -222,6 +295,11 @@ fn copy_current_table_into_new_table(
                 )
             )
         }))
+        .chain(
+            renamed_columns
+                .iter()
+                .map(|rename_column| format!("{}", Quoted::sqlite_ident(&rename_column.name))),
+        )
         .peekable();
 
     while let Some(source_column) = source_columns.next() {
@@ -1,4 +1,9 @@
+mod split_statements;
+
 use crate::*;
+use prisma_value::PrismaValue;
+pub(crate) use split_statements::requalify_mysql_script;
+use split_statements::{is_concurrent_index_statement, split_statements};
 use sql_renderer::{postgres_render_column_type, rendered_step::RenderedStep, IteratorJoin, Quoted, SqlRenderer};
 use sql_schema_describer::*;
 use sql_schema_differ::DiffingOptions;
@@ -50,7 +55,7 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
 
     fn render_steps_pretty(&self, database_migration: &SqlMigration) -> ConnectorResult<Vec<serde_json::Value>> {
         render_steps_pretty(
-            &database_migration,
+            &database_migration.corrected_steps,
             self.renderer().as_ref(),
             self.database_info(),
             &database_migration.before,
@@ -63,9 +68,191 @@ impl DatabaseMigrationStepApplier<SqlMigration> for SqlDatabaseStepApplier<'_> {
         })
         .collect()
     }
+
+    fn render_script(&self, database_migration: &SqlMigration) -> ConnectorResult<String> {
+        if let Some(script) = &database_migration.script {
+            return Ok(script.clone());
+        }
+
+        let steps = render_steps_pretty(
+            &database_migration.corrected_steps,
+            self.renderer().as_ref(),
+            self.database_info(),
+            &database_migration.before,
+            &database_migration.after,
+        )?;
+
+        Ok(join_script(steps))
+    }
+
+    fn render_dry_run_script(&self, database_migration: &SqlMigration) -> ConnectorResult<String> {
+        let renderer = self.renderer();
+        let mut script = String::new();
+
+        for step in &database_migration.corrected_steps {
+            let rendered_step = render_step(
+                step,
+                renderer.as_ref(),
+                self.database_info(),
+                &database_migration.before,
+                &database_migration.after,
+            )
+            .map_err(|err| ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err)))?;
+
+            if rendered_step.statements().is_empty() {
+                continue;
+            }
+
+            if rendered_step.run_in_transaction() {
+                script.push_str("BEGIN;\n");
+            }
+
+            for statement in rendered_step.statements() {
+                script.push_str(statement);
+
+                if !statement.ends_with(';') {
+                    script.push(';');
+                }
+
+                script.push('\n');
+            }
+
+            if rendered_step.run_in_transaction() {
+                script.push_str("COMMIT;\n");
+            }
+
+            script.push('\n');
+        }
+
+        Ok(script)
+    }
+
+    fn render_rollback_script(&self, database_migration: &SqlMigration) -> ConnectorResult<String> {
+        let steps = render_steps_pretty(
+            &database_migration.rollback,
+            self.renderer().as_ref(),
+            self.database_info(),
+            &database_migration.after,
+            &database_migration.before,
+        )?;
+
+        Ok(join_script(steps))
+    }
+
+    async fn apply_script(&self, script: &str) -> ConnectorResult<SqlMigration> {
+        let fut = self
+            .apply_statements_in_transaction(split_statements(script, self.sql_family()))
+            .instrument(tracing::debug_span!("ApplyScript"));
+
+        let database_migration = crate::catch(self.connection_info(), fut).await?;
+
+        Ok(SqlMigration {
+            script: Some(script.to_owned()),
+            ..database_migration
+        })
+    }
+
+    async fn apply_rollback_script(&self, script: &str) -> ConnectorResult<SqlMigration> {
+        let fut = self
+            .apply_statements_in_transaction(split_statements(script, self.sql_family()))
+            .instrument(tracing::debug_span!("ApplyRollbackScript"));
+
+        crate::catch(self.connection_info(), fut).await
+    }
 }
 
 impl SqlDatabaseStepApplier<'_> {
+    async fn apply_statements(&self, statements: Vec<&str>) -> SqlResult<SqlMigration> {
+        let before = self.describe().await?;
+
+        self.run_statements(statements).await?;
+
+        Ok(SqlMigration {
+            before,
+            after: self.describe().await?,
+            ..SqlMigration::empty()
+        })
+    }
+
+    /// MySQL commits implicitly after each DDL statement, Postgres cannot build indexes
+    /// concurrently inside a transaction, and the batches of a backfill are committed one by one
+    /// to not hold the locks of the whole backfill, so the statements are applied one by one there.
+    async fn apply_statements_in_transaction(&self, statements: Vec<&str>) -> SqlResult<SqlMigration> {
+        let sql_family = self.sql_family();
+        let runs_outside_of_transactions = |statement: &&str| {
+            is_concurrent_index_statement(statement) || statement.starts_with(REPEATED_STATEMENT_COMMENT)
+        };
+
+        if sql_family.is_mysql() || statements.iter().any(runs_outside_of_transactions) {
+            return self.apply_statements(statements).await;
+        }
+
+        let before = self.describe().await?;
+
+        // SQLite ignores changes to the foreign keys pragma inside transactions, so the foreign
+        // keys are turned off around the transaction for the table redefinitions.
+        if sql_family.is_sqlite() {
+            self.conn().query_raw("PRAGMA foreign_keys=OFF", &[]).await?;
+        }
+
+        self.conn().query_raw("BEGIN", &[]).await?;
+
+        let result = match self.run_statements(statements).await {
+            Ok(()) => self
+                .conn()
+                .query_raw("COMMIT", &[])
+                .await
+                .map(drop)
+                .map_err(SqlError::from),
+            Err(err) => {
+                // The failed statement is the error to report, not a failure to roll back.
+                if let Err(rollback_error) = self.conn().query_raw("ROLLBACK", &[]).await {
+                    tracing::warn!("Failed to roll back the migration transaction: {}", rollback_error);
+                }
+
+                Err(err)
+            }
+        };
+
+        // The foreign keys are turned on again whether the transaction succeeded or not.
+        let pragma_result = if sql_family.is_sqlite() {
+            self.conn().query_raw("PRAGMA foreign_keys=ON", &[]).await.map(drop)
+        } else {
+            Ok(())
+        };
+
+        result?;
+        pragma_result?;
+
+        Ok(SqlMigration {
+            before,
+            after: self.describe().await?,
+            ..SqlMigration::empty()
+        })
+    }
+
+    async fn run_statements(&self, statements: Vec<&str>) -> SqlResult<()> {
+        for sql_string in statements {
+            tracing::debug!(%sql_string);
+
+            self.run_statement(sql_string).await?;
+        }
+
+        Ok(())
+    }
+
+    /// Runs the statement. The statements updating rows in batches are repeated until they update
+    /// no rows.
+    async fn run_statement(&self, sql_string: &str) -> SqlResult<()> {
+        if sql_string.starts_with(REPEATED_STATEMENT_COMMENT) {
+            while self.conn().execute_raw(sql_string, &[]).await? > 0 {}
+        } else {
+            self.conn().query_raw(sql_string, &[]).await?;
+        }
+
+        Ok(())
+    }
+
     async fn apply_next_step(
         &self,
         steps: &[SqlMigrationStep],
@@ -82,17 +269,42 @@ impl SqlDatabaseStepApplier<'_> {
         let step = &steps[index];
         tracing::debug!(?step);
 
-        for sql_string in render_raw_sql(&step, renderer, self.database_info(), current_schema, next_schema)
-            .map_err(|err: anyhow::Error| SqlError::Generic(err))?
-        {
-            tracing::debug!(index, %sql_string);
+        let rendered_step = render_step(&step, renderer, self.database_info(), current_schema, next_schema)
+            .map_err(|err: anyhow::Error| SqlError::Generic(err))?;
+
+        if rendered_step.run_in_transaction() {
+            self.conn().query_raw("BEGIN", &[]).await?;
+        }
 
-            let result = self.conn().query_raw(&sql_string, &[]).await;
+        let mut result = Ok(());
+
+        for sql_string in rendered_step.statements() {
+            tracing::debug!(index, %sql_string);
 
             // TODO: this does not evaluate the results of SQLites PRAGMA foreign_key_check
-            result?;
+            result = self.run_statement(sql_string).await;
+
+            if result.is_err() {
+                break;
+            }
         }
 
+        if rendered_step.run_in_transaction() {
+            match result {
+                Ok(()) => {
+                    self.conn().query_raw("COMMIT", &[]).await?;
+                }
+                // The failed statement is the error to report, not a failure to roll back.
+                Err(_) => {
+                    if let Err(rollback_error) = self.conn().query_raw("ROLLBACK", &[]).await {
+                        tracing::warn!("Failed to roll back the migration step: {}", rollback_error);
+                    }
+                }
+            }
+        }
+
+        result?;
+
         let has_more = steps.get(index + 1).is_some();
         Ok(has_more)
     }
@@ -103,15 +315,15 @@ impl SqlDatabaseStepApplier<'_> {
 }
 
 fn render_steps_pretty(
-    database_migration: &SqlMigration,
+    migration_steps: &[SqlMigrationStep],
     renderer: &(dyn SqlRenderer + Send + Sync),
     database_info: &DatabaseInfo,
     current_schema: &SqlSchema,
     next_schema: &SqlSchema,
 ) -> ConnectorResult<Vec<PrettySqlMigrationStep>> {
-    let mut steps = Vec::with_capacity(database_migration.corrected_steps.len());
+    let mut steps = Vec::with_capacity(migration_steps.len());
 
-    for step in &database_migration.corrected_steps {
+    for step in migration_steps {
         let sql = render_raw_sql(&step, renderer, database_info, current_schema, next_schema)
             .map_err(|err: anyhow::Error| {
                 ConnectorError::from_kind(migration_connector::ErrorKind::Generic(err.into()))
@@ -129,6 +341,36 @@ fn render_steps_pretty(
     Ok(steps)
 }
 
+fn join_script(steps: Vec<PrettySqlMigrationStep>) -> String {
+    let mut script = String::new();
+
+    for step in steps {
+        script.push_str(&step.raw);
+        script.push_str(";\n\n");
+    }
+
+    script
+}
+
+/// Renders the step with the transaction it runs in. Only the steps made of several statements
+/// that must not be applied partially run in a transaction.
+fn render_step(
+    step: &SqlMigrationStep,
+    renderer: &(dyn SqlRenderer + Send + Sync),
+    database_info: &DatabaseInfo,
+    current_schema: &SqlSchema,
+    next_schema: &SqlSchema,
+) -> anyhow::Result<RenderedStep> {
+    match step {
+        SqlMigrationStep::AlterEnum(alter_enum) if renderer.sql_family().is_postgres() => {
+            let schema_name = database_info.connection_info().schema_name();
+
+            postgres_alter_enum(alter_enum, next_schema, schema_name)
+        }
+        _ => render_raw_sql(step, renderer, database_info, current_schema, next_schema).map(RenderedStep::new),
+    }
+}
+
 fn render_raw_sql(
     step: &SqlMigrationStep,
     renderer: &(dyn SqlRenderer + Send + Sync),
@@ -163,7 +405,7 @@ fn render_raw_sql(
 
             let mut create_table = format!(
                 "CREATE TABLE {} (\n{}",
-                renderer.quote_with_schema(&schema_name, &table.name),
+                renderer.quote_with_schema(table_schema_name(&schema_name, Some(table)), &table.name),
                 columns,
             );
 
@@ -172,7 +414,22 @@ fn render_raw_sql(
 
             if primary_columns.len() > 0 && !primary_key_is_already_set {
                 let column_names = primary_columns.iter().map(|col| renderer.quote(&col)).join(",");
-                write!(create_table, ",\n    PRIMARY KEY ({})", column_names)?;
+                write!(create_table, ",\n    ")?;
+
+                if let Some(constraint_name) = table.primary_key.as_ref().and_then(|pk| pk.constraint_name.as_ref()) {
+                    write!(create_table, "CONSTRAINT {} ", renderer.quote(constraint_name))?;
+                }
+
+                write!(create_table, "PRIMARY KEY ({})", column_names)?;
+            }
+
+            for check_constraint in &table.check_constraints {
+                write!(
+                    create_table,
+                    ",\n    CONSTRAINT {} CHECK ({})",
+                    renderer.quote(&check_constraint.name),
+                    check_constraint.expression
+                )?;
             }
 
             if sql_family == SqlFamily::Sqlite && !table.foreign_keys.is_empty() {
@@ -195,10 +452,10 @@ fn render_raw_sql(
 
             Ok(vec![create_table])
         }
-        SqlMigrationStep::DropTable(DropTable { name }) => match sql_family {
+        SqlMigrationStep::DropTable(DropTable { name, schema }) => match sql_family {
             SqlFamily::Mysql | SqlFamily::Postgres => Ok(vec![format!(
                 "DROP TABLE {};",
-                renderer.quote_with_schema(&schema_name, &name)
+                renderer.quote_with_schema(schema.as_deref().unwrap_or(&schema_name), &name)
             )]),
             // Turning off the pragma is safe, because schema validation would forbid foreign keys
             // to a non-existent model. There appears to be no other way to deal with cyclic
@@ -211,21 +468,28 @@ fn render_raw_sql(
             ]),
         },
         SqlMigrationStep::DropTables(DropTables { names }) => {
-            let fully_qualified_names = names.iter().map(|name| renderer.quote_with_schema(&schema_name, &name));
+            let fully_qualified_names = names.iter().map(|name| {
+                renderer.quote_with_schema(table_schema_name(&schema_name, current_schema.get_table(name)), &name)
+            });
             Ok(vec![format!("DROP TABLE {};", fully_qualified_names.join(","))])
         }
-        SqlMigrationStep::RenameTable { name, new_name } => {
+        SqlMigrationStep::RenameTable(RenameTable { name, new_name, schema }) => {
+            let table_schema = schema.as_deref().unwrap_or(&schema_name);
             let new_name = match sql_family {
                 SqlFamily::Sqlite => renderer.quote(new_name).to_string(),
-                _ => renderer.quote_with_schema(&schema_name, &new_name).to_string(),
+                _ => renderer.quote_with_schema(table_schema, &new_name).to_string(),
             };
             Ok(vec![format!(
                 "ALTER TABLE {} RENAME TO {};",
-                renderer.quote_with_schema(&schema_name, &name),
+                renderer.quote_with_schema(table_schema, &name),
                 new_name
             )])
         }
-        SqlMigrationStep::AddForeignKey(AddForeignKey { table, foreign_key }) => match sql_family {
+        SqlMigrationStep::AddForeignKey(AddForeignKey {
+            table,
+            schema,
+            foreign_key,
+        }) => match sql_family {
             SqlFamily::Sqlite => Ok(Vec::new()),
             _ => {
                 let mut add_constraint = String::with_capacity(120);
@@ -233,7 +497,7 @@ fn render_raw_sql(
                 write!(
                     add_constraint,
                     "ALTER TABLE {table} ADD ",
-                    table = renderer.quote_with_schema(&schema_name, table)
+                    table = renderer.quote_with_schema(schema.as_deref().unwrap_or(&schema_name), table)
                 )?;
 
                 if let Some(constraint_name) = foreign_key.constraint_name.as_ref() {
@@ -252,9 +516,24 @@ fn render_raw_sql(
             }
         },
         SqlMigrationStep::AlterTable(AlterTable { table, changes }) => {
+            let table_name = renderer.quote_with_schema(table_schema_name(&schema_name, Some(table)), &table.name);
+            let mut statements = Vec::new();
             let mut lines = Vec::new();
+            let mut online_statements = Vec::new();
             for change in changes {
                 match change {
+                    TableChange::AddColumn(AddColumn { column })
+                        if postgres_add_column_rewrites_table(database_info, column) =>
+                    {
+                        online_statements.extend(postgres_add_required_column_online(
+                            renderer,
+                            &schema_name,
+                            &table_name.to_string(),
+                            table,
+                            next_schema,
+                            column,
+                        )?);
+                    }
                     TableChange::AddColumn(AddColumn { column }) => {
                         let column = ColumnRef {
                             table,
@@ -268,11 +547,25 @@ fn render_raw_sql(
                         let name = renderer.quote(&name);
                         lines.push(format!("DROP COLUMN {}", name));
                     }
-                    TableChange::AlterColumn(AlterColumn { name, column }) => {
+                    TableChange::AlterColumn(AlterColumn {
+                        name,
+                        column,
+                        is_backfilled,
+                    }) => {
                         match safe_alter_column(
                             renderer,
-                            current_schema.get_table(&table.name).unwrap().column(&name).unwrap(),
-                            &column,
+                            &schema_name,
+                            current_schema
+                                .get_table_in_schema(table.schema.as_deref(), &table.name)
+                                .unwrap()
+                                .column(&name)
+                                .unwrap(),
+                            ColumnRef {
+                                schema: next_schema,
+                                table,
+                                column,
+                            },
+                            *is_backfilled,
                             &DiffingOptions::from_database_info(database_info),
                         ) {
                             Some(safe_sql) => {
@@ -293,6 +586,26 @@ fn render_raw_sql(
                             }
                         }
                     }
+                    TableChange::RenameColumn(RenameColumn { name, column }) => match sql_family {
+                        // `RENAME COLUMN` needs MySQL 8 or MariaDB 10.5, `CHANGE` works on all versions.
+                        SqlFamily::Mysql => {
+                            let name = renderer.quote(&name);
+                            let column = ColumnRef {
+                                schema: next_schema,
+                                table,
+                                column,
+                            };
+                            let col_sql = renderer.render_column(&schema_name, column, true);
+                            lines.push(format!("CHANGE {} {}", name, col_sql));
+                        }
+                        // Renames cannot be combined with other changes in the same statement.
+                        SqlFamily::Postgres | SqlFamily::Sqlite => statements.push(format!(
+                            "ALTER TABLE {} RENAME COLUMN {} TO {};",
+                            table_name,
+                            renderer.quote(&name),
+                            renderer.quote(&column.name)
+                        )),
+                    },
                     TableChange::DropForeignKey(DropForeignKey { constraint_name }) => match sql_family {
                         SqlFamily::Mysql => {
                             let constraint_name = renderer.quote(&constraint_name);
@@ -304,23 +617,57 @@ fn render_raw_sql(
                         }
                         SqlFamily::Sqlite => (),
                     },
+                    TableChange::AddCheckConstraint(AddCheckConstraint { check_constraint }) => lines.push(format!(
+                        "ADD CONSTRAINT {} CHECK ({})",
+                        renderer.quote(&check_constraint.name),
+                        check_constraint.expression
+                    )),
+                    TableChange::DropCheckConstraint(DropCheckConstraint { name }) => {
+                        let name = renderer.quote(&name);
+
+                        // MySQL has its own syntax to drop check constraints, MariaDB uses the standard one.
+                        if sql_family == SqlFamily::Mysql && !database_info.is_mariadb() {
+                            lines.push(format!("DROP CHECK {}", name));
+                        } else {
+                            lines.push(format!("DROP CONSTRAINT {}", name));
+                        }
+                    }
                 };
             }
 
-            if lines.is_empty() {
-                return Ok(Vec::new());
+            // Online migrations ask MySQL to make the changes in place without locking, so it fails
+            // instead of silently copying the table. The changes it can only make by copying the
+            // table keep the default algorithm, the destructive changes checker warns about them.
+            if !lines.is_empty()
+                && sql_family == SqlFamily::Mysql
+                && database_info.online_migrations()
+                && changes.iter().all(|change| {
+                    mysql_supports_online_alter(
+                        change,
+                        current_schema.get_table_in_schema(table.schema.as_deref(), &table.name),
+                        &DiffingOptions::from_database_info(database_info),
+                    )
+                })
+            {
+                lines.push("ALGORITHM=INPLACE, LOCK=NONE".to_owned());
             }
 
-            Ok(vec![format!(
-                "ALTER TABLE {} {};",
-                renderer.quote_with_schema(&schema_name, &table.name),
-                lines.join(",\n")
-            )])
-        }
-        SqlMigrationStep::CreateIndex(CreateIndex { table, index }) => {
-            Ok(vec![render_create_index(renderer, database_info, table, index)])
+            if !lines.is_empty() {
+                statements.push(format!("ALTER TABLE {} {};", table_name, lines.join(",\n")));
+            }
+
+            statements.extend(online_statements);
+
+            Ok(statements)
         }
-        SqlMigrationStep::DropIndex(DropIndex { table, name }) => match sql_family {
+        SqlMigrationStep::CreateIndex(CreateIndex { table, schema, index }) => Ok(vec![render_create_index(
+            renderer,
+            database_info,
+            schema.as_deref().unwrap_or(&schema_name),
+            table,
+            index,
+        )]),
+        SqlMigrationStep::DropIndex(DropIndex { table, schema, name }) => match sql_family {
             SqlFamily::Mysql => Ok(vec![format!(
                 "DROP INDEX {} ON {}",
                 renderer.quote(&name),
@@ -328,11 +675,12 @@ fn render_raw_sql(
             )]),
             SqlFamily::Postgres | SqlFamily::Sqlite => Ok(vec![format!(
                 "DROP INDEX {}",
-                renderer.quote_with_schema(&schema_name, &name)
+                renderer.quote_with_schema(schema.as_deref().unwrap_or(&schema_name), &name)
             )]),
         },
         SqlMigrationStep::AlterIndex(AlterIndex {
             table,
+            schema,
             index_name,
             index_new_name,
         }) => match sql_family {
@@ -362,7 +710,7 @@ fn render_raw_sql(
 
                     // Order matters: dropping the old index first wouldn't work when foreign key constraints are still relying on it.
                     Ok(vec![
-                        render_create_index(renderer, database_info, table, &new_index),
+                        render_create_index(renderer, database_info, &schema_name, table, &new_index),
                         mysql_drop_index(renderer, &schema_name, table, index_name)?,
                     ])
                 } else {
@@ -376,22 +724,104 @@ fn render_raw_sql(
             }
             SqlFamily::Postgres => Ok(vec![format!(
                 "ALTER INDEX {} RENAME TO {}",
-                renderer.quote_with_schema(&schema_name, index_name),
+                renderer.quote_with_schema(schema.as_deref().unwrap_or(&schema_name), index_name),
                 renderer.quote(index_new_name)
             )]),
             SqlFamily::Sqlite => unimplemented!("Index renaming on SQLite."),
         },
+        SqlMigrationStep::RenameConstraint(RenameConstraint {
+            table,
+            schema,
+            name,
+            new_name,
+        }) => match sql_family {
+            SqlFamily::Postgres => Ok(vec![format!(
+                "ALTER TABLE {} RENAME CONSTRAINT {} TO {}",
+                renderer.quote_with_schema(schema.as_deref().unwrap_or(&schema_name), table),
+                renderer.quote(name),
+                renderer.quote(new_name)
+            )]),
+            // The differ only renames constraints on Postgres: MySQL foreign keys are recreated, and SQLite foreign
+            // keys cannot be named.
+            SqlFamily::Mysql | SqlFamily::Sqlite => Err(anyhow::anyhow!(
+                "Renaming the constraint `{}` on `{}` is only supported on Postgres.",
+                name,
+                table
+            )),
+        },
+        SqlMigrationStep::CreateView(CreateView { view }) => {
+            let definition = view
+                .definition
+                .as_ref()
+                .ok_or_else(|| anyhow::anyhow!("Views can only be created from a definition."))?;
+
+            Ok(vec![format!(
+                "CREATE VIEW {} AS {}",
+                renderer.quote_with_schema(&schema_name, &view.name),
+                definition.trim_end().trim_end_matches(';')
+            )])
+        }
+        SqlMigrationStep::DropView(DropView { name }) => Ok(vec![format!(
+            "DROP VIEW {};",
+            renderer.quote_with_schema(&schema_name, &name)
+        )]),
         SqlMigrationStep::RawSql { raw } => Ok(vec![raw.to_owned()]),
+        SqlMigrationStep::DataMigration(DataMigrationStep::RawSql { sql, .. }) => Ok(split_statements(sql, sql_family)
+            .into_iter()
+            .map(str::to_owned)
+            .collect()),
+        SqlMigrationStep::DataMigration(DataMigrationStep::Backfill {
+            table,
+            schema,
+            column,
+            value,
+            ..
+        }) => {
+            let column = renderer.quote(column);
+
+            Ok(vec![format!(
+                "UPDATE {} SET {} = {} WHERE {} IS NULL",
+                renderer.quote_with_schema(schema.as_deref().unwrap_or(&schema_name), table),
+                column,
+                render_backfill_value(renderer, value),
+                column,
+            )])
+        }
+    }
+}
+
+/// Renders a backfill value as an SQL literal. Arrays and objects are rendered as JSON strings.
+fn render_backfill_value(renderer: &dyn SqlRenderer, value: &serde_json::Value) -> String {
+    let render_string = |string: String| {
+        let string = DefaultValue::VALUE(PrismaValue::String(string));
+
+        renderer.render_default(&string, &ColumnTypeFamily::String).into_owned()
+    };
+
+    match value {
+        serde_json::Value::Null => "NULL".to_owned(),
+        serde_json::Value::Bool(boolean) => boolean.to_string(),
+        serde_json::Value::Number(number) => number.to_string(),
+        serde_json::Value::String(string) => render_string(string.clone()),
+        serde_json::Value::Array(_) | serde_json::Value::Object(_) => render_string(value.to_string()),
     }
 }
 
 fn render_create_index(
     renderer: &dyn SqlRenderer,
     database_info: &DatabaseInfo,
+    table_schema_name: &str,
     table_name: &str,
     index: &Index,
 ) -> String {
-    let Index { name, columns, tpe } = index;
+    let Index {
+        name,
+        columns,
+        tpe,
+        algorithm,
+        predicate,
+        ..
+    } = index;
     let index_type = match tpe {
         IndexType::Unique => "UNIQUE",
         IndexType::Normal => "",
@@ -405,21 +835,185 @@ fn render_create_index(
     };
     let table_reference = match sql_family {
         SqlFamily::Sqlite => renderer.quote(table_name).to_string(),
-        _ => renderer
-            .quote_with_schema(database_info.connection_info().schema_name(), table_name)
-            .to_string(),
+        _ => renderer.quote_with_schema(table_schema_name, table_name).to_string(),
+    };
+    let columns = columns.iter().enumerate().map(|(position, column)| {
+        let options = index.column_options(position);
+        let mut rendered = renderer.quote(column).to_string();
+
+        // Prefix lengths only exist on MySQL.
+        if let (Some(length), SqlFamily::Mysql) = (options.length, sql_family) {
+            rendered.push_str(&format!("({})", length));
+        }
+
+        if options.sort_order == SortOrder::Desc {
+            rendered.push_str(" DESC");
+        }
+
+        rendered
+    });
+    let using = match (algorithm, sql_family) {
+        (Some(algorithm), SqlFamily::Postgres) => format!(" USING {}", postgres_index_method(*algorithm)),
+        _ => String::new(),
+    };
+    let predicate = match (predicate, sql_family) {
+        (Some(predicate), SqlFamily::Postgres) | (Some(predicate), SqlFamily::Sqlite) => {
+            format!(" WHERE {}", predicate)
+        }
+        _ => String::new(),
+    };
+    // Online migrations build the index without blocking writes to the table. Postgres cannot
+    // build indexes concurrently inside a transaction.
+    let (concurrently, online_options) = match (database_info.online_migrations(), sql_family) {
+        (true, SqlFamily::Postgres) => (" CONCURRENTLY", ""),
+        (true, SqlFamily::Mysql) => ("", " ALGORITHM=INPLACE LOCK=NONE"),
+        _ => ("", ""),
     };
-    let columns = columns.iter().map(|c| renderer.quote(c));
 
     format!(
-        "CREATE {} INDEX {} ON {}({})",
+        "CREATE {} INDEX{} {} ON {}{}({}){}{}",
         index_type,
+        concurrently,
         index_name,
         table_reference,
-        columns.join(",")
+        using,
+        columns.join(","),
+        predicate,
+        online_options
     )
 }
 
+/// The number of rows updated at once when backfilling a column in an online migration.
+const BACKFILL_BATCH_SIZE: usize = 10_000;
+
+/// Statements starting with this comment update rows in batches: they are repeated until they
+/// update no rows, each batch in its own transaction.
+pub(crate) const REPEATED_STATEMENT_COMMENT: &str = "-- Repeated until no rows are updated.\n";
+
+/// Adding a required column with a default rewrites the table under an exclusive lock on Postgres
+/// before version 11, and the same happens with volatile defaults on later versions. Constant
+/// defaults are only recorded in the catalog since Postgres 11.
+fn postgres_add_column_rewrites_table(database_info: &DatabaseInfo, column: &Column) -> bool {
+    let has_constant_default = match &column.default {
+        Some(DefaultValue::VALUE(_)) => true,
+        Some(_) => false,
+        None => return false,
+    };
+
+    database_info.sql_family() == SqlFamily::Postgres
+        && database_info.online_migrations()
+        && column.is_required()
+        && !column.auto_increment
+        && !(has_constant_default && database_info.postgres_has_fast_column_defaults())
+}
+
+/// Online migrations add the column as nullable, backfill it in batches, and only then make it
+/// required. The `NOT NULL` constraint is checked through a check constraint validated beforehand,
+/// since validating it does not block writes and lets Postgres 12 skip the scan when setting
+/// `NOT NULL`.
+fn postgres_add_required_column_online(
+    renderer: &dyn SqlRenderer,
+    schema_name: &str,
+    table_name: &str,
+    table: &Table,
+    next_schema: &SqlSchema,
+    column: &Column,
+) -> anyhow::Result<Vec<String>> {
+    let default = column
+        .default
+        .as_ref()
+        .ok_or_else(|| anyhow::anyhow!("The column `{}` cannot be added online without a default.", column.name))?;
+    let default = renderer.render_default(default, &column.tpe.family);
+    let column_name = renderer.quote(&column.name);
+    let check_name = renderer
+        .quote(&format!("{}_{}_not_null", table.name, column.name))
+        .to_string();
+    let nullable_column = Column {
+        tpe: ColumnType {
+            arity: ColumnArity::Nullable,
+            ..column.tpe.clone()
+        },
+        default: None,
+        ..column.clone()
+    };
+    let nullable_column = ColumnRef {
+        schema: next_schema,
+        table,
+        column: &nullable_column,
+    };
+
+    Ok(vec![
+        format!(
+            "ALTER TABLE {} ADD COLUMN {};",
+            table_name,
+            renderer.render_column(schema_name, nullable_column, true)
+        ),
+        format!(
+            "ALTER TABLE {} ALTER COLUMN {} SET DEFAULT {};",
+            table_name, column_name, default
+        ),
+        format!(
+            "{}UPDATE {table} SET {column} = {default} WHERE ctid IN (SELECT ctid FROM {table} WHERE {column} IS NULL LIMIT {batch_size});",
+            REPEATED_STATEMENT_COMMENT,
+            table = table_name,
+            column = column_name,
+            default = default,
+            batch_size = BACKFILL_BATCH_SIZE,
+        ),
+        format!(
+            "ALTER TABLE {} ADD CONSTRAINT {} CHECK ({} IS NOT NULL) NOT VALID;",
+            table_name, check_name, column_name
+        ),
+        format!("ALTER TABLE {} VALIDATE CONSTRAINT {};", table_name, check_name),
+        format!("ALTER TABLE {} ALTER COLUMN {} SET NOT NULL;", table_name, column_name),
+        format!("ALTER TABLE {} DROP CONSTRAINT {};", table_name, check_name),
+    ])
+}
+
+/// Whether MySQL can make the change in place without blocking reads and writes. Check
+/// constraints are validated and column types changed by copying the table.
+fn mysql_supports_online_alter(
+    change: &TableChange,
+    previous_table: Option<&Table>,
+    diffing_options: &DiffingOptions,
+) -> bool {
+    match change {
+        TableChange::AddCheckConstraint(_) => false,
+        TableChange::AlterColumn(AlterColumn { name, column, .. }) => {
+            match previous_table.and_then(|table| table.column(name)) {
+                Some(previous) => !crate::sql_schema_differ::ColumnDiffer {
+                    diffing_options,
+                    previous,
+                    next: column,
+                }
+                .all_changes()
+                .type_changed(),
+                None => false,
+            }
+        }
+        TableChange::AddColumn(_)
+        | TableChange::DropColumn(_)
+        | TableChange::RenameColumn(_)
+        | TableChange::DropForeignKey(_)
+        | TableChange::DropCheckConstraint(_) => true,
+    }
+}
+
+fn postgres_index_method(algorithm: IndexAlgorithm) -> &'static str {
+    match algorithm {
+        IndexAlgorithm::BTree => "btree",
+        IndexAlgorithm::Hash => "hash",
+        IndexAlgorithm::Gin => "gin",
+        IndexAlgorithm::Gist => "gist",
+        IndexAlgorithm::Brin => "brin",
+    }
+}
+
+/// Tables outside of the schema of the connection are qualified with their own schema.
+fn table_schema_name<'a>(schema_name: &'a str, table: Option<&'a Table>) -> &'a str {
+    table.and_then(|table| table.schema.as_deref()).unwrap_or(schema_name)
+}
+
 fn mysql_drop_index(
     renderer: &dyn SqlRenderer,
     schema_name: &str,
@@ -443,16 +1037,20 @@ fn create_table_suffix(sql_family: SqlFamily) -> &'static str {
 
 fn safe_alter_column(
     renderer: &dyn SqlRenderer,
+    schema_name: &str,
     previous_column: &Column,
-    next_column: &Column,
+    next: ColumnRef<'_>,
+    is_backfilled: bool,
     diffing_options: &DiffingOptions,
 ) -> Option<Vec<String>> {
     use crate::sql_migration::expanded_alter_column::*;
 
+    let next_column = next.column;
     let expanded = crate::sql_migration::expanded_alter_column::expand_alter_column(
         previous_column,
         next_column,
         &renderer.sql_family(),
+        is_backfilled,
         diffing_options,
     )?;
 
@@ -469,6 +1067,7 @@ fn safe_alter_column(
                     renderer.render_default(&new_default, &next_column.tpe.family)
                 ),
                 PostgresAlterColumn::DropNotNull => format!("{} DROP NOT NULL", &alter_column_prefix),
+                PostgresAlterColumn::SetNotNull => format!("{} SET NOT NULL", &alter_column_prefix),
                 PostgresAlterColumn::SetType(ty) => format!(
                     "{} SET DATA TYPE {}",
                     &alter_column_prefix,
@@ -485,6 +1084,15 @@ fn safe_alter_column(
                     &alter_column_prefix,
                     renderer.render_default(&new_default, &next_column.tpe.family)
                 ),
+                MysqlAlterColumn::Modify => {
+                    let column = ColumnRef {
+                        schema: next.schema,
+                        table: next.table,
+                        column: next.column,
+                    };
+
+                    format!("MODIFY {}", renderer.render_column(schema_name, column, true))
+                }
             })
             .collect(),
         ExpandedAlterColumn::Sqlite(_steps) => vec![],
@@ -581,7 +1189,7 @@ fn postgres_alter_enum(
                         ALTER COLUMN {column_name} TYPE {tmp_name} \
                             USING ({column_name}::text::{tmp_name}),
                         ALTER COLUMN {column_name} SET DEFAULT {new_enum_default}",
-                    schema_name = Quoted::postgres_ident(schema_name),
+                    schema_name = Quoted::postgres_ident(table_schema_name(schema_name, Some(column.table))),
                     table_name = Quoted::postgres_ident(column.table().name()),
                     column_name = Quoted::postgres_ident(column.name()),
                     tmp_name = Quoted::postgres_ident(&tmp_name),
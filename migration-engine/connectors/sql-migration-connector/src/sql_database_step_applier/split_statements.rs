@@ -0,0 +1,279 @@
+use quaint::prelude::SqlFamily;
+
+/// Splits a migration script into its statements. Statements are terminated by semicolons that are
+/// not part of a string literal, a quoted identifier, a dollar-quoted string or a comment.
+pub(crate) fn split_statements(script: &str, sql_family: SqlFamily) -> Vec<&str> {
+    // MySQL string literals escape characters with backslashes, unless the NO_BACKSLASH_ESCAPES
+    // SQL mode is set.
+    let backslash_escapes = sql_family.is_mysql();
+    let bytes = script.as_bytes();
+    let mut statements = Vec::new();
+    let mut statement_start = 0;
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        match bytes[idx] {
+            quote @ b'\'' | quote @ b'"' => {
+                idx = skip_quoted(bytes, idx + 1, quote, backslash_escapes);
+            }
+            b'`' => {
+                idx = skip_quoted(bytes, idx + 1, b'`', false);
+            }
+            b'-' if bytes.get(idx + 1) == Some(&b'-') => {
+                idx = find_from(script, idx, "\n").map(|end| end + 1).unwrap_or(bytes.len());
+            }
+            b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+                idx = find_from(script, idx + 2, "*/")
+                    .map(|end| end + 2)
+                    .unwrap_or(bytes.len());
+            }
+            b'$' => {
+                idx = skip_dollar_quoted(script, idx);
+            }
+            b';' => {
+                statements.push(&script[statement_start..idx]);
+                statement_start = idx + 1;
+                idx += 1;
+            }
+            _ => idx += 1,
+        }
+    }
+
+    statements.push(&script[statement_start..]);
+
+    statements
+        .into_iter()
+        .map(|statement| statement.trim())
+        .filter(|statement| !is_blank(statement))
+        .collect()
+}
+
+/// Replaces the backtick-quoted `schema_name` qualifying the names of a MySQL script with
+/// `new_schema_name`, to run the script in another database. String literals and comments are left
+/// as they are.
+pub(crate) fn requalify_mysql_script(script: &str, schema_name: &str, new_schema_name: &str) -> String {
+    let qualifier = format!("`{}`.", schema_name.replace('`', "``"));
+    let new_qualifier = format!("`{}`.", new_schema_name.replace('`', "``"));
+    let bytes = script.as_bytes();
+    let mut requalified = String::with_capacity(script.len());
+    let mut copied = 0;
+    let mut idx = 0;
+
+    while idx < bytes.len() {
+        match bytes[idx] {
+            quote @ b'\'' | quote @ b'"' => {
+                idx = skip_quoted(bytes, idx + 1, quote, true);
+            }
+            b'`' if script[idx..].starts_with(&qualifier) => {
+                requalified.push_str(&script[copied..idx]);
+                requalified.push_str(&new_qualifier);
+                idx += qualifier.len();
+                copied = idx;
+            }
+            b'`' => {
+                idx = skip_quoted(bytes, idx + 1, b'`', false);
+            }
+            b'-' if bytes.get(idx + 1) == Some(&b'-') => {
+                idx = find_from(script, idx, "\n").map(|end| end + 1).unwrap_or(bytes.len());
+            }
+            b'/' if bytes.get(idx + 1) == Some(&b'*') => {
+                idx = find_from(script, idx + 2, "*/")
+                    .map(|end| end + 2)
+                    .unwrap_or(bytes.len());
+            }
+            _ => idx += 1,
+        }
+    }
+
+    requalified.push_str(&script[copied..]);
+
+    requalified
+}
+
+/// Skips to the end of a quoted string starting at `idx`. Quotes are escaped by doubling them, or
+/// with a backslash if `backslash_escapes` is set.
+fn skip_quoted(bytes: &[u8], mut idx: usize, quote: u8, backslash_escapes: bool) -> usize {
+    while idx < bytes.len() {
+        match bytes[idx] {
+            b'\\' if backslash_escapes => idx += 2,
+            byte if byte == quote && bytes.get(idx + 1) == Some(&quote) => idx += 2,
+            byte if byte == quote => return idx + 1,
+            _ => idx += 1,
+        }
+    }
+
+    bytes.len()
+}
+
+/// Skips a Postgres dollar-quoted string like `$body$ ... $body$` starting at `idx`. Positional
+/// parameters like `$1` are not dollar quotes.
+fn skip_dollar_quoted(script: &str, idx: usize) -> usize {
+    let tag_end = script[idx + 1..]
+        .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+        .map(|offset| idx + 1 + offset);
+
+    match tag_end {
+        Some(tag_end)
+            if script.as_bytes()[tag_end] == b'$' && !script[idx + 1..tag_end].starts_with(char::is_numeric) =>
+        {
+            let tag = &script[idx..=tag_end];
+
+            find_from(script, tag_end + 1, tag)
+                .map(|end| end + tag.len())
+                .unwrap_or_else(|| script.len())
+        }
+        _ => idx + 1,
+    }
+}
+
+fn find_from(script: &str, start: usize, pattern: &str) -> Option<usize> {
+    script[start..].find(pattern).map(|offset| start + offset)
+}
+
+/// Whether the statement builds or drops an index concurrently. Postgres cannot do that inside a
+/// transaction. Keywords in comments, string literals and identifiers are not taken into account.
+pub(crate) fn is_concurrent_index_statement(statement: &str) -> bool {
+    let keywords = leading_keywords(statement, 4);
+    let keywords: Vec<&str> = keywords.iter().map(String::as_str).collect();
+
+    match keywords.as_slice() {
+        ["CREATE", "INDEX", "CONCURRENTLY", ..]
+        | ["CREATE", "UNIQUE", "INDEX", "CONCURRENTLY", ..]
+        | ["DROP", "INDEX", "CONCURRENTLY", ..] => true,
+        _ => false,
+    }
+}
+
+/// The first keywords of a statement, up to `count`, uppercased. Stops at the first token that is
+/// not a keyword, like a quoted identifier or a parenthesis.
+fn leading_keywords(statement: &str, count: usize) -> Vec<String> {
+    let mut keywords = Vec::with_capacity(count);
+    let mut rest = skip_comments(statement);
+
+    while keywords.len() < count {
+        let end = rest
+            .find(|c: char| !(c.is_ascii_alphanumeric() || c == '_'))
+            .unwrap_or_else(|| rest.len());
+
+        if end == 0 {
+            break;
+        }
+
+        keywords.push(rest[..end].to_ascii_uppercase());
+        rest = skip_comments(&rest[end..]);
+    }
+
+    keywords
+}
+
+/// Whether a statement only consists of whitespace and comments.
+fn is_blank(statement: &str) -> bool {
+    skip_comments(statement).is_empty()
+}
+
+/// Skips the whitespace and comments at the start of the statement.
+fn skip_comments(statement: &str) -> &str {
+    let mut rest = statement.trim_start();
+
+    loop {
+        if rest.starts_with("--") {
+            rest = rest.find('\n').map(|end| rest[end..].trim_start()).unwrap_or("");
+        } else if rest.starts_with("/*") {
+            rest = rest.find("*/").map(|end| rest[end + 2..].trim_start()).unwrap_or("");
+        } else {
+            return rest;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn split_statements_splits_on_semicolons() {
+        let script = "CREATE TABLE \"Cat\" (id INTEGER);\n\nCREATE INDEX \"idx\" ON \"Cat\"(id);\n";
+
+        assert_eq!(
+            split_statements(script, SqlFamily::Postgres),
+            &[
+                "CREATE TABLE \"Cat\" (id INTEGER)",
+                "CREATE INDEX \"idx\" ON \"Cat\"(id)"
+            ]
+        );
+    }
+
+    #[test]
+    fn split_statements_ignores_semicolons_in_literals_and_comments() {
+        let script = r#"
+            -- A comment; with a semicolon
+            INSERT INTO "Cat" (name) VALUES ('a;b''c');
+            /* Another; comment */
+            CREATE FUNCTION f() RETURNS trigger AS $body$ BEGIN RETURN NEW; END; $body$ LANGUAGE plpgsql;
+            -- Trailing comment
+        "#;
+
+        let statements = split_statements(script, SqlFamily::Postgres);
+
+        assert_eq!(statements.len(), 2);
+        assert!(statements[0].ends_with(r#"INSERT INTO "Cat" (name) VALUES ('a;b''c')"#));
+        assert!(statements[1].ends_with("$body$ BEGIN RETURN NEW; END; $body$ LANGUAGE plpgsql"));
+    }
+
+    #[test]
+    fn split_statements_handles_backslash_escapes_on_mysql() {
+        let script = r#"INSERT INTO `Cat` (name) VALUES ('it\'s; a cat'); INSERT INTO `Cat` (name) VALUES ("a\";b")"#;
+
+        assert_eq!(
+            split_statements(script, SqlFamily::Mysql),
+            &[
+                r#"INSERT INTO `Cat` (name) VALUES ('it\'s; a cat')"#,
+                r#"INSERT INTO `Cat` (name) VALUES ("a\";b")"#
+            ]
+        );
+    }
+
+    #[test]
+    fn requalify_mysql_script_replaces_the_schema_qualifiers_only() {
+        let script = r#"
+            -- Changes `prisma`.`Cat`
+            CREATE TABLE `prisma`.`Cat` (id INTEGER, name VARCHAR(191) DEFAULT 'in `prisma`.`Cat`');
+            ALTER TABLE `prisma`.`Dog` ADD FOREIGN KEY (catId) REFERENCES `prisma`.`Cat`(id);
+            CREATE TABLE `prisma` (id INTEGER);
+        "#;
+
+        let requalified = requalify_mysql_script(script, "prisma", "shadow");
+
+        assert_eq!(
+            requalified,
+            r#"
+            -- Changes `prisma`.`Cat`
+            CREATE TABLE `shadow`.`Cat` (id INTEGER, name VARCHAR(191) DEFAULT 'in `prisma`.`Cat`');
+            ALTER TABLE `shadow`.`Dog` ADD FOREIGN KEY (catId) REFERENCES `shadow`.`Cat`(id);
+            CREATE TABLE `prisma` (id INTEGER);
+        "#
+        );
+    }
+
+    #[test]
+    fn is_concurrent_index_statement_only_matches_concurrent_index_statements() {
+        assert!(is_concurrent_index_statement(
+            "CREATE INDEX CONCURRENTLY \"idx\" ON \"Cat\"(name)"
+        ));
+        assert!(is_concurrent_index_statement(
+            "-- Online\ncreate unique index concurrently \"idx\" ON \"Cat\"(name)"
+        ));
+        assert!(is_concurrent_index_statement("DROP INDEX CONCURRENTLY \"idx\""));
+
+        assert!(!is_concurrent_index_statement("CREATE INDEX \"idx\" ON \"Cat\"(name)"));
+        assert!(!is_concurrent_index_statement(
+            "INSERT INTO \"Log\" (message) VALUES ('CREATE INDEX CONCURRENTLY')"
+        ));
+        assert!(!is_concurrent_index_statement(
+            "CREATE TABLE \"CONCURRENTLY\" (id INTEGER)"
+        ));
+        assert!(!is_concurrent_index_statement(
+            "/* CREATE INDEX CONCURRENTLY */ CREATE INDEX \"idx\" ON \"Cat\"(name)"
+        ));
+    }
+}
@@ -1,11 +1,13 @@
 mod sql_unexecutable_migration;
 
 use crate::{
-    sql_schema_differ::DiffingOptions, AddColumn, AlterColumn, Component, DropColumn, DropTable, DropTables, SqlError,
-    SqlMigration, SqlMigrationStep, SqlResult, TableChange,
+    sql_schema_differ::{ColumnChange, DiffingOptions},
+    AddColumn, AddForeignKey, AlterColumn, AlterTable, Component, DropCheckConstraint, DropColumn, DropTable,
+    DropTables, RenameTable, SqlError, SqlMigration, SqlMigrationStep, SqlResult, TableChange,
 };
 use migration_connector::{
-    ConnectorResult, DestructiveChangeDiagnostics, DestructiveChangesChecker, MigrationWarning, UnexecutableMigration,
+    ConnectorResult, DataMigrationStep, DestructiveChangeDiagnostics, DestructiveChangesChecker, MigrationWarning,
+    UnexecutableMigration,
 };
 use quaint::{ast::*, prelude::SqlFamily};
 use sql_schema_describer::{ColumnArity, SqlSchema};
@@ -21,12 +23,19 @@ impl Component for SqlDestructiveChangesChecker<'_> {
 }
 
 impl SqlDestructiveChangesChecker<'_> {
+    /// The schema a described table lives in, defaulting to the schema of the connection.
+    fn table_schema_name<'a>(&'a self, table: &'a sql_schema_describer::Table) -> &'a str {
+        table.schema.as_deref().unwrap_or_else(|| self.schema_name())
+    }
+
     async fn check_table_drop(
         &self,
         table_name: &str,
+        schema_name: Option<&str>,
         diagnostics: &mut DestructiveChangeDiagnostics,
     ) -> SqlResult<()> {
-        let rows_count = self.count_rows_in_table(table_name).await?;
+        let schema_name = schema_name.unwrap_or_else(|| self.schema_name());
+        let rows_count = self.count_rows_in_table(schema_name, table_name).await?;
 
         if rows_count > 0 {
             diagnostics.add_warning(MigrationWarning {
@@ -42,7 +51,7 @@ impl SqlDestructiveChangesChecker<'_> {
     }
 
     async fn count_values_in_column(&self, column_name: &str, table: &sql_schema_describer::Table) -> SqlResult<i64> {
-        let query = Select::from_table((self.schema_name(), table.name.as_str()))
+        let query = Select::from_table((self.table_schema_name(table), table.name.as_str()))
             .value(count(quaint::ast::Column::new(column_name)))
             .so_that(column_name.is_not_null());
 
@@ -67,8 +76,8 @@ impl SqlDestructiveChangesChecker<'_> {
         Ok(values_count)
     }
 
-    async fn count_rows_in_table(&self, table_name: &str) -> SqlResult<i64> {
-        let query = Select::from_table((self.schema_name(), table_name)).value(count(asterisk()));
+    async fn count_rows_in_table(&self, schema_name: &str, table_name: &str) -> SqlResult<i64> {
+        let query = Select::from_table((schema_name, table_name)).value(count(asterisk()));
         let result_set = self.conn().query(query.into()).await?;
         let rows_count = result_set
             .first()
@@ -113,6 +122,86 @@ impl SqlDestructiveChangesChecker<'_> {
         Ok(())
     }
 
+    /// Online migrations cannot make some changes without locking the table. They are confirmed
+    /// when the table holds rows, since the application cannot write to it until they complete.
+    async fn check_exclusive_lock(
+        &self,
+        change_description: &str,
+        table: &sql_schema_describer::Table,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        let rows_count = self
+            .count_rows_in_table(self.table_schema_name(table), &table.name)
+            .await?;
+
+        if rows_count > 0 {
+            diagnostics.add_warning(MigrationWarning {
+                description: format!(
+                    "{change_description} takes an exclusive lock on the `{table_name}` table, which holds {rows_count} rows. Writes to the table will wait until it completes.",
+                    change_description = change_description,
+                    table_name = table.name,
+                    rows_count = rows_count,
+                ),
+            });
+        }
+
+        Ok(())
+    }
+
+    async fn check_alter_table_locks(
+        &self,
+        alter_table: &AlterTable,
+        previous_table: &sql_schema_describer::Table,
+        database_table: &sql_schema_describer::Table,
+        diagnostics: &mut DestructiveChangeDiagnostics,
+    ) -> SqlResult<()> {
+        for change in &alter_table.changes {
+            let change_description = match change {
+                TableChange::AlterColumn(alter_column)
+                    if database_table.has_column(&alter_column.name)
+                        && self.alter_column_rewrites_table(alter_column, previous_table) =>
+                {
+                    format!("Changing the type of the column `{}`", alter_column.name)
+                }
+                TableChange::AddCheckConstraint(add_check_constraint) => format!(
+                    "Adding the check constraint `{}`",
+                    add_check_constraint.check_constraint.name
+                ),
+                _ => continue,
+            };
+
+            self.check_exclusive_lock(&change_description, previous_table, diagnostics)
+                .await?;
+        }
+
+        Ok(())
+    }
+
+    /// Postgres rewrites the table under an exclusive lock to change the type of a column. MySQL
+    /// replaces the column instead.
+    fn alter_column_rewrites_table(
+        &self,
+        alter_column: &AlterColumn,
+        previous_table: &sql_schema_describer::Table,
+    ) -> bool {
+        use crate::sql_migration::expanded_alter_column::*;
+
+        let previous_column = match previous_table.column(&alter_column.name) {
+            Some(previous_column) if self.sql_family() == SqlFamily::Postgres => previous_column,
+            _ => return false,
+        };
+        let diffing_options = DiffingOptions::from_database_info(self.database_info());
+        let differ = crate::sql_schema_differ::ColumnDiffer {
+            diffing_options: &diffing_options,
+            previous: previous_column,
+            next: &alter_column.column,
+        };
+
+        expand_postgres_alter_column(&differ, alter_column.is_backfilled)
+            .map(|steps| steps.iter().any(|step| matches!(step, PostgresAlterColumn::SetType(_))))
+            .unwrap_or(false)
+    }
+
     /// Columns cannot be added when all of the following holds:
     ///
     /// - There are existing rows
@@ -132,7 +221,9 @@ impl SqlDestructiveChangesChecker<'_> {
             return Ok(());
         }
 
-        let rows_count = self.count_rows_in_table(&table.name).await?;
+        let rows_count = self
+            .count_rows_in_table(self.table_schema_name(table), &table.name)
+            .await?;
 
         // Empty tables can be safely migrated.
         if rows_count == 0 {
@@ -161,10 +252,13 @@ impl SqlDestructiveChangesChecker<'_> {
     /// Are considered unexecutable:
     ///
     /// - Making an optional column required without a default, when there are existing rows in the table.
+    ///
+    /// Making an optional column required is safe when a data migration backfilled it before.
     async fn check_alter_column(
         &self,
         alter_column: &AlterColumn,
         previous_table: &sql_schema_describer::Table,
+        is_backfilled: bool,
         diagnostics: &mut DestructiveChangeDiagnostics,
     ) -> SqlResult<()> {
         let previous_column = previous_table
@@ -179,13 +273,22 @@ impl SqlDestructiveChangesChecker<'_> {
             next: &alter_column.column,
         };
 
-        if self.alter_column_is_safe(&differ) {
+        let only_made_required_without_default =
+            differ.all_changes().iter().all(|change| change == ColumnChange::Arity)
+                && differ.next.tpe.arity.is_required()
+                && differ.next.default.is_none();
+
+        if is_backfilled && only_made_required_without_default {
             return Ok(());
         }
 
-        self.check_for_column_arity_change(&previous_table.name, &differ, diagnostics)
+        self.check_for_column_arity_change(previous_table, &differ, diagnostics)
             .await?;
 
+        if self.alter_column_is_safe(&differ, alter_column.is_backfilled) {
+            return Ok(());
+        }
+
         let values_count = self.count_values_in_column(&alter_column.name, previous_table).await?;
 
         if values_count > 0 {
@@ -212,7 +315,7 @@ impl SqlDestructiveChangesChecker<'_> {
         Ok(())
     }
 
-    fn alter_column_is_safe(&self, differ: &crate::sql_schema_differ::ColumnDiffer<'_>) -> bool {
+    fn alter_column_is_safe(&self, differ: &crate::sql_schema_differ::ColumnDiffer<'_>, is_backfilled: bool) -> bool {
         use crate::sql_migration::expanded_alter_column::*;
 
         match self.sql_family() {
@@ -233,7 +336,7 @@ impl SqlDestructiveChangesChecker<'_> {
                 !differ.all_changes().type_changed() && arity_change_is_safe
             }
             SqlFamily::Postgres => {
-                let expanded = expand_postgres_alter_column(differ);
+                let expanded = expand_postgres_alter_column(differ, is_backfilled);
 
                 // We keep the match here to keep the exhaustiveness checking for when we add variants.
                 if let Some(steps) = expanded {
@@ -243,7 +346,8 @@ impl SqlDestructiveChangesChecker<'_> {
                         match step {
                             PostgresAlterColumn::SetDefault(_)
                             | PostgresAlterColumn::DropDefault
-                            | PostgresAlterColumn::DropNotNull => (),
+                            | PostgresAlterColumn::DropNotNull
+                            | PostgresAlterColumn::SetNotNull => (),
                             PostgresAlterColumn::SetType(_) => is_safe = false,
                         }
                     }
@@ -254,7 +358,7 @@ impl SqlDestructiveChangesChecker<'_> {
                 }
             }
             SqlFamily::Mysql => {
-                let expanded = expand_mysql_alter_column(differ);
+                let expanded = expand_mysql_alter_column(differ, is_backfilled);
 
                 // We keep the match here to keep the exhaustiveness checking for when we add variants.
                 if let Some(steps) = expanded {
@@ -262,7 +366,9 @@ impl SqlDestructiveChangesChecker<'_> {
 
                     for step in steps {
                         match step {
-                            MysqlAlterColumn::SetDefault(_) | MysqlAlterColumn::DropDefault => (),
+                            MysqlAlterColumn::SetDefault(_)
+                            | MysqlAlterColumn::DropDefault
+                            | MysqlAlterColumn::Modify => (),
                         }
                     }
 
@@ -276,11 +382,13 @@ impl SqlDestructiveChangesChecker<'_> {
 
     async fn check_for_column_arity_change(
         &self,
-        table_name: &str,
+        table: &sql_schema_describer::Table,
         differ: &crate::sql_schema_differ::ColumnDiffer<'_>,
         diagnostics: &mut DestructiveChangeDiagnostics,
     ) -> SqlResult<()> {
-        let rows_count = self.count_rows_in_table(table_name).await?;
+        let rows_count = self
+            .count_rows_in_table(self.table_schema_name(table), &table.name)
+            .await?;
 
         if !differ.all_changes().arity_changed()
             || !differ.next.tpe.arity.is_required()
@@ -291,7 +399,7 @@ impl SqlDestructiveChangesChecker<'_> {
         }
 
         let typed_unexecutable = sql_unexecutable_migration::SqlUnexecutableMigration::MadeOptionalFieldRequired {
-            table: table_name.to_owned(),
+            table: table.name.clone(),
             column: differ.previous.name.clone(),
         };
 
@@ -302,50 +410,155 @@ impl SqlDestructiveChangesChecker<'_> {
         Ok(())
     }
 
+    async fn check_unapply_impl(&self, database_migration: &SqlMigration) -> SqlResult<DestructiveChangeDiagnostics> {
+        let database_schema = self.describe().await?;
+
+        self.check_impl(
+            &database_migration.rollback,
+            &database_migration.after,
+            &database_schema,
+            true,
+        )
+        .await
+    }
+
+    /// `database_schema` is the schema of the database at the time of the check. The tables and
+    /// columns it does not have yet hold no data, so they are not checked.
+    ///
+    /// With `warn_about_all_drops`, every dropped table and column is warned about without looking
+    /// at its data: a rollback can run long after it is checked, once the data is there.
     async fn check_impl(
         &self,
         steps: &[SqlMigrationStep],
         before: &SqlSchema,
+        database_schema: &SqlSchema,
+        warn_about_all_drops: bool,
     ) -> SqlResult<DestructiveChangeDiagnostics> {
         let mut diagnostics = DestructiveChangeDiagnostics::new();
+        let mut renamed_tables: Vec<&RenameTable> = Vec::new();
+        // The (schema, table, column) triples backfilled by the data migrations so far. Raw SQL
+        // data migrations are not inspected.
+        let mut backfilled_columns: Vec<(Option<&str>, &str, &str)> = Vec::new();
 
         for step in steps {
             match step {
                 SqlMigrationStep::AlterTable(alter_table) => {
                     // The table in alter_table is the updated table, but we want to
-                    // check against the current state of the table.
-                    let before_table = before.get_table(&alter_table.table.name);
+                    // check against the current state of the table, under its previous name
+                    // if it was renamed.
+                    let table_schema = alter_table.table.schema.as_deref();
+                    let before_table_name = previous_table_name(&renamed_tables, table_schema, &alter_table.table.name);
+                    let before_table = before.get_table_in_schema(table_schema, before_table_name);
+                    let database_table = database_schema.get_table_in_schema(table_schema, before_table_name);
+
+                    if warn_about_all_drops {
+                        for change in &alter_table.changes {
+                            if let TableChange::DropColumn(drop_column) = change {
+                                warn_about_column_drop(&drop_column.name, before_table_name, &mut diagnostics);
+                            }
+                        }
+                    }
 
-                    if let Some(before_table) = before_table {
+                    if let (Some(before_table), Some(database_table)) = (before_table, database_table) {
                         for change in &alter_table.changes {
                             match *change {
-                                TableChange::DropColumn(ref drop_column) => {
+                                TableChange::DropColumn(ref drop_column)
+                                    if !warn_about_all_drops && database_table.has_column(&drop_column.name) =>
+                                {
                                     self.check_column_drop(drop_column, before_table, &mut diagnostics)
                                         .await?
                                 }
-                                TableChange::AlterColumn(ref alter_column) => {
-                                    self.check_alter_column(alter_column, before_table, &mut diagnostics)
+                                TableChange::AlterColumn(ref alter_column)
+                                    if database_table.has_column(&alter_column.name) =>
+                                {
+                                    let is_backfilled = backfilled_columns.contains(&(
+                                        table_schema,
+                                        before_table_name,
+                                        alter_column.name.as_str(),
+                                    ));
+
+                                    self.check_alter_column(alter_column, before_table, is_backfilled, &mut diagnostics)
                                         .await?
                                 }
                                 TableChange::AddColumn(ref add_column) => {
                                     self.check_add_column(add_column, before_table, &mut diagnostics)
                                         .await?
                                 }
+                                TableChange::DropCheckConstraint(ref drop_check_constraint)
+                                    if database_table.check_constraints.iter().any(|check_constraint| {
+                                        check_constraint.name == drop_check_constraint.name
+                                    }) =>
+                                {
+                                    check_check_constraint_drop(
+                                        drop_check_constraint,
+                                        alter_table,
+                                        before_table,
+                                        &mut diagnostics,
+                                    )
+                                }
                                 _ => (),
                             }
                         }
+
+                        if self.database_info().online_migrations() {
+                            self.check_alter_table_locks(alter_table, before_table, database_table, &mut diagnostics)
+                                .await?;
+                        }
+                    }
+                }
+                SqlMigrationStep::AddForeignKey(AddForeignKey {
+                    table,
+                    schema,
+                    foreign_key,
+                }) if self.database_info().online_migrations() => {
+                    let table_schema = schema.as_deref();
+                    let before_table_name = previous_table_name(&renamed_tables, table_schema, table);
+
+                    if let (Some(before_table), Some(_)) = (
+                        before.get_table_in_schema(table_schema, before_table_name),
+                        database_schema.get_table_in_schema(table_schema, before_table_name),
+                    ) {
+                        let change_description = format!(
+                            "Adding a foreign key on the columns ({})",
+                            foreign_key.columns.join(", ")
+                        );
+
+                        self.check_exclusive_lock(&change_description, before_table, &mut diagnostics)
+                            .await?;
                     }
                 }
                 // Here, check for each table we are going to delete if it is empty. If
                 // not, return a warning.
-                SqlMigrationStep::DropTable(DropTable { name }) => {
-                    self.check_table_drop(name, &mut diagnostics).await?;
+                SqlMigrationStep::DropTable(DropTable { name, .. }) if warn_about_all_drops => {
+                    warn_about_table_drop(name, &mut diagnostics);
                 }
-                SqlMigrationStep::DropTables(DropTables { names }) => {
+                SqlMigrationStep::DropTables(DropTables { names }) if warn_about_all_drops => {
                     for name in names {
-                        self.check_table_drop(name, &mut diagnostics).await?;
+                        warn_about_table_drop(name, &mut diagnostics);
                     }
                 }
+                SqlMigrationStep::DropTable(DropTable { name, schema })
+                    if database_schema.get_table_in_schema(schema.as_deref(), name).is_some() =>
+                {
+                    self.check_table_drop(name, schema.as_deref(), &mut diagnostics).await?;
+                }
+                SqlMigrationStep::DropTables(DropTables { names }) => {
+                    for name in names.iter().filter(|name| database_schema.has_table(name)) {
+                        let schema = before.get_table(name).and_then(|table| table.schema.as_deref());
+
+                        self.check_table_drop(name, schema, &mut diagnostics).await?;
+                    }
+                }
+                SqlMigrationStep::DataMigration(DataMigrationStep::Backfill {
+                    table,
+                    schema,
+                    column,
+                    value,
+                    ..
+                }) if !value.is_null() => {
+                    backfilled_columns.push((schema.as_deref(), table.as_str(), column.as_str()));
+                }
+                SqlMigrationStep::RenameTable(rename_table) => renamed_tables.push(rename_table),
                 // SqlMigrationStep::CreateIndex(CreateIndex { table, index }) if index.is_unique() => todo!(),
                 // do nothing
                 _ => (),
@@ -359,16 +572,77 @@ impl SqlDestructiveChangesChecker<'_> {
     }
 }
 
+/// Emit a warning when we drop a check constraint: the rows inserted afterwards are not validated anymore. Check
+/// constraints whose expression changes are dropped and added again, the added constraint validates the rows.
+fn check_check_constraint_drop(
+    drop_check_constraint: &DropCheckConstraint,
+    alter_table: &AlterTable,
+    table: &sql_schema_describer::Table,
+    diagnostics: &mut DestructiveChangeDiagnostics,
+) {
+    let is_added_again = alter_table.changes.iter().any(|change| match change {
+        TableChange::AddCheckConstraint(add_check_constraint) => {
+            add_check_constraint.check_constraint.name == drop_check_constraint.name
+        }
+        _ => false,
+    });
+
+    if !is_added_again {
+        diagnostics.add_warning(MigrationWarning {
+            description: format!(
+                "You are about to drop the check constraint `{constraint_name}` on the `{table_name}` table. The values it validated will not be checked anymore.",
+                constraint_name = drop_check_constraint.name,
+                table_name = table.name,
+            ),
+        })
+    }
+}
+
+fn warn_about_table_drop(table_name: &str, diagnostics: &mut DestructiveChangeDiagnostics) {
+    diagnostics.add_warning(MigrationWarning {
+        description: format!(
+            "You are about to drop the table `{table_name}`. All the data it holds will be lost.",
+            table_name = table_name,
+        ),
+    })
+}
+
+fn warn_about_column_drop(column_name: &str, table_name: &str, diagnostics: &mut DestructiveChangeDiagnostics) {
+    diagnostics.add_warning(MigrationWarning {
+        description: format!(
+            "You are about to drop the column `{column_name}` on the `{table_name}` table. All the data in the column will be lost.",
+            column_name = column_name,
+            table_name = table_name,
+        ),
+    })
+}
+
+/// The name a table had before the migration, if it was renamed in an earlier step.
+fn previous_table_name<'a>(renamed_tables: &[&'a RenameTable], schema: Option<&str>, table_name: &'a str) -> &'a str {
+    renamed_tables
+        .iter()
+        .find(|rename| rename.new_name == table_name && rename.schema.as_deref() == schema)
+        .map(|rename| rename.name.as_str())
+        .unwrap_or(table_name)
+}
+
 #[async_trait::async_trait]
 impl DestructiveChangesChecker<SqlMigration> for SqlDestructiveChangesChecker<'_> {
     async fn check(&self, database_migration: &SqlMigration) -> ConnectorResult<DestructiveChangeDiagnostics> {
-        self.check_impl(&database_migration.original_steps, &database_migration.before)
-            .await
-            .map_err(|sql_error| sql_error.into_connector_error(&self.connection_info()))
+        self.check_impl(
+            &database_migration.original_steps,
+            &database_migration.before,
+            &database_migration.before,
+            false,
+        )
+        .await
+        .map_err(|sql_error| sql_error.into_connector_error(&self.connection_info()))
     }
 
+    /// The rollback can be checked before the migration is applied, e.g. to document a down script,
+    /// so the database is described to know what it holds.
     async fn check_unapply(&self, database_migration: &SqlMigration) -> ConnectorResult<DestructiveChangeDiagnostics> {
-        self.check_impl(&database_migration.rollback, &database_migration.after)
+        self.check_unapply_impl(database_migration)
             .await
             .map_err(|sql_error| sql_error.into_connector_error(&self.connection_info()))
     }
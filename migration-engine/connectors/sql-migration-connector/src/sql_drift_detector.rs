@@ -0,0 +1,339 @@
+use crate::{
+    catch,
+    sql_database_migration_inferrer::with_managed_view_definitions,
+    sql_database_step_applier::{requalify_mysql_script, SqlDatabaseStepApplier},
+    sql_schema_differ::{DiffingOptions, SqlSchemaDiff, SqlSchemaDiffer},
+    Component, SqlError, SqlMigrationConnector, SqlResult, TableChange,
+};
+use migration_connector::{ConnectorResult, DatabaseMigrationStepApplier, Drift};
+use quaint::{
+    ast::Value,
+    prelude::{ConnectionInfo, SqlFamily},
+};
+use sql_schema_describer::SqlSchema;
+
+/// Compares the database with the schema the migration history leads to. The scripts of the
+/// applied migrations are replayed in a temporary shadow database, on the same server as the
+/// database, which is dropped once it is described.
+pub(crate) struct SqlDriftDetector<'a> {
+    pub connector: &'a SqlMigrationConnector,
+}
+
+impl Component for SqlDriftDetector<'_> {
+    fn connector(&self) -> &SqlMigrationConnector {
+        self.connector
+    }
+}
+
+impl SqlDriftDetector<'_> {
+    pub(crate) async fn diagnose(&self, applied_migration_scripts: &[String]) -> ConnectorResult<Vec<Drift>> {
+        let expected_schema = self.replay(applied_migration_scripts).await?;
+
+        catch(self.connection_info(), async {
+            let database_schema = with_managed_view_definitions(self.describe().await?, &expected_schema);
+
+            let diff = SqlSchemaDiffer::diff(
+                &expected_schema,
+                &database_schema,
+                self.sql_family(),
+                &DiffingOptions::from_database_info(self.database_info()),
+            );
+
+            Ok(drifts_from_diff(diff))
+        })
+        .await
+    }
+
+    /// Applies the scripts to an empty shadow database and describes the schema they lead to.
+    async fn replay(&self, scripts: &[String]) -> ConnectorResult<SqlSchema> {
+        let shadow_database_name = format!(
+            "prisma_shadow_{}_{}",
+            std::process::id(),
+            chrono::Utc::now().timestamp_nanos()
+        );
+
+        let shadow_url = catch(
+            self.connection_info(),
+            self.create_shadow_database(&shadow_database_name),
+        )
+        .await?;
+
+        let schema = self.replay_in_shadow_database(&shadow_url, scripts).await;
+
+        // The shadow database is dropped whether the replay succeeded or not. A failing replay is the
+        // error to report.
+        let dropped = catch(self.connection_info(), self.drop_shadow_database(&shadow_database_name)).await;
+
+        if let Err(err) = &dropped {
+            tracing::warn!("Failed to drop the shadow database `{}`: {}", shadow_database_name, err);
+        }
+
+        let schema = schema?;
+        dropped?;
+
+        Ok(schema)
+    }
+
+    async fn replay_in_shadow_database(&self, shadow_url: &str, scripts: &[String]) -> ConnectorResult<SqlSchema> {
+        let shadow = SqlMigrationConnector::new(shadow_url)
+            .await?
+            .with_additional_schemas(&self.connector.additional_schemas);
+
+        catch(shadow.connection_info(), shadow.initialize_impl()).await?;
+
+        let step_applier = SqlDatabaseStepApplier { connector: &shadow };
+
+        for script in scripts {
+            // The MySQL scripts qualify the names with the database they were written for.
+            let script = match self.sql_family() {
+                SqlFamily::Mysql => requalify_mysql_script(script, self.schema_name(), shadow.schema_name()),
+                _ => script.clone(),
+            };
+
+            step_applier.apply_script(&script).await?;
+        }
+
+        catch(shadow.connection_info(), shadow.describe()).await
+    }
+
+    /// Creates the shadow database next to the database, and returns its connection string.
+    async fn create_shadow_database(&self, shadow_database_name: &str) -> SqlResult<String> {
+        match self.connection_info() {
+            ConnectionInfo::Sqlite { file_path, .. } => {
+                let shadow_file_path = shadow_sqlite_file_path(file_path, shadow_database_name);
+
+                Ok(format!("file:{}?db_name={}", shadow_file_path, self.schema_name()))
+            }
+            ConnectionInfo::Postgres(_) | ConnectionInfo::Mysql(_) => {
+                self.connector.create_database_impl(shadow_database_name).await?;
+
+                let mut url = url::Url::parse(&self.connector.url).map_err(|err| SqlError::Generic(err.into()))?;
+                url.set_path(&format!("/{}", shadow_database_name));
+
+                Ok(url.to_string())
+            }
+        }
+    }
+
+    async fn drop_shadow_database(&self, shadow_database_name: &str) -> SqlResult<()> {
+        match self.connection_info() {
+            ConnectionInfo::Sqlite { file_path, .. } => {
+                std::fs::remove_file(shadow_sqlite_file_path(file_path, shadow_database_name))
+                    .map_err(|err| SqlError::Generic(err.into()))?;
+            }
+            ConnectionInfo::Postgres(_) => {
+                // The connection of the shadow database closes in the background once it is dropped.
+                self.conn()
+                    .query_raw(
+                        "SELECT pg_terminate_backend(pid) FROM pg_stat_activity WHERE datname = $1 AND pid <> pg_backend_pid()",
+                        &[Value::from(shadow_database_name)],
+                    )
+                    .await?;
+                self.conn()
+                    .query_raw(&format!("DROP DATABASE \"{}\"", shadow_database_name), &[])
+                    .await?;
+            }
+            ConnectionInfo::Mysql(_) => {
+                self.conn()
+                    .query_raw(&format!("DROP DATABASE `{}`", shadow_database_name), &[])
+                    .await?;
+            }
+        }
+
+        Ok(())
+    }
+}
+
+/// The shadow SQLite database is a file next to the database file.
+fn shadow_sqlite_file_path(file_path: &str, shadow_database_name: &str) -> String {
+    format!("{}.{}", file_path, shadow_database_name)
+}
+
+/// Describes the differences from the expected schema to the database schema. The changes to
+/// added and removed tables are implied by the table drifts, so they are left out.
+fn drifts_from_diff(diff: SqlSchemaDiff) -> Vec<Drift> {
+    let added_tables: Vec<String> = diff.create_tables.iter().map(|step| step.table.name.clone()).collect();
+    let removed_tables: Vec<String> = diff.drop_tables.iter().map(|step| step.name.clone()).collect();
+    // Tables with the same name in different schemas are different tables.
+    let added_or_removed_tables: Vec<(Option<&str>, &str)> = diff
+        .create_tables
+        .iter()
+        .map(|step| (step.table.schema.as_deref(), step.table.name.as_str()))
+        .chain(
+            diff.drop_tables
+                .iter()
+                .map(|step| (step.schema.as_deref(), step.name.as_str())),
+        )
+        .collect();
+    let is_added_or_removed =
+        |schema: &Option<String>, table: &str| added_or_removed_tables.contains(&(schema.as_deref(), table));
+
+    let mut drifts: Vec<Drift> = Vec::new();
+
+    drifts.extend(
+        added_tables
+            .iter()
+            .map(|table| Drift::AddedTable { table: table.clone() }),
+    );
+    drifts.extend(
+        removed_tables
+            .iter()
+            .map(|table| Drift::RemovedTable { table: table.clone() }),
+    );
+    drifts.extend(diff.rename_tables.iter().map(|step| Drift::RenamedTable {
+        from: step.name.clone(),
+        to: step.new_name.clone(),
+    }));
+
+    for alter_table in diff
+        .alter_tables
+        .iter()
+        .filter(|step| !is_added_or_removed(&step.table.schema, &step.table.name))
+    {
+        let table = &alter_table.table.name;
+
+        drifts.extend(alter_table.changes.iter().map(|change| match change {
+            TableChange::AddColumn(add_column) => Drift::AddedColumn {
+                table: table.clone(),
+                column: add_column.column.name.clone(),
+            },
+            TableChange::DropColumn(drop_column) => Drift::RemovedColumn {
+                table: table.clone(),
+                column: drop_column.name.clone(),
+            },
+            TableChange::RenameColumn(rename_column) => Drift::RenamedColumn {
+                table: table.clone(),
+                from: rename_column.name.clone(),
+                to: rename_column.column.name.clone(),
+            },
+            TableChange::AlterColumn(alter_column) => Drift::ChangedColumn {
+                table: table.clone(),
+                column: alter_column.name.clone(),
+            },
+            TableChange::DropForeignKey(drop_foreign_key) => Drift::RemovedForeignKey {
+                table: table.clone(),
+                constraint: drop_foreign_key.constraint_name.clone(),
+            },
+            TableChange::AddCheckConstraint(add_check_constraint) => Drift::AddedConstraint {
+                table: table.clone(),
+                constraint: add_check_constraint.check_constraint.name.clone(),
+            },
+            TableChange::DropCheckConstraint(drop_check_constraint) => Drift::RemovedConstraint {
+                table: table.clone(),
+                constraint: drop_check_constraint.name.clone(),
+            },
+        }));
+    }
+
+    drifts.extend(
+        diff.add_foreign_keys
+            .iter()
+            .filter(|step| !is_added_or_removed(&step.schema, &step.table))
+            .map(|step| Drift::AddedForeignKey {
+                table: step.table.clone(),
+                columns: step.foreign_key.columns.clone(),
+            }),
+    );
+
+    drifts.extend(
+        diff.create_indexes
+            .iter()
+            .filter(|step| !is_added_or_removed(&step.schema, &step.table))
+            .map(|step| Drift::AddedIndex {
+                table: step.table.clone(),
+                index: step.index.name.clone(),
+            }),
+    );
+
+    drifts.extend(
+        diff.drop_indexes
+            .iter()
+            .filter(|step| !is_added_or_removed(&step.schema, &step.table))
+            .map(|step| Drift::RemovedIndex {
+                table: step.table.clone(),
+                index: step.name.clone(),
+            }),
+    );
+
+    drifts.extend(diff.alter_indexes.iter().map(|step| Drift::ChangedIndex {
+        table: step.table.clone(),
+        index: step.index_name.clone(),
+    }));
+
+    drifts.extend(diff.rename_constraints.iter().map(|step| Drift::ChangedConstraint {
+        table: step.table.clone(),
+        constraint: step.name.clone(),
+    }));
+
+    drifts.extend(diff.create_enums.iter().map(|step| Drift::AddedEnum {
+        name: step.name.clone(),
+    }));
+    drifts.extend(diff.drop_enums.iter().map(|step| Drift::RemovedEnum {
+        name: step.name.clone(),
+    }));
+    drifts.extend(diff.alter_enums.iter().map(|step| Drift::ChangedEnum {
+        name: step.name.clone(),
+    }));
+
+    drifts.extend(diff.create_views.iter().map(|step| Drift::AddedView {
+        name: step.view.name.clone(),
+    }));
+    drifts.extend(diff.drop_views.iter().map(|step| Drift::RemovedView {
+        name: step.name.clone(),
+    }));
+
+    drifts
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use sql_schema_describer::{Column, ColumnArity, ColumnType, ColumnTypeFamily, Table};
+
+    fn table(schema: Option<&str>, columns: &[&str]) -> Table {
+        Table {
+            name: "Invoice".to_owned(),
+            schema: schema.map(str::to_owned),
+            columns: columns
+                .iter()
+                .map(|name| Column {
+                    name: (*name).to_owned(),
+                    tpe: ColumnType::pure(ColumnTypeFamily::Int, ColumnArity::Required),
+                    default: None,
+                    auto_increment: false,
+                })
+                .collect(),
+            indices: Vec::new(),
+            primary_key: None,
+            foreign_keys: Vec::new(),
+            check_constraints: Vec::new(),
+        }
+    }
+
+    #[test]
+    fn tables_with_the_same_name_in_other_schemas_do_not_hide_changes() {
+        let expected = SqlSchema {
+            tables: vec![table(None, &["id"]), table(Some("billing"), &["id"])],
+            ..SqlSchema::empty()
+        };
+        let actual = SqlSchema {
+            tables: vec![table(None, &["id", "total"])],
+            ..SqlSchema::empty()
+        };
+
+        let diff = SqlSchemaDiffer::diff(&expected, &actual, SqlFamily::Postgres, &DiffingOptions::default());
+
+        assert_eq!(
+            drifts_from_diff(diff),
+            &[
+                Drift::RemovedTable {
+                    table: "Invoice".to_owned()
+                },
+                Drift::AddedColumn {
+                    table: "Invoice".to_owned(),
+                    column: "total".to_owned()
+                },
+            ]
+        );
+    }
+}
@@ -1,8 +1,8 @@
 pub(crate) mod expanded_alter_column;
 
-use migration_connector::DatabaseMigrationMarker;
+use migration_connector::{DataMigrationStep, DatabaseMigrationMarker, Rename};
 use serde::{Deserialize, Serialize};
-use sql_schema_describer::{Column, ForeignKey, Index, SqlSchema, Table};
+use sql_schema_describer::{CheckConstraint, Column, ForeignKey, Index, SqlSchema, Table, View};
 
 #[derive(Debug, Serialize, Deserialize)]
 pub struct SqlMigration {
@@ -14,6 +14,13 @@ pub struct SqlMigration {
     /// by the database.
     pub corrected_steps: Vec<SqlMigrationStep>,
     pub rollback: Vec<SqlMigrationStep>,
+    /// The rename candidates that were not accepted, see `DatabaseMigrationMarker::proposed_renames`.
+    #[serde(default)]
+    pub proposed_renames: Vec<Rename>,
+    /// The script the migration was applied from, for the migrations of a migrations directory.
+    /// These have no steps.
+    #[serde(default)]
+    pub script: Option<String>,
 }
 
 impl SqlMigration {
@@ -24,6 +31,8 @@ impl SqlMigration {
             original_steps: Vec::new(),
             corrected_steps: Vec::new(),
             rollback: Vec::new(),
+            proposed_renames: Vec::new(),
+            script: None,
         }
     }
 }
@@ -32,6 +41,10 @@ impl DatabaseMigrationMarker for SqlMigration {
     fn serialize(&self) -> serde_json::Value {
         serde_json::to_value(self).unwrap()
     }
+
+    fn proposed_renames(&self) -> &[Rename] {
+        &self.proposed_renames
+    }
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -42,14 +55,45 @@ pub enum SqlMigrationStep {
     DropTable(DropTable),
     // TODO: remove this variant on next migration format breaking change.
     DropTables(DropTables),
-    RenameTable { name: String, new_name: String },
+    RenameTable(RenameTable),
     RawSql { raw: String },
     CreateIndex(CreateIndex),
     DropIndex(DropIndex),
     AlterIndex(AlterIndex),
+    RenameConstraint(RenameConstraint),
     CreateEnum(CreateEnum),
     DropEnum(DropEnum),
     AlterEnum(AlterEnum),
+    CreateView(CreateView),
+    DropView(DropView),
+    DataMigration(DataMigrationStep),
+}
+
+impl SqlMigrationStep {
+    /// Whether the step changes the table, under its current or its new name.
+    pub(crate) fn changes_table(&self, table_name: &str) -> bool {
+        match self {
+            SqlMigrationStep::CreateTable(CreateTable { table })
+            | SqlMigrationStep::AlterTable(AlterTable { table, .. }) => table.name == table_name,
+            SqlMigrationStep::DropTable(DropTable { name, .. }) => name == table_name,
+            SqlMigrationStep::DropTables(DropTables { names }) => names.iter().any(|name| name == table_name),
+            SqlMigrationStep::RenameTable(RenameTable { name, new_name, .. }) => {
+                name == table_name || new_name == table_name
+            }
+            SqlMigrationStep::AddForeignKey(AddForeignKey { table, .. })
+            | SqlMigrationStep::CreateIndex(CreateIndex { table, .. })
+            | SqlMigrationStep::DropIndex(DropIndex { table, .. })
+            | SqlMigrationStep::AlterIndex(AlterIndex { table, .. })
+            | SqlMigrationStep::RenameConstraint(RenameConstraint { table, .. }) => table == table_name,
+            SqlMigrationStep::RawSql { .. }
+            | SqlMigrationStep::CreateEnum(_)
+            | SqlMigrationStep::DropEnum(_)
+            | SqlMigrationStep::AlterEnum(_)
+            | SqlMigrationStep::CreateView(_)
+            | SqlMigrationStep::DropView(_)
+            | SqlMigrationStep::DataMigration(_) => false,
+        }
+    }
 }
 
 /// A helper struct to serialize an [SqlMigrationStep](/sql-migration/enum.SqlMigrationStep.html)
@@ -69,6 +113,10 @@ pub struct CreateTable {
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropTable {
     pub name: String,
+    /// The schema of the table, when it is not the schema of the connection. The steps that refer
+    /// to a table by name carry its schema as well, tables in different schemas can share a name.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -76,6 +124,16 @@ pub struct DropTables {
     pub names: Vec<String>,
 }
 
+/// Serialized like the former `RenameTable { name, new_name }` struct variant, so persisted
+/// migrations keep deserializing.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RenameTable {
+    pub name: String,
+    pub new_name: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AlterTable {
     pub table: Table,
@@ -87,7 +145,10 @@ pub enum TableChange {
     AddColumn(AddColumn),
     AlterColumn(AlterColumn),
     DropColumn(DropColumn),
+    RenameColumn(RenameColumn),
     DropForeignKey(DropForeignKey),
+    AddCheckConstraint(AddCheckConstraint),
+    DropCheckConstraint(DropCheckConstraint),
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
@@ -104,11 +165,25 @@ pub struct DropColumn {
 pub struct AlterColumn {
     pub name: String,
     pub column: Column,
+    /// Whether a data migration step runs on the column before it is altered. Only then is a
+    /// nullable column made required in place, instead of being dropped and added again.
+    #[serde(default)]
+    pub is_backfilled: bool,
+}
+
+/// A column that was dropped and another one that was added with the same type, detected as a
+/// rename. `column` is the column with its new name.
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RenameColumn {
+    pub name: String,
+    pub column: Column,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AddForeignKey {
     pub table: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub foreign_key: ForeignKey,
 }
 
@@ -117,25 +192,50 @@ pub struct DropForeignKey {
     pub constraint_name: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct AddCheckConstraint {
+    pub check_constraint: CheckConstraint,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropCheckConstraint {
+    pub name: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateIndex {
     pub table: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub index: Index,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct DropIndex {
     pub table: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub name: String,
 }
 
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct AlterIndex {
     pub table: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
     pub index_name: String,
     pub index_new_name: String,
 }
 
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct RenameConstraint {
+    pub table: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub schema: Option<String>,
+    pub name: String,
+    pub new_name: String,
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
 pub struct CreateEnum {
     pub name: String,
@@ -159,3 +259,13 @@ impl AlterEnum {
         self.created_variants.is_empty() && self.dropped_variants.is_empty()
     }
 }
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct CreateView {
+    pub view: View,
+}
+
+#[derive(Debug, Serialize, Deserialize, Clone, PartialEq)]
+pub struct DropView {
+    pub name: String,
+}
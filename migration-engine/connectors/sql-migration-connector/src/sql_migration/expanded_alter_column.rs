@@ -6,6 +6,7 @@ pub(crate) fn expand_alter_column(
     previous_column: &Column,
     next_column: &Column,
     sql_family: &SqlFamily,
+    is_backfilled: bool,
     diffing_options: &DiffingOptions,
 ) -> Option<ExpandedAlterColumn> {
     let column_differ = ColumnDiffer {
@@ -16,8 +17,10 @@ pub(crate) fn expand_alter_column(
 
     match sql_family {
         SqlFamily::Sqlite => expand_sqlite_alter_column(&column_differ).map(ExpandedAlterColumn::Sqlite),
-        SqlFamily::Mysql => expand_mysql_alter_column(&column_differ).map(ExpandedAlterColumn::Mysql),
-        SqlFamily::Postgres => expand_postgres_alter_column(&column_differ).map(ExpandedAlterColumn::Postgres),
+        SqlFamily::Mysql => expand_mysql_alter_column(&column_differ, is_backfilled).map(ExpandedAlterColumn::Mysql),
+        SqlFamily::Postgres => {
+            expand_postgres_alter_column(&column_differ, is_backfilled).map(ExpandedAlterColumn::Postgres)
+        }
     }
 }
 
@@ -25,8 +28,10 @@ pub(crate) fn expand_sqlite_alter_column(_columns: &ColumnDiffer) -> Option<Vec<
     None
 }
 
-pub(crate) fn expand_mysql_alter_column(columns: &ColumnDiffer) -> Option<Vec<MysqlAlterColumn>> {
+/// `is_backfilled` tells whether a data migration step runs on the column before it is altered.
+pub(crate) fn expand_mysql_alter_column(columns: &ColumnDiffer, is_backfilled: bool) -> Option<Vec<MysqlAlterColumn>> {
     let mut changes: Vec<MysqlAlterColumn> = Vec::new();
+    let mut modify = false;
 
     for change in columns.all_changes().iter() {
         match change {
@@ -34,14 +39,31 @@ pub(crate) fn expand_mysql_alter_column(columns: &ColumnDiffer) -> Option<Vec<My
                 (_, Some(next_default)) => changes.push(MysqlAlterColumn::SetDefault(next_default.clone())),
                 (_, None) => changes.push(MysqlAlterColumn::DropDefault),
             },
+            // Without a data migration filling in the null values, or with a default, the column is
+            // dropped and added again, so the existing rows get the default.
+            ColumnChange::Arity => match (&columns.previous.tpe.arity, &columns.next.tpe.arity) {
+                (ColumnArity::Nullable, ColumnArity::Required) if is_backfilled && columns.next.default.is_none() => {
+                    modify = true
+                }
+                _ => return None,
+            },
             _ => return None,
         }
     }
 
+    // MODIFY redefines the whole column, including its default.
+    if modify {
+        return Some(vec![MysqlAlterColumn::Modify]);
+    }
+
     Some(changes)
 }
 
-pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer) -> Option<Vec<PostgresAlterColumn>> {
+/// `is_backfilled` tells whether a data migration step runs on the column before it is altered.
+pub(crate) fn expand_postgres_alter_column(
+    columns: &ColumnDiffer,
+    is_backfilled: bool,
+) -> Option<Vec<PostgresAlterColumn>> {
     let mut changes = Vec::new();
 
     for change in columns.all_changes().iter() {
@@ -52,6 +74,11 @@ pub(crate) fn expand_postgres_alter_column(columns: &ColumnDiffer) -> Option<Vec
             },
             ColumnChange::Arity => match (&columns.previous.tpe.arity, &columns.next.tpe.arity) {
                 (ColumnArity::Required, ColumnArity::Nullable) => changes.push(PostgresAlterColumn::DropNotNull),
+                // Without a data migration filling in the null values, or with a default, the column is
+                // dropped and added again, so the existing rows get the default.
+                (ColumnArity::Nullable, ColumnArity::Required) if is_backfilled && columns.next.default.is_none() => {
+                    changes.push(PostgresAlterColumn::SetNotNull)
+                }
                 _ => return None,
             },
             ColumnChange::Type => match (&columns.previous.tpe.family, &columns.next.tpe.family) {
@@ -81,9 +108,9 @@ pub(crate) enum PostgresAlterColumn {
     SetDefault(sql_schema_describer::DefaultValue),
     DropDefault,
     DropNotNull,
+    SetNotNull,
     SetType(ColumnType),
     // Not used yet:
-    // SetNotNull,
     // Rename { previous_name: String, next_name: String },
 }
 
@@ -92,6 +119,8 @@ pub(crate) enum PostgresAlterColumn {
 pub(crate) enum MysqlAlterColumn {
     SetDefault(sql_schema_describer::DefaultValue),
     DropDefault,
+    /// Redefine the column as it is in the next schema.
+    Modify,
     // Not used yet:
     // Rename { previous_name: String, next_name: String },
 }
@@ -0,0 +1,196 @@
+use crate::{catch, component::Component, SqlError, SqlMigrationConnector, SqlResult};
+use fs2::FileExt;
+use migration_connector::{ConnectorError, ConnectorResult, MigrationLock};
+use quaint::{connector::ResultSet, prelude::ConnectionInfo};
+use std::{
+    fs::{File, OpenOptions},
+    io,
+    time::{Duration, Instant},
+};
+
+/// How long to wait before trying to take a lock held by another migration engine again.
+const RETRY_INTERVAL: Duration = Duration::from_millis(100);
+
+/// The first key of the Postgres advisory lock. The second one is the hash of the schema name, so
+/// migration engines working on different schemas do not wait for each other.
+const POSTGRES_ADVISORY_LOCK_KEY: i32 = 72_707_369;
+
+/// The lock taken by the migration engine while it changes the database: an advisory lock on
+/// Postgres, a named lock on MySQL and an exclusive lock on a file next to the database on SQLite.
+/// The database locks are held by the connection, so they are released if the engine crashes.
+///
+/// The SQLite lock file is removed when the lock is released. An engine that opened it before it
+/// was removed may then lock a file that is no longer there, so a lock is only taken once the
+/// locked file is the one at the lock file path.
+pub(crate) struct SqlMigrationLock<'a> {
+    pub connector: &'a SqlMigrationConnector,
+    /// The locked file on SQLite. Closing it releases the lock.
+    lock_file: Option<File>,
+}
+
+impl Component for SqlMigrationLock<'_> {
+    fn connector(&self) -> &SqlMigrationConnector {
+        self.connector
+    }
+}
+
+impl<'a> SqlMigrationLock<'a> {
+    pub(crate) async fn acquire(connector: &'a SqlMigrationConnector, timeout: Duration) -> ConnectorResult<Self> {
+        let mut lock = SqlMigrationLock {
+            connector,
+            lock_file: None,
+        };
+        let started_at = Instant::now();
+
+        while !catch(connector.connection_info(), lock.try_acquire()).await? {
+            if started_at.elapsed() >= timeout {
+                return Err(ConnectorError::migration_lock_timeout(timeout));
+            }
+
+            tracing::debug!("The migration lock is held by another migration engine, waiting for it.");
+
+            tokio::time::delay_for(RETRY_INTERVAL).await;
+        }
+
+        Ok(lock)
+    }
+
+    async fn try_acquire(&mut self) -> SqlResult<bool> {
+        let connector = self.connector;
+
+        match connector.connection_info() {
+            ConnectionInfo::Postgres(_) => {
+                let sql = format!(
+                    "SELECT pg_try_advisory_lock({}, hashtext($1))",
+                    POSTGRES_ADVISORY_LOCK_KEY
+                );
+                let result_set = self.conn().query_raw(&sql, &[self.schema_name().into()]).await?;
+
+                first_value_is_true(result_set)
+            }
+            ConnectionInfo::Mysql(_) => {
+                let result_set = self
+                    .conn()
+                    .query_raw("SELECT GET_LOCK(?, 0)", &[self.mysql_lock_name().into()])
+                    .await?;
+
+                first_value_is_true(result_set)
+            }
+            ConnectionInfo::Sqlite { file_path, .. } => {
+                let lock_file_path = sqlite_lock_file_path(file_path);
+                let lock_file = OpenOptions::new()
+                    .create(true)
+                    .write(true)
+                    .open(&lock_file_path)
+                    .map_err(|err| SqlError::Generic(err.into()))?;
+
+                match lock_file.try_lock_exclusive() {
+                    Ok(()) => {
+                        // The engine that held the lock removed the file, it is created again on
+                        // the next try.
+                        if !is_file_at_path(&lock_file, &lock_file_path).map_err(|err| SqlError::Generic(err.into()))? {
+                            return Ok(false);
+                        }
+
+                        self.lock_file = Some(lock_file);
+                        Ok(true)
+                    }
+                    Err(err) if err.kind() == fs2::lock_contended_error().kind() => Ok(false),
+                    Err(err) => Err(SqlError::Generic(err.into())),
+                }
+            }
+        }
+    }
+
+    async fn release_impl(&mut self) -> SqlResult<()> {
+        let connector = self.connector;
+
+        match connector.connection_info() {
+            ConnectionInfo::Postgres(_) => {
+                let sql = format!(
+                    "SELECT pg_advisory_unlock({}, hashtext($1))",
+                    POSTGRES_ADVISORY_LOCK_KEY
+                );
+                self.conn().query_raw(&sql, &[self.schema_name().into()]).await?;
+            }
+            ConnectionInfo::Mysql(_) => {
+                self.conn()
+                    .query_raw("SELECT RELEASE_LOCK(?)", &[self.mysql_lock_name().into()])
+                    .await?;
+            }
+            ConnectionInfo::Sqlite { file_path, .. } => {
+                if let Some(lock_file) = self.lock_file.take() {
+                    // The file is removed while it is still locked, so no other engine can take
+                    // the lock on it in between.
+                    match std::fs::remove_file(sqlite_lock_file_path(file_path)) {
+                        Err(err) if err.kind() != io::ErrorKind::NotFound => {
+                            tracing::warn!("Failed to remove the migration lock file: {}", err)
+                        }
+                        _ => (),
+                    }
+
+                    lock_file.unlock().map_err(|err| SqlError::Generic(err.into()))?;
+                }
+            }
+        }
+
+        Ok(())
+    }
+
+    /// MySQL lock names are global to the server and at most 64 characters long.
+    fn mysql_lock_name(&self) -> String {
+        format!("prisma_migrate_{}", self.schema_name())
+            .chars()
+            .take(64)
+            .collect()
+    }
+}
+
+#[async_trait::async_trait]
+impl MigrationLock for SqlMigrationLock<'_> {
+    async fn release(mut self: Box<Self>) -> ConnectorResult<()> {
+        let connector = self.connector;
+
+        catch(connector.connection_info(), self.release_impl()).await
+    }
+}
+
+fn sqlite_lock_file_path(database_file_path: &str) -> String {
+    format!("{}.migration_lock", database_file_path)
+}
+
+/// Whether `file` is the file at `path`, and not a file removed from it since it was opened.
+#[cfg(unix)]
+fn is_file_at_path(file: &File, path: &str) -> io::Result<bool> {
+    use std::os::unix::fs::MetadataExt;
+
+    let file_metadata = file.metadata()?;
+
+    match std::fs::metadata(path) {
+        Ok(path_metadata) => {
+            Ok(file_metadata.dev() == path_metadata.dev() && file_metadata.ino() == path_metadata.ino())
+        }
+        Err(err) if err.kind() == io::ErrorKind::NotFound => Ok(false),
+        Err(err) => Err(err),
+    }
+}
+
+/// Whether `file` is the file at `path`. Without inodes to compare, the file is assumed to be the
+/// one at the path if the path exists.
+#[cfg(not(unix))]
+fn is_file_at_path(_file: &File, path: &str) -> io::Result<bool> {
+    Ok(std::path::Path::new(path).exists())
+}
+
+fn first_value_is_true(result_set: ResultSet) -> SqlResult<bool> {
+    result_set
+        .first()
+        .as_ref()
+        .and_then(|row| row.at(0))
+        .and_then(|value| value.as_bool().or_else(|| value.as_i64().map(|value| value == 1)))
+        .ok_or_else(|| {
+            SqlError::Generic(anyhow::anyhow!(
+                "Unexpected result set shape when taking the migration lock."
+            ))
+        })
+}
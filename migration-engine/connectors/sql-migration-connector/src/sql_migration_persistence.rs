@@ -1,4 +1,4 @@
-use crate::Component;
+use crate::{Component, SqlResult};
 use barrel::types;
 use chrono::*;
 use migration_connector::*;
@@ -40,6 +40,8 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
 
             self.conn().query_raw(&sql_str, &[]).await.ok();
 
+            self.add_missing_checksum_column().await?;
+
             Ok(())
         };
 
@@ -67,8 +69,7 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
                 .so_that(conditions)
                 .order_by(REVISION_COLUMN.descend());
 
-            let result_set = self.conn().query(query.into()).await?;
-            Ok(parse_rows_new(result_set).into_iter().next())
+            Ok(self.query_migrations(query).await?.into_iter().next())
         })
         .await
     }
@@ -77,8 +78,7 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
         crate::catch(self.connection_info(), async {
             let query = Select::from_table(self.table()).order_by(REVISION_COLUMN.ascend());
 
-            let result_set = self.conn().query(query.into()).await?;
-            Ok(parse_rows_new(result_set))
+            self.query_migrations(query).await
         })
         .await
     }
@@ -90,8 +90,7 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
                 .so_that(conditions)
                 .order_by(REVISION_COLUMN.descend());
 
-            let result_set = self.conn().query(query.into()).await?;
-            Ok(parse_rows_new(result_set).into_iter().next())
+            Ok(self.query_migrations(query).await?.into_iter().next())
         })
         .await
     }
@@ -101,6 +100,14 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
         let model_steps_json = serde_json::to_string(&migration.datamodel_steps).unwrap();
         let database_migration_json = serde_json::to_string(&migration.database_migration).unwrap();
         let errors_json = serde_json::to_string(&migration.errors).unwrap();
+        let finished_at_value = match migration.finished_at {
+            Some(x) => self.convert_datetime(x),
+            None => Value::Null,
+        };
+        let checksum_value = match migration.checksum {
+            Some(checksum) => Value::from(checksum),
+            None => Value::Null,
+        };
 
         let insert = Insert::single_into(self.table())
             .value(DATAMODEL_COLUMN, migration.datamodel_string)
@@ -110,9 +117,10 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
             .value(ROLLED_BACK_COLUMN, migration.rolled_back)
             .value(DATAMODEL_STEPS_COLUMN, model_steps_json)
             .value(DATABASE_MIGRATION_COLUMN, database_migration_json)
+            .value(CHECKSUM_COLUMN, checksum_value)
             .value(ERRORS_COLUMN, errors_json)
             .value(STARTED_AT_COLUMN, self.convert_datetime(migration.started_at))
-            .value(FINISHED_AT_COLUMN, Value::Null);
+            .value(FINISHED_AT_COLUMN, finished_at_value);
 
         match self.sql_family() {
             SqlFamily::Sqlite | SqlFamily::Mysql => {
@@ -140,12 +148,14 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
                 Some(x) => self.convert_datetime(x),
                 None => Value::Null,
             };
+            let database_migration_json = serde_json::to_string(&params.database_migration).unwrap();
             let errors_json = serde_json::to_string(&params.errors).unwrap();
             let query = Update::table(self.table())
                 .set(NAME_COLUMN, params.new_name.clone())
                 .set(STATUS_COLUMN, params.status.code())
                 .set(APPLIED_COLUMN, params.applied)
                 .set(ROLLED_BACK_COLUMN, params.rolled_back)
+                .set(DATABASE_MIGRATION_COLUMN, database_migration_json)
                 .set(ERRORS_COLUMN, errors_json)
                 .set(FINISHED_AT_COLUMN, finished_at_value)
                 .so_that(
@@ -160,6 +170,76 @@ impl MigrationPersistence for SqlMigrationPersistence<'_> {
         })
         .await
     }
+
+    async fn squash(&self, squashed: &[Migration], migration: Migration) -> Result<Migration, ConnectorError> {
+        let (last_squashed, other_squashed) = match squashed.split_last() {
+            Some(split) => split,
+            None => return self.create(migration).await,
+        };
+
+        crate::catch(self.connection_info(), async {
+            let mut cloned = migration.clone();
+            cloned.revision = last_squashed.revision;
+
+            let model_steps_json = serde_json::to_string(&migration.datamodel_steps).unwrap();
+            let database_migration_json = serde_json::to_string(&migration.database_migration).unwrap();
+            let errors_json = serde_json::to_string(&migration.errors).unwrap();
+            let finished_at_value = match migration.finished_at {
+                Some(x) => self.convert_datetime(x),
+                None => Value::Null,
+            };
+            let checksum_value = match migration.checksum {
+                Some(checksum) => Value::from(checksum),
+                None => Value::Null,
+            };
+
+            let update = Update::table(self.table())
+                .set(DATAMODEL_COLUMN, migration.datamodel_string)
+                .set(NAME_COLUMN, migration.name)
+                .set(STATUS_COLUMN, migration.status.code())
+                .set(APPLIED_COLUMN, migration.applied)
+                .set(ROLLED_BACK_COLUMN, migration.rolled_back)
+                .set(DATAMODEL_STEPS_COLUMN, model_steps_json)
+                .set(DATABASE_MIGRATION_COLUMN, database_migration_json)
+                .set(CHECKSUM_COLUMN, checksum_value)
+                .set(ERRORS_COLUMN, errors_json)
+                .set(STARTED_AT_COLUMN, self.convert_datetime(migration.started_at))
+                .set(FINISHED_AT_COLUMN, finished_at_value)
+                .so_that(REVISION_COLUMN.equals(last_squashed.revision));
+
+            let other_revisions: Vec<Value> = other_squashed
+                .iter()
+                .map(|migration| Value::from(migration.revision as i64))
+                .collect();
+
+            // The history must not be left half squashed.
+            self.conn().query_raw("BEGIN", &[]).await?;
+
+            let result = async {
+                self.conn().query(update.into()).await?;
+
+                if !other_revisions.is_empty() {
+                    let delete =
+                        Delete::from_table(self.table()).so_that(REVISION_COLUMN.in_selection(other_revisions));
+
+                    self.conn().query(delete.into()).await?;
+                }
+
+                Ok::<(), quaint::error::Error>(())
+            }
+            .await;
+
+            match result {
+                Ok(()) => self.conn().query_raw("COMMIT", &[]).await?,
+                Err(_) => self.conn().query_raw("ROLLBACK", &[]).await?,
+            };
+
+            result?;
+
+            Ok(cloned)
+        })
+        .await
+    }
 }
 
 fn migration_table_setup_sqlite(t: &mut barrel::Table) {
@@ -187,12 +267,51 @@ fn migration_table_setup(
     t.add_column(ROLLED_BACK_COLUMN, types::integer());
     t.add_column(DATAMODEL_STEPS_COLUMN, unlimited_text_type.clone());
     t.add_column(DATABASE_MIGRATION_COLUMN, unlimited_text_type.clone());
+    t.add_column(CHECKSUM_COLUMN, types::text().nullable(true));
     t.add_column(ERRORS_COLUMN, unlimited_text_type.clone());
     t.add_column(STARTED_AT_COLUMN, datetime_type.clone());
     t.add_column(FINISHED_AT_COLUMN, datetime_type.clone().nullable(true));
 }
 
 impl<'a> SqlMigrationPersistence<'a> {
+    /// Reads the migration table as if it were empty when it does not exist: the connector is not
+    /// initialized for dry runs, which must not change the database.
+    async fn query_migrations(&self, query: Select<'_>) -> SqlResult<Vec<Migration>> {
+        match self.conn().query(query.into()).await {
+            Ok(result_set) => Ok(parse_rows_new(result_set)),
+            Err(_) if !self.describe().await?.has_table(MIGRATION_TABLE_NAME) => Ok(Vec::new()),
+            Err(err) => Err(err.into()),
+        }
+    }
+
+    /// Migration tables created before migrations directories existed have no checksum column.
+    async fn add_missing_checksum_column(&self) -> SqlResult<()> {
+        let schema = self.describe().await?;
+        let has_checksum_column = match schema.get_table(MIGRATION_TABLE_NAME) {
+            Some(table) => table.has_column(CHECKSUM_COLUMN),
+            None => return Ok(()),
+        };
+
+        if has_checksum_column {
+            return Ok(());
+        }
+
+        let mut m = barrel::Migration::new().schema(self.schema_name());
+        m.change_table(MIGRATION_TABLE_NAME, |t| {
+            t.add_column(CHECKSUM_COLUMN, types::text().nullable(true));
+        });
+
+        let sql_str = match self.sql_family() {
+            SqlFamily::Sqlite => m.make_from(barrel::SqlVariant::Sqlite),
+            SqlFamily::Postgres => m.make_from(barrel::SqlVariant::Pg),
+            SqlFamily::Mysql => m.make_from(barrel::SqlVariant::Mysql),
+        };
+
+        self.conn().query_raw(&sql_str, &[]).await?;
+
+        Ok(())
+    }
+
     fn table(&self) -> Table {
         match self.sql_family() {
             SqlFamily::Sqlite => {
@@ -260,6 +379,7 @@ fn parse_rows_new(result_set: ResultSet) -> Vec<Migration> {
                 rolled_back: row[ROLLED_BACK_COLUMN].as_i64().unwrap() as usize,
                 datamodel_steps,
                 database_migration: database_migration_json,
+                checksum: row[CHECKSUM_COLUMN].to_string(),
                 errors,
                 started_at: convert_parameterized_date_value(&row[STARTED_AT_COLUMN]),
                 finished_at,
@@ -277,6 +397,7 @@ static APPLIED_COLUMN: &str = "applied";
 static ROLLED_BACK_COLUMN: &str = "rolled_back";
 static DATAMODEL_STEPS_COLUMN: &str = "datamodel_steps";
 static DATABASE_MIGRATION_COLUMN: &str = "database_migration";
+static CHECKSUM_COLUMN: &str = "checksum";
 static ERRORS_COLUMN: &str = "errors";
 static STARTED_AT_COLUMN: &str = "started_at";
 static FINISHED_AT_COLUMN: &str = "finished_at";
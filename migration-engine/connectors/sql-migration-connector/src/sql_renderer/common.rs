@@ -69,6 +69,11 @@ where
     }
 }
 
+/// Renders bytes as a hexadecimal string, as used in binary literals.
+pub(crate) fn render_hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
 pub(crate) fn render_nullability(column: &ColumnRef<'_>) -> &'static str {
     if column.is_required() {
         "NOT NULL"
@@ -65,6 +65,7 @@ impl SqlRenderer for MySqlRenderer {
             (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultValue::VALUE(PrismaValue::Bytes(bytes)), _) => format!("X'{}'", render_hex(bytes)).into(),
             (DefaultValue::VALUE(val), _) => format!("{}", val).into(),
             (DefaultValue::SEQUENCE(_), _) => todo!("rendering of sequence defaults"),
         }
@@ -73,6 +74,10 @@ impl SqlRenderer for MySqlRenderer {
 
 impl MySqlRenderer {
     fn render_column_type(&self, column: &ColumnRef<'_>) -> anyhow::Result<Cow<'static, str>> {
+        if let Some(native_type) = &column.column_type().native_type {
+            return Ok(native_type.clone().into());
+        }
+
         match &column.column_type().family {
             ColumnTypeFamily::Boolean => Ok("boolean".into()),
             ColumnTypeFamily::DateTime => {
@@ -99,6 +104,7 @@ impl MySqlRenderer {
                 Ok(format!("ENUM({})", variants).into())
             }
             ColumnTypeFamily::Json => Ok("json".into()),
+            ColumnTypeFamily::Binary => Ok("longblob".into()),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
@@ -43,7 +43,10 @@ impl super::SqlRenderer for PostgresRenderer {
 
         format!(
             "REFERENCES {}({}) {}  ON UPDATE CASCADE",
-            self.quote_with_schema(schema_name, &foreign_key.referenced_table),
+            self.quote_with_schema(
+                foreign_key.referenced_schema.as_deref().unwrap_or(schema_name),
+                &foreign_key.referenced_table
+            ),
             referenced_columns,
             render_on_delete(&foreign_key.on_delete_action)
         )
@@ -59,6 +62,7 @@ impl super::SqlRenderer for PostgresRenderer {
             (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultValue::VALUE(PrismaValue::Bytes(bytes)), _) => format!("'\\x{}'", render_hex(bytes)).into(),
             (DefaultValue::VALUE(val), _) => val.to_string().into(),
             (DefaultValue::SEQUENCE(_), _) => todo!("rendering of sequence defaults"),
         }
@@ -71,6 +75,10 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
         _ => "",
     };
 
+    if let Some(native_type) = &t.native_type {
+        return format!("{}{}", native_type, array);
+    }
+
     match &t.family {
         ColumnTypeFamily::Boolean => format!("boolean {}", array),
         ColumnTypeFamily::DateTime => format!("timestamp(3) {}", array),
@@ -79,6 +87,7 @@ pub(crate) fn render_column_type(t: &ColumnType) -> String {
         ColumnTypeFamily::String => format!("text {}", array),
         ColumnTypeFamily::Enum(name) => format!("{}{}", Quoted::postgres_ident(name), array),
         ColumnTypeFamily::Json => format!("jsonb {}", array),
+        ColumnTypeFamily::Binary => format!("bytea {}", array),
         x => unimplemented!("{:?} not handled yet", x),
     }
 }
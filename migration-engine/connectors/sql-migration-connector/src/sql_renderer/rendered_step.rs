@@ -16,6 +16,14 @@ impl RenderedStep {
 
         self
     }
+
+    pub(crate) fn statements(&self) -> &[String] {
+        &self.statements
+    }
+
+    pub(crate) fn run_in_transaction(&self) -> bool {
+        self.run_in_transaction
+    }
 }
 
 // TEMPORARY
@@ -62,6 +62,7 @@ impl super::SqlRenderer for SqliteRenderer {
             (DefaultValue::NOW, ColumnTypeFamily::DateTime) => "CURRENT_TIMESTAMP".into(),
             (DefaultValue::NOW, _) => unreachable!("NOW default on non-datetime column"),
             (DefaultValue::VALUE(val), ColumnTypeFamily::DateTime) => format!("'{}'", val).into(),
+            (DefaultValue::VALUE(PrismaValue::Bytes(bytes)), _) => format!("X'{}'", render_hex(bytes)).into(),
             (DefaultValue::VALUE(val), _) => format!("{}", val).into(),
             (DefaultValue::SEQUENCE(_), _) => unreachable!("rendering of sequence defaults"),
         }
@@ -70,12 +71,17 @@ impl super::SqlRenderer for SqliteRenderer {
 
 impl SqliteRenderer {
     fn render_column_type(&self, t: &ColumnType) -> String {
+        if let Some(native_type) = &t.native_type {
+            return native_type.clone();
+        }
+
         match &t.family {
             ColumnTypeFamily::Boolean => format!("BOOLEAN"),
             ColumnTypeFamily::DateTime => format!("DATE"),
             ColumnTypeFamily::Float => format!("REAL"),
             ColumnTypeFamily::Int => format!("INTEGER"),
             ColumnTypeFamily::String => format!("TEXT"),
+            ColumnTypeFamily::Binary => format!("BLOB"),
             x => unimplemented!("{:?} not handled yet", x),
         }
     }
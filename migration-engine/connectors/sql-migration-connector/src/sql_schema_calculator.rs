@@ -48,14 +48,31 @@ impl<'a> SqlSchemaCalculator<'a> {
 
         let enums = self.calculate_enums();
         let sequences = Vec::new();
+        let views = self.calculate_views();
 
         Ok(sql::SqlSchema {
             tables,
             enums,
             sequences,
+            views,
         })
     }
 
+    /// Only views with a definition are managed by migrations, the others are left alone.
+    fn calculate_views(&self) -> Vec<sql::View> {
+        self.data_model
+            .models()
+            .filter(|model| model.is_view)
+            .filter_map(|model| {
+                model.view_definition.as_ref().map(|definition| sql::View {
+                    name: model.final_database_name().to_owned(),
+                    definition: Some(definition.clone()),
+                    columns: Vec::new(),
+                })
+            })
+            .collect()
+    }
+
     fn calculate_enums(&self) -> Vec<sql::Enum> {
         match self.database_info.sql_family() {
             SqlFamily::Postgres => self
@@ -63,6 +80,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                 .enums()
                 .map(|r#enum| sql::Enum {
                     name: r#enum.final_database_name().to_owned(),
+                    schema: None,
                     values: r#enum.database_values(),
                 })
                 .collect(),
@@ -81,6 +99,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             model_name = field.model().database_name(),
                             field_name = field.db_name()
                         ),
+                        schema: None,
                         values: enum_tpe.r#enum.database_values(),
                     };
 
@@ -102,7 +121,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                 .flat_map(|f| match f.field_type() {
                     TypeRef::Base(_) => Some(sql::Column {
                         name: f.db_name().to_owned(),
-                        tpe: column_type(&f),
+                        tpe: column_type(&f, &self.database_info),
                         default: migration_value_new(&f),
                         auto_increment: matches!(f.default_value(), Some(DefaultValue::Expression(ValueGenerator { generator: ValueGeneratorFn::Autoincrement, .. }))),
                     }),
@@ -125,14 +144,21 @@ impl<'a> SqlSchemaCalculator<'a> {
                     .map(|field| field.db_name().to_owned())
                     .collect(),
                 sequence: None,
+                constraint_name: model.primary_key_db_name().map(String::from),
             };
 
             let single_field_indexes = model.fields().filter_map(|f| {
                 if f.is_unique() {
                     Some(sql::Index {
-                        name: format!("{}.{}", &model.db_name(), &f.db_name()),
+                        name: f
+                            .constraint_db_name()
+                            .map(String::from)
+                            .unwrap_or_else(|| format!("{}.{}", &model.db_name(), &f.db_name())),
                         columns: vec![f.db_name().to_owned()],
                         tpe: sql::IndexType::Unique,
+                        column_options: vec![],
+                        algorithm: None,
+                        predicate: None,
                     })
                 } else {
                     None
@@ -147,13 +173,17 @@ impl<'a> SqlSchemaCalculator<'a> {
                     .collect();
 
                 sql::Index {
-                    name: index_definition.name.clone().unwrap_or_else(|| {
-                        format!(
-                            "{}.{}",
-                            &model.db_name(),
-                            referenced_fields.iter().map(|field| field.db_name()).join("_")
-                        )
-                    }),
+                    name: index_definition
+                        .db_name
+                        .clone()
+                        .or_else(|| index_definition.name.clone())
+                        .unwrap_or_else(|| {
+                            format!(
+                                "{}.{}",
+                                &model.db_name(),
+                                referenced_fields.iter().map(|field| field.db_name()).join("_")
+                            )
+                        }),
                     // The model index definition uses the model field names, but the SQL Index
                     // wants the column names.
                     columns: referenced_fields
@@ -165,21 +195,43 @@ impl<'a> SqlSchemaCalculator<'a> {
                     } else {
                         sql::IndexType::Normal
                     },
+                    column_options: index_column_options(index_definition),
+                    algorithm: index_definition.algorithm.and_then(index_algorithm),
+                    predicate: index_definition.predicate.clone(),
                 }
             });
 
             let table = sql::Table {
                 name: model.database_name().to_owned(),
+                schema: self.schema_name(model.schema()),
                 columns,
                 indices: single_field_indexes.chain(multiple_field_indexes).collect(),
                 primary_key: Some(primary_key),
                 foreign_keys: Vec::new(),
+                check_constraints: self.calculate_check_constraints(&model),
             };
 
             Ok((model, table))
         })
     }
 
+    fn calculate_check_constraints(&self, model: &ModelRef<'_>) -> Vec<sql::CheckConstraint> {
+        model
+            .check_constraints()
+            .enumerate()
+            .map(|(idx, check_constraint)| sql::CheckConstraint {
+                name: check_constraint.name.clone().unwrap_or_else(|| {
+                    // Same naming scheme as Postgres for unnamed table check constraints.
+                    match idx {
+                        0 => format!("{}_check", model.database_name()),
+                        idx => format!("{}_check{}", model.database_name(), idx),
+                    }
+                }),
+                expression: check_constraint.expression.clone(),
+            })
+            .collect()
+    }
+
     fn add_inline_relations_to_model_tables(&self, model: ModelRef<'a>, table: &mut sql::Table) {
         let relation_fields = model
             .fields()
@@ -197,9 +249,10 @@ impl<'a> SqlSchemaCalculator<'a> {
             // Foreign key
             {
                 let fk = sql::ForeignKey {
-                    constraint_name: None,
+                    constraint_name: relation_field.fk_db_name().map(String::from),
                     columns: fk_columns,
                     referenced_table: relation_field.referenced_table_name().to_owned(),
+                    referenced_schema: self.schema_name(relation_field.referenced_schema()),
                     referenced_columns: relation_field.referenced_columns().map(String::from).collect(),
                     on_delete_action: match column_arity(relation_field.arity()) {
                         ColumnArity::Required => sql::ForeignKeyAction::Cascade,
@@ -220,14 +273,15 @@ impl<'a> SqlSchemaCalculator<'a> {
                     let model_a = ModelRef::new(&relation.model_a, self.data_model);
                     let model_b = ModelRef::new(&relation.model_b, self.data_model);
 
-                    let a_columns = relation_table_columns(&model_a, relation.model_a_column());
-                    let b_columns = relation_table_columns(&model_b, relation.model_b_column());
+                    let a_columns = relation_table_columns(&model_a, relation.model_a_column(), &self.database_info);
+                    let b_columns = relation_table_columns(&model_b, relation.model_b_column(), &self.database_info);
 
                     let foreign_keys = vec![
                         sql::ForeignKey {
                             constraint_name: None,
                             columns: a_columns.iter().map(|col| col.name.clone()).collect(),
                             referenced_table: model_a.db_name().to_owned(),
+                            referenced_schema: self.schema_name(model_a.schema()),
                             referenced_columns: first_unique_criterion(model_a)
                                 .map_err(SqlError::Generic)?
                                 .into_iter()
@@ -239,6 +293,7 @@ impl<'a> SqlSchemaCalculator<'a> {
                             constraint_name: None,
                             columns: b_columns.iter().map(|col| col.name.clone()).collect(),
                             referenced_table: model_b.db_name().to_owned(),
+                            referenced_schema: self.schema_name(model_b.schema()),
                             referenced_columns: first_unique_criterion(model_b)
                                 .map_err(SqlError::Generic)?
                                 .into_iter()
@@ -256,20 +311,28 @@ impl<'a> SqlSchemaCalculator<'a> {
                             name: format!("{}_AB_unique", relation.table_name()),
                             columns: columns.iter().map(|col| col.name.clone()).collect(),
                             tpe: sql::IndexType::Unique,
+                            column_options: vec![],
+                            algorithm: None,
+                            predicate: None,
                         },
                         sql::Index {
                             name: format!("{}_B_index", relation.table_name()),
                             columns: b_columns.into_iter().map(|col| col.name).collect(),
                             tpe: sql::IndexType::Normal,
+                            column_options: vec![],
+                            algorithm: None,
+                            predicate: None,
                         },
                     ];
 
                     let table = sql::Table {
                         name: relation.table_name(),
+                        schema: None,
                         columns,
                         indices: indexes,
                         primary_key: None,
                         foreign_keys,
+                        check_constraints: vec![],
                     };
                     result.push(table);
                 }
@@ -282,9 +345,20 @@ impl<'a> SqlSchemaCalculator<'a> {
     fn calculate_relations(&self) -> Vec<TempRelationHolder> {
         DatamodelConverter::calculate_relations(&self.data_model)
     }
+
+    /// Tables in the schema of the connection are not qualified, like in the sql-schema-describer.
+    fn schema_name(&self, schema: Option<&str>) -> Option<String> {
+        schema
+            .filter(|schema| *schema != self.database_info.connection_info().schema_name())
+            .map(String::from)
+    }
 }
 
-fn relation_table_columns(referenced_model: &ModelRef<'_>, reference_field_name: String) -> Vec<sql::Column> {
+fn relation_table_columns(
+    referenced_model: &ModelRef<'_>,
+    reference_field_name: String,
+    database_info: &DatabaseInfo,
+) -> Vec<sql::Column> {
     if referenced_model.model().id_fields.is_empty() {
         let unique_field = referenced_model.fields().find(|f| f.is_unique());
         let id_field = referenced_model.fields().find(|f| f.is_id());
@@ -296,7 +370,7 @@ fn relation_table_columns(referenced_model: &ModelRef<'_>, reference_field_name:
 
         vec![sql::Column {
             name: reference_field_name,
-            tpe: column_type(&unique_field),
+            tpe: column_type(&unique_field, database_info),
             default: None,
             auto_increment: false,
         }]
@@ -309,7 +383,7 @@ fn relation_table_columns(referenced_model: &ModelRef<'_>, reference_field_name:
                     reference_field_name = reference_field_name,
                     referenced_column_name = referenced_field.db_name()
                 ),
-                tpe: column_type(&referenced_field),
+                tpe: column_type(&referenced_field, database_info),
                 default: None,
                 auto_increment: false,
             })
@@ -318,6 +392,13 @@ fn relation_table_columns(referenced_model: &ModelRef<'_>, reference_field_name:
 }
 
 fn migration_value_new(field: &FieldRef<'_>) -> Option<sql_schema_describer::DefaultValue> {
+    // Database-side defaults are rendered for all fields, ids included.
+    if let Some(dml::DefaultValue::Expression(expression)) = field.default_value() {
+        if let Some(database_expression) = expression.database_expression() {
+            return Some(sql_schema_describer::DefaultValue::DBGENERATED(database_expression));
+        }
+    }
+
     if field.is_id() {
         return None;
     }
@@ -352,12 +433,27 @@ fn enum_column_type(field: &FieldRef<'_>, database_info: &DatabaseInfo, db_name:
             sql::ColumnTypeFamily::Enum(format!("{}_{}", field.model().db_name(), field.db_name())),
             arity,
         ),
-        _ => column_type(field),
+        _ => column_type(field, database_info),
     }
 }
 
-fn column_type(field: &FieldRef<'_>) -> sql::ColumnType {
-    column_type_for_scalar_type(&scalar_type_for_field(field), column_arity(field.arity()))
+fn column_type(field: &FieldRef<'_>, database_info: &DatabaseInfo) -> sql::ColumnType {
+    let scalar_type = scalar_type_for_field(field);
+    let mut column_type = column_type_for_scalar_type(&scalar_type, column_arity(field.arity()));
+    column_type.native_type = field
+        .native_type()
+        .or_else(|| default_native_type(scalar_type, database_info.sql_family()))
+        .map(String::from);
+    column_type
+}
+
+/// Scalar types that share a column type family with another scalar type need a more specific database type.
+fn default_native_type(scalar_type: ScalarType, sql_family: SqlFamily) -> Option<&'static str> {
+    match (scalar_type, sql_family) {
+        (ScalarType::BigInt, SqlFamily::Postgres) => Some("int8"),
+        (ScalarType::BigInt, SqlFamily::Mysql) => Some("bigint"),
+        _ => None,
+    }
 }
 
 fn scalar_type_for_field(field: &FieldRef<'_>) -> ScalarType {
@@ -388,6 +484,35 @@ fn column_type_for_scalar_type(scalar_type: &ScalarType, column_arity: ColumnAri
         ScalarType::String => sql::ColumnType::pure(sql::ColumnTypeFamily::String, column_arity),
         ScalarType::DateTime => sql::ColumnType::pure(sql::ColumnTypeFamily::DateTime, column_arity),
         ScalarType::Json => sql::ColumnType::pure(sql::ColumnTypeFamily::Json, column_arity),
+        // Float columns are already exact decimals. BigInt columns get their database type from `default_native_type`.
+        ScalarType::Decimal => sql::ColumnType::pure(sql::ColumnTypeFamily::Float, column_arity),
+        ScalarType::BigInt => sql::ColumnType::pure(sql::ColumnTypeFamily::Int, column_arity),
+        ScalarType::Bytes => sql::ColumnType::pure(sql::ColumnTypeFamily::Binary, column_arity),
+    }
+}
+
+fn index_column_options(index_definition: &IndexDefinition) -> Vec<sql::IndexColumnOptions> {
+    index_definition
+        .field_options
+        .iter()
+        .map(|options| sql::IndexColumnOptions {
+            sort_order: match options.sort_order {
+                SortOrder::Asc => sql::SortOrder::Asc,
+                SortOrder::Desc => sql::SortOrder::Desc,
+            },
+            length: options.length,
+        })
+        .collect()
+}
+
+/// BTree is the default index method, so it is described as `None`.
+fn index_algorithm(algorithm: IndexAlgorithm) -> Option<sql::IndexAlgorithm> {
+    match algorithm {
+        IndexAlgorithm::BTree => None,
+        IndexAlgorithm::Hash => Some(sql::IndexAlgorithm::Hash),
+        IndexAlgorithm::Gin => Some(sql::IndexAlgorithm::Gin),
+        IndexAlgorithm::Gist => Some(sql::IndexAlgorithm::Gist),
+        IndexAlgorithm::Brin => Some(sql::IndexAlgorithm::Brin),
     }
 }
 
@@ -406,6 +531,9 @@ fn add_one_to_one_relation_unique_index(table: &mut sql::Table, column_names: &[
         name: format!("{}_{}", table.name, columns_suffix),
         columns: column_names.to_owned(),
         tpe: sql::IndexType::Unique,
+        column_options: vec![],
+        algorithm: None,
+        predicate: None,
     };
 
     table.indices.push(index);
@@ -8,6 +8,7 @@ pub(crate) use table::TableDiffer;
 
 use crate::*;
 use enums::EnumDiffer;
+use migration_connector::Rename;
 use sql_schema_describer::*;
 use tracing::debug;
 
@@ -15,14 +16,28 @@ use tracing::debug;
 #[cfg_attr(test, derive(Default))]
 pub(crate) struct DiffingOptions {
     is_mariadb: bool,
+    is_postgres: bool,
+    /// The proposed renames the user accepted. The other rename candidates are dropped and added.
+    accepted_renames: Vec<Rename>,
 }
 
 impl DiffingOptions {
     pub(crate) fn from_database_info(database_info: &DatabaseInfo) -> Self {
         DiffingOptions {
             is_mariadb: database_info.is_mariadb(),
+            is_postgres: database_info.sql_family() == SqlFamily::Postgres,
+            accepted_renames: Vec::new(),
         }
     }
+
+    pub(crate) fn with_accepted_renames(mut self, accepted_renames: &[Rename]) -> Self {
+        self.accepted_renames = accepted_renames.to_vec();
+        self
+    }
+
+    fn accepts_rename(&self, rename: &Rename) -> bool {
+        self.accepted_renames.contains(rename)
+    }
 }
 
 #[derive(Debug)]
@@ -37,21 +52,33 @@ pub struct SqlSchemaDiffer<'a> {
 pub struct SqlSchemaDiff {
     pub add_foreign_keys: Vec<AddForeignKey>,
     pub drop_tables: Vec<DropTable>,
+    pub rename_tables: Vec<RenameTable>,
     pub create_tables: Vec<CreateTable>,
     pub alter_tables: Vec<AlterTable>,
     pub create_indexes: Vec<CreateIndex>,
     pub drop_indexes: Vec<DropIndex>,
     pub alter_indexes: Vec<AlterIndex>,
+    pub rename_constraints: Vec<RenameConstraint>,
     pub create_enums: Vec<CreateEnum>,
     pub drop_enums: Vec<DropEnum>,
     pub alter_enums: Vec<AlterEnum>,
+    pub create_views: Vec<CreateView>,
+    pub drop_views: Vec<DropView>,
+    /// The rename candidates that were not accepted, and are dropped and added instead.
+    pub proposed_renames: Vec<Rename>,
 }
 
 impl SqlSchemaDiff {
     pub fn into_steps(self) -> Vec<SqlMigrationStep> {
-        wrap_as_step(self.create_enums, SqlMigrationStep::CreateEnum)
+        // Order matters: views depend on the tables they select from, so they are dropped before
+        // and created after all the table changes.
+        wrap_as_step(self.drop_views, SqlMigrationStep::DropView)
+            .chain(wrap_as_step(self.create_enums, SqlMigrationStep::CreateEnum))
             .chain(wrap_as_step(self.alter_enums, SqlMigrationStep::AlterEnum))
             .chain(wrap_as_step(self.drop_indexes, SqlMigrationStep::DropIndex))
+            // Order matters: the changes to renamed tables refer to them by their new name, and the
+            // indexes dropped above by their previous name.
+            .chain(wrap_as_step(self.rename_tables, SqlMigrationStep::RenameTable))
             // Order matters: we must create tables before `alter_table`s because we could
             // be adding foreign keys to the new tables there.
             .chain(wrap_as_step(self.create_tables, SqlMigrationStep::CreateTable))
@@ -67,6 +94,11 @@ impl SqlSchemaDiff {
             .chain(wrap_as_step(self.drop_tables, SqlMigrationStep::DropTable))
             .chain(wrap_as_step(self.drop_enums, SqlMigrationStep::DropEnum))
             .chain(wrap_as_step(self.alter_indexes, SqlMigrationStep::AlterIndex))
+            .chain(wrap_as_step(
+                self.rename_constraints,
+                SqlMigrationStep::RenameConstraint,
+            ))
+            .chain(wrap_as_step(self.create_views, SqlMigrationStep::CreateView))
             .collect()
     }
 }
@@ -96,14 +128,19 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         SqlSchemaDiff {
             add_foreign_keys: self.add_foreign_keys(),
             drop_tables,
+            rename_tables: self.rename_tables(),
             create_tables: self.create_tables(),
             alter_tables,
             create_indexes: self.create_indexes(),
             drop_indexes: self.drop_indexes(),
             alter_indexes,
+            rename_constraints: self.rename_constraints(),
             create_enums: self.create_enums(),
             drop_enums: self.drop_enums(),
             alter_enums: self.alter_enums(),
+            create_views: self.create_views(),
+            drop_views: self.drop_views(),
+            proposed_renames: self.proposed_renames(),
         }
     }
 
@@ -115,6 +152,38 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .collect()
     }
 
+    fn proposed_renames(&self) -> Vec<Rename> {
+        let table_renames = self
+            .table_rename_candidates()
+            .map(|(previous, next)| Rename::Table {
+                from: previous.name.clone(),
+                to: next.name.clone(),
+            })
+            .filter(|rename| !self.diffing_options.accepts_rename(rename));
+        let mut proposed_renames: Vec<Rename> = table_renames.collect();
+
+        for differ in self.table_pairs() {
+            proposed_renames.extend(
+                differ
+                    .column_rename_candidates()
+                    .map(|columns| differ.column_rename(&columns))
+                    .filter(|rename| !self.diffing_options.accepts_rename(rename)),
+            );
+        }
+
+        proposed_renames
+    }
+
+    fn rename_tables(&self) -> Vec<RenameTable> {
+        self.renamed_tables()
+            .map(|(previous, next)| RenameTable {
+                name: previous.name.clone(),
+                new_name: next.name.clone(),
+                schema: previous.schema.clone(),
+            })
+            .collect()
+    }
+
     // We drop the foreign keys of dropped tables first, so we can drop tables in whatever order we
     // please later.
     fn drop_tables(&self) -> (Vec<DropTable>, Vec<AlterTable>) {
@@ -127,6 +196,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         for dropped_table in self.dropped_tables() {
             let drop_table = DropTable {
                 name: dropped_table.name.clone(),
+                schema: dropped_table.schema.clone(),
             };
 
             dropped_tables.push(drop_table);
@@ -156,33 +226,40 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         push_foreign_keys_from_created_tables(&mut add_foreign_keys, self.created_tables());
         push_created_foreign_keys(&mut add_foreign_keys, self.table_pairs());
 
+        // MySQL cannot rename foreign keys, they are recreated with their new name instead.
+        if self.sql_family.is_mysql() {
+            for differ in self.table_pairs() {
+                add_foreign_keys.extend(differ.renamed_foreign_keys().map(|(_, next_fk)| AddForeignKey {
+                    table: differ.next.name.clone(),
+                    schema: differ.next.schema.clone(),
+                    foreign_key: next_fk.clone(),
+                }));
+            }
+        }
+
         add_foreign_keys
     }
 
     fn alter_tables(&self) -> Vec<AlterTable> {
         // TODO: this does not diff primary key columns yet
         let mut result = Vec::new();
-        for previous_table in &self.previous.tables {
-            if let Ok(next_table) = self.next.table(&previous_table.name) {
-                let differ = TableDiffer {
-                    diffing_options: &self.diffing_options,
-                    previous: &previous_table,
-                    next: &next_table,
+        for differ in self.table_pairs() {
+            let changes: Vec<TableChange> = Self::drop_foreign_keys(&differ)
+                .chain(self.drop_renamed_foreign_keys(&differ))
+                .chain(Self::drop_check_constraints(&differ))
+                .chain(Self::drop_columns(&differ))
+                .chain(Self::rename_columns(&differ))
+                .chain(Self::add_columns(&differ))
+                .chain(Self::alter_columns(&differ))
+                .chain(Self::add_check_constraints(&differ))
+                .collect();
+
+            if !changes.is_empty() {
+                let update = AlterTable {
+                    table: differ.next.clone(),
+                    changes,
                 };
-
-                let changes: Vec<TableChange> = Self::drop_foreign_keys(&differ)
-                    .chain(Self::drop_columns(&differ))
-                    .chain(Self::add_columns(&differ))
-                    .chain(Self::alter_columns(&differ))
-                    .collect();
-
-                if !changes.is_empty() {
-                    let update = AlterTable {
-                        table: next_table.clone(),
-                        changes,
-                    };
-                    result.push(update);
-                }
+                result.push(update);
             }
         }
 
@@ -199,6 +276,15 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         })
     }
 
+    fn rename_columns<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ.renamed_columns().map(|columns| {
+            TableChange::RenameColumn(RenameColumn {
+                name: columns.previous.name.clone(),
+                column: columns.next.clone(),
+            })
+        })
+    }
+
     fn add_columns<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
         differ.added_columns().map(move |column| {
             let change = AddColumn { column: column.clone() };
@@ -219,6 +305,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                 let change = AlterColumn {
                     name: column_differ.previous.name.clone(),
                     column: column_differ.next.clone(),
+                    is_backfilled: false,
                 };
 
                 return Some(TableChange::AlterColumn(change));
@@ -244,6 +331,39 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             })
     }
 
+    fn drop_renamed_foreign_keys<'a>(
+        &self,
+        differ: &'a TableDiffer<'schema>,
+    ) -> impl Iterator<Item = TableChange> + 'a {
+        let is_mysql = self.sql_family.is_mysql();
+
+        differ
+            .renamed_foreign_keys()
+            .filter(move |_| is_mysql)
+            .filter_map(|(previous_fk, _)| previous_fk.constraint_name.as_ref())
+            .map(|constraint_name| {
+                TableChange::DropForeignKey(DropForeignKey {
+                    constraint_name: constraint_name.clone(),
+                })
+            })
+    }
+
+    fn drop_check_constraints<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ.dropped_check_constraints().map(|check_constraint| {
+            TableChange::DropCheckConstraint(DropCheckConstraint {
+                name: check_constraint.name.clone(),
+            })
+        })
+    }
+
+    fn add_check_constraints<'a>(differ: &'a TableDiffer<'schema>) -> impl Iterator<Item = TableChange> + 'a {
+        differ.created_check_constraints().map(|check_constraint| {
+            TableChange::AddCheckConstraint(AddCheckConstraint {
+                check_constraint: check_constraint.clone(),
+            })
+        })
+    }
+
     fn create_indexes(&self) -> Vec<CreateIndex> {
         let mut steps = Vec::new();
 
@@ -251,6 +371,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             for index in &table.indices {
                 let create = CreateIndex {
                     table: table.name.clone(),
+                    schema: table.schema.clone(),
                     index: index.clone(),
                 };
 
@@ -262,6 +383,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             for index in tables.created_indexes() {
                 let create = CreateIndex {
                     table: tables.next.name.clone(),
+                    schema: tables.next.schema.clone(),
                     index: index.clone(),
                 };
 
@@ -284,6 +406,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                 }
                 drop_indexes.push(DropIndex {
                     table: tables.previous.name.clone(),
+                    schema: tables.previous.schema.clone(),
                     name: index.name.clone(),
                 })
             }
@@ -327,24 +450,121 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .collect()
     }
 
-    /// An iterator over the tables that are present in both schemas.
+    /// Views are (re)created when their definition is new or changed. Views without definition are
+    /// not managed and never created.
+    fn create_views(&self) -> Vec<CreateView> {
+        self.next
+            .views
+            .iter()
+            .filter(|next| next.definition.is_some())
+            .filter(|next| match self.previous.get_view(&next.name) {
+                Some(previous) => previous.definition != next.definition,
+                None => true,
+            })
+            .map(|view| CreateView { view: view.clone() })
+            .collect()
+    }
+
+    /// Views are dropped when they are replaced, or when a managed view is removed. Views without
+    /// definition are left alone.
+    fn drop_views(&self) -> Vec<DropView> {
+        self.previous
+            .views
+            .iter()
+            .filter(|previous| match self.next.get_view(&previous.name) {
+                Some(next) => next.definition.is_some() && next.definition != previous.definition,
+                None => previous.definition.is_some(),
+            })
+            .map(|view| DropView {
+                name: view.name.clone(),
+            })
+            .collect()
+    }
+
+    /// An iterator over the tables that are present in both schemas, including renamed tables.
     fn table_pairs<'a>(&'a self) -> impl Iterator<Item = TableDiffer<'schema>> + 'a
     where
         'schema: 'a,
     {
-        self.previous.tables.iter().filter_map(move |previous_table| {
-            self.next
-                .tables
-                .iter()
-                .find(move |next_table| tables_match(previous_table, next_table))
-                .map(move |next_table| TableDiffer {
-                    diffing_options: &self.diffing_options,
-                    previous: previous_table,
-                    next: next_table,
-                })
+        self.previous
+            .tables
+            .iter()
+            .filter_map(move |previous_table| {
+                self.next
+                    .tables
+                    .iter()
+                    .find(move |next_table| tables_match(previous_table, next_table))
+                    .map(move |next_table| (previous_table, next_table))
+            })
+            .chain(self.renamed_tables())
+            .map(move |(previous_table, next_table)| TableDiffer {
+                diffing_options: &self.diffing_options,
+                previous: previous_table,
+                next: next_table,
+            })
+    }
+
+    /// The rename candidates the user accepted.
+    fn renamed_tables<'a>(&'a self) -> impl Iterator<Item = (&'schema Table, &'schema Table)> + 'a
+    where
+        'schema: 'a,
+    {
+        self.table_rename_candidates().filter(move |(previous, next)| {
+            self.diffing_options.accepts_rename(&Rename::Table {
+                from: previous.name.clone(),
+                to: next.name.clone(),
+            })
+        })
+    }
+
+    /// Pairs of a dropped and a created table with the same columns. Like column renames, a pair is
+    /// only considered a rename when neither table has another candidate.
+    fn table_rename_candidates<'a>(&'a self) -> impl Iterator<Item = (&'schema Table, &'schema Table)> + 'a
+    where
+        'schema: 'a,
+    {
+        self.unmatched_previous_tables().filter_map(move |previous_table| {
+            let mut candidates = self
+                .unmatched_next_tables()
+                .filter(|next_table| self.is_table_rename_candidate(previous_table, next_table));
+
+            let next_table = candidates.next()?;
+
+            if candidates.next().is_some() {
+                return None;
+            }
+
+            let competing_candidates = self
+                .unmatched_previous_tables()
+                .filter(|other| self.is_table_rename_candidate(other, next_table))
+                .count();
+
+            if competing_candidates > 1 {
+                return None;
+            }
+
+            Some((previous_table, next_table))
         })
     }
 
+    fn is_table_rename_candidate(&self, previous: &Table, next: &Table) -> bool {
+        previous.schema == next.schema
+            && previous.columns.len() == next.columns.len()
+            && previous.columns.iter().all(|previous_column| {
+                next.column(&previous_column.name)
+                    .map(|next_column| {
+                        let differ = ColumnDiffer {
+                            diffing_options: self.diffing_options,
+                            previous: previous_column,
+                            next: next_column,
+                        };
+
+                        previous_column.auto_increment == next_column.auto_increment && !differ.differs_in_something()
+                    })
+                    .unwrap_or(false)
+            })
+    }
+
     fn alter_indexes<'a>(&'a self) -> Vec<AlterIndex> {
         let mut alter_indexes = Vec::new();
         self.table_pairs().for_each(|differ| {
@@ -353,6 +573,7 @@ impl<'schema> SqlSchemaDiffer<'schema> {
                     index_name: previous_index.name.clone(),
                     index_new_name: renamed_index.name.clone(),
                     table: differ.next.name.clone(),
+                    schema: differ.next.schema.clone(),
                 })
             })
         });
@@ -360,12 +581,58 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         alter_indexes
     }
 
-    fn created_tables<'a>(&'a self) -> impl Iterator<Item = &'a Table> + 'a {
-        self.next_tables()
-            .filter(move |next_table| !self.previous.has_table(&next_table.name))
+    /// Primary keys and foreign keys with a new explicit name. Only Postgres can rename
+    /// constraints in place.
+    fn rename_constraints(&self) -> Vec<RenameConstraint> {
+        let mut rename_constraints = Vec::new();
+
+        if !self.sql_family.is_postgres() {
+            return rename_constraints;
+        }
+
+        for differ in self.table_pairs() {
+            if let Some((name, new_name)) = differ.renamed_primary_key() {
+                rename_constraints.push(RenameConstraint {
+                    table: differ.next.name.clone(),
+                    schema: differ.next.schema.clone(),
+                    name: name.to_owned(),
+                    new_name: new_name.to_owned(),
+                });
+            }
+
+            for (previous_fk, next_fk) in differ.renamed_foreign_keys() {
+                if let (Some(name), Some(new_name)) = (&previous_fk.constraint_name, &next_fk.constraint_name) {
+                    rename_constraints.push(RenameConstraint {
+                        table: differ.next.name.clone(),
+                        schema: differ.next.schema.clone(),
+                        name: name.clone(),
+                        new_name: new_name.clone(),
+                    });
+                }
+            }
+        }
+
+        rename_constraints
     }
 
-    fn dropped_tables(&self) -> impl Iterator<Item = &Table> {
+    fn created_tables<'a>(&'a self) -> impl Iterator<Item = &'schema Table> + 'a {
+        self.unmatched_next_tables().filter(move |next_table| {
+            !self
+                .renamed_tables()
+                .any(|(_, renamed_table)| tables_match(renamed_table, next_table))
+        })
+    }
+
+    fn dropped_tables<'a>(&'a self) -> impl Iterator<Item = &'schema Table> + 'a {
+        self.unmatched_previous_tables().filter(move |previous_table| {
+            !self
+                .renamed_tables()
+                .any(|(renamed_table, _)| tables_match(previous_table, renamed_table))
+        })
+    }
+
+    /// The previous tables without a next table of the same name.
+    fn unmatched_previous_tables<'a>(&'a self) -> impl Iterator<Item = &'schema Table> + 'a {
         self.previous_tables().filter(move |previous_table| {
             !self
                 .next_tables()
@@ -373,18 +640,27 @@ impl<'schema> SqlSchemaDiffer<'schema> {
         })
     }
 
-    fn previous_tables(&self) -> impl Iterator<Item = &Table> {
+    /// The next tables without a previous table of the same name.
+    fn unmatched_next_tables<'a>(&'a self) -> impl Iterator<Item = &'schema Table> + 'a {
+        self.next_tables().filter(move |next_table| {
+            !self
+                .previous_tables()
+                .any(|previous_table| tables_match(previous_table, next_table))
+        })
+    }
+
+    fn previous_tables(&self) -> impl Iterator<Item = &'schema Table> {
         self.previous
             .tables
             .iter()
-            .filter(|table| table.name != MIGRATION_TABLE_NAME)
+            .filter(|table| table.name != MIGRATION_TABLE_NAME && table.name != CHANGE_EVENTS_TABLE_NAME)
     }
 
-    fn next_tables(&self) -> impl Iterator<Item = &Table> {
+    fn next_tables(&self) -> impl Iterator<Item = &'schema Table> {
         self.next
             .tables
             .iter()
-            .filter(|table| table.name != MIGRATION_TABLE_NAME)
+            .filter(|table| table.name != MIGRATION_TABLE_NAME && table.name != CHANGE_EVENTS_TABLE_NAME)
     }
 
     fn enum_pairs(&self) -> impl Iterator<Item = EnumDiffer<'_>> {
@@ -405,12 +681,14 @@ impl<'schema> SqlSchemaDiffer<'schema> {
             .filter(move |previous| !self.next_enums().any(|next| enums_match(previous, next)))
     }
 
+    /// The datamodel only declares enums in the schema of the connection. The enums of additional
+    /// schemas are described for the columns that use them, they are never created or dropped.
     fn previous_enums(&self) -> impl Iterator<Item = &Enum> {
-        self.previous.enums.iter()
+        self.previous.enums.iter().filter(|r#enum| r#enum.schema.is_none())
     }
 
     fn next_enums(&self) -> impl Iterator<Item = &Enum> {
-        self.next.enums.iter()
+        self.next.enums.iter().filter(|r#enum| r#enum.schema.is_none())
     }
 }
 
@@ -421,6 +699,7 @@ fn push_created_foreign_keys<'a, 'schema>(
     table_pairs.for_each(|differ| {
         added_foreign_keys.extend(differ.created_foreign_keys().map(|created_fk| AddForeignKey {
             table: differ.next.name.clone(),
+            schema: differ.next.schema.clone(),
             foreign_key: created_fk.clone(),
         }))
     })
@@ -433,6 +712,7 @@ fn push_foreign_keys_from_created_tables<'a>(
     for table in created_tables {
         steps.extend(table.foreign_keys.iter().map(|fk| AddForeignKey {
             table: table.name.clone(),
+            schema: table.schema.clone(),
             foreign_key: fk.clone(),
         }));
     }
@@ -452,13 +732,15 @@ fn foreign_key_changed(previous: Option<&ForeignKey>, next: Option<&ForeignKey>)
 /// should be considered equivalent for schema diffing purposes.
 fn foreign_keys_match(previous: &ForeignKey, next: &ForeignKey) -> bool {
     previous.referenced_table == next.referenced_table
+        && previous.referenced_schema == next.referenced_schema
         && previous.referenced_columns == next.referenced_columns
         && previous.columns == next.columns
         && previous.on_delete_action == next.on_delete_action
 }
 
+/// Tables with the same name in different schemas are different tables.
 fn tables_match(previous: &Table, next: &Table) -> bool {
-    previous.name == next.name
+    previous.name == next.name && previous.schema == next.schema
 }
 
 fn enums_match(previous: &Enum, next: &Enum) -> bool {
@@ -1,4 +1,5 @@
-use sql_schema_describer::{Column, ColumnTypeFamily, DefaultValue};
+use crate::normalize_database_expression;
+use sql_schema_describer::{Column, ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue};
 
 #[derive(Debug)]
 pub(crate) struct ColumnDiffer<'a> {
@@ -7,6 +8,54 @@ pub(crate) struct ColumnDiffer<'a> {
     pub(crate) next: &'a Column,
 }
 
+/// The type of a described column, with the arguments the database reports.
+fn described_type(tpe: &ColumnType) -> Option<String> {
+    if tpe.full_data_type.is_empty() {
+        return None;
+    }
+
+    // Postgres reports arrays with the `_` prefixed element type.
+    let full_data_type = match tpe.arity {
+        ColumnArity::List => tpe.full_data_type.trim_start_matches('_'),
+        _ => tpe.full_data_type.as_str(),
+    };
+
+    if full_data_type.contains('(') {
+        return Some(full_data_type.to_owned());
+    }
+
+    let args: Vec<String> = tpe
+        .character_maximum_length
+        .into_iter()
+        .chain(tpe.numeric_precision)
+        .chain(tpe.numeric_scale)
+        .chain(tpe.datetime_precision)
+        .map(|arg| arg.to_string())
+        .collect();
+
+    match args.as_slice() {
+        [] => Some(full_data_type.to_owned()),
+        args => Some(format!("{}({})", full_data_type, args.join(","))),
+    }
+}
+
+/// Splits e.g. `decimal(10, 2)` into `decimal` and `10,2`.
+fn split_type(tpe: &str) -> (String, Option<String>) {
+    match tpe.find('(') {
+        Some(start) => {
+            let args = tpe[start + 1..].trim_end_matches(')').split_whitespace().collect();
+            (tpe[..start].trim().to_lowercase(), Some(args))
+        }
+        None => (tpe.trim().to_lowercase(), None),
+    }
+}
+
+/// On MySQL, integer types take a display width that does not change the type.
+const INTEGER_TYPES: &[&str] = &["tinyint", "smallint", "mediumint", "int", "integer", "bigint"];
+
+/// The datetime types taking a fractional seconds precision.
+const DATETIME_TYPES: &[&str] = &["datetime", "timestamp", "timestamptz", "time", "timetz"];
+
 /// On MariaDB, JSON is an alias for LONGTEXT. https://mariadb.com/kb/en/json-data-type/
 const MARIADB_ALIASES: &[ColumnTypeFamily] = &[ColumnTypeFamily::String, ColumnTypeFamily::Json];
 
@@ -59,7 +108,48 @@ impl<'a> ColumnDiffer<'a> {
             return false;
         }
 
-        self.previous.tpe.family != self.next.tpe.family
+        self.previous.tpe.family != self.next.tpe.family || self.native_type_changed()
+    }
+
+    /// Only relevant when the next column declares a native type. The arguments are compared exactly, once the
+    /// defaults the database applies to omitted arguments are filled in.
+    fn native_type_changed(&self) -> bool {
+        let next = match &self.next.tpe.native_type {
+            Some(native_type) => native_type,
+            None => return false,
+        };
+
+        let previous = match &self.previous.tpe.native_type {
+            Some(native_type) => native_type.clone(),
+            None => match described_type(&self.previous.tpe) {
+                Some(described_type) => described_type,
+                None => return true,
+            },
+        };
+
+        let (previous_name, previous_args) = split_type(&previous);
+        let (next_name, next_args) = split_type(next);
+
+        previous_name != next_name
+            || self.type_arguments(&previous_name, previous_args) != self.type_arguments(&next_name, next_args)
+    }
+
+    /// The arguments of a type, with the defaults the database applies when they are omitted.
+    fn type_arguments(&self, name: &str, args: Option<String>) -> Option<String> {
+        let is_datetime = DATETIME_TYPES.contains(&name);
+        let is_postgres = self.diffing_options.is_postgres;
+
+        match (name, args) {
+            (name, _) if INTEGER_TYPES.contains(&name) => None,
+            // MySQL decimals default to `decimal(10,0)`. Postgres numerics without arguments are unconstrained.
+            ("decimal", None) => Some("10,0".to_owned()),
+            ("decimal", Some(args)) | ("numeric", Some(args)) if !args.contains(',') => Some(format!("{},0", args)),
+            ("char", None) | ("bpchar", None) => Some("1".to_owned()),
+            // Postgres defaults to microseconds, MySQL to seconds.
+            (_, None) if is_datetime && is_postgres => Some("6".to_owned()),
+            (_, Some(args)) if is_datetime && !is_postgres && args == "0" => None,
+            (_, args) => args,
+        }
     }
 
     /// There are workarounds to cope with current migration and introspection limitations.
@@ -76,7 +166,7 @@ impl<'a> ColumnDiffer<'a> {
 
         match (&self.previous.default, &self.next.default) {
             (Some(DefaultValue::VALUE(prev)), Some(DefaultValue::VALUE(next))) => prev == next,
-            (Some(DefaultValue::VALUE(_)), Some(DefaultValue::DBGENERATED(_))) => true,
+            (Some(DefaultValue::VALUE(_)), Some(DefaultValue::DBGENERATED(_))) => false,
             (Some(DefaultValue::VALUE(_)), Some(DefaultValue::SEQUENCE(_))) => true,
             (Some(DefaultValue::VALUE(_)), Some(DefaultValue::NOW)) => false,
             (Some(DefaultValue::VALUE(_)), None) => false,
@@ -86,6 +176,10 @@ impl<'a> ColumnDiffer<'a> {
             (Some(DefaultValue::NOW), None) => false,
             (Some(DefaultValue::NOW), Some(DefaultValue::VALUE(_))) => false,
 
+            // The database normalizes the expressions it stores, so they are compared normalized.
+            (Some(DefaultValue::DBGENERATED(prev)), Some(DefaultValue::DBGENERATED(next))) => {
+                normalize_database_expression(prev) == normalize_database_expression(next)
+            }
             (Some(DefaultValue::DBGENERATED(_)), Some(DefaultValue::SEQUENCE(_))) => true,
             (Some(DefaultValue::DBGENERATED(_)), Some(DefaultValue::VALUE(_))) => false,
             (Some(DefaultValue::DBGENERATED(_)), Some(DefaultValue::NOW)) => false,
@@ -101,8 +195,7 @@ impl<'a> ColumnDiffer<'a> {
             (None, Some(DefaultValue::VALUE(_))) => false,
             (None, Some(DefaultValue::NOW)) => false,
 
-            // We can never migrate to @dbgenerated
-            (_, Some(DefaultValue::DBGENERATED(_))) => true,
+            (_, Some(DefaultValue::DBGENERATED(_))) => false,
         }
     }
 }
@@ -137,6 +230,7 @@ impl ColumnChanges {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::sql_schema_differ::DiffingOptions;
     use prisma_value::PrismaValue;
     use sql_schema_describer::{ColumnArity, ColumnType, ColumnTypeFamily, DefaultValue};
 
@@ -249,4 +343,121 @@ mod tests {
         }
         .defaults_match());
     }
+
+    #[test]
+    fn native_types_are_compared_with_described_types() {
+        let described = |full_data_type: &str, character_maximum_length: Option<i64>| Column {
+            name: "A".to_owned(),
+            tpe: ColumnType {
+                data_type: "character varying".to_owned(),
+                full_data_type: full_data_type.to_owned(),
+                character_maximum_length,
+                numeric_precision: None,
+                numeric_scale: None,
+                datetime_precision: None,
+                native_type: None,
+                family: ColumnTypeFamily::String,
+                arity: ColumnArity::Required,
+            },
+            default: None,
+            auto_increment: false,
+        };
+
+        let calculated = |native_type: &str| Column {
+            name: "A".to_owned(),
+            tpe: ColumnType {
+                native_type: Some(native_type.to_owned()),
+                ..ColumnType::pure(ColumnTypeFamily::String, ColumnArity::Required)
+            },
+            default: None,
+            auto_increment: false,
+        };
+
+        let type_changed = |previous: &Column, next: &Column| {
+            ColumnDiffer {
+                diffing_options: &Default::default(),
+                previous,
+                next,
+            }
+            .column_type_changed()
+        };
+
+        assert!(!type_changed(
+            &described("varchar", Some(255)),
+            &calculated("varchar(255)")
+        ));
+        assert!(!type_changed(
+            &described("varchar(255)", Some(255)),
+            &calculated("VARCHAR(255)")
+        ));
+        assert!(type_changed(&described("numeric", None), &calculated("numeric(10,2)")));
+        assert!(type_changed(
+            &described("varchar", Some(255)),
+            &calculated("varchar(191)")
+        ));
+        assert!(type_changed(&described("text", None), &calculated("varchar(191)")));
+        assert!(!type_changed(
+            &calculated("varchar(191)"),
+            &described("varchar", Some(255))
+        ));
+        assert!(!type_changed(&described("int(11)", None), &calculated("int")));
+        assert!(!type_changed(&described("decimal(10,0)", None), &calculated("decimal")));
+        assert!(type_changed(
+            &described("decimal(10,0)", None),
+            &calculated("decimal(10,2)")
+        ));
+        assert!(!type_changed(&described("datetime", None), &calculated("datetime(0)")));
+        assert!(type_changed(&described("datetime(3)", None), &calculated("datetime")));
+    }
+
+    #[test]
+    fn postgres_native_types_are_compared_with_described_precisions() {
+        let described = |full_data_type: &str, numeric_precision, numeric_scale, datetime_precision| Column {
+            name: "A".to_owned(),
+            tpe: ColumnType {
+                full_data_type: full_data_type.to_owned(),
+                numeric_precision,
+                numeric_scale,
+                datetime_precision,
+                ..ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required)
+            },
+            default: None,
+            auto_increment: false,
+        };
+
+        let calculated = |native_type: &str| Column {
+            name: "A".to_owned(),
+            tpe: ColumnType {
+                native_type: Some(native_type.to_owned()),
+                ..ColumnType::pure(ColumnTypeFamily::Float, ColumnArity::Required)
+            },
+            default: None,
+            auto_increment: false,
+        };
+
+        let type_changed = |previous: &Column, next: &Column| {
+            ColumnDiffer {
+                diffing_options: &DiffingOptions {
+                    is_postgres: true,
+                    ..Default::default()
+                },
+                previous,
+                next,
+            }
+            .column_type_changed()
+        };
+
+        let numeric = |precision, scale| described("numeric", precision, scale, None);
+        let timestamp = |precision| described("timestamp", None, None, precision);
+
+        assert!(!type_changed(&numeric(None, None), &calculated("numeric")));
+        assert!(!type_changed(&numeric(Some(10), Some(2)), &calculated("numeric(10,2)")));
+        assert!(!type_changed(&numeric(Some(10), Some(0)), &calculated("numeric(10)")));
+        assert!(type_changed(&numeric(None, None), &calculated("numeric(10,2)")));
+        assert!(type_changed(&numeric(Some(10), Some(2)), &calculated("numeric")));
+        assert!(type_changed(&numeric(Some(10), Some(2)), &calculated("numeric(12,2)")));
+        assert!(!type_changed(&timestamp(Some(6)), &calculated("timestamp")));
+        assert!(!type_changed(&timestamp(Some(3)), &calculated("timestamp(3)")));
+        assert!(type_changed(&timestamp(Some(3)), &calculated("timestamp")));
+    }
 }
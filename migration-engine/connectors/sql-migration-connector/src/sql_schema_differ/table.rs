@@ -1,5 +1,7 @@
-use super::column::ColumnDiffer;
-use sql_schema_describer::{Column, ForeignKey, Index, Table};
+use super::column::{ColumnChange, ColumnDiffer};
+use crate::normalize_database_expression;
+use migration_connector::Rename;
+use sql_schema_describer::{CheckConstraint, Column, ForeignKey, Index, Table};
 
 pub(crate) struct TableDiffer<'a> {
     pub(crate) diffing_options: &'a super::DiffingOptions,
@@ -23,6 +25,89 @@ impl<'schema> TableDiffer<'schema> {
     }
 
     pub(crate) fn dropped_columns<'a>(&'a self) -> impl Iterator<Item = &'schema Column> + 'a {
+        self.unmatched_previous_columns().filter(move |previous_column| {
+            !self
+                .renamed_columns()
+                .any(|renamed| renamed.previous.name == previous_column.name)
+        })
+    }
+
+    pub(crate) fn added_columns<'a>(&'a self) -> impl Iterator<Item = &'schema Column> + 'a {
+        self.unmatched_next_columns().filter(move |next_column| {
+            !self
+                .renamed_columns()
+                .any(|renamed| renamed.next.name == next_column.name)
+        })
+    }
+
+    /// The rename candidates the user accepted.
+    pub(crate) fn renamed_columns<'a>(&'a self) -> impl Iterator<Item = ColumnDiffer<'schema>> + 'a {
+        self.column_rename_candidates()
+            .filter(move |columns| self.diffing_options.accepts_rename(&self.column_rename(columns)))
+    }
+
+    pub(crate) fn column_rename(&self, columns: &ColumnDiffer<'_>) -> Rename {
+        Rename::Column {
+            table: self.next.name.clone(),
+            from: columns.previous.name.clone(),
+            to: columns.next.name.clone(),
+        }
+    }
+
+    /// Pairs of a dropped and an added column that only differ by name. A pair is only considered a
+    /// rename when neither column has another candidate, and when neither is part of a foreign key,
+    /// since these follow the relations of the datamodel.
+    pub(crate) fn column_rename_candidates<'a>(&'a self) -> impl Iterator<Item = ColumnDiffer<'schema>> + 'a {
+        self.unmatched_previous_columns().filter_map(move |previous_column| {
+            let mut candidates = self
+                .unmatched_next_columns()
+                .filter(|next_column| self.is_rename_candidate(previous_column, next_column));
+
+            let next_column = candidates.next()?;
+
+            if candidates.next().is_some() {
+                return None;
+            }
+
+            let competing_candidates = self
+                .unmatched_previous_columns()
+                .filter(|other| self.is_rename_candidate(other, next_column))
+                .count();
+
+            if competing_candidates > 1 {
+                return None;
+            }
+
+            Some(ColumnDiffer {
+                diffing_options: self.diffing_options,
+                previous: previous_column,
+                next: next_column,
+            })
+        })
+    }
+
+    fn is_rename_candidate(&self, previous_column: &Column, next_column: &Column) -> bool {
+        if self.previous.is_part_of_foreign_key(&previous_column.name)
+            || self.next.is_part_of_foreign_key(&next_column.name)
+            || previous_column.auto_increment != next_column.auto_increment
+        {
+            return false;
+        }
+
+        let differ = ColumnDiffer {
+            diffing_options: self.diffing_options,
+            previous: previous_column,
+            next: next_column,
+        };
+
+        differ
+            .all_changes()
+            .iter()
+            .all(|change| change == ColumnChange::Renaming)
+    }
+
+    /// The previous columns without a next column of the same name.
+    fn unmatched_previous_columns<'a>(&'a self) -> impl Iterator<Item = &'schema Column> + 'a {
         self.previous_columns().filter(move |previous_column| {
             self.next_columns()
                 .find(|next_column| columns_match(previous_column, next_column))
@@ -30,7 +115,8 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
-    pub(crate) fn added_columns<'a>(&'a self) -> impl Iterator<Item = &'schema Column> + 'a {
+    /// The next columns without a previous column of the same name.
+    fn unmatched_next_columns<'a>(&'a self) -> impl Iterator<Item = &'schema Column> + 'a {
         self.next_columns().filter(move |next_column| {
             self.previous_columns()
                 .find(|previous_column| columns_match(previous_column, next_column))
@@ -54,6 +140,31 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    /// Foreign keys present on both sides whose explicit constraint name changed. Foreign keys
+    /// without a name in the next schema keep whatever name they have in the database.
+    pub(crate) fn renamed_foreign_keys(&self) -> impl Iterator<Item = (&ForeignKey, &ForeignKey)> {
+        self.previous_foreign_keys().filter_map(move |previous_fk| {
+            self.next_foreign_keys()
+                .find(|next_fk| {
+                    super::foreign_keys_match(previous_fk, next_fk)
+                        && constraint_renamed(previous_fk.constraint_name.as_ref(), next_fk.constraint_name.as_ref())
+                })
+                .map(|next_fk| (previous_fk, next_fk))
+        })
+    }
+
+    /// The previous and next names of the primary key constraint, if it was explicitly renamed.
+    pub(crate) fn renamed_primary_key(&self) -> Option<(&'schema str, &'schema str)> {
+        let previous = self.previous.primary_key.as_ref()?.constraint_name.as_ref()?;
+        let next = self.next.primary_key.as_ref()?.constraint_name.as_ref()?;
+
+        if previous != next {
+            Some((previous, next))
+        } else {
+            None
+        }
+    }
+
     pub(crate) fn created_indexes<'a>(&'a self) -> impl Iterator<Item = &'schema Index> + 'a {
         self.next_indexes().filter(move |next_index| {
             !self
@@ -78,6 +189,22 @@ impl<'schema> TableDiffer<'schema> {
         })
     }
 
+    pub(crate) fn created_check_constraints<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.next_check_constraints().filter(move |next| {
+            !self
+                .previous_check_constraints()
+                .any(|previous| check_constraints_match(previous, next))
+        })
+    }
+
+    pub(crate) fn dropped_check_constraints<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.previous_check_constraints().filter(move |previous| {
+            !self
+                .next_check_constraints()
+                .any(|next| check_constraints_match(previous, next))
+        })
+    }
+
     fn previous_columns(&self) -> impl Iterator<Item = &'schema Column> {
         self.previous.columns.iter()
     }
@@ -101,13 +228,42 @@ impl<'schema> TableDiffer<'schema> {
     fn next_indexes<'a>(&'a self) -> impl Iterator<Item = &'schema Index> + 'a {
         self.next.indices.iter()
     }
+
+    fn previous_check_constraints<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.previous.check_constraints.iter()
+    }
+
+    fn next_check_constraints<'a>(&'a self) -> impl Iterator<Item = &'schema CheckConstraint> + 'a {
+        self.next.check_constraints.iter()
+    }
 }
 
 fn columns_match(a: &Column, b: &Column) -> bool {
     a.name == b.name
 }
 
+/// Databases normalize the expressions they store, so the expressions are compared normalized.
+fn check_constraints_match(previous: &CheckConstraint, next: &CheckConstraint) -> bool {
+    previous.name == next.name
+        && normalize_database_expression(&previous.expression) == normalize_database_expression(&next.expression)
+}
+
+fn constraint_renamed(previous: Option<&String>, next: Option<&String>) -> bool {
+    match (previous, next) {
+        (Some(previous), Some(next)) => previous != next,
+        _ => false,
+    }
+}
+
 /// Compare two SQL indexes and return whether they only differ by name.
+///
+/// Like check constraint expressions, predicates are normalized by the database, so they are
+/// compared normalized.
 fn indexes_match(first: &Index, second: &Index) -> bool {
-    first.columns == second.columns && first.tpe == second.tpe
+    first.columns == second.columns
+        && first.tpe == second.tpe
+        && first.column_options == second.column_options
+        && first.algorithm == second.algorithm
+        && first.predicate.as_deref().map(normalize_database_expression)
+            == second.predicate.as_deref().map(normalize_database_expression)
 }
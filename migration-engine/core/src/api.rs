@@ -6,6 +6,7 @@ pub use rpc::*;
 
 use crate::{commands::*, migration_engine::MigrationEngine, CoreResult};
 use migration_connector::*;
+use std::time::Duration;
 use tracing_futures::Instrument;
 
 pub struct MigrationApi<C, D>
@@ -27,6 +28,13 @@ where
         Ok(Self { engine })
     }
 
+    /// See [MigrationEngine::uninitialized](../migration_engine/struct.MigrationEngine.html#method.uninitialized).
+    pub fn uninitialized(connector: C) -> Self {
+        MigrationApi {
+            engine: MigrationEngine::uninitialized(connector),
+        }
+    }
+
     pub async fn handle_command<'a, E>(&'a self, input: &'a E::Input) -> CoreResult<E::Output>
     where
         E: MigrationCommand,
@@ -34,6 +42,22 @@ where
         Ok(E::execute(input, &self.engine).await?)
     }
 
+    /// Like `handle_command`, for the commands changing the database. See
+    /// [MigrationEngine::with_migration_lock](../migration_engine/struct.MigrationEngine.html#method.with_migration_lock).
+    pub async fn handle_command_with_migration_lock<'a, E>(&'a self, input: &'a E::Input) -> CoreResult<E::Output>
+    where
+        E: MigrationCommand,
+    {
+        Ok(self.engine.with_migration_lock(E::execute(input, &self.engine)).await?)
+    }
+
+    /// See [MigrationEngine::with_migration_lock_timeout](../migration_engine/struct.MigrationEngine.html#method.with_migration_lock_timeout).
+    pub fn with_migration_lock_timeout(self, timeout: Duration) -> Self {
+        MigrationApi {
+            engine: self.engine.with_migration_lock_timeout(timeout),
+        }
+    }
+
     pub fn connector(&self) -> &C {
         self.engine.connector()
     }
@@ -44,15 +68,22 @@ where
 #[async_trait::async_trait]
 pub trait GenericApi: Send + Sync + 'static {
     async fn apply_migration(&self, input: &ApplyMigrationInput) -> CoreResult<MigrationStepsResultOutput>;
+    async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput>;
+    async fn baseline(&self, input: &BaselineInput) -> CoreResult<BaselineOutput>;
     async fn calculate_database_steps(
         &self,
         input: &CalculateDatabaseStepsInput,
     ) -> CoreResult<MigrationStepsResultOutput>;
     async fn calculate_datamodel(&self, input: &CalculateDatamodelInput) -> CoreResult<CalculateDatamodelOutput>;
+    async fn create_migration(&self, input: &CreateMigrationInput) -> CoreResult<CreateMigrationOutput>;
+    async fn diagnose_drift(&self, input: &serde_json::Value) -> CoreResult<DiagnoseDriftOutput>;
+    async fn enable_change_events(&self, input: &EnableChangeEventsInput) -> CoreResult<EnableChangeEventsOutput>;
     async fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> CoreResult<MigrationStepsResultOutput>;
     async fn list_migrations(&self, input: &serde_json::Value) -> CoreResult<Vec<ListMigrationsOutput>>;
     async fn migration_progress(&self, input: &MigrationProgressInput) -> CoreResult<MigrationProgressOutput>;
     async fn reset(&self, input: &serde_json::Value) -> CoreResult<serde_json::Value>;
+    async fn rollback_migrations(&self, input: &RollbackMigrationsInput) -> CoreResult<RollbackMigrationsOutput>;
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput>;
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput>;
     fn migration_persistence<'a>(&'a self) -> Box<dyn MigrationPersistence + 'a>;
     fn connector_type(&self) -> &'static str;
@@ -73,10 +104,32 @@ where
     D: DatabaseMigrationMarker + Send + Sync + 'static,
 {
     async fn apply_migration(&self, input: &ApplyMigrationInput) -> CoreResult<MigrationStepsResultOutput> {
-        self.handle_command::<ApplyMigrationCommand>(input)
+        let span = tracing::info_span!("ApplyMigration", migration_id = input.migration_id.as_str());
+
+        // Dry runs do not change the database, so they do not wait for other migration engines.
+        if input.dry_run {
+            return self
+                .handle_command::<ApplyMigrationCommand>(input)
+                .instrument(span)
+                .await;
+        }
+
+        self.handle_command_with_migration_lock::<ApplyMigrationCommand>(input)
+            .instrument(span)
+            .await
+    }
+
+    async fn apply_migrations(&self, input: &ApplyMigrationsInput) -> CoreResult<ApplyMigrationsOutput> {
+        self.handle_command_with_migration_lock::<ApplyMigrationsCommand>(input)
+            .instrument(tracing::info_span!("ApplyMigrations"))
+            .await
+    }
+
+    async fn baseline(&self, input: &BaselineInput) -> CoreResult<BaselineOutput> {
+        self.handle_command_with_migration_lock::<BaselineCommand>(input)
             .instrument(tracing::info_span!(
-                "ApplyMigration",
-                migration_id = input.migration_id.as_str()
+                "Baseline",
+                migration_name = input.migration_name.as_str()
             ))
             .await
     }
@@ -96,6 +149,27 @@ where
             .await
     }
 
+    async fn create_migration(&self, input: &CreateMigrationInput) -> CoreResult<CreateMigrationOutput> {
+        self.handle_command::<CreateMigrationCommand>(input)
+            .instrument(tracing::info_span!(
+                "CreateMigration",
+                migration_name = input.migration_name.as_str()
+            ))
+            .await
+    }
+
+    async fn diagnose_drift(&self, input: &serde_json::Value) -> CoreResult<DiagnoseDriftOutput> {
+        self.handle_command::<DiagnoseDriftCommand>(input)
+            .instrument(tracing::info_span!("DiagnoseDrift"))
+            .await
+    }
+
+    async fn enable_change_events(&self, input: &EnableChangeEventsInput) -> CoreResult<EnableChangeEventsOutput> {
+        self.handle_command::<EnableChangeEventsCommand>(input)
+            .instrument(tracing::info_span!("EnableChangeEvents"))
+            .await
+    }
+
     async fn infer_migration_steps(&self, input: &InferMigrationStepsInput) -> CoreResult<MigrationStepsResultOutput> {
         self.handle_command::<InferMigrationStepsCommand>(input)
             .instrument(tracing::info_span!(
@@ -121,13 +195,31 @@ where
     }
 
     async fn reset(&self, input: &serde_json::Value) -> CoreResult<serde_json::Value> {
-        self.handle_command::<ResetCommand>(input)
+        self.handle_command_with_migration_lock::<ResetCommand>(input)
             .instrument(tracing::info_span!("Reset"))
             .await
     }
 
+    async fn rollback_migrations(&self, input: &RollbackMigrationsInput) -> CoreResult<RollbackMigrationsOutput> {
+        self.handle_command_with_migration_lock::<RollbackMigrationsCommand>(input)
+            .instrument(tracing::info_span!(
+                "RollbackMigrations",
+                target_migration_name = input.target_migration_name.as_str()
+            ))
+            .await
+    }
+
+    async fn squash_migrations(&self, input: &SquashMigrationsInput) -> CoreResult<SquashMigrationsOutput> {
+        self.handle_command_with_migration_lock::<SquashMigrationsCommand>(input)
+            .instrument(tracing::info_span!(
+                "SquashMigrations",
+                squashed_migration_id = input.squashed_migration_id.as_str()
+            ))
+            .await
+    }
+
     async fn unapply_migration(&self, input: &UnapplyMigrationInput) -> CoreResult<UnapplyMigrationOutput> {
-        self.handle_command::<UnapplyMigrationCommand>(input)
+        self.handle_command_with_migration_lock::<UnapplyMigrationCommand>(input)
             .instrument(tracing::info_span!("UnapplyMigration"))
             .await
     }
@@ -21,6 +21,13 @@ enum RpcCommand {
     Reset,
     CalculateDatamodel,
     CalculateDatabaseSteps,
+    EnableChangeEvents,
+    CreateMigration,
+    ApplyMigrations,
+    DiagnoseDrift,
+    RollbackMigrations,
+    Baseline,
+    SquashMigrations,
 }
 
 impl RpcCommand {
@@ -34,6 +41,13 @@ impl RpcCommand {
             RpcCommand::Reset => "reset",
             RpcCommand::CalculateDatamodel => "calculateDatamodel",
             RpcCommand::CalculateDatabaseSteps => "calculateDatabaseSteps",
+            RpcCommand::EnableChangeEvents => "enableChangeEvents",
+            RpcCommand::CreateMigration => "createMigration",
+            RpcCommand::ApplyMigrations => "applyMigrations",
+            RpcCommand::DiagnoseDrift => "diagnoseDrift",
+            RpcCommand::RollbackMigrations => "rollbackMigrations",
+            RpcCommand::Baseline => "baseline",
+            RpcCommand::SquashMigrations => "squashMigrations",
         }
     }
 }
@@ -47,6 +61,13 @@ static AVAILABLE_COMMANDS: &[RpcCommand] = &[
     RpcCommand::Reset,
     RpcCommand::CalculateDatamodel,
     RpcCommand::CalculateDatabaseSteps,
+    RpcCommand::EnableChangeEvents,
+    RpcCommand::CreateMigration,
+    RpcCommand::ApplyMigrations,
+    RpcCommand::DiagnoseDrift,
+    RpcCommand::RollbackMigrations,
+    RpcCommand::Baseline,
+    RpcCommand::SquashMigrations,
 ];
 
 impl RpcApi {
@@ -146,6 +167,31 @@ impl RpcApi {
                 let input: CalculateDatabaseStepsInput = params.clone().parse()?;
                 render(executor.calculate_database_steps(&input).await?)
             }
+            RpcCommand::EnableChangeEvents => {
+                let input: EnableChangeEventsInput = params.clone().parse()?;
+                render(executor.enable_change_events(&input).await?)
+            }
+            RpcCommand::CreateMigration => {
+                let input: CreateMigrationInput = params.clone().parse()?;
+                render(executor.create_migration(&input).await?)
+            }
+            RpcCommand::ApplyMigrations => {
+                let input: ApplyMigrationsInput = params.clone().parse()?;
+                render(executor.apply_migrations(&input).await?)
+            }
+            RpcCommand::DiagnoseDrift => render(executor.diagnose_drift(&serde_json::Value::Null).await?),
+            RpcCommand::RollbackMigrations => {
+                let input: RollbackMigrationsInput = params.clone().parse()?;
+                render(executor.rollback_migrations(&input).await?)
+            }
+            RpcCommand::Baseline => {
+                let input: BaselineInput = params.clone().parse()?;
+                render(executor.baseline(&input).await?)
+            }
+            RpcCommand::SquashMigrations => {
+                let input: SquashMigrationsInput = params.clone().parse()?;
+                render(executor.squash_migrations(&input).await?)
+            }
         }
     }
 }
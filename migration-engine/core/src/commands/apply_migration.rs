@@ -122,7 +122,13 @@ impl<'a> ApplyMigrationCommand<'a> {
 
         let database_migration = connector
             .database_migration_inferrer()
-            .infer(&current_datamodel, &next_datamodel, &self.input.steps)
+            .infer_with_accepted_renames(
+                &current_datamodel,
+                &next_datamodel,
+                &self.input.steps,
+                &self.input.data_migration_steps,
+                &self.input.accepted_renames,
+            )
             .await?;
 
         let database_steps_json_pretty = connector
@@ -144,7 +150,19 @@ impl<'a> ApplyMigrationCommand<'a> {
             .check(&database_migration)
             .await?;
 
+        let script = if self.input.dry_run {
+            Some(
+                connector
+                    .database_migration_step_applier()
+                    .render_dry_run_script(&database_migration)?,
+            )
+        } else {
+            None
+        };
+
         match (diagnostics.has_warnings(), self.input.force.unwrap_or(false)) {
+            // Dry runs only render the migration.
+            _ if self.input.dry_run => tracing::info!("Dry run, the migration will not be applied."),
             // We have no warnings, or the force flag is passed.
             (false, _) | (true, true) => {
                 tracing::debug!("Applying the migration");
@@ -175,6 +193,8 @@ impl<'a> ApplyMigrationCommand<'a> {
             warnings,
             general_errors: Vec::new(),
             unexecutable_migrations,
+            script,
+            proposed_renames: database_migration.proposed_renames().to_vec(),
         })
     }
 }
@@ -185,6 +205,15 @@ pub struct ApplyMigrationInput {
     pub migration_id: String,
     pub steps: Vec<MigrationStep>,
     pub force: Option<bool>,
+    /// User-authored changes to the data, added to the inferred database migration.
+    #[serde(default)]
+    pub data_migration_steps: Vec<DataMigrationStep>,
+    /// The proposed renames to make, instead of dropping and adding the tables or columns.
+    #[serde(default)]
+    pub accepted_renames: Vec<Rename>,
+    /// Return the SQL script of the migration instead of applying it.
+    #[serde(default)]
+    pub dry_run: bool,
 }
 
 impl IsWatchMigration for ApplyMigrationInput {
@@ -0,0 +1,125 @@
+//! The ApplyMigrations RPC method.
+//!
+//! Applies the migrations of the migrations directory that have not been applied yet, in order. The
+//! scripts are run as they are on disk, including hand edits. The checksum of each applied script is
+//! recorded in the migrations table, to report scripts that were edited after they were applied.
+
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub struct ApplyMigrationsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for ApplyMigrationsCommand {
+    type Input = ApplyMigrationsInput;
+    type Output = ApplyMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+        let step_applier = connector.database_migration_step_applier();
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+
+        check_migration_lock_provider(migrations_directory_path, connector.connector_type())?;
+
+        let migration_directories =
+            list_migrations(migrations_directory_path).map_err(|err| CommandError::Generic(err.into()))?;
+        let applied_migrations = migration_persistence.load_all().await?;
+
+        let mut applied_migration_names = Vec::new();
+        let mut edited_migration_names = Vec::new();
+
+        for directory in migration_directories {
+            let script = directory
+                .read_migration_script()
+                .map_err(|err| CommandError::Generic(err.into()))?;
+            let checksum = script_checksum(&script);
+
+            let applied_migration = applied_migrations
+                .iter()
+                .find(|migration| migration.name == directory.migration_name() && migration.status.is_success());
+
+            if let Some(applied_migration) = applied_migration {
+                if applied_migration.checksum.as_ref() != Some(&checksum) {
+                    tracing::warn!(
+                        "The migration `{}` was modified after it was applied.",
+                        directory.migration_name()
+                    );
+
+                    edited_migration_names.push(directory.migration_name().to_owned());
+                }
+
+                continue;
+            }
+
+            tracing::info!("Applying migration `{}`", directory.migration_name());
+
+            let mut migration = Migration::new(directory.migration_name().to_owned());
+            migration.status = MigrationStatus::MigrationInProgress;
+            migration.checksum = Some(checksum);
+
+            let migration = migration_persistence.create(migration).await?;
+            let mut migration_updates = migration.update_params();
+
+            match step_applier.apply_script(&script).await {
+                Ok(database_migration) => {
+                    // The database migration records the script, which drift detection replays, and the
+                    // schemas before and after it.
+                    migration_updates.database_migration = database_migration.serialize();
+                    migration_updates.applied = 1;
+                    migration_updates.mark_as_finished();
+                    migration_persistence.update(&migration_updates).await?;
+                }
+                Err(err) => {
+                    migration_updates.status = MigrationStatus::MigrationFailure;
+                    migration_updates.errors = vec![format!("{}", err)];
+                    migration_persistence.update(&migration_updates).await?;
+
+                    return Err(err.into());
+                }
+            }
+
+            applied_migration_names.push(directory.migration_name().to_owned());
+        }
+
+        Ok(ApplyMigrationsOutput {
+            applied_migration_names,
+            edited_migration_names,
+        })
+    }
+}
+
+/// Checks that the migrations directory was written for the provider of the connector.
+pub(super) fn check_migration_lock_provider(migrations_directory_path: &Path, provider: &str) -> CommandResult<()> {
+    match read_provider_from_lock_file(migrations_directory_path).map_err(|err| CommandError::Generic(err.into()))? {
+        Some(locked_provider) if locked_provider != provider => Err(CommandError::Input(anyhow::anyhow!(
+            "The migrations directory was created for `{locked_provider}`, but the datasource provider is `{provider}`.",
+            locked_provider = locked_provider,
+            provider = provider,
+        ))),
+        _ => Ok(()),
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyMigrationsInput {
+    /// The path to the migrations directory.
+    pub migrations_directory_path: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct ApplyMigrationsOutput {
+    /// The migrations applied by this command, in order.
+    pub applied_migration_names: Vec<String>,
+    /// The applied migrations whose script changed since they were applied.
+    pub edited_migration_names: Vec<String>,
+}
@@ -0,0 +1,111 @@
+//! The Baseline RPC method.
+//!
+//! Starts the migration history of a database created outside of migrations. The current schema of
+//! the database is described and written as the first migration of the migrations directory. The
+//! migration is recorded as applied without running its script, so the next migrations are
+//! inferred from the database as it is.
+//!
+//! The migration engine does not introspect datamodels: the caller passes the datamodel the
+//! introspection engine produced for the database.
+//!
+//! The baseline migration has no down script: rolling it back would drop the whole database.
+
+use super::apply_migrations::check_migration_lock_provider;
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use crate::parse_datamodel;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub struct BaselineCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for BaselineCommand {
+    type Input = BaselineInput;
+    type Output = BaselineOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+
+        check_migration_lock_provider(migrations_directory_path, connector.connector_type())?;
+
+        let has_migration_directories = !list_migrations(migrations_directory_path)
+            .map_err(|err| CommandError::Generic(err.into()))?
+            .is_empty();
+        let has_applied_migrations = migration_persistence
+            .load_all()
+            .await?
+            .iter()
+            .any(|migration| migration.status.is_success());
+
+        if has_migration_directories || has_applied_migrations {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The database already has a migration history. Only databases without migrations can be baselined."
+            )));
+        }
+
+        let introspected_datamodel = parse_datamodel(&input.datamodel)?;
+        let database_migration = connector.baseline().await?;
+
+        let script = connector
+            .database_migration_step_applier()
+            .render_script(&database_migration)?;
+
+        if script.is_empty() {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The database is empty, there is nothing to baseline."
+            )));
+        }
+
+        let directory = create_migration_directory(migrations_directory_path, &input.migration_name)
+            .map_err(|err| CommandError::Generic(err.into()))?;
+
+        directory
+            .write_migration_script(&script)
+            .map_err(|err| CommandError::Generic(err.into()))?;
+
+        write_migration_lock_file(migrations_directory_path, connector.connector_type())
+            .map_err(|err| CommandError::Generic(err.into()))?;
+
+        // The introspected datamodel is recorded too, so `applyMigration` infers the next
+        // migrations from it rather than from an empty datamodel.
+        let mut migration = Migration::new(directory.migration_name().to_owned());
+        migration.checksum = Some(script_checksum(&script));
+        migration.datamodel_string = datamodel::render_datamodel_to_string(&introspected_datamodel)
+            .map_err(CommandError::ProducedBadDatamodel)?;
+        migration.database_migration = database_migration.serialize();
+        migration.applied = 1;
+        migration.status = MigrationStatus::MigrationSuccess;
+        migration.finished_at = Some(Migration::timestamp_without_nanos());
+        migration_persistence.create(migration).await?;
+
+        Ok(BaselineOutput {
+            baseline_migration_name: directory.migration_name().to_owned(),
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineInput {
+    /// The path to the migrations directory.
+    pub migrations_directory_path: String,
+    /// The name of the baseline migration, the directory name is prefixed with a timestamp.
+    pub migration_name: String,
+    /// The datamodel introspected from the database by the introspection engine.
+    pub datamodel: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct BaselineOutput {
+    /// The name of the baseline migration directory.
+    pub baseline_migration_name: String,
+}
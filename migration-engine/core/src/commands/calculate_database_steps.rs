@@ -71,6 +71,8 @@ impl<'a> MigrationCommand for CalculateDatabaseStepsCommand<'a> {
             warnings,
             general_errors: Vec::new(),
             unexecutable_migrations,
+            script: None,
+            proposed_renames: database_migration.proposed_renames().to_vec(),
         })
     }
 }
@@ -0,0 +1,152 @@
+//! The CreateMigration RPC method.
+//!
+//! Writes a new migration to the migrations directory. The migration script takes the database from
+//! the state reached by applying the migrations directory to the state described by the schema. The
+//! script is not applied: it can be reviewed and edited before `applyMigrations` runs it.
+//!
+//! A down script rolling the migration back is written next to it, starting with the warnings about
+//! the data the rollback would lose as comments.
+
+use super::apply_migrations::check_migration_lock_provider;
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use datamodel::Datamodel;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub struct CreateMigrationCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for CreateMigrationCommand {
+    type Input = CreateMigrationInput;
+    type Output = CreateMigrationOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+
+        check_migration_lock_provider(migrations_directory_path, connector.connector_type())?;
+
+        let applied_migrations = connector.migration_persistence().load_all().await?;
+        let unapplied_migration_names: Vec<String> = list_migrations(migrations_directory_path)
+            .map_err(|err| CommandError::Generic(err.into()))?
+            .iter()
+            .map(|directory| directory.migration_name())
+            .filter(|name| {
+                !applied_migrations
+                    .iter()
+                    .any(|migration| migration.name == *name && migration.status.is_success())
+            })
+            .map(String::from)
+            .collect();
+
+        if !unapplied_migration_names.is_empty() {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The following migrations have not been applied yet: {}. Apply them before creating a new migration.",
+                unapplied_migration_names.join(", ")
+            )));
+        }
+
+        let next_datamodel = datamodel::parse_datamodel(&input.prisma_schema).map_err(|err| {
+            CommandError::ReceivedBadDatamodel(err.to_pretty_string("schema.prisma", &input.prisma_schema))
+        })?;
+
+        // The database is up to date with the migrations directory, so the migration is inferred
+        // from its current state. The datamodel of the last applied migration is the previous
+        // datamodel, e.g. for the definitions of the views it manages.
+        let previous_datamodel = applied_migrations
+            .iter()
+            .rev()
+            .find(|migration| migration.status.is_success())
+            .map(|migration| migration.parse_datamodel())
+            .unwrap_or_else(|| Ok(Datamodel::empty()))
+            .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
+
+        let database_migration = connector
+            .database_migration_inferrer()
+            .infer(&previous_datamodel, &next_datamodel, &[])
+            .await?;
+
+        let step_applier = connector.database_migration_step_applier();
+        let script = step_applier.render_script(&database_migration)?;
+
+        if script.is_empty() && !input.draft {
+            tracing::info!("The database is already in sync with the schema, no migration was created.");
+
+            return Ok(CreateMigrationOutput {
+                generated_migration_name: None,
+            });
+        }
+
+        let directory = create_migration_directory(migrations_directory_path, &input.migration_name)
+            .map_err(|err| CommandError::Generic(err.into()))?;
+
+        directory
+            .write_migration_script(&script)
+            .map_err(|err| CommandError::Generic(err.into()))?;
+
+        let rollback_diagnostics = connector
+            .destructive_changes_checker()
+            .check_unapply(&database_migration)
+            .await?;
+        let down_script = render_down_script(
+            &step_applier.render_rollback_script(&database_migration)?,
+            &rollback_diagnostics.warnings,
+        );
+
+        directory
+            .write_down_script(&down_script)
+            .map_err(|err| CommandError::Generic(err.into()))?;
+
+        write_migration_lock_file(migrations_directory_path, connector.connector_type())
+            .map_err(|err| CommandError::Generic(err.into()))?;
+
+        Ok(CreateMigrationOutput {
+            generated_migration_name: Some(directory.migration_name().to_owned()),
+        })
+    }
+}
+
+fn render_down_script(rollback_script: &str, warnings: &[MigrationWarning]) -> String {
+    let mut down_script = String::new();
+
+    for warning in warnings {
+        down_script.push_str("-- Warning: ");
+        down_script.push_str(&warning.description);
+        down_script.push('\n');
+    }
+
+    if !warnings.is_empty() {
+        down_script.push('\n');
+    }
+
+    down_script.push_str(rollback_script);
+
+    down_script
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMigrationInput {
+    /// The path to the migrations directory.
+    pub migrations_directory_path: String,
+    /// The schema the migration brings the database to.
+    pub prisma_schema: String,
+    /// The name of the migration, the directory name is prefixed with a timestamp.
+    pub migration_name: String,
+    /// Create the migration even if it is empty, to be written by hand.
+    #[serde(default)]
+    pub draft: bool,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct CreateMigrationOutput {
+    /// The name of the created migration directory. `None` if there was nothing to migrate.
+    pub generated_migration_name: Option<String>,
+}
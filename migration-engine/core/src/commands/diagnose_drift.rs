@@ -0,0 +1,63 @@
+//! The DiagnoseDrift RPC method.
+//!
+//! Compares the schema the applied migrations lead to, replayed in a shadow database, with the
+//! schema of the database, to find changes made outside of migrations, like hotfixes applied by
+//! hand.
+
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use migration_connector::*;
+use serde::Serialize;
+
+pub struct DiagnoseDriftCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for DiagnoseDriftCommand {
+    type Input = serde_json::Value;
+    type Output = DiagnoseDriftOutput;
+
+    async fn execute<C, D>(_input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let step_applier = connector.database_migration_step_applier();
+
+        let migrations = connector.migration_persistence().load_all().await?;
+        let mut applied_migration_scripts = Vec::new();
+
+        // The migrations record the steps or the script they applied, the history is replayed from
+        // these. Migrations recorded without them make the expected schema unknown.
+        for migration in migrations.into_iter().filter(|migration| migration.status.is_success()) {
+            let database_migration = match connector.deserialize_database_migration(migration.database_migration) {
+                Ok(database_migration) => database_migration,
+                Err(_) => {
+                    return Err(CommandError::Generic(anyhow::anyhow!(
+                        "The migration `{}` does not record what it applied, the migration history cannot be replayed to diagnose drift.",
+                        migration.name
+                    )))
+                }
+            };
+
+            applied_migration_scripts.push(step_applier.render_script(&database_migration)?);
+        }
+
+        let drifts = connector.diagnose_drift(&applied_migration_scripts).await?;
+
+        tracing::info!("Found {} drifts from the migration history.", drifts.len());
+
+        Ok(DiagnoseDriftOutput {
+            summary: render_drifts(&drifts),
+            drifts,
+        })
+    }
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct DiagnoseDriftOutput {
+    /// The drifts, rendered for humans.
+    pub summary: String,
+    pub drifts: Vec<Drift>,
+}
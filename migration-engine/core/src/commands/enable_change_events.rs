@@ -0,0 +1,39 @@
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+
+/// Installs the triggers producing the record change events consumed by query engine subscriptions.
+pub struct EnableChangeEventsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for EnableChangeEventsCommand {
+    type Input = EnableChangeEventsInput;
+    type Output = EnableChangeEventsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let tables = engine.connector().enable_change_events(&input.tables).await?;
+
+        tracing::info!("Enabled change events on {} tables.", tables.len());
+
+        Ok(EnableChangeEventsOutput { tables })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EnableChangeEventsInput {
+    /// The tables to enable change events on. All tables if empty.
+    #[serde(default)]
+    pub tables: Vec<String>,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct EnableChangeEventsOutput {
+    pub tables: Vec<String>,
+}
@@ -60,8 +60,15 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             .infer(&assumed_datamodel_ast, &next_datamodel_ast);
 
         let database_migration = database_migration_inferrer
-            .infer(&assumed_datamodel, &next_datamodel, &model_migration_steps)
+            .infer_with_accepted_renames(
+                &assumed_datamodel,
+                &next_datamodel,
+                &model_migration_steps,
+                &[],
+                &cmd.input.accepted_renames,
+            )
             .await?;
+        let proposed_renames = database_migration.proposed_renames().to_vec();
 
         let DestructiveChangeDiagnostics {
             warnings,
@@ -114,6 +121,8 @@ impl<'a> MigrationCommand for InferMigrationStepsCommand<'a> {
             warnings,
             general_errors: vec![],
             unexecutable_migrations,
+            script: None,
+            proposed_renames,
         })
     }
 }
@@ -171,6 +180,9 @@ pub struct InferMigrationStepsInput {
     /// These steps must be provided and correct for migration inferrence to work.
     pub assume_to_be_applied: Option<Vec<MigrationStep>>,
     pub assume_applied_migrations: Option<Vec<AppliedMigration>>,
+    /// The proposed renames to make, instead of dropping and adding the tables or columns.
+    #[serde(default)]
+    pub accepted_renames: Vec<Rename>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -1,24 +1,38 @@
 mod apply_migration;
+mod apply_migrations;
+mod baseline;
 mod calculate_database_steps;
 mod calculate_datamodel;
 mod command;
+mod create_migration;
+mod diagnose_drift;
+mod enable_change_events;
 mod infer_migration_steps;
 mod list_migrations;
 mod migration_progress;
 mod reset;
+mod rollback_migrations;
+mod squash_migrations;
 mod unapply_migration;
 
 pub use apply_migration::*;
+pub use apply_migrations::*;
+pub use baseline::*;
 pub use calculate_database_steps::*;
 pub use calculate_datamodel::*;
 pub use command::*;
+pub use create_migration::*;
+pub use diagnose_drift::*;
+pub use enable_change_events::*;
 pub use infer_migration_steps::*;
 pub use list_migrations::*;
 pub use migration_progress::*;
 pub use reset::*;
+pub use rollback_migrations::*;
+pub use squash_migrations::*;
 pub use unapply_migration::*;
 
-use migration_connector::{MigrationError, MigrationStep, MigrationWarning, UnexecutableMigration};
+use migration_connector::{MigrationError, MigrationStep, MigrationWarning, Rename, UnexecutableMigration};
 use serde::{Deserialize, Serialize};
 
 #[derive(Debug, Serialize, Deserialize)]
@@ -32,4 +46,11 @@ pub struct MigrationStepsResultOutput {
     pub general_errors: Vec<String>,
     #[serde(skip)]
     pub unexecutable_migrations: Vec<UnexecutableMigration>,
+    /// The SQL script the migration would run. Only returned by dry runs of `applyMigration`.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub script: Option<String>,
+    /// The renames the migration could make instead of dropping and adding tables or columns. They
+    /// are only made once passed back as `acceptedRenames`.
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub proposed_renames: Vec<Rename>,
 }
@@ -0,0 +1,128 @@
+//! The RollbackMigrations RPC method.
+//!
+//! Rolls the database back to a migration of the migrations directory, by running the down scripts
+//! of the migrations applied after it, in reverse order. The migration rolled back to stays applied.
+
+use super::apply_migrations::check_migration_lock_provider;
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+use std::path::Path;
+
+pub struct RollbackMigrationsCommand;
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for RollbackMigrationsCommand {
+    type Input = RollbackMigrationsInput;
+    type Output = RollbackMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+        let step_applier = connector.database_migration_step_applier();
+        let migrations_directory_path = Path::new(&input.migrations_directory_path);
+
+        check_migration_lock_provider(migrations_directory_path, connector.connector_type())?;
+
+        let migration_directories =
+            list_migrations(migrations_directory_path).map_err(|err| CommandError::Generic(err.into()))?;
+        let applied_migrations: Vec<Migration> = migration_persistence
+            .load_all()
+            .await?
+            .into_iter()
+            .filter(|migration| migration.status.is_success())
+            .collect();
+
+        let is_applied = |name: &str| applied_migrations.iter().any(|migration| migration.name == name);
+
+        let target_position = migration_directories
+            .iter()
+            .position(|directory| directory.migration_name() == input.target_migration_name)
+            .filter(|_| is_applied(&input.target_migration_name))
+            .ok_or_else(|| {
+                CommandError::Input(anyhow::anyhow!(
+                    "The migration `{}` is not an applied migration of the migrations directory.",
+                    input.target_migration_name
+                ))
+            })?;
+
+        // All the down scripts are read before the first one runs, so a missing script does not
+        // leave the database between two migrations.
+        let mut rollbacks = Vec::new();
+
+        for directory in migration_directories[target_position + 1..].iter().rev() {
+            let applied_migration = match applied_migrations
+                .iter()
+                .rev()
+                .find(|migration| migration.name == directory.migration_name())
+            {
+                Some(applied_migration) => applied_migration,
+                None => continue,
+            };
+
+            let down_script = directory
+                .read_down_script()
+                .map_err(|err| CommandError::Generic(err.into()))?
+                .ok_or_else(|| {
+                    CommandError::Input(anyhow::anyhow!(
+                        "The migration `{}` has no {} to roll it back.",
+                        directory.migration_name(),
+                        DOWN_SCRIPT_FILENAME,
+                    ))
+                })?;
+
+            rollbacks.push((applied_migration, down_script));
+        }
+
+        let mut rolled_back_migration_names = Vec::with_capacity(rollbacks.len());
+
+        for (applied_migration, down_script) in rollbacks {
+            tracing::info!("Rolling back migration `{}`", applied_migration.name);
+
+            let mut migration_updates = applied_migration.update_params();
+
+            match step_applier.apply_rollback_script(&down_script).await {
+                Ok(_) => {
+                    migration_updates.status = MigrationStatus::RollbackSuccess;
+                    migration_updates.rolled_back = 1;
+                    migration_updates.finished_at = Some(Migration::timestamp_without_nanos());
+                    migration_persistence.update(&migration_updates).await?;
+                }
+                Err(err) => {
+                    migration_updates.status = MigrationStatus::RollbackFailure;
+                    migration_updates.errors = vec![format!("{}", err)];
+                    migration_persistence.update(&migration_updates).await?;
+
+                    return Err(err.into());
+                }
+            }
+
+            rolled_back_migration_names.push(applied_migration.name.clone());
+        }
+
+        Ok(RollbackMigrationsOutput {
+            rolled_back_migration_names,
+        })
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RollbackMigrationsInput {
+    /// The path to the migrations directory.
+    pub migrations_directory_path: String,
+    /// The name of the migration to roll back to. It stays applied.
+    pub target_migration_name: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct RollbackMigrationsOutput {
+    /// The migrations rolled back by this command, in the order they were rolled back.
+    pub rolled_back_migration_names: Vec<String>,
+}
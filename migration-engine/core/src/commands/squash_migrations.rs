@@ -0,0 +1,200 @@
+//! The SquashMigrations RPC method.
+//!
+//! Collapses a range of applied migrations into a single migration, so long histories stop slowing
+//! down the commands loading and replaying them. The squashed migration migrates from the datamodel
+//! before the range to the datamodel of its last migration, in datamodel steps and database steps.
+//!
+//! The range is already applied, so only the persisted history is rewritten: nothing is run against
+//! the database.
+
+use crate::commands::command::*;
+use crate::migration_engine::MigrationEngine;
+use datamodel::{ast::SchemaAst, Datamodel};
+use migration_connector::*;
+use serde::{Deserialize, Serialize};
+
+pub struct SquashMigrationsCommand<'a> {
+    input: &'a SquashMigrationsInput,
+}
+
+#[async_trait::async_trait]
+impl<'a> MigrationCommand for SquashMigrationsCommand<'a> {
+    type Input = SquashMigrationsInput;
+    type Output = SquashMigrationsOutput;
+
+    async fn execute<C, D>(input: &Self::Input, engine: &MigrationEngine<C, D>) -> CommandResult<Self::Output>
+    where
+        C: MigrationConnector<DatabaseMigration = D>,
+        D: DatabaseMigrationMarker + Send + Sync + 'static,
+    {
+        let cmd = SquashMigrationsCommand { input };
+        tracing::debug!("{:?}", cmd.input);
+
+        let connector = engine.connector();
+        let migration_persistence = connector.migration_persistence();
+        let database_migration_inferrer = connector.database_migration_inferrer();
+        let step_applier = connector.database_migration_step_applier();
+
+        // Failed and rolled back migrations are not part of the history the datamodel is replayed from.
+        let applied_migrations: Vec<Migration> = migration_persistence
+            .load_all()
+            .await?
+            .into_iter()
+            .filter(|migration| migration.status.is_success())
+            .collect();
+
+        let (from_idx, to_idx) = cmd.squashed_range(&applied_migrations)?;
+        let squashed_migrations = &applied_migrations[from_idx..=to_idx];
+        let last_squashed_migration = &squashed_migrations[squashed_migrations.len() - 1];
+
+        let previous_migration = from_idx.checked_sub(1).map(|idx| &applied_migrations[idx]);
+        let previous_datamodel_ast = previous_migration
+            .map(|migration| migration.parse_schema_ast())
+            .unwrap_or_else(|| Ok(SchemaAst::empty()))
+            .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
+        let previous_datamodel = previous_migration
+            .map(|migration| migration.parse_datamodel())
+            .unwrap_or_else(|| Ok(Datamodel::empty()))
+            .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
+        let squashed_datamodel_ast = last_squashed_migration
+            .parse_schema_ast()
+            .map_err(|(err, schema)| CommandError::InvalidPersistedDatamodel(err, schema))?;
+
+        let datamodel_steps = engine
+            .datamodel_migration_steps_inferrer()
+            .infer(&previous_datamodel_ast, &squashed_datamodel_ast);
+
+        let squashed_datamodel = datamodel::lift_ast(
+            &engine
+                .datamodel_calculator()
+                .infer(&previous_datamodel_ast, &datamodel_steps)?,
+        )
+        .map_err(CommandError::ProducedBadDatamodel)?;
+
+        let database_migration = database_migration_inferrer
+            .infer_from_datamodels(&previous_datamodel, &squashed_datamodel, &datamodel_steps)
+            .await?;
+
+        // The squashed migration must lead to the database schema the squashed migrations led to
+        // when they were applied.
+        let replayed_database_migration =
+            connector.deserialize_database_migration(last_squashed_migration.database_migration.clone())?;
+
+        let differences = step_applier.render_steps_pretty(
+            &database_migration_inferrer.infer_between_migrations(&replayed_database_migration, &database_migration)?,
+        )?;
+
+        if !differences.is_empty() {
+            return Err(CommandError::Generic(anyhow::anyhow!(
+                "The squashed migration does not lead to the same database schema as the squashed migrations. Differences: {}",
+                serde_json::Value::Array(differences)
+            )));
+        }
+
+        let database_steps = step_applier.render_steps_pretty(&database_migration)?;
+
+        let mut migration = Migration::new(cmd.input.squashed_migration_id.clone());
+        migration.datamodel_string = last_squashed_migration.datamodel_string.clone();
+        migration.datamodel_steps = datamodel_steps.clone();
+        migration.database_migration = database_migration.serialize();
+        migration.applied = database_steps.len();
+        migration.status = MigrationStatus::MigrationSuccess;
+        migration.started_at = squashed_migrations[0].started_at;
+        migration.finished_at = last_squashed_migration.finished_at;
+
+        migration_persistence.squash(squashed_migrations, migration).await?;
+
+        Ok(SquashMigrationsOutput {
+            squashed_migration_id: cmd.input.squashed_migration_id.clone(),
+            squashed_migrations: squashed_migrations
+                .iter()
+                .map(|migration| migration.name.clone())
+                .collect(),
+            datamodel_steps,
+            database_steps: serde_json::Value::Array(database_steps),
+        })
+    }
+}
+
+impl SquashMigrationsCommand<'_> {
+    /// The indexes of the first and last squashed migrations in the applied migrations.
+    fn squashed_range(&self, applied_migrations: &[Migration]) -> CommandResult<(usize, usize)> {
+        let position = |migration_id: &str| {
+            applied_migrations
+                .iter()
+                .position(|migration| migration.name == migration_id)
+                .ok_or_else(|| {
+                    CommandError::Input(anyhow::anyhow!(
+                        "The migration `{}` is not applied. Only applied migrations can be squashed.",
+                        migration_id
+                    ))
+                })
+        };
+
+        let from_idx = position(&self.input.from_migration_id)?;
+        let to_idx = position(&self.input.to_migration_id)?;
+
+        if from_idx > to_idx {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "The migration `{}` was applied after `{}`.",
+                self.input.from_migration_id,
+                self.input.to_migration_id
+            )));
+        }
+
+        // Migrations applied from a migrations directory are looked up by their directory name, they
+        // would be applied again if their records were squashed.
+        if applied_migrations[from_idx..=to_idx]
+            .iter()
+            .any(|migration| migration.checksum.is_some())
+        {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "Migrations applied from a migrations directory cannot be squashed."
+            )));
+        }
+
+        if self.input.squashed_migration_id.starts_with("watch")
+            || applied_migrations[from_idx..=to_idx]
+                .iter()
+                .any(|migration| migration.is_watch_migration())
+        {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "Watch migrations cannot be squashed."
+            )));
+        }
+
+        let name_is_taken = applied_migrations.iter().enumerate().any(|(idx, migration)| {
+            (idx < from_idx || idx > to_idx) && migration.name == self.input.squashed_migration_id
+        });
+
+        if name_is_taken {
+            return Err(CommandError::Input(anyhow::anyhow!(
+                "Another migration is named `{}`.",
+                self.input.squashed_migration_id
+            )));
+        }
+
+        Ok((from_idx, to_idx))
+    }
+}
+
+#[derive(Deserialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsInput {
+    /// The first squashed migration.
+    pub from_migration_id: String,
+    /// The last squashed migration.
+    pub to_migration_id: String,
+    /// The id of the migration replacing the squashed migrations.
+    pub squashed_migration_id: String,
+}
+
+#[derive(Serialize, Debug)]
+#[serde(rename_all = "camelCase")]
+pub struct SquashMigrationsOutput {
+    pub squashed_migration_id: String,
+    /// The ids of the squashed migrations, in the order they were applied.
+    pub squashed_migrations: Vec<String>,
+    pub datamodel_steps: Vec<MigrationStep>,
+    pub database_steps: serde_json::Value,
+}
@@ -197,6 +197,7 @@ fn apply_create_model(datamodel: &mut ast::SchemaAst, step: &steps::CreateModel)
         fields: vec![],
         directives: vec![],
         commented_out: false,
+        is_composite_type: step.is_composite_type,
     };
 
     datamodel.tops.push(ast::Top::Model(model));
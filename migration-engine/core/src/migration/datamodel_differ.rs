@@ -261,6 +261,7 @@ fn push_created_models<'a>(steps: &mut Steps, models: impl Iterator<Item = &'a a
 
         let create_model_step = steps::CreateModel {
             model: created_model.name.name.clone(),
+            is_composite_type: created_model.is_composite_type,
         };
 
         steps.push(MigrationStep::CreateModel(create_model_step));
@@ -473,6 +474,16 @@ fn push_updated_directives<'a>(
 }
 
 fn push_updated_directive(steps: &mut Steps, directive_path: steps::DirectivePath, directive: DirectiveDiffer<'_>) {
+    // Argument steps address arguments by name, so directives with positional arguments are replaced as a whole.
+    if directive.has_positional_arguments() {
+        if directive.positional_arguments_changed() {
+            push_deleted_directive(steps, directive_path.clone(), directive.previous);
+            push_created_directive(steps, directive_path, directive.next);
+        }
+
+        return;
+    }
+
     let location = steps::ArgumentLocation::Directive(DirectiveLocation {
         path: directive_path,
         directive: directive.previous.name.name.clone(),
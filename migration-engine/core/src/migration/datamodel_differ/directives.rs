@@ -32,6 +32,29 @@ impl<'a> DirectiveDiffer<'a> {
         })
     }
 
+    /// Whether the directive takes several unnamed arguments, e.g. `@db.Decimal(10, 2)`. These can only be told apart
+    /// by their position, not by their name.
+    pub(crate) fn has_positional_arguments(&self) -> bool {
+        let unnamed_arguments = |directive: &ast::Directive| {
+            directive
+                .arguments
+                .iter()
+                .filter(|argument| argument.name.name.is_empty())
+                .count()
+        };
+
+        unnamed_arguments(self.previous) > 1 || unnamed_arguments(self.next) > 1
+    }
+
+    /// Compares the arguments in order.
+    pub(crate) fn positional_arguments_changed(&self) -> bool {
+        self.previous.arguments.len() != self.next.arguments.len()
+            || self
+                .previous_arguments()
+                .zip(self.next_arguments())
+                .any(|(previous, next)| !arguments_are_identical(previous, next))
+    }
+
     fn previous_arguments(&self) -> impl Iterator<Item = &ast::Argument> {
         self.previous.arguments.iter()
     }
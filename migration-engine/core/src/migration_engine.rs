@@ -3,7 +3,11 @@ use crate::migration::datamodel_migration_steps_inferrer::*;
 use crate::{commands::CommandResult, CoreResult};
 use datamodel::ast::SchemaAst;
 use migration_connector::*;
-use std::sync::Arc;
+use std::{future::Future, sync::Arc, time::Duration};
+
+/// How long to wait for the migration lock by default. It can be set in seconds with the
+/// `MIGRATION_LOCK_TIMEOUT` environment variable.
+const DEFAULT_MIGRATION_LOCK_TIMEOUT: Duration = Duration::from_secs(10);
 
 pub struct MigrationEngine<C, D>
 where
@@ -13,6 +17,7 @@ where
     datamodel_migration_steps_inferrer: Arc<dyn DataModelMigrationStepsInferrer>,
     datamodel_calculator: Arc<dyn DataModelCalculator>,
     connector: C,
+    migration_lock_timeout: Duration,
 }
 
 impl<C, D> MigrationEngine<C, D>
@@ -21,17 +26,24 @@ where
     D: DatabaseMigrationMarker + Send + Sync + 'static,
 {
     pub async fn new(connector: C) -> CoreResult<Self> {
-        let engine = MigrationEngine {
-            datamodel_migration_steps_inferrer: Arc::new(DataModelMigrationStepsInferrerImplWrapper {}),
-            datamodel_calculator: Arc::new(DataModelCalculatorImpl),
-            connector,
-        };
+        let engine = Self::uninitialized(connector);
 
         engine.init().await?;
 
         Ok(engine)
     }
 
+    /// An engine that does not initialize the connector, so the migration table is not created.
+    /// The commands it runs must not change the database.
+    pub fn uninitialized(connector: C) -> Self {
+        MigrationEngine {
+            datamodel_migration_steps_inferrer: Arc::new(DataModelMigrationStepsInferrerImplWrapper {}),
+            datamodel_calculator: Arc::new(DataModelCalculatorImpl),
+            connector,
+            migration_lock_timeout: migration_lock_timeout_from_env(),
+        }
+    }
+
     pub async fn init(&self) -> CommandResult<()> {
         self.connector().initialize().await?;
         Ok(())
@@ -42,6 +54,32 @@ where
         Ok(())
     }
 
+    /// Wait at most `timeout` for the migration lock, instead of the default timeout.
+    pub fn with_migration_lock_timeout(mut self, timeout: Duration) -> Self {
+        self.migration_lock_timeout = timeout;
+        self
+    }
+
+    /// Run the future holding the migration lock, so migration engines started at the same time
+    /// change the database one after the other.
+    pub async fn with_migration_lock<T, F>(&self, fut: F) -> CommandResult<T>
+    where
+        F: Future<Output = CommandResult<T>>,
+    {
+        let lock = self
+            .connector()
+            .acquire_migration_lock(self.migration_lock_timeout)
+            .await?;
+
+        let result = fut.await;
+        let release_result = lock.release().await;
+        let output = result?;
+
+        release_result?;
+
+        Ok(output)
+    }
+
     pub fn connector(&self) -> &C {
         &self.connector
     }
@@ -58,3 +96,11 @@ where
         datamodel::render_schema_ast_to_string(&schema_ast).expect("Rendering the schema failed")
     }
 }
+
+fn migration_lock_timeout_from_env() -> Duration {
+    std::env::var("MIGRATION_LOCK_TIMEOUT")
+        .ok()
+        .and_then(|seconds| seconds.parse().ok())
+        .map(Duration::from_secs)
+        .unwrap_or(DEFAULT_MIGRATION_LOCK_TIMEOUT)
+}
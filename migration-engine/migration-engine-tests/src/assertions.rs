@@ -349,6 +349,12 @@ impl<'a> PrimaryKeyAssertion<'a> {
 
         Ok(self)
     }
+
+    pub fn assert_constraint_name(self, name: &str) -> AssertionResult<Self> {
+        assert_eq!(self.0.constraint_name.as_deref(), Some(name));
+
+        Ok(self)
+    }
 }
 
 pub struct ForeignKeyAssertion<'a>(&'a ForeignKey);
@@ -367,6 +373,12 @@ impl<'a> ForeignKeyAssertion<'a> {
         Ok(self)
     }
 
+    pub fn assert_constraint_name(self, name: &str) -> AssertionResult<Self> {
+        assert_eq!(self.0.constraint_name.as_deref(), Some(name));
+
+        Ok(self)
+    }
+
     pub fn assert_cascades_on_delete(self) -> AssertionResult<Self> {
         anyhow::ensure!(
             self.0.on_delete_action == ForeignKeyAction::Cascade,
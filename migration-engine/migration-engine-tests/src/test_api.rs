@@ -1,13 +1,19 @@
 mod apply;
+mod apply_migrations;
 mod calculate_database_steps;
+mod create_migration;
 mod infer;
 mod infer_apply;
+mod rollback_migrations;
 mod unapply_migration;
 
 pub use apply::Apply;
+pub use apply_migrations::ApplyMigrations;
 pub use calculate_database_steps::CalculateDatabaseSteps;
+pub use create_migration::CreateMigration;
 pub use infer::Infer;
 pub use infer_apply::InferApply;
+pub use rollback_migrations::RollbackMigrations;
 pub use unapply_migration::UnapplyMigration;
 
 use super::assertions::SchemaAssertion;
@@ -19,12 +25,15 @@ use super::{
 use migration_connector::{MigrationPersistence, MigrationStep};
 use migration_core::{
     api::{GenericApi, MigrationApi},
-    commands::ApplyMigrationInput,
+    commands::{
+        ApplyMigrationInput, BaselineInput, BaselineOutput, DiagnoseDriftOutput, SquashMigrationsInput,
+        SquashMigrationsOutput,
+    },
 };
 use quaint::prelude::{ConnectionInfo, Queryable, SqlFamily};
 use sql_migration_connector::MIGRATION_TABLE_NAME;
 use sql_schema_describer::*;
-use std::sync::Arc;
+use std::{path::Path, sync::Arc, time::Duration};
 use test_setup::*;
 
 /// A handle to all the context needed for end-to-end testing of the migration engine across
@@ -35,6 +44,7 @@ pub struct TestApi {
     database: Arc<dyn Queryable + Send + Sync + 'static>,
     api: MigrationApi<sql_migration_connector::SqlMigrationConnector, sql_migration_connector::SqlMigration>,
     connection_info: ConnectionInfo,
+    url: String,
 }
 
 impl TestApi {
@@ -62,6 +72,51 @@ impl TestApi {
         self.connector_name == "mysql_mariadb"
     }
 
+    /// Recreates the engine with online migrations, as with `onlineMigrations = true` in the datasource.
+    pub async fn with_online_migrations(&self) -> TestApi {
+        let connector = self.api.connector();
+        let connector = sql_migration_connector::SqlMigrationConnector {
+            schema_name: connector.schema_name.clone(),
+            additional_schemas: connector.additional_schemas.clone(),
+            database: Arc::clone(&connector.database),
+            database_info: connector.database_info.clone(),
+            database_describer: Arc::clone(&connector.database_describer),
+            url: connector.url.clone(),
+        }
+        .with_online_migrations(true);
+
+        TestApi {
+            connector_name: self.connector_name,
+            database: Arc::clone(&self.database),
+            api: MigrationApi::new(connector).await.unwrap(),
+            connection_info: self.connection_info.clone(),
+            url: self.url.clone(),
+        }
+    }
+
+    /// Starts another migration engine on the same database, with its own connection, like a
+    /// second deployment starting at the same time.
+    pub async fn concurrent_engine(&self, migration_lock_timeout: Duration) -> TestApi {
+        let connector = sql_migration_connector::SqlMigrationConnector::new(&self.url)
+            .await
+            .unwrap();
+
+        TestApi {
+            connector_name: self.connector_name,
+            database: Arc::clone(&connector.database),
+            api: MigrationApi::new(connector)
+                .await
+                .unwrap()
+                .with_migration_lock_timeout(migration_lock_timeout),
+            connection_info: self.connection_info.clone(),
+            url: self.url.clone(),
+        }
+    }
+
+    pub fn connector(&self) -> &sql_migration_connector::SqlMigrationConnector {
+        self.api.connector()
+    }
+
     pub fn migration_persistence<'a>(&'a self) -> Box<dyn MigrationPersistence + 'a> {
         self.api.migration_persistence()
     }
@@ -92,6 +147,9 @@ impl TestApi {
             migration_id: migration_id.into(),
             steps,
             force: None,
+            data_migration_steps: Vec::new(),
+            accepted_renames: Vec::new(),
+            dry_run: false,
         };
 
         let migration_output = self.api.apply_migration(&input).await.expect("ApplyMigration failed");
@@ -131,6 +189,67 @@ impl TestApi {
         Apply::new(&self.api)
     }
 
+    /// Writes a new migration to the migrations directory, without applying it.
+    pub fn create_migration<'a>(
+        &'a self,
+        name: &'a str,
+        schema: &'a str,
+        migrations_directory: &'a Path,
+    ) -> CreateMigration<'a> {
+        CreateMigration::new(&self.api, name, schema, migrations_directory)
+    }
+
+    pub fn apply_migrations<'a>(&'a self, migrations_directory: &'a Path) -> ApplyMigrations<'a> {
+        ApplyMigrations::new(&self.api, migrations_directory)
+    }
+
+    /// Rolls back the migrations applied after `target_migration_name` with their down scripts.
+    pub fn rollback_migrations<'a>(
+        &'a self,
+        migrations_directory: &'a Path,
+        target_migration_name: &'a str,
+    ) -> RollbackMigrations<'a> {
+        RollbackMigrations::new(&self.api, migrations_directory, target_migration_name)
+    }
+
+    /// Writes the current schema of the database as the first migration of the migrations
+    /// directory, and records it as applied. `datamodel` stands for the datamodel the introspection
+    /// engine returns for the database.
+    pub async fn baseline(
+        &self,
+        name: &str,
+        datamodel: &str,
+        migrations_directory: &Path,
+    ) -> Result<BaselineOutput, anyhow::Error> {
+        let input = BaselineInput {
+            migrations_directory_path: migrations_directory.to_str().unwrap().to_owned(),
+            migration_name: name.to_owned(),
+            datamodel: datamodel.to_owned(),
+        };
+
+        Ok(self.api.baseline(&input).await?)
+    }
+
+    pub async fn diagnose_drift(&self) -> Result<DiagnoseDriftOutput, anyhow::Error> {
+        Ok(self.api.diagnose_drift(&serde_json::Value::Null).await?)
+    }
+
+    /// Replaces the applied migrations from `from` to `to` with a single `squashed` migration.
+    pub async fn squash_migrations(
+        &self,
+        from: &str,
+        to: &str,
+        squashed: &str,
+    ) -> Result<SquashMigrationsOutput, anyhow::Error> {
+        let input = SquashMigrationsInput {
+            from_migration_id: from.to_owned(),
+            to_migration_id: to.to_owned(),
+            squashed_migration_id: squashed.to_owned(),
+        };
+
+        Ok(self.api.squash_migrations(&input).await?)
+    }
+
     pub fn unapply_migration<'a>(&'a self) -> UnapplyMigration<'a> {
         UnapplyMigration {
             api: &self.api,
@@ -259,6 +378,7 @@ pub async fn mysql_8_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -272,6 +392,7 @@ pub async fn mysql_5_6_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -285,6 +406,7 @@ pub async fn mysql_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -298,6 +420,7 @@ pub async fn mysql_mariadb_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -311,6 +434,7 @@ pub async fn postgres9_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -324,6 +448,7 @@ pub async fn postgres_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -337,6 +462,7 @@ pub async fn postgres11_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
@@ -350,11 +476,13 @@ pub async fn postgres12_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
 
 pub async fn sqlite_test_api(db_name: &str) -> TestApi {
-    let connection_info = ConnectionInfo::from_url(&sqlite_test_url(db_name)).unwrap();
+    let url = sqlite_test_url(db_name);
+    let connection_info = ConnectionInfo::from_url(&url).unwrap();
     let connector = sqlite_migration_connector(db_name).await;
 
     TestApi {
@@ -362,5 +490,6 @@ pub async fn sqlite_test_api(db_name: &str) -> TestApi {
         connection_info,
         database: Arc::clone(&connector.database),
         api: test_api(connector).await,
+        url,
     }
 }
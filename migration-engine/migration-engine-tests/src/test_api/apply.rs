@@ -54,6 +54,9 @@ impl Apply<'_> {
             migration_id,
             force: self.force,
             steps: self.steps.unwrap_or_else(Vec::new),
+            data_migration_steps: Vec::new(),
+            accepted_renames: Vec::new(),
+            dry_run: false,
         };
 
         self.api.apply_migration(&input).await
@@ -0,0 +1,62 @@
+use super::super::assertions::AssertionResult;
+use migration_core::{
+    api::GenericApi,
+    commands::{ApplyMigrationsInput, ApplyMigrationsOutput},
+};
+use std::path::Path;
+
+pub struct ApplyMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a Path,
+}
+
+impl<'a> ApplyMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a Path) -> Self {
+        ApplyMigrations {
+            api,
+            migrations_directory,
+        }
+    }
+
+    pub async fn send(self) -> Result<ApplyMigrationsAssertion, anyhow::Error> {
+        let input = ApplyMigrationsInput {
+            migrations_directory_path: self.migrations_directory.to_str().unwrap().to_owned(),
+        };
+
+        let output = self.api.apply_migrations(&input).await?;
+
+        Ok(ApplyMigrationsAssertion { output })
+    }
+}
+
+pub struct ApplyMigrationsAssertion {
+    output: ApplyMigrationsOutput,
+}
+
+impl ApplyMigrationsAssertion {
+    pub fn assert_applied_migrations(self, names: &[&str]) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.output.applied_migration_names == names,
+            "Assertion failed. Expected the migrations {:?} to be applied, got {:?}",
+            names,
+            self.output.applied_migration_names
+        );
+
+        Ok(self)
+    }
+
+    pub fn assert_edited_migrations(self, names: &[&str]) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.output.edited_migration_names == names,
+            "Assertion failed. Expected the migrations {:?} to be reported as edited, got {:?}",
+            names,
+            self.output.edited_migration_names
+        );
+
+        Ok(self)
+    }
+
+    pub fn into_output(self) -> ApplyMigrationsOutput {
+        self.output
+    }
+}
@@ -0,0 +1,79 @@
+use super::super::assertions::AssertionResult;
+use migration_core::{
+    api::GenericApi,
+    commands::{CreateMigrationInput, CreateMigrationOutput},
+};
+use std::path::{Path, PathBuf};
+
+pub struct CreateMigration<'a> {
+    api: &'a dyn GenericApi,
+    schema: &'a str,
+    migrations_directory: &'a Path,
+    migration_name: &'a str,
+    draft: bool,
+}
+
+impl<'a> CreateMigration<'a> {
+    pub fn new(api: &'a dyn GenericApi, name: &'a str, schema: &'a str, migrations_directory: &'a Path) -> Self {
+        CreateMigration {
+            api,
+            schema,
+            migrations_directory,
+            migration_name: name,
+            draft: false,
+        }
+    }
+
+    pub fn draft(mut self, draft: bool) -> Self {
+        self.draft = draft;
+        self
+    }
+
+    pub async fn send(self) -> Result<CreateMigrationAssertion<'a>, anyhow::Error> {
+        let input = CreateMigrationInput {
+            migrations_directory_path: self.migrations_directory.to_str().unwrap().to_owned(),
+            prisma_schema: self.schema.to_owned(),
+            migration_name: self.migration_name.to_owned(),
+            draft: self.draft,
+        };
+
+        let output = self.api.create_migration(&input).await?;
+
+        Ok(CreateMigrationAssertion {
+            output,
+            migrations_directory: self.migrations_directory,
+        })
+    }
+}
+
+pub struct CreateMigrationAssertion<'a> {
+    output: CreateMigrationOutput,
+    migrations_directory: &'a Path,
+}
+
+impl CreateMigrationAssertion<'_> {
+    /// Asserts that a migration was created, and returns the path to its directory.
+    pub fn migration_directory(&self) -> AssertionResult<PathBuf> {
+        let name = self
+            .output
+            .generated_migration_name
+            .as_ref()
+            .ok_or_else(|| anyhow::anyhow!("Assertion failed. Expected a migration to be created."))?;
+
+        Ok(self.migrations_directory.join(name))
+    }
+
+    pub fn assert_no_migration_created(self) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.output.generated_migration_name.is_none(),
+            "Assertion failed. Expected no migration to be created, got {:?}",
+            self.output.generated_migration_name
+        );
+
+        Ok(self)
+    }
+
+    pub fn into_output(self) -> CreateMigrationOutput {
+        self.output
+    }
+}
@@ -55,6 +55,7 @@ impl<'a> Infer<'a> {
             assume_applied_migrations: self.assume_applied_migrations,
             datamodel: self.datamodel,
             migration_id,
+            accepted_renames: Vec::new(),
         };
 
         let output = self.api.infer_migration_steps(&input).await?;
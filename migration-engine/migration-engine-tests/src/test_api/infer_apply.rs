@@ -1,4 +1,5 @@
 use super::super::{assertions::AssertionResult, unique_migration_id};
+use migration_connector::{DataMigrationStep, Rename};
 use migration_core::{
     api::GenericApi,
     commands::{ApplyMigrationInput, InferMigrationStepsInput, MigrationStepsResultOutput},
@@ -9,6 +10,9 @@ pub struct InferApply<'a> {
     schema: &'a str,
     migration_id: Option<String>,
     force: Option<bool>,
+    data_migration_steps: Vec<DataMigrationStep>,
+    accepted_renames: Vec<Rename>,
+    dry_run: bool,
 }
 
 impl<'a> InferApply<'a> {
@@ -18,6 +22,9 @@ impl<'a> InferApply<'a> {
             schema,
             migration_id: None,
             force: None,
+            data_migration_steps: Vec::new(),
+            accepted_renames: Vec::new(),
+            dry_run: false,
         }
     }
 
@@ -26,6 +33,21 @@ impl<'a> InferApply<'a> {
         self
     }
 
+    pub fn data_migration_steps(mut self, data_migration_steps: Vec<DataMigrationStep>) -> Self {
+        self.data_migration_steps = data_migration_steps;
+        self
+    }
+
+    pub fn accepted_renames(mut self, accepted_renames: Vec<Rename>) -> Self {
+        self.accepted_renames = accepted_renames;
+        self
+    }
+
+    pub fn dry_run(mut self, dry_run: bool) -> Self {
+        self.dry_run = dry_run;
+        self
+    }
+
     pub fn migration_id(mut self, migration_id: Option<impl Into<String>>) -> Self {
         self.migration_id = migration_id.map(Into::into);
         self
@@ -51,6 +73,7 @@ impl<'a> InferApply<'a> {
             datamodel: self.schema.to_owned(),
             assume_to_be_applied: Some(Vec::new()),
             assume_applied_migrations: None,
+            accepted_renames: self.accepted_renames.clone(),
         };
 
         let steps = self.api.infer_migration_steps(&input).await?.datamodel_steps;
@@ -59,6 +82,9 @@ impl<'a> InferApply<'a> {
             migration_id,
             steps,
             force: self.force,
+            data_migration_steps: self.data_migration_steps,
+            accepted_renames: self.accepted_renames,
+            dry_run: self.dry_run,
         };
 
         let migration_output = self.api.apply_migration(&input).await?;
@@ -0,0 +1,54 @@
+use super::super::assertions::AssertionResult;
+use migration_core::{
+    api::GenericApi,
+    commands::{RollbackMigrationsInput, RollbackMigrationsOutput},
+};
+use std::path::Path;
+
+pub struct RollbackMigrations<'a> {
+    api: &'a dyn GenericApi,
+    migrations_directory: &'a Path,
+    target_migration_name: &'a str,
+}
+
+impl<'a> RollbackMigrations<'a> {
+    pub fn new(api: &'a dyn GenericApi, migrations_directory: &'a Path, target_migration_name: &'a str) -> Self {
+        RollbackMigrations {
+            api,
+            migrations_directory,
+            target_migration_name,
+        }
+    }
+
+    pub async fn send(self) -> Result<RollbackMigrationsAssertion, anyhow::Error> {
+        let input = RollbackMigrationsInput {
+            migrations_directory_path: self.migrations_directory.to_str().unwrap().to_owned(),
+            target_migration_name: self.target_migration_name.to_owned(),
+        };
+
+        let output = self.api.rollback_migrations(&input).await?;
+
+        Ok(RollbackMigrationsAssertion { output })
+    }
+}
+
+pub struct RollbackMigrationsAssertion {
+    output: RollbackMigrationsOutput,
+}
+
+impl RollbackMigrationsAssertion {
+    pub fn assert_rolled_back_migrations(self, names: &[&str]) -> AssertionResult<Self> {
+        anyhow::ensure!(
+            self.output.rolled_back_migration_names == names,
+            "Assertion failed. Expected the migrations {:?} to be rolled back, got {:?}",
+            names,
+            self.output.rolled_back_migration_names
+        );
+
+        Ok(self)
+    }
+
+    pub fn into_output(self) -> RollbackMigrationsOutput {
+        self.output
+    }
+}
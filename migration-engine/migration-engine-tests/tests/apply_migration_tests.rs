@@ -174,3 +174,43 @@ async fn applying_an_already_applied_migration_must_return_an_error(api: &TestAp
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn dry_runs_render_the_script_without_applying_the_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int     @id
+            name String?
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).dry_run(true).send().await?.into_inner();
+    let script = output.script.expect("a dry run returns the script");
+
+    // SQLite redefines the table to make the column optional.
+    if api.is_sqlite() {
+        assert!(script.contains("PRAGMA foreign_keys=OFF;"), "{}", script);
+        assert!(script.contains("CREATE TABLE"), "{}", script);
+    } else {
+        assert!(script.contains("ALTER TABLE"), "{}", script);
+    }
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("name", |column| column.assert_is_required())
+    })?;
+
+    let migrations = api.migration_persistence().load_all().await?;
+
+    assert_eq!(migrations.len(), 1);
+
+    Ok(())
+}
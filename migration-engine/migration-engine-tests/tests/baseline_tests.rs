@@ -0,0 +1,100 @@
+use barrel::types;
+use migration_connector::MIGRATION_SCRIPT_FILENAME;
+use migration_engine_tests::*;
+
+#[test_each_connector]
+async fn baselining_records_the_existing_schema_as_an_applied_migration(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Cat", |t| {
+                t.add_column("id", types::primary());
+                t.add_column("name", types::text());
+            });
+        })
+        .await?;
+
+    let introspected_dm = r#"
+        model Cat {
+            id   Int    @id @default(autoincrement())
+            name String
+        }
+    "#;
+
+    let output = api
+        .baseline("baseline", introspected_dm, migrations_directory.path())
+        .await?;
+
+    let script = std::fs::read_to_string(
+        migrations_directory
+            .path()
+            .join(&output.baseline_migration_name)
+            .join(MIGRATION_SCRIPT_FILENAME),
+    )?;
+
+    assert!(script.contains("CREATE TABLE"), "{}", script);
+
+    let migrations = api.migration_persistence().load_all().await?;
+
+    assert_eq!(migrations.len(), 1);
+    assert_eq!(migrations[0].name, output.baseline_migration_name);
+    assert!(migrations[0].status.is_success());
+    assert!(migrations[0].datamodel_string.contains("model Cat"));
+
+    let drift = api.diagnose_drift().await?;
+
+    assert!(drift.drifts.is_empty(), "{:?}", drift.drifts);
+
+    // The baseline migration is not applied again.
+    api.apply_migrations(migrations_directory.path())
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    let dm = r#"
+        model Cat {
+            id      Int    @id @default(autoincrement())
+            name    String
+            hungry  Boolean?
+        }
+    "#;
+
+    let migration_directory = api
+        .create_migration("hungry_cats", dm, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+
+    let script = std::fs::read_to_string(migration_directory.join(MIGRATION_SCRIPT_FILENAME))?;
+
+    assert!(!script.contains("CREATE TABLE"), "{}", script);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn baselining_a_database_with_migrations_is_rejected(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.create_migration("initial", dm, migrations_directory.path())
+        .send()
+        .await?;
+    api.apply_migrations(migrations_directory.path()).send().await?;
+
+    let error = api
+        .baseline("baseline", dm, migrations_directory.path())
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("already has a migration history"), "{}", error);
+
+    Ok(())
+}
@@ -0,0 +1,198 @@
+use migration_connector::{DataMigrationPosition, DataMigrationStep};
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+use sql_migration_connector::PrettySqlMigrationStep;
+
+#[test_each_connector]
+async fn backfilling_an_optional_column_makes_it_safe_to_make_it_required(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id   String @id
+            name String
+            age  Int?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Test")
+        .value("id", "abc")
+        .value("name", "george")
+        .result_raw()
+        .await?;
+
+    api.insert("Test")
+        .value("id", "def")
+        .value("name", "georgina")
+        .value("age", 7)
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Test {
+            id   String @id
+            name String
+            age  Int
+        }
+    "#;
+
+    api.infer_apply(dm2)
+        .data_migration_steps(vec![DataMigrationStep::Backfill {
+            table: "Test".into(),
+            schema: None,
+            column: "age".into(),
+            value: serde_json::json!(30),
+            position: None,
+        }])
+        .send()
+        .await?
+        .assert_green()?;
+
+    api.assert_schema().await?.assert_table("Test", |table| {
+        table.assert_column("age", |column| column.assert_is_required())
+    })?;
+
+    let mut rows = api.select("Test").column("id").column("age").send_debug().await?;
+    rows.sort();
+
+    assert_eq!(
+        rows,
+        &[&[r#"Text("abc")"#, "Integer(30)"], &[r#"Text("def")"#, "Integer(7)"]]
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn raw_sql_data_migrations_run_before_the_changes_to_their_table(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model cat {
+            id       String  @id
+            name     String
+            nickname String?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("cat")
+        .value("id", "garfield")
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model cat {
+            id       String @id
+            name     String
+            nickname String
+        }
+    "#;
+
+    // The raw SQL is not inspected, so the migration has to be forced.
+    api.infer_apply(dm2)
+        .data_migration_steps(vec![DataMigrationStep::RawSql {
+            sql: "UPDATE cat SET nickname = name WHERE nickname IS NULL;".into(),
+            position: Some(DataMigrationPosition::BeforeTable { table: "cat".into() }),
+        }])
+        .force(Some(true))
+        .send()
+        .await?
+        .assert_no_error()?;
+
+    let rows = api.select("cat").column("id").column("nickname").send_debug().await?;
+
+    assert_eq!(rows, &[&[r#"Text("garfield")"#, r#"Text("Garfield")"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn data_migrations_can_run_after_the_changes_to_their_table(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model cat {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("cat")
+        .value("id", "garfield")
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model cat {
+            id       String  @id
+            name     String
+            nickname String?
+        }
+    "#;
+
+    // The column does not exist before the changes to the table.
+    api.infer_apply(dm2)
+        .data_migration_steps(vec![DataMigrationStep::RawSql {
+            sql: "UPDATE cat SET nickname = name;".into(),
+            position: Some(DataMigrationPosition::AfterTable { table: "cat".into() }),
+        }])
+        .send()
+        .await?
+        .assert_green()?;
+
+    let rows = api.select("cat").column("id").column("nickname").send_debug().await?;
+
+    assert_eq!(rows, &[&[r#"Text("garfield")"#, r#"Text("Garfield")"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn columns_are_only_made_required_in_place_with_a_data_migration(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Test {
+            id  String @id
+            age Int?
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Test {
+            id  String @id
+            age Int
+        }
+    "#;
+
+    let alters_column_in_place = |database_steps: serde_json::Value| -> bool {
+        serde_json::from_value::<Vec<PrettySqlMigrationStep>>(database_steps)
+            .unwrap()
+            .iter()
+            .any(|step| step.raw.contains("SET NOT NULL") || step.raw.contains("MODIFY"))
+    };
+
+    let output = api.infer(dm2).send().await?;
+
+    assert!(!alters_column_in_place(output.database_steps));
+
+    let output = api
+        .infer_apply(dm2)
+        .data_migration_steps(vec![DataMigrationStep::Backfill {
+            table: "Test".into(),
+            schema: None,
+            column: "age".into(),
+            value: serde_json::json!(30),
+            position: None,
+        }])
+        .send()
+        .await?
+        .assert_green()?
+        .into_inner();
+
+    assert!(alters_column_in_place(output.database_steps));
+
+    Ok(())
+}
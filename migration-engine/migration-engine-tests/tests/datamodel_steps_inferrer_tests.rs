@@ -20,6 +20,7 @@ fn infer_CreateModel_if_it_does_not_exist_yet() {
     let expected = &[
         MigrationStep::CreateModel(CreateModel {
             model: "Test".to_string(),
+            is_composite_type: false,
         }),
         MigrationStep::CreateField(CreateField {
             model: "Test".to_string(),
@@ -1411,6 +1412,59 @@ fn infer_UpdateArgument_on_type_alias() {
     assert_eq!(steps, expected);
 }
 
+#[test]
+fn infer_directive_replacement_for_positional_arguments() {
+    let dm1 = parse(
+        r##"
+        model Product {
+            id Int @id
+            price Decimal @db.Decimal(10, 2)
+        }
+    "##,
+    );
+
+    let dm2 = parse(
+        r##"
+        model Product {
+            id Int @id
+            price Decimal @db.Decimal(12, 2)
+        }
+    "##,
+    );
+
+    let steps = infer(&dm1, &dm2);
+
+    let directive_location = DirectiveLocation {
+        path: DirectivePath::Field {
+            model: "Product".to_owned(),
+            field: "price".to_owned(),
+        },
+        directive: "db.Decimal".to_owned(),
+    };
+    let argument_location = ArgumentLocation::Directive(directive_location.clone());
+
+    let expected = &[
+        MigrationStep::DeleteDirective(DeleteDirective {
+            location: directive_location.clone(),
+        }),
+        MigrationStep::CreateDirective(CreateDirective {
+            location: directive_location,
+        }),
+        MigrationStep::CreateArgument(CreateArgument {
+            location: argument_location.clone(),
+            argument: "".to_owned(),
+            value: MigrationExpression("12".to_owned()),
+        }),
+        MigrationStep::CreateArgument(CreateArgument {
+            location: argument_location,
+            argument: "".to_owned(),
+            value: MigrationExpression("2".to_owned()),
+        }),
+    ];
+
+    assert_eq!(steps, expected);
+}
+
 #[test]
 fn infer_CreateTypeAlias() {
     let dm1 = parse("");
@@ -1460,6 +1514,7 @@ fn infer_CreateTypeAlias() {
         }),
         MigrationStep::CreateModel(CreateModel {
             model: "User".to_string(),
+            is_composite_type: false,
         }),
         MigrationStep::CreateField(CreateField {
             model: "User".to_string(),
@@ -0,0 +1,144 @@
+use barrel::types;
+use migration_connector::{Drift, Migration, MigrationStatus};
+use migration_engine_tests::*;
+
+#[test_each_connector]
+async fn diagnose_drift_finds_nothing_after_migrating(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let output = api.diagnose_drift().await?;
+
+    assert!(output.drifts.is_empty(), "{:?}", output.drifts);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diagnose_drift_finds_changes_made_outside_of_migrations(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.barrel()
+        .execute(|migration| {
+            migration.change_table("Cat", |t| {
+                t.add_column("hotfix", types::integer().nullable(true));
+            });
+            migration.create_table("Dog", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await?;
+
+    let output = api.diagnose_drift().await?;
+
+    assert_eq!(
+        output.drifts,
+        &[
+            Drift::AddedTable { table: "Dog".into() },
+            Drift::AddedColumn {
+                table: "Cat".into(),
+                column: "hotfix".into()
+            },
+        ]
+    );
+    assert!(output.summary.contains("Added column `hotfix` on table `Cat`"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diagnose_drift_compares_with_an_empty_schema_without_migrations(api: &TestApi) -> TestResult {
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Dog", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await?;
+
+    let output = api.diagnose_drift().await?;
+
+    assert_eq!(output.drifts, &[Drift::AddedTable { table: "Dog".into() }]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diagnose_drift_finds_changes_made_before_a_migration_was_applied(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let migration_directory = api
+        .create_migration("initial", dm, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+    let migration_name = migration_directory.file_name().unwrap().to_str().unwrap().to_owned();
+
+    // The hotfix is part of the database when the migration is applied, but not of the history.
+    api.barrel()
+        .execute(|migration| {
+            migration.create_table("Dog", |t| {
+                t.add_column("id", types::primary());
+            });
+        })
+        .await?;
+
+    api.apply_migrations(migrations_directory.path())
+        .send()
+        .await?
+        .assert_applied_migrations(&[&migration_name])?;
+
+    let output = api.diagnose_drift().await?;
+
+    assert_eq!(output.drifts, &[Drift::AddedTable { table: "Dog".into() }]);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn diagnose_drift_errors_on_migrations_that_cannot_be_replayed(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    // Applied scripts used to be recorded without a database migration.
+    let mut legacy_migration = Migration::new("legacy-migration".to_owned());
+    legacy_migration.status = MigrationStatus::MigrationSuccess;
+    legacy_migration.applied = 1;
+    api.migration_persistence().create(legacy_migration).await?;
+
+    let error = format!("{:#}", api.diagnose_drift().await.unwrap_err());
+
+    assert!(
+        error.contains("The migration `legacy-migration` does not record what it applied"),
+        "{}",
+        error
+    );
+
+    Ok(())
+}
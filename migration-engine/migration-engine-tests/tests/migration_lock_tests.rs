@@ -0,0 +1,63 @@
+use migration_connector::MigrationConnector;
+use migration_engine_tests::*;
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+#[test_each_connector]
+async fn migration_engines_wait_for_the_migration_lock(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let lock = api.connector().acquire_migration_lock(Duration::from_secs(1)).await?;
+    let concurrent_engine = api.concurrent_engine(Duration::from_secs(1)).await;
+
+    let error = concurrent_engine.infer_apply(dm).send_user_facing().await.unwrap_err();
+
+    let expected_message = "Timed out after 1 seconds waiting for the migration lock. Another migration engine is changing the database, try again once it is done.";
+    let expected_error = user_facing_errors::Error::from(user_facing_errors::KnownError {
+        error_code: "P3004".into(),
+        message: expected_message.into(),
+        meta: serde_json::json!({ "timeout_seconds": 1 }),
+    });
+
+    assert_eq!(error, expected_error);
+
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    lock.release().await?;
+
+    concurrent_engine.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| Ok(table))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sqlite"))]
+async fn the_sqlite_lock_file_is_removed_on_release(api: &TestApi) -> TestResult {
+    let lock_file_path = match api.connection_info() {
+        quaint::prelude::ConnectionInfo::Sqlite { file_path, .. } => format!("{}.migration_lock", file_path),
+        _ => unreachable!(),
+    };
+
+    let lock = api.connector().acquire_migration_lock(Duration::from_secs(1)).await?;
+
+    assert!(std::path::Path::new(&lock_file_path).exists());
+
+    lock.release().await?;
+
+    assert!(!std::path::Path::new(&lock_file_path).exists());
+
+    // The lock can be taken again once its file is gone.
+    api.connector()
+        .acquire_migration_lock(Duration::from_secs(1))
+        .await?
+        .release()
+        .await?;
+
+    Ok(())
+}
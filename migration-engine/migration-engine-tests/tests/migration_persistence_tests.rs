@@ -53,6 +53,34 @@ async fn load_all_must_return_all_created_migrations(api: &TestApi) {
     assert_eq!(result, vec![migration1, migration2, migration3])
 }
 
+#[test_each_connector]
+async fn squash_must_replace_the_squashed_migrations_in_place(api: &TestApi) -> TestResult {
+    let persistence = api.migration_persistence();
+    let migration1 = persistence.create(Migration::new("migration_1".to_string())).await?;
+    let migration2 = persistence.create(Migration::new("migration_2".to_string())).await?;
+    persistence.create(Migration::new("migration_3".to_string())).await?;
+
+    let squashed = persistence
+        .squash(
+            &[migration1, migration2.clone()],
+            Migration::new("squashed".to_string()),
+        )
+        .await?;
+
+    assert_eq!(squashed.revision, migration2.revision);
+
+    let names: Vec<String> = persistence
+        .load_all()
+        .await?
+        .into_iter()
+        .map(|migration| migration.name)
+        .collect();
+
+    assert_eq!(names, &["squashed", "migration_3"]);
+
+    Ok(())
+}
+
 #[test_each_connector]
 async fn create_should_allow_to_create_a_new_migration(api: &TestApi) {
     let dm = r#"
@@ -149,3 +177,45 @@ async fn migration_is_already_applied_must_work(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector]
+async fn init_must_add_the_checksum_column_to_existing_migration_tables(api: &TestApi) -> TestResult {
+    let datetime_type = match api.sql_family() {
+        SqlFamily::Sqlite => barrel::types::date(),
+        SqlFamily::Postgres => barrel::types::custom("timestamp(3)"),
+        SqlFamily::Mysql => barrel::types::custom("datetime(3)"),
+    };
+
+    // The migration table as created before migrations directories existed.
+    api.barrel()
+        .execute(|migration| {
+            migration.drop_table(sql_migration_connector::MIGRATION_TABLE_NAME);
+            migration.create_table(sql_migration_connector::MIGRATION_TABLE_NAME, move |t| {
+                t.add_column("revision", barrel::types::primary());
+                t.add_column("name", barrel::types::text());
+                t.add_column("datamodel", barrel::types::text());
+                t.add_column("status", barrel::types::text());
+                t.add_column("applied", barrel::types::integer());
+                t.add_column("rolled_back", barrel::types::integer());
+                t.add_column("datamodel_steps", barrel::types::text());
+                t.add_column("database_migration", barrel::types::text());
+                t.add_column("errors", barrel::types::text());
+                t.add_column("started_at", datetime_type.clone());
+                t.add_column("finished_at", datetime_type.clone().nullable(true));
+            });
+        })
+        .await?;
+
+    let persistence = api.migration_persistence();
+    persistence.init().await?;
+
+    let mut migration = Migration::new("checksummed".to_string());
+    migration.checksum = Some("abc".to_string());
+    persistence.create(migration).await?;
+
+    let loaded = persistence.by_name("checksummed").await?.unwrap();
+
+    assert_eq!(loaded.checksum.as_deref(), Some("abc"));
+
+    Ok(())
+}
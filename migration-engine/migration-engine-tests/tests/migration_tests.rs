@@ -293,6 +293,7 @@ async fn changing_the_type_of_an_id_field_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -322,6 +323,7 @@ async fn changing_the_type_of_an_id_field_must_work(api: &TestApi) {
             },
             columns: vec!["b_id".into()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -377,6 +379,7 @@ async fn changing_a_relation_field_to_a_scalar_field_must_work(api: &TestApi) ->
                 },
                 columns: vec!["b".to_owned()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
             })
@@ -448,6 +451,7 @@ async fn changing_a_scalar_field_to_a_relation_field_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -519,6 +523,7 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
                 },
                 columns: vec![a_column.name.clone()],
                 referenced_table: "A".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
             },
@@ -530,6 +535,7 @@ async fn adding_a_many_to_many_relation_with_custom_name_must_work(api: &TestApi
                 },
                 columns: vec![b_column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade,
             }
@@ -579,6 +585,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![b_column.name.clone()],
                 referenced_table: "B".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::Cascade, // required relations can't set ON DELETE SET NULL
             },
@@ -590,6 +597,7 @@ async fn adding_an_inline_relation_must_result_in_a_foreign_key_in_the_model_tab
                 },
                 columns: vec![c_column.name.clone()],
                 referenced_table: "C".to_string(),
+                referenced_schema: None,
                 referenced_columns: vec!["id".to_string()],
                 on_delete_action: ForeignKeyAction::SetNull,
             }
@@ -626,6 +634,7 @@ async fn specifying_a_db_name_for_an_inline_relation_must_work(api: &TestApi) {
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -659,6 +668,7 @@ async fn adding_an_inline_relation_to_a_model_with_an_exotic_id_type(api: &TestA
             },
             columns: vec![column.name.clone()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -731,6 +741,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             },
             columns: vec!["b_id".to_string()],
             referenced_table: "B".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -759,6 +770,7 @@ async fn moving_an_inline_relation_to_the_other_side_must_work(api: &TestApi) ->
             },
             columns: vec!["a_id".to_string()],
             referenced_table: "A".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::Cascade,
         }]
@@ -1008,6 +1020,7 @@ async fn index_renaming_must_work(api: &TestApi) -> TestResult {
     if !api.is_sqlite() {
         let expected_steps = vec![SqlMigrationStep::AlterIndex(AlterIndex {
             table: "A".into(),
+            schema: None,
             index_new_name: "customNameA".into(),
             index_name: "customName".into(),
         })];
@@ -1061,6 +1074,7 @@ async fn index_renaming_must_work_when_renaming_to_default(api: &TestApi) {
     if !api.is_sqlite() {
         let expected_steps = vec![SqlMigrationStep::AlterIndex(AlterIndex {
             table: "A".into(),
+            schema: None,
             index_new_name: "A.field_secondField".into(),
             index_name: "customName".into(),
         })];
@@ -1111,6 +1125,7 @@ async fn index_renaming_must_work_when_renaming_to_custom(api: &TestApi) -> Test
     if !api.is_sqlite() {
         let expected_steps = &[SqlMigrationStep::AlterIndex(AlterIndex {
             table: "A".into(),
+            schema: None,
             index_name: "A.field_secondField".into(),
             index_new_name: "somethingCustom".into(),
         })];
@@ -1164,14 +1179,19 @@ async fn index_updates_with_rename_must_work(api: &TestApi) {
         let expected_steps = vec![
             SqlMigrationStep::DropIndex(DropIndex {
                 table: "A".into(),
+                schema: None,
                 name: "customName".into(),
             }),
             SqlMigrationStep::CreateIndex(CreateIndex {
                 table: "A".into(),
+                schema: None,
                 index: Index {
                     name: "customNameA".into(),
                     columns: vec!["field".into(), "id".into()],
                     tpe: IndexType::Unique,
+                    column_options: vec![],
+                    algorithm: None,
+                    predicate: None,
                 },
             }),
         ];
@@ -1231,6 +1251,7 @@ async fn reserved_sql_key_words_must_work(api: &TestApi) {
             },
             columns: vec!["parent_id".to_string()],
             referenced_table: "Group".to_string(),
+            referenced_schema: None,
             referenced_columns: vec!["id".to_string()],
             on_delete_action: ForeignKeyAction::SetNull,
         }]
@@ -1419,6 +1440,9 @@ async fn foreign_keys_of_inline_one_to_one_relations_have_a_unique_constraint(ap
         name: "Box_cat_id".into(),
         columns: vec!["cat_id".into()],
         tpe: IndexType::Unique,
+        column_options: vec![],
+        algorithm: None,
+        predicate: None,
     }];
 
     assert_eq!(box_table.indices, expected_indexes);
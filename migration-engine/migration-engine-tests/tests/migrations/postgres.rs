@@ -1,5 +1,5 @@
 use migration_engine_tests::*;
-use sql_schema_describer::{ColumnArity, ColumnTypeFamily};
+use sql_schema_describer::{ColumnArity, ColumnTypeFamily, DefaultValue, IndexAlgorithm, SortOrder};
 
 #[test_each_connector(tags("postgres"))]
 async fn enums_can_be_dropped_on_postgres(api: &TestApi) -> TestResult {
@@ -65,3 +65,380 @@ async fn adding_a_scalar_list_for_a_model_with_id_type_int_must_work(api: &TestA
     assert_eq!(enum_column.tpe.family, ColumnTypeFamily::Enum("Status".to_owned()));
     assert_eq!(enum_column.tpe.arity, ColumnArity::List);
 }
+
+#[test_each_connector(tags("postgres"))]
+async fn native_types_are_rendered_and_idempotent(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        datasource pg {
+            provider = "postgres"
+            url = "postgres://localhost:5432"
+        }
+
+        model Cat {
+            id Int @id @db.SmallInt
+            name String @db.VarChar(120)
+            weight Decimal @db.Decimal(5, 2)
+            birthday DateTime @db.Timestamptz(6)
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let table = schema.table_bang("Cat");
+    assert_eq!(table.column_bang("id").tpe.full_data_type, "int2");
+    assert_eq!(table.column_bang("name").tpe.full_data_type, "varchar");
+    assert_eq!(table.column_bang("name").tpe.character_maximum_length, Some(120));
+    assert_eq!(table.column_bang("weight").tpe.full_data_type, "numeric");
+    assert_eq!(table.column_bang("birthday").tpe.full_data_type, "timestamptz");
+
+    api.infer_apply(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        datasource pg {
+            provider = "postgres"
+            url = "postgres://localhost:5432"
+        }
+
+        model Cat {
+            id Int @id @db.SmallInt
+            name String @db.VarChar(200)
+            weight Decimal @db.Decimal(5, 2)
+            birthday DateTime @db.Timestamptz(6)
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("name", |col| col.assert_type_is_string())
+    })?;
+
+    let schema = api.describe_database().await?;
+    assert_eq!(
+        schema
+            .table_bang("Cat")
+            .column_bang("name")
+            .tpe
+            .character_maximum_length,
+        Some(200)
+    );
+
+    let dm3 = r#"
+        datasource pg {
+            provider = "postgres"
+            url = "postgres://localhost:5432"
+        }
+
+        model Cat {
+            id Int @id @db.SmallInt
+            name String @db.VarChar(200)
+            weight Decimal @db.Decimal(7, 3)
+            birthday DateTime @db.Timestamptz(3)
+        }
+    "#;
+
+    api.infer_apply(dm3).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let table = schema.table_bang("Cat");
+    assert_eq!(table.column_bang("weight").tpe.numeric_precision, Some(7));
+    assert_eq!(table.column_bang("weight").tpe.numeric_scale, Some(3));
+    assert_eq!(table.column_bang("birthday").tpe.datetime_precision, Some(3));
+
+    api.infer_apply(dm3).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn check_constraints_can_be_created_and_dropped_on_postgres(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price >= 0", name: "price_not_negative")
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let check_constraints = &schema.table_bang("Product").check_constraints;
+
+    assert_eq!(check_constraints.len(), 1);
+    assert_eq!(check_constraints[0].name, "price_not_negative");
+
+    // The expression is normalized by the database, that must not trigger a migration.
+    api.infer_apply(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int @id
+            price Int
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).force(Some(true)).send().await?.into_inner();
+
+    assert_eq!(
+        output.warnings,
+        &[migration_connector::MigrationWarning {
+            description: "You are about to drop the check constraint `price_not_negative` on the `Product` table. The values it validated will not be checked anymore.".into(),
+        }]
+    );
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.table_bang("Product").check_constraints.is_empty());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn changing_check_constraint_expressions_migrates_them_on_postgres(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            discount Int
+
+            @@check("price >= 0 AND discount >= 0", name: "amounts_not_negative")
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+    api.infer_apply(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Product {
+            id       Int @id
+            price    Int
+            discount Int
+
+            @@check("price > 0 AND discount >= 0", name: "amounts_not_negative")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let check_constraints = &schema.table_bang("Product").check_constraints;
+
+    assert_eq!(check_constraints.len(), 1);
+    assert_eq!(check_constraints[0].expression, "((price > 0) AND (discount >= 0))");
+
+    api.infer_apply(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn index_options_are_migrated_and_idempotent_on_postgres(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        datasource pg {
+            provider = "postgres"
+            url = "postgres://localhost:5432"
+        }
+
+        model Post {
+            id        Int     @id
+            title     String
+            published Boolean
+            tags      Json
+
+            @@index([title(sort: Desc)], name: "Post_title", where: "published")
+            @@index([tags], name: "Post_tags", type: Gin)
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let table = schema.table_bang("Post");
+    let title_index = table.indices.iter().find(|index| index.name == "Post_title").unwrap();
+    let tags_index = table.indices.iter().find(|index| index.name == "Post_tags").unwrap();
+
+    assert_eq!(title_index.column_options(0).sort_order, SortOrder::Desc);
+    assert!(title_index.predicate.is_some());
+    assert_eq!(tags_index.algorithm, Some(IndexAlgorithm::Gin));
+
+    // The predicate is normalized by the database, that must not trigger a migration.
+    api.infer_apply(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm_changed_predicate = r#"
+        datasource pg {
+            provider = "postgres"
+            url = "postgres://localhost:5432"
+        }
+
+        model Post {
+            id        Int     @id
+            title     String
+            published Boolean
+            tags      Json
+
+            @@index([title(sort: Desc)], name: "Post_title", where: "NOT published")
+            @@index([tags], name: "Post_tags", type: Gin)
+        }
+    "#;
+
+    api.infer_apply(dm_changed_predicate).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let title_index = schema
+        .table_bang("Post")
+        .indices
+        .iter()
+        .find(|index| index.name == "Post_title")
+        .unwrap();
+
+    assert_eq!(title_index.predicate.as_deref(), Some("(NOT published)"));
+
+    api.infer_apply(dm_changed_predicate)
+        .send()
+        .await?
+        .assert_green()?
+        .assert_no_steps()?;
+
+    let dm2 = r#"
+        datasource pg {
+            provider = "postgres"
+            url = "postgres://localhost:5432"
+        }
+
+        model Post {
+            id        Int     @id
+            title     String
+            published Boolean
+            tags      Json
+
+            @@index([title], name: "Post_title")
+            @@index([tags], name: "Post_tags")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let table = schema.table_bang("Post");
+    let title_index = table.indices.iter().find(|index| index.name == "Post_title").unwrap();
+    let tags_index = table.indices.iter().find(|index| index.name == "Post_tags").unwrap();
+
+    assert!(!title_index.has_options());
+    assert!(!tags_index.has_options());
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn constraint_names_can_be_mapped_and_renamed_on_postgres(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model User {
+            id    Int    @id(map: "pk_users")
+            email String @unique(map: "uq_users_email")
+            posts Post[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id], map: "fk_posts_author")
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+    api.assert_schema()
+        .await?
+        .assert_table("User", |table| {
+            table
+                .assert_pk(|pk| pk.assert_constraint_name("pk_users"))?
+                .assert_index_on_columns(&["email"], |idx| idx.assert_name("uq_users_email"))
+        })?
+        .assert_table("Post", |table| {
+            table.assert_fk_on_columns(&["authorId"], |fk| fk.assert_constraint_name("fk_posts_author"))
+        })?;
+
+    api.infer_apply(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model User {
+            id    Int    @id(map: "users_pk")
+            email String @unique(map: "users_email_uq")
+            posts Post[]
+        }
+
+        model Post {
+            id       Int  @id
+            authorId Int
+            author   User @relation(fields: [authorId], references: [id], map: "posts_author_fk")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+    api.assert_schema()
+        .await?
+        .assert_table("User", |table| {
+            table
+                .assert_pk(|pk| pk.assert_constraint_name("users_pk"))?
+                .assert_index_on_columns(&["email"], |idx| idx.assert_name("users_email_uq"))
+        })?
+        .assert_table("Post", |table| {
+            table.assert_fk_on_columns(&["authorId"], |fk| fk.assert_constraint_name("posts_author_fk"))
+        })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn dbgenerated_expressions_are_rendered_and_idempotent_on_postgres(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id    Int    @id
+            token String @default(dbgenerated("md5(random()::text)"))
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("token", |col| {
+            col.assert_default(Some(DefaultValue::DBGENERATED("md5((random())::text)".to_owned())))
+        })
+    })?;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn changing_dbgenerated_expressions_migrates_the_default_on_postgres(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id    Int    @id
+            token String @default(dbgenerated("md5(random()::text)"))
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id    Int    @id
+            token String @default(dbgenerated("md5(clock_timestamp()::text)"))
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("token", |col| {
+            col.assert_default(Some(DefaultValue::DBGENERATED(
+                "md5((clock_timestamp())::text)".to_owned(),
+            )))
+        })
+    })?;
+
+    api.infer_apply(dm2).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
@@ -1,4 +1,5 @@
 use migration_engine_tests::sql::*;
+use sql_schema_describer::ColumnTypeFamily;
 use std::borrow::Cow;
 
 #[test_each_connector(tags("sql"))]
@@ -388,3 +389,171 @@ async fn dropping_mutually_referencing_tables_works(api: &TestApi) -> TestResult
 
     Ok(())
 }
+
+#[test_each_connector(tags("sql"))]
+async fn bigint_and_bytes_fields_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Account {
+            id Int @id
+            views BigInt
+            avatar Bytes?
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Account", |table| {
+        table
+            .assert_column("views", |col| col.assert_type_is_int())?
+            .assert_column("avatar", |col| col.assert_type_family(ColumnTypeFamily::Binary))
+    })?;
+
+    api.infer_apply(dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn decimal_fields_are_idempotent(api: &TestApi) -> TestResult {
+    let dm = format!(
+        r#"
+            {}
+
+            model Account {{
+                id Int @id
+                balance Decimal
+                fee Decimal @db.Decimal(10, 2)
+            }}
+        "#,
+        api.datasource()
+    );
+
+    api.infer_apply(&dm).send().await?.assert_green()?;
+
+    api.assert_schema().await?.assert_table("Account", |table| {
+        table
+            .assert_column("balance", |col| col.assert_type_family(ColumnTypeFamily::Float))?
+            .assert_column("fee", |col| col.assert_type_family(ColumnTypeFamily::Float))
+    })?;
+
+    api.infer_apply(&dm).send().await?.assert_green()?.assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn renamed_fields_keep_their_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let insert = quaint::ast::Insert::single_into(api.render_table_name("Cat"))
+        .value("id", "the-id")
+        .value("name", "Garfield");
+    api.database().execute(insert.into()).await?;
+
+    let dm2 = r#"
+        model Cat {
+            id String @id
+            fullName String
+        }
+    "#;
+
+    let rename = migration_connector::Rename::Column {
+        table: "Cat".into(),
+        from: "name".into(),
+        to: "fullName".into(),
+    };
+
+    // The rename is only proposed until it is accepted in the migration input.
+    let output = api.infer(dm2).send().await?;
+
+    assert_eq!(output.proposed_renames, &[rename.clone()]);
+
+    api.infer_apply(dm2)
+        .accepted_renames(vec![rename])
+        .send()
+        .await?
+        .assert_green()?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table
+            .assert_does_not_have_column("name")?
+            .assert_column("fullName", |col| col.assert_type_is_string()?.assert_is_required())
+    })?;
+
+    let rows = api
+        .database()
+        .query(
+            quaint::ast::Select::from_table(api.render_table_name("Cat"))
+                .column("fullName")
+                .into(),
+        )
+        .await?;
+
+    assert_eq!(rows.into_single()?.get("fullName").unwrap().as_str(), Some("Garfield"));
+
+    Ok(())
+}
+
+#[test_each_connector(tags("sql"))]
+async fn renamed_models_keep_their_data(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let insert = quaint::ast::Insert::single_into(api.render_table_name("Cat"))
+        .value("id", "the-id")
+        .value("name", "Garfield");
+    api.database().execute(insert.into()).await?;
+
+    let dm2 = r#"
+        model Kitten {
+            id String @id
+            name String
+        }
+    "#;
+
+    let rename = migration_connector::Rename::Table {
+        from: "Cat".into(),
+        to: "Kitten".into(),
+    };
+
+    let output = api.infer(dm2).send().await?;
+
+    assert_eq!(output.proposed_renames, &[rename.clone()]);
+
+    api.infer_apply(dm2)
+        .accepted_renames(vec![rename])
+        .send()
+        .await?
+        .assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(1)?
+        .assert_table("Kitten", |table| table.assert_has_column("name"))?;
+
+    let rows = api
+        .database()
+        .query(
+            quaint::ast::Select::from_table(api.render_table_name("Kitten"))
+                .column("name")
+                .into(),
+        )
+        .await?;
+
+    assert_eq!(rows.into_single()?.get("name").unwrap().as_str(), Some("Garfield"));
+
+    Ok(())
+}
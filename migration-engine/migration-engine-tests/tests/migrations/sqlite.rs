@@ -73,3 +73,51 @@ async fn sqlite_must_recreate_multi_field_indexes(api: &TestApi) -> TestResult {
 
     Ok(())
 }
+
+#[test_each_connector(tags("sqlite"))]
+async fn check_constraints_survive_table_redefinitions_on_sqlite(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price >= 0", name: "price_not_negative")
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+    api.infer_apply(dm1).send().await?.assert_green()?.assert_no_steps()?;
+
+    let dm2 = r#"
+        model Product {
+            id    Int @id
+            price Int
+            name  String?
+
+            @@check("price >= 0", name: "price_not_negative")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    // Dropping a column redefines the table on SQLite.
+    let dm3 = r#"
+        model Product {
+            id    Int @id
+            price Int
+
+            @@check("price >= 0", name: "price_not_negative")
+        }
+    "#;
+
+    api.infer_apply(dm3).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+    let check_constraints = &schema.table_bang("Product").check_constraints;
+
+    assert_eq!(check_constraints.len(), 1);
+    assert_eq!(check_constraints[0].name, "price_not_negative");
+    assert_eq!(check_constraints[0].expression, "price >= 0");
+
+    Ok(())
+}
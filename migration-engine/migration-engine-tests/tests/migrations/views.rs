@@ -0,0 +1,98 @@
+use migration_engine_tests::sql::*;
+use quaint::prelude::SqlFamily;
+
+#[test_each_connector]
+async fn views_with_a_definition_are_created(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@map("cats")
+        }
+
+        model CatName {
+            id   Int    @id
+            name String
+
+            @@view("SELECT id, name FROM cats")
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.get_table("CatName").is_none());
+    assert!(schema.get_view("CatName").is_some());
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@map("cats")
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.get_view("CatName").is_none());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn views_without_a_definition_are_left_alone(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@map("cats")
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    let create_view = match api.sql_family() {
+        SqlFamily::Mysql => format!(
+            "CREATE VIEW `{0}`.`CatName` AS SELECT id, name FROM `{0}`.`cats`",
+            api.schema_name()
+        ),
+        _ => format!(
+            "CREATE VIEW \"{0}\".\"CatName\" AS SELECT id, name FROM cats",
+            api.schema_name()
+        ),
+    };
+
+    api.database().query_raw(&create_view, &[]).await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+            age  Int?
+
+            @@map("cats")
+        }
+
+        model CatName {
+            id   Int    @id
+            name String
+
+            @@view
+        }
+    "#;
+
+    api.infer_apply(dm2).send().await?.assert_green()?;
+
+    let schema = api.describe_database().await?;
+
+    assert!(schema.get_table("CatName").is_none());
+    assert!(schema.get_view("CatName").is_some());
+
+    Ok(())
+}
@@ -0,0 +1,338 @@
+use migration_connector::{
+    read_provider_from_lock_file, write_migration_lock_file, DOWN_SCRIPT_FILENAME, MIGRATION_SCRIPT_FILENAME,
+};
+use migration_engine_tests::*;
+
+#[test_each_connector]
+async fn create_migration_writes_a_script_and_apply_migrations_runs_it(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    let migration_directory = api
+        .create_migration("initial", dm, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+
+    let script = std::fs::read_to_string(migration_directory.join(MIGRATION_SCRIPT_FILENAME))?;
+
+    assert!(script.contains("CREATE TABLE"));
+    assert!(read_provider_from_lock_file(migrations_directory.path())?.is_some());
+
+    // Creating a migration does not apply it.
+    api.assert_schema().await?.assert_tables_count(0)?;
+
+    let migration_name = migration_directory.file_name().unwrap().to_str().unwrap().to_owned();
+
+    api.apply_migrations(migrations_directory.path())
+        .send()
+        .await?
+        .assert_applied_migrations(&[&migration_name])?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?;
+
+    api.apply_migrations(migrations_directory.path())
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?;
+
+    api.create_migration("nothing_changed", dm, migrations_directory.path())
+        .send()
+        .await?
+        .assert_no_migration_created()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn create_migration_starts_from_the_datamodel_of_the_last_applied_migration(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@map("cats")
+        }
+
+        model CatName {
+            id   Int    @id
+            name String
+
+            @@view("SELECT id, name FROM cats")
+        }
+    "#;
+
+    api.infer_apply(dm).send().await?.assert_green()?;
+
+    // The view is managed by the applied datamodel, so it is not created again.
+    api.create_migration("nothing_changed", dm, migrations_directory.path())
+        .send()
+        .await?
+        .assert_no_migration_created()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn apply_migrations_respects_hand_edits(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let migration_directory = api
+        .create_migration("initial", dm, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+
+    let script_path = migration_directory.join(MIGRATION_SCRIPT_FILENAME);
+    let mut script = std::fs::read_to_string(&script_path)?;
+    script.push_str("-- Added by hand; not part of the schema.\nCREATE TABLE hand_written (id INTEGER PRIMARY KEY);\n");
+    std::fs::write(&script_path, &script)?;
+
+    api.apply_migrations(migrations_directory.path()).send().await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("id"))?
+        .assert_table("hand_written", |table| table.assert_has_column("id"))?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn apply_migrations_reports_scripts_edited_after_they_were_applied(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let migration_directory = api
+        .create_migration("initial", dm, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+
+    api.apply_migrations(migrations_directory.path()).send().await?;
+
+    let script_path = migration_directory.join(MIGRATION_SCRIPT_FILENAME);
+    let script = std::fs::read_to_string(&script_path)?;
+    std::fs::write(&script_path, format!("-- Edited\n{}", script))?;
+
+    let migration_name = migration_directory.file_name().unwrap().to_str().unwrap().to_owned();
+
+    api.apply_migrations(migrations_directory.path())
+        .send()
+        .await?
+        .assert_applied_migrations(&[])?
+        .assert_edited_migrations(&[&migration_name])?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn migrations_directories_for_another_provider_are_rejected(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    write_migration_lock_file(migrations_directory.path(), "not-a-provider")?;
+
+    let error = api
+        .apply_migrations(migrations_directory.path())
+        .send()
+        .await
+        .map(drop)
+        .unwrap_err();
+
+    assert!(error
+        .to_string()
+        .contains("The migrations directory was created for `not-a-provider`"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rollback_migrations_runs_the_down_scripts_back_to_the_target(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let initial_directory = api
+        .create_migration("initial", dm1, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+
+    let dm2 = r#"
+        model Cat {
+            id Int @id
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.apply_migrations(migrations_directory.path()).send().await?;
+
+    let dogs_directory = api
+        .create_migration("dogs", dm2, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+
+    let down_script = std::fs::read_to_string(dogs_directory.join(DOWN_SCRIPT_FILENAME))?;
+
+    assert!(down_script.contains("DROP TABLE"));
+    // The table is empty when the down script is written, but it may not be when the script runs.
+    assert!(
+        down_script
+            .starts_with("-- Warning: You are about to drop the table `Dog`. All the data it holds will be lost."),
+        "{}",
+        down_script
+    );
+
+    api.apply_migrations(migrations_directory.path()).send().await?;
+    api.assert_schema().await?.assert_tables_count(2)?;
+
+    let initial_migration_name = initial_directory.file_name().unwrap().to_str().unwrap().to_owned();
+    let dogs_migration_name = dogs_directory.file_name().unwrap().to_str().unwrap().to_owned();
+
+    api.rollback_migrations(migrations_directory.path(), &initial_migration_name)
+        .send()
+        .await?
+        .assert_rolled_back_migrations(&[&dogs_migration_name])?;
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(1)?
+        .assert_table("Cat", |table| table.assert_has_column("id"))?;
+
+    // The rolled back migration is applied again.
+    api.apply_migrations(migrations_directory.path())
+        .send()
+        .await?
+        .assert_applied_migrations(&[&dogs_migration_name])?;
+
+    api.assert_schema().await?.assert_tables_count(2)?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn down_scripts_start_with_the_rollback_warnings(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm1 = r#"
+        model Cat {
+            id  Int  @id
+            age Int?
+        }
+    "#;
+
+    api.create_migration("initial", dm1, migrations_directory.path())
+        .send()
+        .await?;
+    api.apply_migrations(migrations_directory.path()).send().await?;
+
+    let insert = quaint::ast::Insert::single_into(api.render_table_name("Cat"))
+        .value("id", 1)
+        .value("age", 7);
+    api.database().execute(insert.into()).await?;
+
+    let dm2 = r#"
+        model Cat {
+            id  Int     @id
+            age String?
+        }
+    "#;
+
+    let migration_directory = api
+        .create_migration("age_as_text", dm2, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+
+    let down_script = std::fs::read_to_string(migration_directory.join(DOWN_SCRIPT_FILENAME))?;
+
+    assert!(
+        down_script.starts_with("-- Warning: You are about to alter the column `age` on the `Cat` table"),
+        "{}",
+        down_script
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn rollback_migrations_requires_down_scripts(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm1 = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let initial_directory = api
+        .create_migration("initial", dm1, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+
+    api.apply_migrations(migrations_directory.path()).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String?
+        }
+    "#;
+
+    let names_directory = api
+        .create_migration("names", dm2, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+
+    api.apply_migrations(migrations_directory.path()).send().await?;
+
+    std::fs::remove_file(names_directory.join(DOWN_SCRIPT_FILENAME))?;
+
+    let initial_migration_name = initial_directory.file_name().unwrap().to_str().unwrap().to_owned();
+
+    let error = api
+        .rollback_migrations(migrations_directory.path(), &initial_migration_name)
+        .send()
+        .await
+        .map(drop)
+        .unwrap_err();
+
+    assert!(error.to_string().contains("has no down.sql to roll it back"));
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_has_column("name"))?;
+
+    Ok(())
+}
@@ -0,0 +1,296 @@
+use migration_connector::{MigrationWarning, MIGRATION_SCRIPT_FILENAME};
+use migration_engine_tests::*;
+use quaint::prelude::SqlFamily;
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn online_migrations_create_indexes_without_locking_the_table(api: &TestApi) -> TestResult {
+    let api = api.with_online_migrations().await;
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.create_migration("initial", dm1, migrations_directory.path())
+        .send()
+        .await?;
+    api.apply_migrations(migrations_directory.path()).send().await?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+
+            @@index([name])
+        }
+    "#;
+
+    let migration_directory = api
+        .create_migration("index_names", dm2, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+
+    let script = std::fs::read_to_string(migration_directory.join(MIGRATION_SCRIPT_FILENAME))?;
+
+    match api.sql_family() {
+        SqlFamily::Postgres => assert!(script.contains("INDEX CONCURRENTLY"), "{}", script),
+        _ => assert!(script.contains("ALGORITHM=INPLACE LOCK=NONE"), "{}", script),
+    }
+
+    api.apply_migrations(migrations_directory.path()).send().await?;
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_index_on_columns(&["name"], |index| Ok(index))
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn online_migrations_add_required_columns_with_a_default_in_steps(api: &TestApi) -> TestResult {
+    let api = api.with_online_migrations().await;
+
+    let dm1 = r#"
+        model Cat {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", "garfield")
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id        String   @id
+            name      String
+            adoptedAt DateTime @default(now())
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).send().await?.assert_green()?.into_inner();
+    let rendered_steps = serde_json::to_string(&output.database_steps)?;
+
+    // Volatile defaults rewrite the table on all versions.
+    assert!(rendered_steps.contains("LIMIT 10000"), "{}", rendered_steps);
+    assert!(rendered_steps.contains("VALIDATE CONSTRAINT"), "{}", rendered_steps);
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("adoptedAt", |column| column.assert_is_required())
+    })?;
+
+    let rows = api.select("Cat").column("id").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text("garfield")"#]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn online_migration_scripts_commit_the_backfill_outside_of_a_transaction(api: &TestApi) -> TestResult {
+    let api = api.with_online_migrations().await;
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm1 = r#"
+        model Cat {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.create_migration("initial", dm1, migrations_directory.path())
+        .send()
+        .await?;
+    api.apply_migrations(migrations_directory.path()).send().await?;
+
+    api.insert("Cat")
+        .value("id", "garfield")
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id        String   @id
+            name      String
+            adoptedAt DateTime @default(now())
+        }
+    "#;
+
+    let migration_directory = api
+        .create_migration("adopted_at", dm2, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+
+    let script_path = migration_directory.join(MIGRATION_SCRIPT_FILENAME);
+    let script = std::fs::read_to_string(&script_path)?;
+
+    assert!(script.contains("LIMIT 10000"), "{}", script);
+
+    // The statements before the failing one stay applied, the script does not run in one transaction.
+    std::fs::write(&script_path, format!("{}\nSELECT 1 / 0;\n", script))?;
+
+    assert!(api.apply_migrations(migrations_directory.path()).send().await.is_err());
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("adoptedAt", |column| column.assert_is_required())
+    })?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres"))]
+async fn online_migrations_add_required_columns_with_a_constant_default_in_steps_before_postgres_11(
+    api: &TestApi,
+) -> TestResult {
+    let api = api.with_online_migrations().await;
+
+    let dm1 = r#"
+        model Cat {
+            id   String @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", "garfield")
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id    String @id
+            name  String
+            lives Int    @default(9)
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).send().await?.assert_green()?.into_inner();
+    let rendered_steps = serde_json::to_string(&output.database_steps)?;
+    let before_postgres_11 = api.connector_name() == "postgres9" || api.connector_name() == "postgres";
+
+    assert_eq!(
+        rendered_steps.contains("VALIDATE CONSTRAINT"),
+        before_postgres_11,
+        "{}",
+        rendered_steps
+    );
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("lives", |column| column.assert_is_required()?.assert_type_is_int())
+    })?;
+
+    let rows = api.select("Cat").column("id").column("lives").send_debug().await?;
+    assert_eq!(rows, &[&[r#"Text("garfield")"#, "Integer(9)"]]);
+
+    Ok(())
+}
+
+#[test_each_connector(tags("postgres", "mysql"))]
+async fn online_migrations_warn_about_exclusive_locks(api: &TestApi) -> TestResult {
+    let api = api.with_online_migrations().await;
+
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+
+        model Human {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    api.insert("Cat")
+        .value("id", 1)
+        .value("name", "Garfield")
+        .result_raw()
+        .await?;
+
+    let dm2 = r#"
+        model Cat {
+            id      Int    @id
+            name    String
+            ownerId Int?
+            owner   Human? @relation(fields: [ownerId], references: [id])
+        }
+
+        model Human {
+            id   Int   @id
+            cats Cat[]
+        }
+    "#;
+
+    let output = api.infer_apply(dm2).send().await?.into_inner();
+
+    assert_eq!(
+        output.warnings,
+        &[MigrationWarning {
+            description: "Adding a foreign key on the columns (ownerId) takes an exclusive lock on the `Cat` table, which holds 1 rows. Writes to the table will wait until it completes.".into(),
+        }]
+    );
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_does_not_have_column("ownerId"))?;
+
+    api.infer_apply(dm2).force(Some(true)).send().await?;
+
+    api.assert_schema()
+        .await?
+        .assert_table("Cat", |table| table.assert_fk_on_columns(&["ownerId"], |fk| Ok(fk)))?;
+
+    Ok(())
+}
+
+#[test_each_connector(tags("mysql"))]
+async fn online_migrations_change_column_types_with_the_default_algorithm_on_mysql(api: &TestApi) -> TestResult {
+    let api = api.with_online_migrations().await;
+
+    let dm1 = r#"
+        model Cat {
+            id  Int @id
+            age Int
+        }
+    "#;
+
+    api.infer_apply(dm1).send().await?.assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id  Int    @id
+            age String
+        }
+    "#;
+
+    let output = api
+        .infer_apply(dm2)
+        .force(Some(true))
+        .send()
+        .await?
+        .assert_no_error()?
+        .into_inner();
+    let rendered_steps = serde_json::to_string(&output.database_steps)?;
+
+    assert!(!rendered_steps.contains("ALGORITHM=INPLACE"), "{}", rendered_steps);
+
+    api.assert_schema().await?.assert_table("Cat", |table| {
+        table.assert_column("age", |column| column.assert_type_is_string())
+    })?;
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use datamodel::SchemaFiles;
+use migration_core::api::{pretty_print_datamodel_errors, RpcApi};
+use migration_engine_tests::sql::*;
+
+#[test_each_connector]
+async fn schemas_split_across_files_can_be_migrated(api: &TestApi) -> TestResult {
+    let schema_directory = tempfile::tempdir()?;
+
+    std::fs::write(
+        schema_directory.path().join("users.prisma"),
+        r#"
+            model User {
+                id    Int    @id
+                posts Post[]
+            }
+        "#,
+    )?;
+    std::fs::write(schema_directory.path().join("empty.prisma"), "")?;
+    std::fs::write(
+        schema_directory.path().join("posts.prisma"),
+        r#"
+            model Post {
+                id       Int  @id
+                authorId Int
+                author   User @relation(fields: [authorId], references: [id])
+            }
+        "#,
+    )?;
+
+    let schema_files = SchemaFiles::load(schema_directory.path())?;
+
+    api.infer_apply(schema_files.schema()).send().await?.assert_green()?;
+
+    api.assert_schema()
+        .await?
+        .assert_tables_count(2)?
+        .assert_table("User", |table| table.assert_has_column("id"))?
+        .assert_table("Post", |table| {
+            table.assert_fk_on_columns(&["authorId"], |fk| fk.assert_references("User", &["id"]))
+        })?;
+
+    Ok(())
+}
+
+#[tokio::test]
+async fn datamodel_errors_must_point_at_the_file_they_originate_from() -> TestResult {
+    let schema_directory = tempfile::tempdir()?;
+
+    std::fs::write(
+        schema_directory.path().join("datasource.prisma"),
+        format!(
+            r#"
+                datasource db {{
+                    provider = "sqlite"
+                    url      = "{}"
+                }}
+            "#,
+            sqlite_test_url("datamodel_errors_must_point_at_the_file_they_originate_from")
+        ),
+    )?;
+    std::fs::write(
+        schema_directory.path().join("models.prisma"),
+        r#"
+            model Cat {
+                id    Int @id
+                owner Owner
+            }
+        "#,
+    )?;
+
+    let schema_files = SchemaFiles::load(schema_directory.path())?;
+
+    let errors = match RpcApi::new(schema_files.schema()).await {
+        Err(migration_core::error::Error::DatamodelError(errors)) => errors,
+        other => panic!("Expected a datamodel error, got {:?}", other.map(drop)),
+    };
+
+    let rendered = pretty_print_datamodel_errors(&errors, &schema_files)?;
+
+    assert!(rendered.contains("models.prisma:4"), "{}", rendered);
+    assert!(!rendered.contains("datasource.prisma"), "{}", rendered);
+
+    Ok(())
+}
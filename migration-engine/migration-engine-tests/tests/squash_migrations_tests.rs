@@ -0,0 +1,149 @@
+use migration_engine_tests::sql::*;
+use pretty_assertions::assert_eq;
+
+#[test_each_connector]
+async fn squashing_migrations_keeps_the_history_consistent(api: &TestApi) -> TestResult {
+    let dm1 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+        }
+    "#;
+
+    api.infer_apply(dm1)
+        .migration_id(Some("mig01"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let dm2 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+            age  Int?
+        }
+
+        model Dog {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm2)
+        .migration_id(Some("mig02"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let dm3 = r#"
+        model Cat {
+            id   Int    @id
+            name String
+            age  Int?
+        }
+    "#;
+
+    api.infer_apply(dm3)
+        .migration_id(Some("mig03"))
+        .force(Some(true))
+        .send()
+        .await?
+        .assert_no_error()?;
+
+    let dm4 = r#"
+        model Cat {
+            id    Int    @id
+            name  String
+            age   Int?
+            color String?
+        }
+    "#;
+
+    api.infer_apply(dm4)
+        .migration_id(Some("mig04"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let schema_before_squashing = api.describe_database().await?;
+
+    let output = api.squash_migrations("mig01", "mig03", "squashed").await?;
+
+    assert_eq!(output.squashed_migrations, &["mig01", "mig02", "mig03"]);
+
+    let migrations = api.migration_persistence().load_all().await?;
+    let names: Vec<&str> = migrations.iter().map(|migration| migration.name.as_str()).collect();
+
+    assert_eq!(names, &["squashed", "mig04"]);
+    assert!(migrations[0].status.is_success());
+
+    // Squashing does not touch the database.
+    api.assert_schema().await?.assert_equals(&schema_before_squashing)?;
+
+    // The next migrations are inferred from the squashed history.
+    api.infer_apply(dm4)
+        .migration_id(Some("mig05"))
+        .send()
+        .await?
+        .assert_green()?
+        .assert_no_steps()?;
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashing_migrations_that_are_not_applied_is_rejected(api: &TestApi) -> TestResult {
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    api.infer_apply(dm)
+        .migration_id(Some("mig01"))
+        .send()
+        .await?
+        .assert_green()?;
+
+    let error = api
+        .squash_migrations("mig01", "mig02", "squashed")
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("The migration `mig02` is not applied"), "{}", error);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn squashing_migrations_from_a_migrations_directory_is_rejected(api: &TestApi) -> TestResult {
+    let migrations_directory = tempfile::tempdir()?;
+
+    let dm = r#"
+        model Cat {
+            id Int @id
+        }
+    "#;
+
+    let migration_directory = api
+        .create_migration("initial", dm, migrations_directory.path())
+        .send()
+        .await?
+        .migration_directory()?;
+    let migration_name = migration_directory.file_name().unwrap().to_str().unwrap();
+
+    api.apply_migrations(migrations_directory.path())
+        .send()
+        .await?
+        .assert_applied_migrations(&[migration_name])?;
+
+    let error = api
+        .squash_migrations(migration_name, migration_name, "squashed")
+        .await
+        .unwrap_err()
+        .to_string();
+
+    assert!(error.contains("cannot be squashed"), "{}", error);
+
+    Ok(())
+}
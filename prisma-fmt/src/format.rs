@@ -1,12 +1,17 @@
-use datamodel::ast::reformat::Reformatter;
+use datamodel::{ast::reformat::Reformatter, SchemaFiles};
 use std::{
     fs::{self, File},
     io::{self, BufWriter, Read},
+    path::Path,
 };
 
 use crate::FormatOpts;
 
 pub fn run(opts: FormatOpts) {
+    if let Some(directory) = opts.input.as_ref().filter(|input| input.is_dir()) {
+        return format_directory(directory, opts.tabwidth);
+    }
+
     let datamodel_string = match opts.input {
         Some(file_name) => {
             fs::read_to_string(&file_name).expect(&format!("Unable to open file {}", file_name.display()))
@@ -34,3 +39,16 @@ pub fn run(opts: FormatOpts) {
         }
     }
 }
+
+/// Formats every schema file of a directory in place.
+fn format_directory(directory: &Path, tabwidth: usize) {
+    let schema_files =
+        SchemaFiles::load(directory).expect(&format!("Unable to read schema files from {}", directory.display()));
+
+    for file in schema_files.files() {
+        let mut formatted: Vec<u8> = Vec::new();
+
+        Reformatter::new_for_file(&file.content, schema_files.schema()).reformat_to(&mut formatted, tabwidth);
+        fs::write(&file.name, formatted).expect(&format!("Unable to write file {}", file.name));
+    }
+}
@@ -1,14 +1,26 @@
 use crate::{LintOpts, MiniError};
-use datamodel::error::DatamodelError;
+use datamodel::{error::DatamodelError, SchemaFiles};
 use serde_json;
 use std::io::{self, Read};
 
 pub fn run(opts: LintOpts) {
-    let mut datamodel_string = String::new();
+    let schema_files = opts
+        .input
+        .as_ref()
+        .map(|path| SchemaFiles::load(path).expect(&format!("Unable to read schema files from {}", path.display())));
 
-    io::stdin()
-        .read_to_string(&mut datamodel_string)
-        .expect("Unable to read from stdin.");
+    let datamodel_string = match &schema_files {
+        Some(schema_files) => schema_files.schema().to_owned(),
+        None => {
+            let mut buf = String::new();
+
+            io::stdin()
+                .read_to_string(&mut buf)
+                .expect("Unable to read from stdin.");
+
+            buf
+        }
+    };
 
     let datamodel_result = if opts.no_env_errors {
         datamodel::parse_datamodel_and_ignore_env_errors(&datamodel_string)
@@ -21,10 +33,18 @@ pub fn run(opts: LintOpts) {
             let mini_errors: Vec<MiniError> = err
                 .errors
                 .iter()
-                .map(|err: &DatamodelError| MiniError {
-                    start: err.span().start,
-                    end: err.span().end,
-                    text: format!("{}", err),
+                .map(|err: &DatamodelError| {
+                    let (file, span) = match schema_files.as_ref().and_then(|files| files.file_for_span(err.span())) {
+                        Some((file, span)) => (Some(file.name.clone()), span),
+                        None => (None, err.span()),
+                    };
+
+                    MiniError {
+                        file,
+                        start: span.start,
+                        end: span.end,
+                        text: format!("{}", err),
+                    }
                 })
                 .collect();
 
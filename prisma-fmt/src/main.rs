@@ -11,12 +11,17 @@ pub struct LintOpts {
     /// If set, silences all `environment variable not found` errors
     #[structopt(long)]
     no_env_errors: bool,
+    /// Specifies the schema file, or the directory of schema files, to lint. If none is given,
+    /// the input is read from STDIN
+    #[structopt(short = "i", long)]
+    input: Option<PathBuf>,
 }
 
 #[derive(Debug, StructOpt, Clone)]
 pub struct FormatOpts {
     /// Specifies the input file to use. If none is given, the input is read
-    /// from STDIN
+    /// from STDIN. If it is a directory, all schema files in it are
+    /// formatted in place
     #[structopt(short = "i", long)]
     input: Option<PathBuf>,
     /// Specifies the output file to use. If none is given, the output is
@@ -40,6 +45,9 @@ pub enum FmtOpts {
 
 #[derive(serde::Serialize)]
 pub struct MiniError {
+    /// The schema file the error originates from, when linting files.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub file: Option<String>,
     pub start: usize,
     pub end: usize,
     pub text: String,
@@ -0,0 +1,26 @@
+use super::{Filter, ScalarCondition};
+use prisma_models::{ScalarFieldRef, TypeIdentifier};
+
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+/// Filtering on a value inside a composite field. From a GraphQL point of view
+/// this is in the head of the query:
+///
+/// ```graphql
+/// findManyUser(where: { address: { city: "Berlin" } })
+/// ````
+///
+/// This translates to the `address` column with a JSON path `city` and a
+/// condition where the value at the path equals `"Berlin"`.
+pub struct CompositeFilter {
+    pub field: ScalarFieldRef,
+    pub path: Vec<String>,
+    /// The type of the sub-field at the path.
+    pub type_identifier: TypeIdentifier,
+    pub condition: ScalarCondition,
+}
+
+impl From<CompositeFilter> for Filter {
+    fn from(cf: CompositeFilter) -> Self {
+        Filter::Composite(cf)
+    }
+}
@@ -4,6 +4,7 @@
 //! [ScalarCompare](/query-connector/trait.ScalarCompare.html) and
 //! [RelationCompare](/query-connector/trait.RelationCompare.html).
 
+mod composite;
 mod id_filter;
 mod list;
 mod relation;
@@ -12,6 +13,7 @@ mod scalar;
 use prisma_models::dml;
 use prisma_models::prelude::*;
 
+pub use composite::*;
 pub use id_filter::*;
 pub use list::*;
 pub use relation::*;
@@ -26,6 +28,7 @@ pub enum Filter {
     ScalarList(ScalarListFilter),
     OneRelationIsNull(OneRelationIsNullFilter),
     Relation(RelationFilter),
+    Composite(CompositeFilter),
     NodeSubscription,
     BoolFilter(bool),
     Empty,
@@ -167,6 +170,7 @@ pub fn test_data_model() -> InternalDataModelRef {
             is_auto_generated_int_id: false,
             behaviour: None,
             internal_enum: None,
+            composite_type: None,
             arity: dml::FieldArity::Optional,
             default_value: None,
             db_name: None,
@@ -181,6 +185,7 @@ pub fn test_data_model() -> InternalDataModelRef {
             is_auto_generated_int_id: false,
             behaviour: None,
             internal_enum: None,
+            composite_type: None,
             arity: dml::FieldArity::Optional,
             default_value: None,
             db_name: None,
@@ -208,6 +213,7 @@ pub fn test_data_model() -> InternalDataModelRef {
         is_auto_generated_int_id: false,
         behaviour: None,
         internal_enum: None,
+        composite_type: None,
         arity: dml::FieldArity::Optional,
         default_value: None,
         db_name: None,
@@ -217,6 +223,8 @@ pub fn test_data_model() -> InternalDataModelRef {
         ModelTemplate {
             name: "User".to_owned(),
             is_embedded: false,
+            is_view: false,
+            schema: None,
             fields: user_field_templates,
             manifestation: None,
             id_field_names: vec![],
@@ -225,6 +233,8 @@ pub fn test_data_model() -> InternalDataModelRef {
         ModelTemplate {
             name: "Site".to_owned(),
             is_embedded: false,
+            is_view: false,
+            schema: None,
             fields: site_field_templates,
             manifestation: None,
             id_field_names: vec![],
@@ -236,6 +246,7 @@ pub fn test_data_model() -> InternalDataModelRef {
         models: model_templates,
         relations: vec![],
         enums: vec![],
+        composite_types: vec![],
         version: None,
     };
 
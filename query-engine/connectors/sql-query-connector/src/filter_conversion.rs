@@ -118,6 +118,7 @@ impl AliasedCondition for Filter {
             Filter::Scalar(filter) => filter.aliased_cond(alias),
             Filter::OneRelationIsNull(filter) => filter.aliased_cond(alias),
             Filter::Relation(filter) => filter.aliased_cond(alias),
+            Filter::Composite(filter) => filter.aliased_cond(alias),
             Filter::BoolFilter(b) => {
                 if b {
                     ConditionTree::NoCondition
@@ -134,53 +135,6 @@ impl AliasedCondition for Filter {
 impl AliasedCondition for ScalarFilter {
     /// Conversion from a `ScalarFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
-        fn compare(comparable: impl Comparable<'static>, cond: ScalarCondition) -> ConditionTree<'static> {
-            let condition = match cond {
-                ScalarCondition::Equals(PrismaValue::Null) => comparable.is_null(),
-                ScalarCondition::NotEquals(PrismaValue::Null) => comparable.is_not_null(),
-                ScalarCondition::Equals(value) => comparable.equals(value),
-                ScalarCondition::NotEquals(value) => comparable.not_equals(value),
-                ScalarCondition::Contains(value) => comparable.like(format!("{}", value)),
-                ScalarCondition::NotContains(value) => comparable.not_like(format!("{}", value)),
-                ScalarCondition::StartsWith(value) => comparable.begins_with(format!("{}", value)),
-                ScalarCondition::NotStartsWith(value) => comparable.not_begins_with(format!("{}", value)),
-                ScalarCondition::EndsWith(value) => comparable.ends_into(format!("{}", value)),
-                ScalarCondition::NotEndsWith(value) => comparable.not_ends_into(format!("{}", value)),
-                ScalarCondition::LessThan(value) => comparable.less_than(value),
-                ScalarCondition::LessThanOrEquals(value) => comparable.less_than_or_equals(value),
-                ScalarCondition::GreaterThan(value) => comparable.greater_than(value),
-                ScalarCondition::GreaterThanOrEquals(value) => comparable.greater_than_or_equals(value),
-                ScalarCondition::In(values) => match values.split_first() {
-                    Some((PrismaValue::List(_), _)) => {
-                        let mut sql_values = Values::with_capacity(values.len());
-
-                        for pv in values {
-                            let list_value = pv.into_list().unwrap();
-                            sql_values.push(list_value);
-                        }
-
-                        comparable.in_selection(sql_values)
-                    }
-                    _ => comparable.in_selection(values),
-                },
-                ScalarCondition::NotIn(values) => match values.split_first() {
-                    Some((PrismaValue::List(_), _)) => {
-                        let mut sql_values = Values::with_capacity(values.len());
-
-                        for pv in values {
-                            let list_value = pv.into_list().unwrap();
-                            sql_values.push(list_value);
-                        }
-
-                        comparable.not_in_selection(sql_values)
-                    }
-                    _ => comparable.not_in_selection(values),
-                },
-            };
-
-            ConditionTree::single(condition)
-        }
-
         match (alias, self.projection) {
             (Some(alias), ScalarProjection::Single(field)) => {
                 compare(field.as_column().table(alias.to_string(None)), self.condition)
@@ -203,6 +157,52 @@ impl AliasedCondition for ScalarFilter {
     }
 }
 
+impl AliasedCondition for CompositeFilter {
+    /// Conversion from a `CompositeFilter` to a query condition tree. The condition applies to the value at the
+    /// JSON path inside the composite column. Aliased when in a nested `SELECT`.
+    fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
+        let column = match alias {
+            Some(alias) => self.field.as_column().table(alias.to_string(None)),
+            None => self.field.as_column(),
+        };
+
+        let path = JsonPath::string(format!("$.{}", self.path.join(".")));
+
+        match self.type_identifier {
+            // Extracted as text, numbers would compare lexicographically and booleans would not match their
+            // parameters. The values are compared as JSON values instead.
+            TypeIdentifier::Int | TypeIdentifier::BigInt | TypeIdentifier::Float | TypeIdentifier::Boolean => compare(
+                Expression::from(json_extract(column, path, false)),
+                json_condition(self.condition),
+            ),
+            _ => compare(Expression::from(json_extract(column, path, true)), self.condition),
+        }
+    }
+}
+
+/// Converts the values of a condition to JSON values, to compare them with values extracted from JSON documents.
+/// `null` stays as is, it matches absent values.
+fn json_condition(cond: ScalarCondition) -> ScalarCondition {
+    fn json_value(value: PrismaValue) -> PrismaValue {
+        match value {
+            PrismaValue::Null => PrismaValue::Null,
+            value => PrismaValue::Json(serde_json::to_string(&value).unwrap()),
+        }
+    }
+
+    match cond {
+        ScalarCondition::Equals(value) => ScalarCondition::Equals(json_value(value)),
+        ScalarCondition::NotEquals(value) => ScalarCondition::NotEquals(json_value(value)),
+        ScalarCondition::LessThan(value) => ScalarCondition::LessThan(json_value(value)),
+        ScalarCondition::LessThanOrEquals(value) => ScalarCondition::LessThanOrEquals(json_value(value)),
+        ScalarCondition::GreaterThan(value) => ScalarCondition::GreaterThan(json_value(value)),
+        ScalarCondition::GreaterThanOrEquals(value) => ScalarCondition::GreaterThanOrEquals(json_value(value)),
+        ScalarCondition::In(values) => ScalarCondition::In(values.into_iter().map(json_value).collect()),
+        ScalarCondition::NotIn(values) => ScalarCondition::NotIn(values.into_iter().map(json_value).collect()),
+        cond => cond,
+    }
+}
+
 impl AliasedCondition for RelationFilter {
     /// Conversion from a `RelationFilter` to a query condition tree. Aliased when in a nested `SELECT`.
     fn aliased_cond(self, alias: Option<Alias>) -> ConditionTree<'static> {
@@ -344,3 +344,51 @@ impl AliasedCondition for OneRelationIsNullFilter {
         ConditionTree::single(condition)
     }
 }
+
+/// Applies a scalar condition to anything comparable, e.g. a column, a row of columns or an expression.
+fn compare(comparable: impl Comparable<'static>, cond: ScalarCondition) -> ConditionTree<'static> {
+    let condition = match cond {
+        ScalarCondition::Equals(PrismaValue::Null) => comparable.is_null(),
+        ScalarCondition::NotEquals(PrismaValue::Null) => comparable.is_not_null(),
+        ScalarCondition::Equals(value) => comparable.equals(value),
+        ScalarCondition::NotEquals(value) => comparable.not_equals(value),
+        ScalarCondition::Contains(value) => comparable.like(format!("{}", value)),
+        ScalarCondition::NotContains(value) => comparable.not_like(format!("{}", value)),
+        ScalarCondition::StartsWith(value) => comparable.begins_with(format!("{}", value)),
+        ScalarCondition::NotStartsWith(value) => comparable.not_begins_with(format!("{}", value)),
+        ScalarCondition::EndsWith(value) => comparable.ends_into(format!("{}", value)),
+        ScalarCondition::NotEndsWith(value) => comparable.not_ends_into(format!("{}", value)),
+        ScalarCondition::LessThan(value) => comparable.less_than(value),
+        ScalarCondition::LessThanOrEquals(value) => comparable.less_than_or_equals(value),
+        ScalarCondition::GreaterThan(value) => comparable.greater_than(value),
+        ScalarCondition::GreaterThanOrEquals(value) => comparable.greater_than_or_equals(value),
+        ScalarCondition::In(values) => match values.split_first() {
+            Some((PrismaValue::List(_), _)) => {
+                let mut sql_values = Values::with_capacity(values.len());
+
+                for pv in values {
+                    let list_value = pv.into_list().unwrap();
+                    sql_values.push(list_value);
+                }
+
+                comparable.in_selection(sql_values)
+            }
+            _ => comparable.in_selection(values),
+        },
+        ScalarCondition::NotIn(values) => match values.split_first() {
+            Some((PrismaValue::List(_), _)) => {
+                let mut sql_values = Values::with_capacity(values.len());
+
+                for pv in values {
+                    let list_value = pv.into_list().unwrap();
+                    sql_values.push(list_value);
+                }
+
+                comparable.not_in_selection(sql_values)
+            }
+            _ => comparable.not_in_selection(values),
+        },
+    };
+
+    ConditionTree::single(condition)
+}
@@ -155,6 +155,46 @@ pub fn row_value_to_prisma_value(p_value: Value, type_identifier: &TypeIdentifie
                 return Err(SqlError::ConversionError(error.into()));
             }
         },
+        TypeIdentifier::Decimal => match p_value {
+            Value::Null => PrismaValue::Null,
+            Value::Real(d) => PrismaValue::Decimal(d),
+            Value::Integer(i) => PrismaValue::Decimal(Decimal::from(i)),
+            Value::Text(_) | Value::Bytes(_) => PrismaValue::Decimal(
+                p_value
+                    .as_str()
+                    .expect("text/bytes as str")
+                    .parse()
+                    .map_err(|err: rust_decimal::Error| SqlError::ColumnReadFailure(err.into()))?,
+            ),
+            _ => {
+                let error = io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    "Decimal value not stored as decimal, int or text",
+                );
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::BigInt => match p_value {
+            Value::Null => PrismaValue::Null,
+            Value::Integer(i) => PrismaValue::BigInt(i),
+            Value::Bytes(bytes) => PrismaValue::BigInt(interpret_bytes_as_i64(&bytes)),
+            Value::Text(txt) => PrismaValue::BigInt(
+                i64::from_str(txt.trim_start_matches('\0')).map_err(|err| SqlError::ConversionError(err.into()))?,
+            ),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "BigInt value not stored as int or text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
+        TypeIdentifier::Bytes => match p_value {
+            Value::Null => PrismaValue::Null,
+            Value::Bytes(bytes) => PrismaValue::Bytes(bytes.into_owned()),
+            Value::Text(txt) => PrismaValue::Bytes(txt.into_owned().into_bytes()),
+            _ => {
+                let error = io::Error::new(io::ErrorKind::InvalidData, "Bytes value not stored as bytes or text");
+                return Err(SqlError::ConversionError(error.into()));
+            }
+        },
         TypeIdentifier::Int => match p_value {
             Value::Integer(i) => PrismaValue::Int(i),
             Value::Bytes(bytes) => PrismaValue::Int(interpret_bytes_as_i64(&bytes)),
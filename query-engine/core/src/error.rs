@@ -32,6 +32,9 @@ pub enum CoreError {
 
     #[fail(display = "{}", _0)]
     InterpreterError(InterpreterError),
+
+    #[fail(display = "The query executor is closed, the query engine is shutting down.")]
+    ExecutorClosed,
 }
 
 impl From<QueryGraphBuilderError> for CoreError {
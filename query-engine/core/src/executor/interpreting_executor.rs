@@ -1,13 +1,26 @@
 use super::{pipeline::QueryPipeline, QueryExecutor};
-use crate::{Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, Response, Responses};
+use crate::{
+    CoreError, Operation, QueryGraphBuilder, QueryInterpreter, QuerySchemaRef, Response, Responses, ValidatedOperation,
+};
 use async_trait::async_trait;
 use connector::{ConnectionLike, Connector};
+use futures::{
+    channel::oneshot,
+    future::{self, Either, FutureExt, Shared},
+    pin_mut, Future,
+};
+use std::sync::{Arc, Mutex, RwLock};
 
 /// Central query executor and main entry point into the query core.
 pub struct InterpretingExecutor<C> {
-    connector: C,
+    /// Taken on close. The connector and its connections are dropped with the last operation using it.
+    connector: RwLock<Option<Arc<C>>>,
     primary_connector: &'static str,
     force_transactions: bool,
+    /// Resolves as soon as the executor is closed.
+    closed: Shared<oneshot::Receiver<()>>,
+    /// Dropped on close, resolving `closed`.
+    close_sender: Mutex<Option<oneshot::Sender<()>>>,
 }
 
 // Todo:
@@ -17,10 +30,24 @@ where
     C: Connector + Send + Sync,
 {
     pub fn new(connector: C, primary_connector: &'static str, force_transactions: bool) -> Self {
+        let (close_sender, closed) = oneshot::channel();
+
         InterpretingExecutor {
-            connector,
+            connector: RwLock::new(Some(Arc::new(connector))),
             primary_connector,
             force_transactions,
+            closed: closed.shared(),
+            close_sender: Mutex::new(Some(close_sender)),
+        }
+    }
+
+    /// Runs a pipeline until it finishes or the executor is closed.
+    async fn interruptible(&self, pipeline: impl Future<Output = crate::Result<Response>>) -> crate::Result<Response> {
+        pin_mut!(pipeline);
+
+        match future::select(pipeline, self.closed.clone()).await {
+            Either::Left((result, _)) => result,
+            Either::Right(_) => Err(CoreError::ExecutorClosed),
         }
     }
 }
@@ -31,10 +58,27 @@ where
     C: Connector + Send + Sync,
 {
     async fn execute(&self, operation: Operation, query_schema: QuerySchemaRef) -> crate::Result<Responses> {
-        let conn = self.connector.get_connection().await?;
+        // Parse and validate the query document.
+        let operation = QueryGraphBuilder::new(query_schema.clone()).validate(operation)?;
 
-        // Parse, validate, and extract query graphs from query document.
-        let (query, info) = QueryGraphBuilder::new(query_schema).build(operation)?;
+        self.execute_validated(operation, query_schema).await
+    }
+
+    async fn execute_validated(
+        &self,
+        operation: ValidatedOperation,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<Responses> {
+        let connector = self
+            .connector
+            .read()
+            .unwrap()
+            .clone()
+            .ok_or(CoreError::ExecutorClosed)?;
+        let conn = connector.get_connection().await?;
+
+        // Extract query graphs from the validated query document.
+        let (query, info) = QueryGraphBuilder::new(query_schema).build_validated(operation)?;
 
         // Create pipelines for all separate queries
         let mut responses = Responses::with_capacity(1);
@@ -44,8 +88,11 @@ where
             let tx = conn.start_transaction().await?;
 
             let interpreter = QueryInterpreter::new(ConnectionLike::Transaction(tx.as_ref()));
-            let result = QueryPipeline::new(query, interpreter, info).execute().await;
+            let result = self
+                .interruptible(QueryPipeline::new(query, interpreter, info).execute())
+                .await;
 
+            // Interrupted operations are rolled back as well.
             if result.is_ok() {
                 tx.commit().await?;
             } else {
@@ -55,7 +102,8 @@ where
             result?
         } else {
             let interpreter = QueryInterpreter::new(ConnectionLike::Connection(conn.as_ref()));
-            QueryPipeline::new(query, interpreter, info).execute().await?
+            self.interruptible(QueryPipeline::new(query, interpreter, info).execute())
+                .await?
         };
 
         match result {
@@ -69,4 +117,9 @@ where
     fn primary_connector(&self) -> &'static str {
         self.primary_connector
     }
+
+    fn close(&self) {
+        self.close_sender.lock().unwrap().take();
+        self.connector.write().unwrap().take();
+    }
 }
@@ -10,12 +10,26 @@ mod pipeline;
 
 pub use interpreting_executor::*;
 
-use crate::{query_document::Operation, response_ir::Responses, schema::QuerySchemaRef};
+use crate::{
+    query_document::Operation, query_graph_builder::ValidatedOperation, response_ir::Responses, schema::QuerySchemaRef,
+};
 use async_trait::async_trait;
 
 #[async_trait]
 pub trait QueryExecutor {
     async fn execute(&self, query_doc: Operation, query_schema: QuerySchemaRef) -> crate::Result<Responses>;
 
+    /// Executes an operation that has already been validated against the given query schema.
+    async fn execute_validated(
+        &self,
+        operation: ValidatedOperation,
+        query_schema: QuerySchemaRef,
+    ) -> crate::Result<Responses>;
+
     fn primary_connector(&self) -> &'static str;
+
+    /// Closes the executor for shutdown. Running operations are interrupted, rolling back their transactions,
+    /// and the connections of the executor are closed as soon as the interrupted operations released them.
+    /// Operations executed afterwards fail.
+    fn close(&self);
 }
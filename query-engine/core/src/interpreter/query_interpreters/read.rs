@@ -41,6 +41,7 @@ fn read_one<'conn, 'tx>(
                 Ok(QueryResult::RecordSelection(RecordSelection {
                     name: query.name,
                     fields: query.selection_order,
+                    composite_selections: query.composite_selections,
                     scalars: records,
                     nested,
                     model_id,
@@ -76,6 +77,7 @@ fn read_many<'a, 'b>(
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
             fields: query.selection_order,
+            composite_selections: query.composite_selections,
             query_arguments: query.args,
             model_id,
             scalars,
@@ -122,6 +124,7 @@ fn read_related<'a, 'b>(
         Ok(QueryResult::RecordSelection(RecordSelection {
             name: query.name,
             fields: query.selection_order,
+            composite_selections: query.composite_selections,
             query_arguments: query.args,
             model_id,
             scalars,
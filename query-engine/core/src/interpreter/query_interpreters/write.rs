@@ -3,7 +3,8 @@ use crate::{
     query_ast::*,
     QueryResult,
 };
-use connector::{ConnectionLike, WriteOperations};
+use connector::{ConnectionLike, Filter, IdFilter, ReadOperations, WriteOperations};
+use prisma_models::{ModelProjection, ScalarFieldRef};
 use prisma_value::PrismaValue;
 
 pub async fn execute<'a, 'b>(
@@ -37,12 +38,59 @@ async fn create_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: CreateRecord) ->
     Ok(QueryResult::Id(Some(res)))
 }
 
-async fn update_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: UpdateRecord) -> InterpretationResult<QueryResult> {
+async fn update_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, mut q: UpdateRecord) -> InterpretationResult<QueryResult> {
+    if !q.composite_updates.is_empty() {
+        apply_composite_updates(tx, &mut q).await?;
+    }
+
     let mut res = tx.update_records(&q.model, q.record_filter, q.args).await?;
 
     Ok(QueryResult::Id(res.pop()))
 }
 
+/// Composite values are stored as a whole. Partial updates are merged into the current
+/// values of the record, which are then written as regular arguments of the update.
+async fn apply_composite_updates<'a, 'b>(
+    tx: &'a ConnectionLike<'a, 'b>,
+    q: &mut UpdateRecord,
+) -> InterpretationResult<()> {
+    let filter = match q.record_filter.selectors {
+        Some(ref selectors) => Filter::and(vec![q.record_filter.filter.clone(), selectors.clone().filter()]),
+        None => q.record_filter.filter.clone(),
+    };
+
+    let fields: Vec<ScalarFieldRef> = q.composite_updates.iter().map(|(field, _)| field.clone()).collect();
+    let projection = ModelProjection::new(fields.iter().map(|field| field.clone().into()).collect());
+
+    // Without a record, the update itself determines the outcome (e.g. "record not found").
+    let record = match tx.get_single_record(&q.model, &filter, &projection).await? {
+        Some(record) => record,
+        None => return Ok(()),
+    };
+
+    for (field, update) in std::mem::replace(&mut q.composite_updates, vec![]) {
+        let current = record.get_field_value(field.db_name())?;
+        let mut value = match current {
+            PrismaValue::Json(s) | PrismaValue::String(s) => serde_json::from_str(s).map_err(|err| {
+                InterpreterError::InterpretationError(
+                    format!("Unable to parse composite field '{}' as JSON: {}", field.name, err),
+                    None,
+                )
+            })?,
+            _ => serde_json::Value::Null,
+        };
+
+        update.apply(&mut value).map_err(|err| {
+            InterpreterError::InterpretationError(format!("Composite field '{}': {}", field.name, err), None)
+        })?;
+
+        q.args
+            .insert(field.db_name().clone(), PrismaValue::Json(value.to_string()));
+    }
+
+    Ok(())
+}
+
 async fn delete_one<'a, 'b>(tx: &'a ConnectionLike<'a, 'b>, q: DeleteRecord) -> InterpretationResult<QueryResult> {
     // We need to ensure that we have a record finder, else we delete everything (conversion to empty filter).
     let filter = match q.record_filter {
@@ -2,7 +2,7 @@
 use super::FilteredQuery;
 use connector::{filter::Filter, QueryArguments};
 use prisma_models::prelude::*;
-use std::fmt::Display;
+use std::{collections::HashMap, fmt::Display};
 
 #[derive(Debug, Clone)]
 pub enum ReadQuery {
@@ -101,6 +101,7 @@ pub struct RecordQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub composite_selections: CompositeSelections,
 }
 
 #[derive(Debug, Clone)]
@@ -112,6 +113,7 @@ pub struct ManyRecordsQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub composite_selections: CompositeSelections,
 }
 
 #[derive(Debug, Clone)]
@@ -123,12 +125,27 @@ pub struct RelatedRecordsQuery {
     pub selected_fields: ModelProjection,
     pub nested: Vec<ReadQuery>,
     pub selection_order: Vec<String>,
+    pub composite_selections: CompositeSelections,
 
     /// Fields and values of the parent to satisfy the relation query without
     /// relying on the parent result passed by the interpreter.
     pub parent_projections: Option<Vec<RecordProjection>>,
 }
 
+/// Sub-selections of composite fields, keyed by the name of the selected composite field.
+pub type CompositeSelections = HashMap<String, CompositeSelection>;
+
+/// The selected sub-fields of a composite field.
+/// Composite values are read as a whole, the selection only shapes the response.
+#[derive(Debug, Default, Clone)]
+pub struct CompositeSelection {
+    /// Ordered list of selected sub-field names.
+    pub fields: Vec<String>,
+
+    /// Selections of nested composite sub-fields.
+    pub nested: CompositeSelections,
+}
+
 #[derive(Debug, Clone)]
 pub struct AggregateRecordsQuery {
     pub name: String,
@@ -136,6 +136,48 @@ pub struct UpdateRecord {
     pub model: ModelRef,
     pub record_filter: RecordFilter,
     pub args: WriteArgs,
+
+    /// Partial updates of composite fields, which are merged into the current values of the record.
+    pub composite_updates: Vec<(ScalarFieldRef, CompositeUpdate)>,
+}
+
+/// An update of a composite value, which is stored as a JSON document.
+#[derive(Debug, Clone)]
+pub enum CompositeUpdate {
+    /// Replaces the value as a whole.
+    Set(serde_json::Value),
+
+    /// Updates the given sub-fields of the existing value.
+    Update(Vec<(String, CompositeUpdate)>),
+}
+
+impl CompositeUpdate {
+    /// Applies the update to the given value.
+    /// Errors if sub-fields are updated on a value that is not a composite (e.g. `null`).
+    pub fn apply(self, value: &mut serde_json::Value) -> Result<(), String> {
+        match self {
+            CompositeUpdate::Set(new_value) => {
+                *value = new_value;
+                Ok(())
+            }
+
+            CompositeUpdate::Update(updates) => match value {
+                serde_json::Value::Object(object) => {
+                    for (field_name, update) in updates {
+                        let field_value = object.entry(field_name).or_insert(serde_json::Value::Null);
+                        update.apply(field_value)?;
+                    }
+
+                    Ok(())
+                }
+
+                other => Err(format!(
+                    "Unable to update the sub-fields of composite value '{}', use `set` instead.",
+                    other
+                )),
+            },
+        }
+    }
 }
 
 #[derive(Debug, Clone)]
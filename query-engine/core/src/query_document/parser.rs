@@ -187,6 +187,14 @@ impl QueryDocumentParser {
             },
             (QueryValue::Boolean(b), ScalarType::Boolean) => Ok(PrismaValue::Boolean(b)),
 
+            // Exact numbers and bytes are usually sent as strings to avoid precision loss on the client.
+            (QueryValue::String(s), ScalarType::Decimal)  => Self::parse_decimal(s.as_str()).map(PrismaValue::Decimal),
+            (QueryValue::Int(i), ScalarType::Decimal)     => Ok(PrismaValue::Decimal(Decimal::from(i))),
+            (QueryValue::Float(f), ScalarType::Decimal)   => Ok(PrismaValue::Decimal(f)),
+            (QueryValue::String(s), ScalarType::BigInt)   => Self::parse_bigint(s.as_str()).map(PrismaValue::BigInt),
+            (QueryValue::Int(i), ScalarType::BigInt)      => Ok(PrismaValue::BigInt(i)),
+            (QueryValue::String(s), ScalarType::Bytes)    => Self::parse_bytes(s.as_str()).map(PrismaValue::Bytes),
+
             // All other combinations are invalid.
             (qv, _)                                       => Err(QueryParserError::ValueTypeMismatchError { have: qv, want: InputType::Scalar(scalar_type.clone()) }),
         }
@@ -232,6 +240,21 @@ impl QueryDocumentParser {
         Uuid::parse_str(s).map_err(|err| QueryParserError::ValueParseError(format!("Invalid UUID: {}", err)))
     }
 
+    pub fn parse_decimal(s: &str) -> QueryParserResult<Decimal> {
+        s.parse()
+            .map_err(|err| QueryParserError::ValueParseError(format!("Invalid Decimal: {}", err)))
+    }
+
+    pub fn parse_bigint(s: &str) -> QueryParserResult<i64> {
+        s.parse()
+            .map_err(|err| QueryParserError::ValueParseError(format!("Invalid BigInt: {}", err)))
+    }
+
+    pub fn parse_bytes(s: &str) -> QueryParserResult<Vec<u8>> {
+        prisma_value::decode_bytes(s)
+            .map_err(|_| QueryParserError::ValueParseError("Invalid Bytes: expected a base64 encoded string".into()))
+    }
+
     pub fn parse_list(values: Vec<QueryValue>, value_type: &InputType) -> QueryParserResult<Vec<ParsedInputValue>> {
         values
             .into_iter()
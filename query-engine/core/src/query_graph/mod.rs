@@ -16,7 +16,8 @@ pub use formatters::*;
 pub use transformers::*;
 
 use crate::{
-    interpreter::ExpressionResult, FilteredQuery, ManyRecordsQuery, Query, QueryGraphBuilderResult, ReadQuery,
+    interpreter::ExpressionResult, CompositeSelections, FilteredQuery, ManyRecordsQuery, Query,
+    QueryGraphBuilderResult, ReadQuery,
 };
 use connector::{IdFilter, QueryArguments};
 use guard::*;
@@ -693,6 +694,7 @@ impl QueryGraph {
                 selected_fields: ModelProjection::union(identifiers),
                 nested: vec![],
                 selection_order: vec![],
+                composite_selections: CompositeSelections::new(),
             });
 
             let query = Query::Read(read_query);
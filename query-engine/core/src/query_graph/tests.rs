@@ -1,5 +1,5 @@
 use super::*;
-use crate::{Query, ReadQuery, RecordQuery};
+use crate::{CompositeSelections, Query, ReadQuery, RecordQuery};
 use std::sync::Arc;
 
 #[test]
@@ -136,5 +136,6 @@ fn dummy_query() -> Query {
         selected_fields: ModelProjection::default(),
         nested: vec![],
         selection_order: vec![],
+        composite_selections: CompositeSelections::new(),
     }))
 }
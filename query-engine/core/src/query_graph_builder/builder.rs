@@ -54,6 +54,15 @@ impl From<Vec<ParsedArgument>> for RawArgs {
     }
 }
 
+/// An operation that has been parsed and validated against the query schema.
+/// It doesn't depend on a request anymore and can be cached and built into query graphs repeatedly.
+#[derive(Debug, Clone)]
+pub struct ValidatedOperation {
+    selection: Selection,
+    parsed_field: ParsedField,
+    is_write: bool,
+}
+
 impl QueryGraphBuilder {
     pub fn new(query_schema: QuerySchemaRef) -> Self {
         Self { query_schema }
@@ -61,42 +70,39 @@ impl QueryGraphBuilder {
 
     /// Maps an operation to a query.
     pub fn build(self, operation: Operation) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
-        match operation {
-            Operation::Read(selection) => self.map_read_operation(selection),
-            Operation::Write(selection) => self.map_write_operation(selection),
-        }
+        let operation = self.validate(operation)?;
+        self.build_validated(operation)
     }
 
-    /// Maps a read operation to one or more queries.
-    fn map_read_operation(&self, read_selection: Selection) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
-        let query_object = self.query_schema.query();
-        Self::process(read_selection, &query_object)
-    }
-
-    /// Maps a write operation to one or more queries.
-    fn map_write_operation(&self, write_selection: Selection) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
-        let mutation_object = self.query_schema.mutation();
-
-        let (mut graph, ir_ser) = Self::process(write_selection, &mutation_object)?;
-
-        if let QueryType::Graph(ref mut graph) = graph {
-            graph.flag_transactional();
+    /// Parses and validates an operation against the query schema, without building a query.
+    pub fn validate(&self, operation: Operation) -> QueryGraphBuilderResult<ValidatedOperation> {
+        let (selection, object, is_write) = match operation {
+            Operation::Read(selection) => (selection, self.query_schema.query(), false),
+            Operation::Write(selection) => (selection, self.query_schema.mutation(), true),
         };
 
-        Ok((graph, ir_ser))
+        let mut parsed_object = QueryDocumentParser::parse_object(std::slice::from_ref(&selection), &object)?;
+        let parsed_field = parsed_object.fields.pop().unwrap();
+
+        Ok(ValidatedOperation {
+            selection,
+            parsed_field,
+            is_write,
+        })
     }
 
-    fn process(
-        selection: Selection,
-        object: &ObjectTypeStrongRef,
-    ) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
-        let mut selections = vec![selection];
-        let mut parsed_object = QueryDocumentParser::parse_object(&selections, object)?;
+    /// Maps an already validated operation to a query.
+    /// Write operations are always flagged as transactional.
+    pub fn build_validated(self, operation: ValidatedOperation) -> QueryGraphBuilderResult<(QueryType, IrSerializer)> {
+        let ValidatedOperation {
+            selection,
+            parsed_field,
+            is_write,
+        } = operation;
 
-        let parsed_field = parsed_object.fields.pop().unwrap();
-        let result_info = Self::derive_serializer(&selections.pop().unwrap(), &parsed_field);
+        let result_info = Self::derive_serializer(&selection, &parsed_field);
 
-        let query_type = match &parsed_field.schema_field.clone().query_builder {
+        let mut query_type = match &parsed_field.schema_field.clone().query_builder {
             Some(builder) => Ok(QueryType::Graph(builder.build(parsed_field)?)),
             None if parsed_field.is_raw_query() => {
                 let raw_args = RawArgs::from(parsed_field.arguments);
@@ -106,13 +112,27 @@ impl QueryGraphBuilder {
                     parameters: raw_args.parameters,
                 })
             }
-            None => Err(QueryGraphBuilderError::SchemaError(format!(
-                "Expected attached query builder on {} object, root level field '{}'.",
-                object.name(),
-                parsed_field.name
-            ))),
+            None => {
+                let object = if is_write {
+                    self.query_schema.mutation()
+                } else {
+                    self.query_schema.query()
+                };
+
+                Err(QueryGraphBuilderError::SchemaError(format!(
+                    "Expected attached query builder on {} object, root level field '{}'.",
+                    object.name(),
+                    parsed_field.name
+                )))
+            }
         }?;
 
+        if is_write {
+            if let QueryType::Graph(ref mut graph) = query_type {
+                graph.flag_transactional();
+            };
+        }
+
         Ok((query_type, result_info))
     }
 
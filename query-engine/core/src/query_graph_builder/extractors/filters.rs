@@ -3,9 +3,9 @@ use crate::{
     query_document::{ParsedInputMap, ParsedInputValue},
     QueryGraphBuilderError, QueryGraphBuilderResult,
 };
-use connector::{filter::Filter, RelationCompare, ScalarCompare};
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
-use std::{collections::BTreeMap, convert::TryInto};
+use connector::{filter::Filter, CompositeFilter, RelationCompare, ScalarCompare, ScalarCondition};
+use prisma_models::{CompositeType, Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
+use std::{collections::BTreeMap, convert::TryInto, sync::Arc};
 
 static FILTER_OPERATIONS: &'static [FilterOp] = &[
     FilterOp::NotIn,
@@ -157,6 +157,7 @@ pub fn extract_filter(
                     // Always try to resolve regular fields first. If that fails, try to resolve compound fields.
                     match model.fields().find_from_all(&field_name) {
                         Ok(field) => match field {
+                            Field::Scalar(field) if field.is_composite() => handle_composite_field(field, value),
                             Field::Scalar(field) => handle_scalar_field(field, value, &op),
                             Field::Relation(field) => handle_relation_field(field, value, &op),
                         },
@@ -203,6 +204,81 @@ fn handle_scalar_field(
     })
 }
 
+fn handle_composite_field(field: &ScalarFieldRef, value: ParsedInputValue) -> QueryGraphBuilderResult<Filter> {
+    let composite_type = field
+        .composite_type()
+        .expect("Invariant violation: Composite fields refer to existing composite types.");
+
+    match value {
+        ParsedInputValue::Single(PrismaValue::Null) => Ok(field.equals(PrismaValue::Null)),
+        value => extract_composite_filter(field, &composite_type, vec![], value.try_into()?),
+    }
+}
+
+/// Extracts filters on the sub-fields of a composite value. Each filter addresses
+/// its sub-field by the path from the composite field.
+fn extract_composite_filter(
+    field: &ScalarFieldRef,
+    composite_type: &CompositeType,
+    path: Vec<String>,
+    value_map: ParsedInputMap,
+) -> QueryGraphBuilderResult<Filter> {
+    let filters = value_map
+        .into_iter()
+        .map(|(key, value): (String, ParsedInputValue)| {
+            let op = FilterOp::find_op(key.as_str());
+            let sub_field_name = key.trim_end_matches(op.suffix());
+
+            // Unwrap is safe due to query validation.
+            let sub_field = composite_type.find_field(sub_field_name).unwrap();
+
+            let mut path = path.clone();
+            path.push(sub_field.name.clone());
+
+            match (&sub_field.composite_type, value) {
+                (Some(nested), ParsedInputValue::Map(map)) => {
+                    let nested_type = field.internal_data_model().find_composite_type(nested)?.clone();
+                    extract_composite_filter(field, &nested_type, path, map)
+                }
+                (_, value) => {
+                    let value: PrismaValue = value.try_into()?;
+
+                    Ok(Filter::from(CompositeFilter {
+                        field: Arc::clone(field),
+                        path,
+                        type_identifier: sub_field.type_identifier.clone(),
+                        condition: composite_condition(&op, value),
+                    }))
+                }
+            }
+        })
+        .collect::<QueryGraphBuilderResult<Vec<Filter>>>()?;
+
+    Ok(Filter::and(filters))
+}
+
+fn composite_condition(op: &FilterOp, value: PrismaValue) -> ScalarCondition {
+    match (op, value) {
+        (FilterOp::In, PrismaValue::Null) => ScalarCondition::Equals(PrismaValue::Null),
+        (FilterOp::In, PrismaValue::List(values)) => ScalarCondition::In(values),
+        (FilterOp::NotIn, PrismaValue::Null) => ScalarCondition::NotEquals(PrismaValue::Null),
+        (FilterOp::NotIn, PrismaValue::List(values)) => ScalarCondition::NotIn(values),
+        (FilterOp::Not, val) => ScalarCondition::NotEquals(val),
+        (FilterOp::Lt, val) => ScalarCondition::LessThan(val),
+        (FilterOp::Lte, val) => ScalarCondition::LessThanOrEquals(val),
+        (FilterOp::Gt, val) => ScalarCondition::GreaterThan(val),
+        (FilterOp::Gte, val) => ScalarCondition::GreaterThanOrEquals(val),
+        (FilterOp::Contains, val) => ScalarCondition::Contains(val),
+        (FilterOp::NotContains, val) => ScalarCondition::NotContains(val),
+        (FilterOp::StartsWith, val) => ScalarCondition::StartsWith(val),
+        (FilterOp::NotStartsWith, val) => ScalarCondition::NotStartsWith(val),
+        (FilterOp::EndsWith, val) => ScalarCondition::EndsWith(val),
+        (FilterOp::NotEndsWith, val) => ScalarCondition::NotEndsWith(val),
+        (FilterOp::Field, val) => ScalarCondition::Equals(val),
+        (_, _) => unreachable!(),
+    }
+}
+
 fn handle_relation_field(
     field: &RelationFieldRef,
     value: ParsedInputValue,
@@ -9,7 +9,7 @@ pub use one::*;
 pub use related::*;
 
 use super::*;
-use crate::{query_document::ParsedField, ReadQuery};
+use crate::{query_document::ParsedField, CompositeSelection, CompositeSelections, ReadQuery};
 use prisma_models::{Field, ModelProjection, ModelRef, RelationFieldRef};
 use std::sync::Arc;
 
@@ -63,6 +63,42 @@ pub fn collect_selected_fields(from: &[ParsedField], model: &ModelRef) -> ModelP
     model_id.merge(selected_projection)
 }
 
+/// Collects the sub-selections of all selected composite fields.
+pub fn collect_composite_selections(from: &[ParsedField], model: &ModelRef) -> CompositeSelections {
+    from.iter()
+        .filter(
+            |selected_field| match model.fields().find_from_scalar(&selected_field.name) {
+                Ok(sf) => sf.is_composite(),
+                Err(_) => false,
+            },
+        )
+        .filter_map(|selected_field| {
+            selected_field
+                .nested_fields
+                .as_ref()
+                .map(|nested| (selected_field.name.clone(), composite_selection(&nested.fields)))
+        })
+        .collect()
+}
+
+/// Only composite sub-fields can have sub-selections of their own, which makes the model lookup redundant here.
+fn composite_selection(from: &[ParsedField]) -> CompositeSelection {
+    let nested = from
+        .iter()
+        .filter_map(|selected_field| {
+            selected_field
+                .nested_fields
+                .as_ref()
+                .map(|nested| (selected_field.name.clone(), composite_selection(&nested.fields)))
+        })
+        .collect();
+
+    CompositeSelection {
+        fields: from.iter().map(|selected_field| selected_field.name.clone()).collect(),
+        nested,
+    }
+}
+
 pub fn collect_nested_queries(from: Vec<ParsedField>, model: &ModelRef) -> QueryGraphBuilderResult<Vec<ReadQuery>> {
     from.into_iter()
         .filter_map(|selected_field| {
@@ -35,6 +35,7 @@ impl Builder<ReadQuery> for ReadOneRecordBuilder {
         let model = self.model;
         let nested_fields = self.field.nested_fields.unwrap().fields;
         let selection_order: Vec<String> = collect_selection_order(&nested_fields);
+        let composite_selections = collect_composite_selections(&nested_fields, &model);
         let selected_fields = collect_selected_fields(&nested_fields, &model);
         let nested = collect_nested_queries(nested_fields, &model)?;
         let selected_fields = merge_relation_selections(selected_fields, None, &nested);
@@ -47,6 +48,7 @@ impl Builder<ReadQuery> for ReadOneRecordBuilder {
             selected_fields,
             nested,
             selection_order,
+            composite_selections,
         }))
     }
 }
@@ -69,6 +69,12 @@ pub fn update_many_records(
     let data_map: ParsedInputMap = data_argument.value.try_into()?;
     let update_args = WriteArgsParser::from(&model, data_map)?;
 
+    if !update_args.composite_updates.is_empty() {
+        return Err(QueryGraphBuilderError::InputError(
+            "Composite fields can only be replaced using `set` when updating many records.".to_owned(),
+        ));
+    }
+
     let mut args = update_args.args;
     args.update_datetimes(Arc::clone(&model));
 
@@ -104,6 +110,7 @@ where
         model,
         record_filter,
         args,
+        composite_updates: update_args.composite_updates,
     };
 
     let node = graph.create_node(Query::Write(WriteQuery::UpdateRecord(ur)));
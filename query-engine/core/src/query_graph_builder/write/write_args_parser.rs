@@ -1,13 +1,17 @@
 use super::*;
-use crate::query_document::{ParsedInputMap, ParsedInputValue};
+use crate::{
+    query_ast::CompositeUpdate,
+    query_document::{InputAssertions, ParsedInputMap, ParsedInputValue},
+};
 use connector::WriteArgs;
-use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef};
+use prisma_models::{Field, ModelRef, PrismaValue, RelationFieldRef, ScalarFieldRef};
 use std::{convert::TryInto, sync::Arc};
 
 #[derive(Default, Debug)]
 pub struct WriteArgsParser {
     pub args: WriteArgs,
     pub nested: Vec<(RelationFieldRef, ParsedInputMap)>,
+    pub composite_updates: Vec<(ScalarFieldRef, CompositeUpdate)>,
 }
 
 impl WriteArgsParser {
@@ -20,6 +24,20 @@ impl WriteArgsParser {
                 let field = model.fields().find_from_all(&k).unwrap();
 
                 match field {
+                    Field::Scalar(sf) if sf.is_composite() => {
+                        let envelope: ParsedInputMap = v.try_into()?;
+
+                        match composite_envelope(envelope)? {
+                            CompositeUpdate::Set(serde_json::Value::Null) => {
+                                args.args.insert(sf.db_name().clone(), PrismaValue::Null)
+                            }
+                            CompositeUpdate::Set(value) => args
+                                .args
+                                .insert(sf.db_name().clone(), PrismaValue::Json(value.to_string())),
+                            update => args.composite_updates.push((sf, update)),
+                        }
+                    }
+
                     Field::Scalar(sf) if sf.is_list => {
                         let vals: ParsedInputMap = v.try_into()?;
                         let set_value = vals.into_iter().find(|(k, _)| k == "set");
@@ -54,3 +72,64 @@ impl WriteArgsParser {
         )
     }
 }
+
+/// Parses a composite envelope (`{ set: ... }` or `{ update: ... }`), which holds exactly one operation.
+fn composite_envelope(mut envelope: ParsedInputMap) -> QueryGraphBuilderResult<CompositeUpdate> {
+    envelope.assert_size(1)?;
+
+    if let Some(value) = envelope.remove("set") {
+        return Ok(CompositeUpdate::Set(composite_json(value)?));
+    }
+
+    let update: ParsedInputMap = envelope.remove("update").unwrap().try_into()?;
+    let updates = update
+        .into_iter()
+        .map(|(field_name, value)| {
+            // Within updates, only nested composites are wrapped in envelopes.
+            let update = match value {
+                ParsedInputValue::Map(nested) => composite_envelope(nested)?,
+                value => CompositeUpdate::Set(composite_json(value)?),
+            };
+
+            Ok((field_name, update))
+        })
+        .collect::<QueryGraphBuilderResult<Vec<_>>>()?;
+
+    Ok(CompositeUpdate::Update(updates))
+}
+
+/// Converts a (validated) composite input value into the JSON document that is stored.
+fn composite_json(value: ParsedInputValue) -> QueryGraphBuilderResult<serde_json::Value> {
+    match value {
+        ParsedInputValue::Single(value) => prisma_value_json(value),
+        ParsedInputValue::List(values) => Ok(serde_json::Value::Array(
+            values
+                .into_iter()
+                .map(composite_json)
+                .collect::<QueryGraphBuilderResult<Vec<_>>>()?,
+        )),
+        ParsedInputValue::Map(map) => Ok(serde_json::Value::Object(
+            map.into_iter()
+                .map(|(k, v)| Ok((k, composite_json(v)?)))
+                .collect::<QueryGraphBuilderResult<serde_json::Map<_, _>>>()?,
+        )),
+        ParsedInputValue::OrderBy(_) => Err(QueryGraphBuilderError::InputError(
+            "Ordering arguments are not valid composite values.".to_owned(),
+        )),
+    }
+}
+
+fn prisma_value_json(value: PrismaValue) -> QueryGraphBuilderResult<serde_json::Value> {
+    match value {
+        PrismaValue::Json(s) => serde_json::from_str(&s)
+            .map_err(|err| QueryGraphBuilderError::InputError(format!("Invalid JSON value '{}': {}", s, err))),
+        PrismaValue::List(values) => Ok(serde_json::Value::Array(
+            values
+                .into_iter()
+                .map(prisma_value_json)
+                .collect::<QueryGraphBuilderResult<Vec<_>>>()?,
+        )),
+        value => serde_json::to_value(&value)
+            .map_err(|err| QueryGraphBuilderError::InputError(format!("Invalid composite value: {}", err))),
+    }
+}
@@ -1,12 +1,17 @@
 use super::*;
 use crate::{
     schema::{IntoArc, ObjectTypeStrongRef, OutputType, OutputTypeRef, ScalarType},
-    AggregationQueryResult, CoreError, EnumType, QueryResult, RecordAggregation, RecordSelection,
+    AggregationQueryResult, CompositeSelection, CoreError, EnumType, QueryResult, RecordAggregation, RecordSelection,
 };
+use chrono::{DateTime, Utc};
 use indexmap::IndexMap;
 use prisma_models::{InternalEnum, PrismaValue, RecordProjection};
-use rust_decimal::prelude::ToPrimitive;
-use std::{borrow::Borrow, collections::HashMap};
+use rust_decimal::{
+    prelude::{FromPrimitive, ToPrimitive},
+    Decimal,
+};
+use std::{borrow::Borrow, collections::HashMap, str::FromStr};
+use uuid::Uuid;
 
 /// A grouping of items to their parent record.
 /// The item implicitly holds the information of the type of item contained.
@@ -199,7 +204,14 @@ fn serialize_objects(
         for (val, scalar_field_name) in values.into_iter().zip(field_names.iter()) {
             let field = typ.find_field(scalar_field_name).unwrap();
 
-            if !field.field_type.is_object() {
+            // Scalar values of object type are composite values, relations are never part of the scalars.
+            if field.field_type.as_object_type().is_some() {
+                let selection = result.composite_selections.get(scalar_field_name);
+                object.insert(
+                    scalar_field_name.to_owned(),
+                    serialize_composite(val, &field.field_type, selection)?,
+                );
+            } else {
                 object.insert(scalar_field_name.to_owned(), serialize_scalar(val, &field.field_type)?);
             }
         }
@@ -334,6 +346,105 @@ fn serialize_scalar(value: PrismaValue, typ: &OutputTypeRef) -> crate::Result<It
     }
 }
 
+/// Serializes a composite value, which is stored as a JSON document, into a map of the selected sub-fields.
+fn serialize_composite(
+    value: PrismaValue,
+    typ: &OutputTypeRef,
+    selection: Option<&CompositeSelection>,
+) -> crate::Result<Item> {
+    let json = match value {
+        PrismaValue::Null => serde_json::Value::Null,
+        PrismaValue::Json(s) | PrismaValue::String(s) => serde_json::from_str(&s).map_err(|err| {
+            CoreError::SerializationError(format!("Unable to parse composite value as JSON: {}", err))
+        })?,
+        pv => {
+            return Err(CoreError::SerializationError(format!(
+                "Attempted to serialize non-JSON value '{}' as composite value",
+                pv
+            )))
+        }
+    };
+
+    serialize_composite_json(json, typ, selection)
+}
+
+fn serialize_composite_json(
+    json: serde_json::Value,
+    typ: &OutputTypeRef,
+    selection: Option<&CompositeSelection>,
+) -> crate::Result<Item> {
+    use serde_json::Value as Json;
+
+    match (json, typ.as_ref()) {
+        (Json::Null, OutputType::Opt(_)) => Ok(Item::Value(PrismaValue::Null)),
+        (Json::Null, OutputType::List(_)) => Ok(Item::list(Vec::new())),
+        (json, OutputType::Opt(inner)) => serialize_composite_json(json, inner, selection),
+        (Json::Array(values), OutputType::List(inner)) => {
+            let items = values
+                .into_iter()
+                .map(|value| serialize_composite_json(value, inner, selection))
+                .collect::<crate::Result<Vec<Item>>>()?;
+
+            Ok(Item::list(items))
+        }
+        (Json::Object(mut values), OutputType::Object(obj)) => {
+            let obj = obj.into_arc();
+            let field_names: Vec<String> = match selection {
+                Some(selection) => selection.fields.clone(),
+                None => obj.get_fields().iter().map(|field| field.name.clone()).collect(),
+            };
+
+            let mut map = Map::with_capacity(field_names.len());
+
+            for field_name in field_names {
+                // Unwrap is safe due to query validation.
+                let field = obj.find_field(&field_name).unwrap();
+                let value = values.remove(&field_name).unwrap_or(Json::Null);
+
+                let item = if field.field_type.as_object_type().is_some() {
+                    let nested_selection = selection.and_then(|s| s.nested.get(&field_name));
+                    serialize_composite_json(value, &field.field_type, nested_selection)?
+                } else {
+                    serialize_scalar(json_to_prisma_value(value), &field.field_type)?
+                };
+
+                map.insert(field_name, item);
+            }
+
+            Ok(Item::Map(map))
+        }
+        (Json::Null, _) => Err(CoreError::SerializationError(
+            "Required composite value is missing".to_owned(),
+        )),
+        (json, ot) => Err(CoreError::SerializationError(format!(
+            "Attempted to serialize composite value '{}' with incompatible type '{:?}'",
+            json, ot
+        ))),
+    }
+}
+
+/// Untyped conversion of a JSON value into a `PrismaValue`.
+/// The output type conversion takes care of the rest (e.g. parsing of date times).
+fn json_to_prisma_value(json: serde_json::Value) -> PrismaValue {
+    use serde_json::Value as Json;
+
+    match json {
+        Json::Null => PrismaValue::Null,
+        Json::Bool(b) => PrismaValue::Boolean(b),
+        Json::Number(n) => match n.as_i64() {
+            Some(i) => PrismaValue::Int(i),
+            None => n
+                .as_f64()
+                .and_then(Decimal::from_f64)
+                .map(PrismaValue::Float)
+                .unwrap_or(PrismaValue::Null),
+        },
+        Json::String(s) => PrismaValue::String(s),
+        Json::Array(values) => PrismaValue::List(values.into_iter().map(json_to_prisma_value).collect()),
+        json @ Json::Object(_) => PrismaValue::Json(json.to_string()),
+    }
+}
+
 fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaValue, CoreError> {
     let item_value = match (st, value) {
         (ScalarType::String, PrismaValue::String(s)) => PrismaValue::String(s),
@@ -353,6 +464,33 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
         (ScalarType::DateTime, PrismaValue::DateTime(dt)) => PrismaValue::DateTime(dt),
         (ScalarType::UUID, PrismaValue::Uuid(u)) => PrismaValue::Uuid(u),
 
+        (ScalarType::Decimal, PrismaValue::Decimal(d)) => PrismaValue::Decimal(d),
+        (ScalarType::Decimal, PrismaValue::Float(f)) => PrismaValue::Decimal(f),
+        (ScalarType::Decimal, PrismaValue::Int(i)) => PrismaValue::Decimal(i.into()),
+
+        (ScalarType::BigInt, PrismaValue::BigInt(i)) => PrismaValue::BigInt(i),
+        (ScalarType::BigInt, PrismaValue::Int(i)) => PrismaValue::BigInt(i),
+
+        (ScalarType::Bytes, PrismaValue::Bytes(b)) => PrismaValue::Bytes(b),
+
+        // Values inside of composite documents are stored in their JSON representation.
+        (ScalarType::DateTime, PrismaValue::String(s)) => DateTime::parse_from_rfc3339(&s)
+            .map(|dt| PrismaValue::DateTime(dt.with_timezone(&Utc)))
+            .map_err(|err| invalid_string_value(&s, st, err))?,
+        (ScalarType::UUID, PrismaValue::String(s)) => Uuid::parse_str(&s)
+            .map(PrismaValue::Uuid)
+            .map_err(|err| invalid_string_value(&s, st, err))?,
+        (ScalarType::Decimal, PrismaValue::String(s)) => Decimal::from_str(&s)
+            .map(PrismaValue::Decimal)
+            .map_err(|err| invalid_string_value(&s, st, err))?,
+        (ScalarType::BigInt, PrismaValue::String(s)) => s
+            .parse::<i64>()
+            .map(PrismaValue::BigInt)
+            .map_err(|err| invalid_string_value(&s, st, err))?,
+        (ScalarType::Bytes, PrismaValue::String(s)) => prisma_value::decode_bytes(&s)
+            .map(PrismaValue::Bytes)
+            .map_err(|err| invalid_string_value(&s, st, err))?,
+
         (st, pv) => {
             return Err(CoreError::SerializationError(format!(
                 "Attempted to serialize scalar '{}' with incompatible type '{:?}'",
@@ -364,6 +502,10 @@ fn convert_prisma_value(value: PrismaValue, st: &ScalarType) -> Result<PrismaVal
     Ok(item_value)
 }
 
+fn invalid_string_value(s: &str, st: &ScalarType, err: impl std::fmt::Debug) -> CoreError {
+    CoreError::SerializationError(format!("Unable to parse '{}' as '{:?}': {:?}", s, st, err))
+}
+
 fn convert_enum(value: PrismaValue, i: &InternalEnum) -> Result<Item, CoreError> {
     match value {
         PrismaValue::String(s) | PrismaValue::Enum(s) => match i.map_output_value(&s) {
@@ -1,3 +1,4 @@
+use crate::CompositeSelections;
 use connector::QueryArguments;
 use prisma_models::{ManyRecords, ModelProjection, RecordProjection};
 
@@ -20,6 +21,9 @@ pub struct RecordSelection {
     /// Holds an ordered list of selected field names for each contained record.
     pub fields: Vec<String>,
 
+    /// Sub-selections of the selected composite fields.
+    pub composite_selections: CompositeSelections,
+
     /// Scalar field results
     pub scalars: ManyRecords,
 
@@ -352,6 +352,18 @@ impl InputType {
     pub fn uuid() -> InputType {
         InputType::Scalar(ScalarType::UUID)
     }
+
+    pub fn decimal() -> InputType {
+        InputType::Scalar(ScalarType::Decimal)
+    }
+
+    pub fn bigint() -> InputType {
+        InputType::Scalar(ScalarType::BigInt)
+    }
+
+    pub fn bytes() -> InputType {
+        InputType::Scalar(ScalarType::Bytes)
+    }
 }
 
 #[derive(Debug)]
@@ -404,6 +416,18 @@ impl OutputType {
         OutputType::Scalar(ScalarType::UUID)
     }
 
+    pub fn decimal() -> OutputType {
+        OutputType::Scalar(ScalarType::Decimal)
+    }
+
+    pub fn bigint() -> OutputType {
+        OutputType::Scalar(ScalarType::BigInt)
+    }
+
+    pub fn bytes() -> OutputType {
+        OutputType::Scalar(ScalarType::Bytes)
+    }
+
     /// Attempts to recurse through the type until an object type is found.
     /// Returns Some(ObjectTypeStrongRef) if ab object type is found, None otherwise.
     pub fn as_object_type(&self) -> Option<ObjectTypeStrongRef> {
@@ -444,6 +468,9 @@ pub enum ScalarType {
     Json,
     JsonList,
     UUID,
+    Decimal,
+    BigInt,
+    Bytes,
 }
 
 impl From<EnumType> for OutputType {
@@ -113,16 +113,23 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
         ModelField::Relation(_) if field.is_list() => vec![&args.multi_relation],
         ModelField::Scalar(_) if field.is_list() => vec![],
         ModelField::Relation(_) => vec![&args.one_relation],
-        ModelField::Scalar(sf) => match sf.type_identifier {
-            TypeIdentifier::UUID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
-            TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
-            TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Boolean => vec![&args.base],
-            TypeIdentifier::Enum(_) => vec![&args.base, &args.inclusion],
-            TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
-            TypeIdentifier::Json => vec![&args.base],
-        },
+        ModelField::Scalar(sf) if sf.is_composite() => vec![&args.one_relation],
+        ModelField::Scalar(sf) => type_identifier_filters(&sf.type_identifier),
+    };
+
+    filters
+        .into_iter()
+        .map(|l| l.iter().collect::<Vec<&'a FilterArgument>>())
+        .flatten()
+        .collect()
+}
+
+/// Filters of sub-fields of composite types. As with scalar lists, list sub-fields can't be filtered.
+pub fn get_composite_field_filters<'a>(field: &CompositeTypeField) -> Vec<&'a FilterArgument> {
+    let filters = match field.composite_type {
+        _ if field.is_list => vec![],
+        Some(_) => vec![&FILTER_ARGUMENTS.one_relation],
+        None => type_identifier_filters(&field.type_identifier),
     };
 
     filters
@@ -131,3 +138,21 @@ pub fn get_field_filters<'a>(field: &ModelField) -> Vec<&'a FilterArgument> {
         .flatten()
         .collect()
 }
+
+fn type_identifier_filters(type_identifier: &TypeIdentifier) -> Vec<&'static &'static [FilterArgument]> {
+    let args = &FILTER_ARGUMENTS;
+
+    match type_identifier {
+        TypeIdentifier::UUID => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
+        TypeIdentifier::String => vec![&args.base, &args.inclusion, &args.alphanumeric, &args.string],
+        TypeIdentifier::Int => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::Float => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::Boolean => vec![&args.base],
+        TypeIdentifier::Enum(_) => vec![&args.base, &args.inclusion],
+        TypeIdentifier::DateTime => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::Json => vec![&args.base],
+        TypeIdentifier::Decimal => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::BigInt => vec![&args.base, &args.inclusion, &args.alphanumeric],
+        TypeIdentifier::Bytes => vec![&args.base, &args.inclusion],
+    }
+}
@@ -3,6 +3,7 @@ use super::*;
 /// Filter object and scalar filter object type builder.
 #[derive(Debug)]
 pub struct FilterObjectTypeBuilder<'a> {
+    internal_data_model: InternalDataModelRef,
     capabilities: &'a SupportedCapabilities,
     input_object_cache: TypeRefCache<InputObjectType>,
 }
@@ -20,8 +21,9 @@ impl<'a> CachedBuilder<InputObjectType> for FilterObjectTypeBuilder<'a> {
 }
 
 impl<'a> FilterObjectTypeBuilder<'a> {
-    pub fn new(capabilities: &'a SupportedCapabilities) -> Self {
+    pub fn new(internal_data_model: InternalDataModelRef, capabilities: &'a SupportedCapabilities) -> Self {
         FilterObjectTypeBuilder {
+            internal_data_model,
             capabilities,
             input_object_cache: TypeRefCache::new(),
         }
@@ -124,6 +126,13 @@ impl<'a> FilterObjectTypeBuilder<'a> {
     }
 
     fn map_input_field(&self, field: ScalarFieldRef) -> Vec<InputField> {
+        if let Some(composite_type) = field.composite_type() {
+            return get_field_filters(&ModelField::Scalar(Arc::clone(&field)))
+                .into_iter()
+                .map(|_| self.composite_input_field(field.name.clone(), &composite_type))
+                .collect();
+        }
+
         get_field_filters(&ModelField::Scalar(Arc::clone(&field)))
             .into_iter()
             .map(|arg| {
@@ -145,6 +154,60 @@ impl<'a> FilterObjectTypeBuilder<'a> {
             .collect()
     }
 
+    /// Builds the "<x>WhereInput" filter object type of a composite type, which filters on its sub-fields.
+    fn composite_filter_object_type(&self, composite_type: &CompositeType) -> InputObjectTypeRef {
+        let name = format!("{}WhereInput", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let fields = composite_type
+            .fields
+            .iter()
+            .flat_map(|field| match field.composite_type {
+                Some(ref nested) => {
+                    let nested_type = self
+                        .internal_data_model
+                        .find_composite_type(nested)
+                        .expect("Invariant violation: Composite fields refer to existing composite types.");
+
+                    get_composite_field_filters(field)
+                        .into_iter()
+                        .map(|_| self.composite_input_field(field.name.clone(), nested_type))
+                        .collect::<Vec<_>>()
+                }
+                None => get_composite_field_filters(field)
+                    .into_iter()
+                    .map(|arg| {
+                        let field_name = format!("{}{}", field.name, arg.suffix);
+                        let mapped = self.map_composite_field_input_type(field);
+
+                        match (arg.is_list, field.is_required) {
+                            (true, true) => input_field(field_name, InputType::opt(InputType::list(mapped)), None),
+                            (true, false) => input_field(
+                                field_name,
+                                InputType::opt(InputType::null(InputType::list(mapped))),
+                                None,
+                            ),
+                            (false, true) => input_field(field_name, InputType::opt(mapped), None),
+                            (false, false) => input_field(field_name, InputType::opt(InputType::null(mapped)), None),
+                        }
+                    })
+                    .collect(),
+            })
+            .collect();
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    /// Filter input field for a composite (sub-)field. Filtering with `null` matches absent values.
+    fn composite_input_field(&self, field_name: String, composite_type: &CompositeType) -> InputField {
+        let input_type = InputType::object(self.composite_filter_object_type(composite_type));
+        input_field(field_name, InputType::opt(InputType::null(input_type)), None)
+    }
+
     /// Maps relations to (filter) input fields.
     fn map_relation_filter_input_field(&self, field: RelationFieldRef) -> Vec<InputField> {
         let related_model = field.related_model();
@@ -0,0 +1,172 @@
+use super::*;
+
+/// Composite fields are written through envelopes:
+/// - On create: `{ set: <value> }`.
+/// - On update: `{ set: <value> }` to replace the value, or `{ update: { ... } }` to update sub-fields.
+///   Composite lists can only be replaced as a whole.
+pub trait CompositeInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
+    /// Builds the input fields for the composite fields of a create input type.
+    fn composite_input_fields_create(&self, fields: Vec<ScalarFieldRef>) -> Vec<InputField> {
+        fields
+            .into_iter()
+            .map(|f| {
+                let composite_type = Self::composite_type_of(&f);
+                let envelope =
+                    InputType::object(self.composite_create_envelope_object_type(&composite_type, f.is_list));
+
+                if f.is_required && f.default_value.is_none() {
+                    input_field(f.name.clone(), envelope, None)
+                } else {
+                    input_field(f.name.clone(), InputType::opt(envelope), None)
+                }
+            })
+            .collect()
+    }
+
+    /// Builds the input fields for the composite fields of an update input type.
+    fn composite_input_fields_update(&self, fields: Vec<ScalarFieldRef>) -> Vec<InputField> {
+        fields
+            .into_iter()
+            .map(|f| {
+                let composite_type = Self::composite_type_of(&f);
+                let envelope = self.composite_update_envelope_object_type(&composite_type, f.is_list);
+
+                input_field(f.name.clone(), InputType::opt(InputType::object(envelope)), None)
+            })
+            .collect()
+    }
+
+    /// Builds "<x>CreateEnvelopeInput" / "<x>ListCreateEnvelopeInput" input object types.
+    fn composite_create_envelope_object_type(
+        &self,
+        composite_type: &CompositeType,
+        is_list: bool,
+    ) -> InputObjectTypeRef {
+        let list_part = if is_list { "List" } else { "" };
+        let name = format!("{}{}CreateEnvelopeInput", composite_type.name, list_part);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let create_object = InputType::object(self.composite_create_object_type(composite_type));
+        let set_type = if is_list {
+            InputType::list(create_object)
+        } else {
+            create_object
+        };
+
+        input_object.set_fields(vec![input_field("set", set_type, None)]);
+        Arc::downgrade(&input_object)
+    }
+
+    /// Builds "<x>UpdateEnvelopeInput" / "<x>ListUpdateEnvelopeInput" input object types.
+    fn composite_update_envelope_object_type(
+        &self,
+        composite_type: &CompositeType,
+        is_list: bool,
+    ) -> InputObjectTypeRef {
+        let list_part = if is_list { "List" } else { "" };
+        let name = format!("{}{}UpdateEnvelopeInput", composite_type.name, list_part);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let create_object = InputType::object(self.composite_create_object_type(composite_type));
+        let mut fields = vec![];
+
+        if is_list {
+            fields.push(input_field("set", InputType::opt(InputType::list(create_object)), None));
+        } else {
+            let update_object = InputType::object(self.composite_update_object_type(composite_type));
+
+            fields.push(input_field("set", InputType::opt(InputType::null(create_object)), None));
+            fields.push(input_field("update", InputType::opt(update_object), None));
+        }
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    /// Builds the "<x>CreateInput" input object type of a composite type.
+    /// Nested composites are written directly, without envelope.
+    fn composite_create_object_type(&self, composite_type: &CompositeType) -> InputObjectTypeRef {
+        let name = format!("{}CreateInput", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let fields = composite_type
+            .fields
+            .iter()
+            .map(|f| {
+                let typ = match f.composite_type {
+                    Some(ref nested) => {
+                        let nested_type = self.find_composite_type(nested);
+                        InputType::object(self.composite_create_object_type(&nested_type))
+                    }
+                    None => self.map_composite_field_input_type(f),
+                };
+
+                let typ = if f.is_list { InputType::list(typ) } else { typ };
+
+                if f.is_required {
+                    input_field(f.name.clone(), typ, None)
+                } else {
+                    input_field(f.name.clone(), InputType::opt(InputType::null(typ)), None)
+                }
+            })
+            .collect();
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    /// Builds the "<x>UpdateInput" input object type of a composite type. All sub-fields are optional,
+    /// nested composites are updated through envelopes.
+    fn composite_update_object_type(&self, composite_type: &CompositeType) -> InputObjectTypeRef {
+        let name = format!("{}UpdateInput", composite_type.name);
+        return_cached!(self.get_cache(), &name);
+
+        let input_object = Arc::new(init_input_object_type(name.clone()));
+        self.cache(name, Arc::clone(&input_object));
+
+        let fields = composite_type
+            .fields
+            .iter()
+            .map(|f| {
+                let typ = match f.composite_type {
+                    Some(ref nested) => {
+                        let nested_type = self.find_composite_type(nested);
+                        InputType::object(self.composite_update_envelope_object_type(&nested_type, f.is_list))
+                    }
+                    None => {
+                        let typ = self.map_composite_field_input_type(f);
+                        let typ = if f.is_list { InputType::list(typ) } else { typ };
+
+                        if f.is_required {
+                            typ
+                        } else {
+                            InputType::null(typ)
+                        }
+                    }
+                };
+
+                input_field(f.name.clone(), InputType::opt(typ), None)
+            })
+            .collect();
+
+        input_object.set_fields(fields);
+        Arc::downgrade(&input_object)
+    }
+
+    fn composite_type_of(field: &ScalarFieldRef) -> CompositeType {
+        field
+            .composite_type()
+            .expect("Invariant violation: Composite fields refer to existing composite types.")
+    }
+
+    fn find_composite_type(&self, name: &str) -> CompositeType;
+}
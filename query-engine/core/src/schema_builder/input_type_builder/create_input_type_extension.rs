@@ -1,6 +1,8 @@
 use super::*;
 
-pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
+pub trait CreateInputTypeBuilderExtension<'a>:
+    InputTypeBuilderBase<'a> + CompositeInputTypeBuilderExtension<'a>
+{
     /// Builds the create input type (<x>CreateInput / <x>CreateWithout<y>Input)
     #[rustfmt::skip]
     fn create_input_type(&self, model: ModelRef, parent_field: Option<RelationFieldRef>) -> InputObjectTypeRef {
@@ -17,12 +19,12 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
         self.cache(name, Arc::clone(&input_object));
 
         // Compute input fields for scalar fields.
-        let scalar_fields: Vec<ScalarFieldRef> = model
+        let (composite_fields, scalar_fields): (Vec<ScalarFieldRef>, Vec<ScalarFieldRef>) = model
             .fields()
             .scalar_writable()
             .into_iter()
             .filter(|f|  Self::field_should_be_kept_for_create_input_type(&f))
-            .collect();
+            .partition(|f| f.is_composite());
 
         let mut fields = self.scalar_input_fields(
             model.name.clone(),
@@ -40,6 +42,10 @@ pub trait CreateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> {
             true
         );
 
+        // Compute input fields for composite fields.
+        let mut composite_fields = self.composite_input_fields_create(composite_fields);
+        fields.append(&mut composite_fields);
+
         // Compute input fields for relational fields.
         let mut relational_fields = self.relation_input_fields_create(Arc::clone(&model), parent_field.as_ref());
         fields.append(&mut relational_fields);
@@ -15,6 +15,9 @@ pub trait InputBuilderExtensions {
             TypeIdentifier::UUID => InputType::uuid(),
             TypeIdentifier::DateTime => InputType::date_time(),
             TypeIdentifier::Json => InputType::json(),
+            TypeIdentifier::Decimal => InputType::decimal(),
+            TypeIdentifier::BigInt => InputType::bigint(),
+            TypeIdentifier::Bytes => InputType::bytes(),
             TypeIdentifier::Enum(_) => self.map_enum_input_type(&field),
         };
 
@@ -24,6 +27,31 @@ pub trait InputBuilderExtensions {
         typ
     }
 
+    /// Maps a scalar sub-field of a composite type to its input type, without list or null wrapping.
+    fn map_composite_field_input_type(&self, field: &CompositeTypeField) -> InputType {
+        match field.type_identifier {
+            TypeIdentifier::String => InputType::string(),
+            TypeIdentifier::Int => InputType::int(),
+            TypeIdentifier::Float => InputType::float(),
+            TypeIdentifier::Boolean => InputType::boolean(),
+            TypeIdentifier::UUID => InputType::uuid(),
+            TypeIdentifier::DateTime => InputType::date_time(),
+            TypeIdentifier::Json => InputType::json(),
+            TypeIdentifier::Decimal => InputType::decimal(),
+            TypeIdentifier::BigInt => InputType::bigint(),
+            TypeIdentifier::Bytes => InputType::bytes(),
+            TypeIdentifier::Enum(_) => {
+                let internal_enum = field
+                    .internal_enum
+                    .as_ref()
+                    .expect("A field with TypeIdentifier Enum must always have an enum.");
+
+                let et: EnumType = internal_enum.clone().into();
+                et.into()
+            }
+        }
+    }
+
     fn map_enum_input_type(&self, field: &ScalarFieldRef) -> InputType {
         let internal_enum = field
             .internal_enum
@@ -1,9 +1,11 @@
 use super::*;
 
+mod composite_input_type_extension;
 mod create_input_type_extension;
 mod input_builder_extensions;
 mod update_input_type_extension;
 
+pub use composite_input_type_extension::*;
 pub use create_input_type_extension::*;
 pub use input_builder_extensions::*;
 pub use update_input_type_extension::*;
@@ -212,6 +214,15 @@ impl<'a> InputTypeBuilderBase<'a> for InputTypeBuilder<'a> {
 }
 
 impl<'a> InputBuilderExtensions for InputTypeBuilder<'a> {}
+impl<'a> CompositeInputTypeBuilderExtension<'a> for InputTypeBuilder<'a> {
+    fn find_composite_type(&self, name: &str) -> CompositeType {
+        self.internal_data_model
+            .find_composite_type(name)
+            .expect("Invariant violation: Composite fields refer to existing composite types.")
+            .clone()
+    }
+}
+
 impl<'a> CreateInputTypeBuilderExtension<'a> for InputTypeBuilder<'a> {}
 impl<'a> UpdateInputTypeBuilderExtension<'a> for InputTypeBuilder<'a> {}
 
@@ -33,13 +33,22 @@ pub trait UpdateInputTypeBuilderExtension<'a>: InputTypeBuilderBase<'a> + Create
     }
 
     fn scalar_input_fields_for_update(&self, model: ModelRef) -> Vec<InputField> {
-        self.scalar_input_fields(
+        let (composite_fields, scalar_fields): (Vec<ScalarFieldRef>, Vec<ScalarFieldRef>) = model
+            .fields()
+            .scalar_writable()
+            .into_iter()
+            .partition(|f| f.is_composite());
+
+        let mut fields = self.scalar_input_fields(
             model.name.clone(),
             "Update",
-            model.fields().scalar_writable(),
+            scalar_fields,
             |f: ScalarFieldRef| self.map_optional_input_type(&f),
             false,
-        )
+        );
+
+        fields.append(&mut self.composite_input_fields_update(composite_fields));
+        fields
     }
 
     /// For update input types only. Compute input fields for relational fields.
@@ -18,8 +18,8 @@ use object_type_builder::*;
 /// Common module imports shared accross submodules.
 use crate::schema::*;
 use prisma_models::{
-    Field as ModelField, Index, InternalDataModelRef, ModelRef, RelationFieldRef, ScalarField, ScalarFieldRef,
-    SortOrder, TypeIdentifier,
+    CompositeType, CompositeTypeField, Field as ModelField, Index, InternalDataModelRef, ModelRef, RelationFieldRef,
+    ScalarField, ScalarFieldRef, SortOrder, TypeIdentifier,
 };
 use std::sync::{Arc, Weak};
 
@@ -104,15 +104,12 @@ impl<'a> ObjectTypeBuilder<'a> {
                     related_model_obj
                 }
             }
-            ModelField::Scalar(sf) => match sf.type_identifier {
-                TypeIdentifier::String => OutputType::string(),
-                TypeIdentifier::Float => OutputType::float(),
-                TypeIdentifier::Boolean => OutputType::boolean(),
-                TypeIdentifier::Enum(_) => Self::map_enum_field(sf).into(),
-                TypeIdentifier::Json => OutputType::json(),
-                TypeIdentifier::DateTime => OutputType::date_time(),
-                TypeIdentifier::UUID => OutputType::uuid(),
-                TypeIdentifier::Int => OutputType::int(),
+            ModelField::Scalar(sf) => match sf.composite_type() {
+                Some(composite_type) => OutputType::object(self.composite_object_type(&composite_type)),
+                None => match sf.type_identifier {
+                    TypeIdentifier::Enum(_) => Self::map_enum_field(sf).into(),
+                    ref type_identifier => Self::map_type_identifier(type_identifier),
+                },
             },
         };
 
@@ -169,7 +166,7 @@ impl<'a> ObjectTypeBuilder<'a> {
             .fields()
             .scalar()
             .into_iter()
-            .filter(|field| !field.is_list)
+            .filter(|field| !field.is_list && !field.is_composite())
             .map(|field| {
                 vec![
                     (
@@ -197,6 +194,67 @@ impl<'a> ObjectTypeBuilder<'a> {
         argument("orderBy", InputType::opt(enum_type.into()), None)
     }
 
+    /// Builds the output object type of a composite type, e.g. `Address`.
+    /// Composite object types are not backed by a model.
+    pub fn composite_object_type(&self, composite_type: &CompositeType) -> ObjectTypeRef {
+        return_cached!(self.get_cache(), &composite_type.name);
+
+        let object = ObjectTypeStrongRef::new(ObjectType::new(&composite_type.name, None));
+
+        // Cache first to allow composite types to (indirectly) contain themselves.
+        self.cache(composite_type.name.clone(), ObjectTypeStrongRef::clone(&object));
+
+        let fields = composite_type
+            .fields
+            .iter()
+            .map(|f| field(f.name.clone(), vec![], self.map_composite_field_output_type(f), None))
+            .collect();
+
+        object.set_fields(fields);
+        ObjectTypeStrongRef::downgrade(&object)
+    }
+
+    fn map_composite_field_output_type(&self, composite_field: &CompositeTypeField) -> OutputType {
+        let output_type = match (&composite_field.composite_type, &composite_field.internal_enum) {
+            (Some(name), _) => {
+                let composite_type = self
+                    .internal_data_model
+                    .find_composite_type(name)
+                    .expect("Invariant violation: Composite fields refer to existing composite types.");
+
+                OutputType::object(self.composite_object_type(composite_type))
+            }
+            (None, Some(internal_enum)) => EnumType::from(internal_enum.clone()).into(),
+            (None, None) => Self::map_type_identifier(&composite_field.type_identifier),
+        };
+
+        if composite_field.is_list {
+            OutputType::list(output_type)
+        } else if !composite_field.is_required {
+            OutputType::opt(output_type)
+        } else {
+            output_type
+        }
+    }
+
+    fn map_type_identifier(type_identifier: &TypeIdentifier) -> OutputType {
+        match type_identifier {
+            TypeIdentifier::String => OutputType::string(),
+            TypeIdentifier::Float => OutputType::float(),
+            TypeIdentifier::Boolean => OutputType::boolean(),
+            TypeIdentifier::Json => OutputType::json(),
+            TypeIdentifier::DateTime => OutputType::date_time(),
+            TypeIdentifier::UUID => OutputType::uuid(),
+            TypeIdentifier::Int => OutputType::int(),
+            TypeIdentifier::Decimal => OutputType::decimal(),
+            TypeIdentifier::BigInt => OutputType::bigint(),
+            TypeIdentifier::Bytes => OutputType::bytes(),
+            TypeIdentifier::Enum(_) => {
+                panic!("Invariant violation: Enum fields are mapped via their internal enum.")
+            }
+        }
+    }
+
     pub fn map_enum_field(scalar_field: &Arc<ScalarField>) -> EnumType {
         match scalar_field.type_identifier {
             TypeIdentifier::Enum(_) => {
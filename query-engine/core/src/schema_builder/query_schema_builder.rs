@@ -63,7 +63,10 @@ impl<'a> QuerySchemaBuilder<'a> {
         mode: BuildMode,
         enable_raw_queries: bool,
     ) -> Self {
-        let filter_object_type_builder = Arc::new(FilterObjectTypeBuilder::new(capabilities));
+        let filter_object_type_builder = Arc::new(FilterObjectTypeBuilder::new(
+            Arc::clone(internal_data_model),
+            capabilities,
+        ));
         let input_type_builder = Arc::new(InputTypeBuilder::new(
             Arc::clone(internal_data_model),
             Arc::downgrade(&filter_object_type_builder),
@@ -155,10 +158,12 @@ impl<'a> QuerySchemaBuilder<'a> {
     }
 
     /// Builds the root mutation type.
+    /// Views are read-only, no mutations are generated for them.
     fn build_mutation_type(&self) -> (OutputType, ObjectTypeStrongRef) {
         let non_embedded_models = self.non_embedded_models();
         let mut fields: Vec<Field> = non_embedded_models
             .into_iter()
+            .filter(|model| !model.is_view)
             .map(|model| {
                 let mut vec = vec![self.create_item_field(Arc::clone(&model))];
 
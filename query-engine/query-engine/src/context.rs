@@ -1,4 +1,9 @@
-use crate::{exec_loader, PrismaError, PrismaResult};
+use crate::{
+    exec_loader,
+    request_handlers::DocumentCache,
+    subscriptions::{ChangeEvents, DEFAULT_POLL_INTERVAL},
+    PrismaError, PrismaResult,
+};
 use query_core::{
     schema::{QuerySchemaRef, SupportedCapabilities},
     BuildMode, QueryExecutor, QuerySchemaBuilder,
@@ -6,7 +11,13 @@ use query_core::{
 // use prisma_models::InternalDataModelRef;
 use datamodel::{Configuration, Datamodel};
 use prisma_models::DatamodelConverter;
-use std::sync::Arc;
+use std::{sync::Arc, time::Duration};
+
+/// Number of query documents cached if not configured otherwise.
+pub const DEFAULT_QUERY_CACHE_SIZE: usize = 500;
+
+/// Number of registered persisted queries kept if not configured otherwise.
+pub const DEFAULT_PERSISTED_QUERY_CACHE_SIZE: usize = 1000;
 
 /// Prisma request context containing all immutable state of the process.
 /// There is usually only one context initialized per process.
@@ -17,11 +28,18 @@ pub struct PrismaContext {
     dm: Datamodel,
     /// Central query executor.
     pub executor: Box<dyn QueryExecutor + Send + Sync + 'static>,
+    /// Cache of parsed and validated query documents, also holding persisted queries.
+    document_cache: DocumentCache,
+    /// Record change events of the database, streamed to subscriptions.
+    change_events: ChangeEvents,
 }
 
 pub struct ContextBuilder {
     legacy: bool,
     enable_raw_queries: bool,
+    query_cache_size: usize,
+    persisted_query_cache_size: usize,
+    subscription_poll_interval: Duration,
     datamodel: Datamodel,
     config: Configuration,
 }
@@ -37,14 +55,46 @@ impl ContextBuilder {
         self
     }
 
+    pub fn query_cache_size(mut self, val: usize) -> Self {
+        self.query_cache_size = val;
+        self
+    }
+
+    pub fn persisted_query_cache_size(mut self, val: usize) -> Self {
+        self.persisted_query_cache_size = val;
+        self
+    }
+
+    pub fn subscription_poll_interval(mut self, val: Duration) -> Self {
+        self.subscription_poll_interval = val;
+        self
+    }
+
     pub async fn build(self) -> PrismaResult<PrismaContext> {
-        PrismaContext::new(self.config, self.datamodel, self.legacy, self.enable_raw_queries).await
+        PrismaContext::new(
+            self.config,
+            self.datamodel,
+            self.legacy,
+            self.enable_raw_queries,
+            self.query_cache_size,
+            self.persisted_query_cache_size,
+            self.subscription_poll_interval,
+        )
+        .await
     }
 }
 
 impl PrismaContext {
     /// Initializes a new Prisma context.
-    async fn new(config: Configuration, dm: Datamodel, legacy: bool, enable_raw_queries: bool) -> PrismaResult<Self> {
+    async fn new(
+        config: Configuration,
+        dm: Datamodel,
+        legacy: bool,
+        enable_raw_queries: bool,
+        query_cache_size: usize,
+        persisted_query_cache_size: usize,
+        subscription_poll_interval: Duration,
+    ) -> PrismaResult<Self> {
         let template = DatamodelConverter::convert(&dm);
 
         // We only support one data source at the moment, so take the first one (default not exposed yet).
@@ -56,6 +106,8 @@ impl PrismaContext {
         // Load executor
         let (db_name, executor) = exec_loader::load(&**data_source).await?;
 
+        let change_events = ChangeEvents::new(&**data_source, &db_name, subscription_poll_interval);
+
         // Build internal data model
         let internal_data_model = template.build(db_name);
 
@@ -73,6 +125,8 @@ impl PrismaContext {
             query_schema,
             dm,
             executor,
+            document_cache: DocumentCache::new(query_cache_size, persisted_query_cache_size),
+            change_events,
         })
     }
 
@@ -80,6 +134,9 @@ impl PrismaContext {
         ContextBuilder {
             legacy: false,
             enable_raw_queries: false,
+            query_cache_size: DEFAULT_QUERY_CACHE_SIZE,
+            persisted_query_cache_size: DEFAULT_PERSISTED_QUERY_CACHE_SIZE,
+            subscription_poll_interval: DEFAULT_POLL_INTERVAL,
             datamodel,
             config,
         }
@@ -93,6 +150,14 @@ impl PrismaContext {
         &self.dm
     }
 
+    pub fn document_cache(&self) -> &DocumentCache {
+        &self.document_cache
+    }
+
+    pub fn change_events(&self) -> &ChangeEvents {
+        &self.change_events
+    }
+
     pub fn primary_connector(&self) -> &'static str {
         self.executor.primary_connector()
     }
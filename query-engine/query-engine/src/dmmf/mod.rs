@@ -1,5 +1,6 @@
 mod schema;
 
+use crate::request_handlers::{JSON_PROTOCOL_CONTENT_TYPE, SCALARS_SELECTION};
 use datamodel;
 use query_core::schema::{QuerySchemaRef, QuerySchemaRenderer};
 use schema::*;
@@ -14,6 +15,25 @@ pub struct DataModelMetaFormat {
     pub data_model: serde_json::Value,
     pub schema: DMMFSchema,
     pub mappings: Vec<DMMFMapping>,
+    pub json_protocol: DMMFJsonProtocol,
+}
+
+/// Describes how to address the JSON protocol. Queries are identified by model name and action,
+/// the available actions of a model are the keys of its entry in the `mappings`.
+#[derive(Debug, Serialize)]
+#[serde(rename_all = "camelCase")]
+pub struct DMMFJsonProtocol {
+    pub content_type: &'static str,
+    pub scalars_selection: &'static str,
+}
+
+impl Default for DMMFJsonProtocol {
+    fn default() -> Self {
+        Self {
+            content_type: JSON_PROTOCOL_CONTENT_TYPE,
+            scalars_selection: SCALARS_SELECTION,
+        }
+    }
 }
 
 #[derive(Debug)]
@@ -80,5 +100,6 @@ pub fn render_dmmf(dml: &datamodel::Datamodel, query_schema: QuerySchemaRef) ->
         data_model: datamodel_json,
         schema,
         mappings,
+        json_protocol: DMMFJsonProtocol::default(),
     }
 }
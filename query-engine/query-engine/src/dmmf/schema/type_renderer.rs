@@ -88,6 +88,9 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
                     ScalarType::JsonList => "Json",
+                    ScalarType::Decimal => "Decimal",
+                    ScalarType::BigInt => "BigInt",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
 
@@ -165,6 +168,9 @@ impl<'a> DMMFTypeRenderer<'a> {
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
                     ScalarType::JsonList => "Json",
+                    ScalarType::Decimal => "Decimal",
+                    ScalarType::BigInt => "BigInt",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
 
@@ -1,5 +1,5 @@
 use connector::error::ConnectorError;
-use datamodel::error::ErrorCollection;
+use datamodel::{error::ErrorCollection, SchemaFiles};
 use failure::{Error, Fail};
 use graphql_parser::query::ParseError as GqlParseError;
 use query_core::{response_ir, CoreError};
@@ -23,7 +23,7 @@ pub enum PrismaError {
     ConnectorError(ConnectorError),
 
     #[fail(display = "{}", _0)]
-    ConversionError(ErrorCollection, String),
+    ConversionError(ErrorCollection, SchemaFiles),
 
     #[fail(display = "{}", _0)]
     IOError(Error),
@@ -40,6 +40,17 @@ pub enum PrismaError {
 
     #[fail(display = "{}", _0)]
     QueryConversionError(String),
+
+    /// (Validation error, position in the query)
+    #[fail(display = "Query validation error at {}: {}", _1, _0)]
+    QueryValidationError(String, String),
+
+    /// (SHA-256 hash of the query)
+    #[fail(display = "Persisted query not found: {}", _0)]
+    PersistedQueryNotFound(String),
+
+    #[fail(display = "Error in change event source: {}", _0)]
+    ChangeEventsError(String),
 }
 
 impl PrismaError {
@@ -52,8 +63,8 @@ impl PrismaError {
                 user_facing_error: Some(err),
                 ..
             }) => err.into(),
-            PrismaError::ConversionError(errors, dml_string) => {
-                let mut full_error = errors.to_pretty_string("schema.prisma", &dml_string);
+            PrismaError::ConversionError(errors, schema_files) => {
+                let mut full_error = schema_files.to_pretty_string(&errors);
                 write!(full_error, "\nValidation Error Count: {}", errors.to_iter().len())?;
 
                 user_facing_errors::Error::from(
@@ -93,6 +104,25 @@ impl From<PrismaError> for response_ir::ResponseError {
     fn from(other: PrismaError) -> Self {
         match other {
             PrismaError::CoreError(core_error) => response_ir::ResponseError::from(core_error),
+            PrismaError::QueryValidationError(query_validation_error, query_position) => {
+                let known_error =
+                    user_facing_errors::KnownError::new(user_facing_errors::query_engine::QueryValidationFailed {
+                        query_validation_error,
+                        query_position,
+                    })
+                    .unwrap();
+
+                response_ir::ResponseError::from(user_facing_errors::Error::from(known_error))
+            }
+            PrismaError::PersistedQueryNotFound(sha256_hash) => {
+                let known_error =
+                    user_facing_errors::KnownError::new(user_facing_errors::query_engine::PersistedQueryNotFound {
+                        sha256_hash,
+                    })
+                    .unwrap();
+
+                response_ir::ResponseError::from(user_facing_errors::Error::from(known_error))
+            }
             err => response_ir::ResponseError::from(user_facing_errors::Error::from_dyn_error(&err.compat())),
         }
     }
@@ -9,7 +9,7 @@ use once_cell::sync::Lazy;
 use opt::*;
 use request_handlers::{PrismaRequest, PrismaResponse, RequestHandler};
 use server::{HttpServer, HttpServerBuilder};
-use std::{convert::TryFrom, error::Error, net::SocketAddr, process};
+use std::{convert::TryFrom, error::Error, net::SocketAddr, process, time::Duration};
 use structopt::StructOpt;
 use tracing::subscriber;
 use tracing_subscriber::{EnvFilter, FmtSubscriber};
@@ -22,6 +22,7 @@ mod exec_loader;
 mod opt;
 mod request_handlers;
 mod server;
+mod subscriptions;
 
 #[cfg(test)]
 mod tests;
@@ -70,7 +71,12 @@ async fn main() -> Result<(), AnyError> {
                     HttpServer::builder(config, datamodel)
                         .legacy(opts.legacy)
                         .enable_raw_queries(opts.enable_raw_queries)
-                        .enable_playground(opts.enable_playground),
+                        .enable_playground(opts.enable_playground)
+                        .query_cache_size(opts.query_cache_size)
+                        .persisted_query_cache_size(opts.persisted_query_cache_size)
+                        .subscription_poll_interval(Duration::from_millis(opts.subscription_poll_interval))
+                        .drain_grace_period(Duration::from_secs(opts.drain_grace_period))
+                        .shutdown_timeout(Duration::from_secs(opts.shutdown_timeout)),
                 )
             };
 
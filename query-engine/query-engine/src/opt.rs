@@ -1,7 +1,7 @@
 use crate::{error::PrismaError, PrismaResult};
-use datamodel::{Configuration, Datamodel};
+use datamodel::{Configuration, Datamodel, SchemaFiles};
 use serde::Deserialize;
-use std::{ffi::OsStr, fs::File, io::Read};
+use std::{ffi::OsStr, path::Path};
 use structopt::StructOpt;
 
 #[derive(Debug, StructOpt, Clone)]
@@ -51,9 +51,9 @@ pub struct PrismaOpt {
     /// The port the query engine should bind to.
     #[structopt(long, short, env, default_value = "4466")]
     pub port: u16,
-    /// Path to the Prisma datamodel file
-    #[structopt(long, env = "PRISMA_DML_PATH", parse(from_os_str = load_datamodel_file))]
-    datamodel_path: Option<String>,
+    /// Path to the Prisma datamodel file, or to a directory of `.prisma` files making up the datamodel
+    #[structopt(long, env = "PRISMA_DML_PATH", parse(from_os_str = load_datamodel_files))]
+    datamodel_path: Option<SchemaFiles>,
     /// Base64 encoded Prisma datamodel
     #[structopt(long, env = "PRISMA_DML", parse(try_from_str = parse_base64_string))]
     datamodel: Option<String>,
@@ -69,6 +69,23 @@ pub struct PrismaOpt {
     /// Enables the GraphQL playground
     #[structopt(long, short = "g")]
     pub enable_playground: bool,
+    /// Number of parsed query documents kept in memory. 0 disables caching, persisted queries are
+    /// registered regardless.
+    #[structopt(long, env, default_value = "500")]
+    pub query_cache_size: usize,
+    /// Number of registered persisted queries kept in memory, the least recently used ones are
+    /// evicted first. 0 disables persisted queries.
+    #[structopt(long, env, default_value = "1000")]
+    pub persisted_query_cache_size: usize,
+    /// Interval in milliseconds in which SQLite is polled for record change events of subscriptions.
+    #[structopt(long, env, default_value = "500")]
+    pub subscription_poll_interval: u64,
+    /// Seconds during which `/status` reports draining on SIGTERM or SIGINT while requests are still served.
+    #[structopt(long, env, default_value = "5")]
+    pub drain_grace_period: u64,
+    /// Seconds to wait for in-flight requests after the drain grace period, before their transactions are rolled back.
+    #[structopt(long, env, default_value = "10")]
+    pub shutdown_timeout: u64,
     #[structopt(subcommand)]
     pub subcommand: Option<Subcommand>,
 }
@@ -80,12 +97,12 @@ struct SourceOverride {
 }
 
 impl PrismaOpt {
-    fn datamodel_str(&self) -> PrismaResult<&str> {
+    fn schema_files(&self) -> PrismaResult<SchemaFiles> {
         let res = self
             .datamodel
             .as_ref()
-            .map(|dm| dm.as_str())
-            .or(self.datamodel_path.as_ref().map(|dm| dm.as_str()))
+            .map(|dm| SchemaFiles::single("schema.prisma", dm))
+            .or_else(|| self.datamodel_path.clone())
             .ok_or_else(|| {
                 PrismaError::ConfigurationError(
                     "Datamodel should be provided either as path or base64-encoded string.".into(),
@@ -96,7 +113,8 @@ impl PrismaOpt {
     }
 
     pub fn datamodel(&self, ignore_env_errors: bool) -> PrismaResult<Datamodel> {
-        let datamodel_str = self.datamodel_str()?;
+        let schema_files = self.schema_files()?;
+        let datamodel_str = schema_files.schema();
 
         let datamodel = if ignore_env_errors {
             datamodel::parse_datamodel_and_ignore_env_errors(datamodel_str)
@@ -105,13 +123,14 @@ impl PrismaOpt {
         };
 
         match datamodel {
-            Err(errors) => Err(PrismaError::ConversionError(errors, datamodel_str.to_string())),
+            Err(errors) => Err(PrismaError::ConversionError(errors, schema_files)),
             _ => Ok(datamodel?),
         }
     }
 
     pub fn configuration(&self, ignore_env_errors: bool) -> PrismaResult<Configuration> {
-        let datamodel_str = self.datamodel_str()?;
+        let schema_files = self.schema_files()?;
+        let datamodel_str = schema_files.schema();
 
         let config_result = if ignore_env_errors {
             datamodel::parse_configuration_and_ignore_env_errors(datamodel_str)
@@ -120,7 +139,7 @@ impl PrismaOpt {
         };
 
         match config_result {
-            Err(errors) => Err(PrismaError::ConversionError(errors, datamodel_str.to_string())),
+            Err(errors) => Err(PrismaError::ConversionError(errors, schema_files)),
             Ok(mut configuration) => {
                 if let Some(ref overwrites) = self.overwrite_datasources {
                     let datasource_overwrites: Vec<SourceOverride> = serde_json::from_str(&overwrites)?;
@@ -157,12 +176,6 @@ fn parse_base64_string(s: &str) -> PrismaResult<String> {
     }
 }
 
-fn load_datamodel_file(path: &OsStr) -> String {
-    let mut f = File::open(path).expect(&format!("Could not open datamodel file {:?}", path));
-    let mut datamodel = String::new();
-
-    f.read_to_string(&mut datamodel)
-        .expect(&format!("Could not read datamodel file: {:?}", path));
-
-    datamodel
+fn load_datamodel_files(path: &OsStr) -> SchemaFiles {
+    SchemaFiles::load(Path::new(path)).expect(&format!("Could not read datamodel files from {:?}", path))
 }
@@ -0,0 +1,169 @@
+//! Execution of converted query documents, shared by all request handlers.
+use crate::{context::PrismaContext, PrismaError, PrismaResponse, PrismaResult};
+use futures::{future, FutureExt};
+use indexmap::IndexMap;
+use query_core::{
+    response_ir, BatchDocument, CompactedDocument, CoreError, Item, Operation, QueryGraphBuilder, QueryValue,
+    Responses, ValidatedOperation,
+};
+use std::{panic::AssertUnwindSafe, sync::Arc};
+
+pub(crate) fn error_response(err: PrismaError) -> PrismaResponse {
+    let mut responses = response_ir::Responses::default();
+    responses.insert_error(err);
+
+    PrismaResponse::Single(responses)
+}
+
+pub(crate) async fn handle_single_query(query: ValidatedOperation, ctx: Arc<PrismaContext>) -> PrismaResponse {
+    use user_facing_errors::Error;
+
+    let responses = match AssertUnwindSafe(handle_validated_query(query, &*ctx))
+        .catch_unwind()
+        .await
+    {
+        Ok(Ok(responses)) => responses,
+        Ok(Err(err)) => {
+            let mut responses = response_ir::Responses::default();
+            responses.insert_error(err);
+            responses
+        }
+        // panicked
+        Err(err) => {
+            let mut responses = response_ir::Responses::default();
+            let error = Error::from_panic_payload(&err);
+
+            responses.insert_error(error);
+            responses
+        }
+    };
+
+    PrismaResponse::Single(responses)
+}
+
+/// Executes a batch of operations, compacting it into a single query if possible.
+pub(crate) async fn handle_batch_document(batch: BatchDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+    match batch.compact() {
+        BatchDocument::Multi(batch) => handle_batch(batch, ctx).await,
+        BatchDocument::Compact(compacted) => handle_compacted(compacted, ctx).await,
+    }
+}
+
+async fn handle_batch(queries: Vec<Operation>, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+    let mut futures = Vec::with_capacity(queries.len());
+
+    for operation in queries.into_iter() {
+        let validated = QueryGraphBuilder::new(Arc::clone(ctx.query_schema())).validate(operation);
+
+        let future = match validated {
+            Ok(operation) => handle_single_query(operation, ctx.clone()).boxed(),
+            Err(err) => future::ready(error_response(CoreError::from(err).into())).boxed(),
+        };
+
+        futures.push(tokio::spawn(future));
+    }
+
+    let responses = future::join_all(futures)
+        .await
+        .into_iter()
+        .map(|res| res.expect("IO Error in tokio::spawn"))
+        .collect();
+
+    PrismaResponse::Multi(responses)
+}
+
+async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
+    use user_facing_errors::Error;
+
+    let plural_name = document.plural_name();
+    let singular_name = document.single_name();
+    let keys = document.keys;
+    let arguments = document.arguments;
+    let nested_selection = document.nested_selection;
+
+    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
+        .catch_unwind()
+        .await
+    {
+        Ok(Ok(mut responses)) => {
+            // We find the response data and make a hash from the given unique
+            // keys.
+            let data = responses
+                .take_data(plural_name)
+                .unwrap()
+                .into_list()
+                .unwrap()
+                .index_by(keys.as_slice());
+
+            let results = arguments
+                .into_iter()
+                .map(|args| {
+                    let vals: Vec<QueryValue> = args.into_iter().map(|(_, v)| v).collect();
+                    let mut responses = Responses::with_capacity(1);
+
+                    // Copying here is mandatory due to some of the queries
+                    // might be repeated with the same arguments in the original
+                    // batch. We need to give the same answer for both of them.
+                    match data.get(&vals) {
+                        Some(result) => {
+                            // Filter out all the keys not selected in the
+                            // original query.
+                            let result: IndexMap<String, Item> = result
+                                .clone()
+                                .into_iter()
+                                .filter(|(k, _)| nested_selection.contains(k))
+                                .collect();
+
+                            responses.insert_data(&singular_name, Item::Map(result));
+                        }
+                        _ => {
+                            responses.insert_data(&singular_name, Item::null());
+                        }
+                    }
+
+                    PrismaResponse::Single(responses)
+                })
+                .collect();
+
+            PrismaResponse::Multi(results)
+        }
+        Ok(Err(err)) => {
+            let mut responses = response_ir::Responses::default();
+            responses.insert_error(err);
+            PrismaResponse::Single(responses)
+        }
+        // panicked
+        Err(err) => {
+            let mut responses = response_ir::Responses::default();
+            let error = Error::from_panic_payload(&err);
+
+            responses.insert_error(error);
+            PrismaResponse::Single(responses)
+        }
+    }
+}
+
+async fn handle_validated_query(
+    operation: ValidatedOperation,
+    ctx: &PrismaContext,
+) -> PrismaResult<response_ir::Responses> {
+    ctx.executor
+        .execute_validated(operation, Arc::clone(ctx.query_schema()))
+        .await
+        .map_err(|err| {
+            debug!("{}", err);
+            let ce: CoreError = err.into();
+            ce.into()
+        })
+}
+
+async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<response_ir::Responses> {
+    ctx.executor
+        .execute(query_doc, Arc::clone(ctx.query_schema()))
+        .await
+        .map_err(|err| {
+            debug!("{}", err);
+            let ce: CoreError = err.into();
+            ce.into()
+        })
+}
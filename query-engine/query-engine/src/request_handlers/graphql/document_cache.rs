@@ -0,0 +1,130 @@
+use super::{protocol_adapter::GraphQLProtocolAdapter, SingleQuery};
+use crate::{PrismaError, PrismaResult};
+use graphql_parser::{self as gql, query::Document};
+use lru_cache::LruCache;
+use query_core::{CoreError, Operation, QueryGraphBuilder, QuerySchemaRef, Selection, ValidatedOperation};
+use sha2::{Digest, Sha256};
+use std::sync::{Arc, Mutex};
+
+/// The only supported version of the persisted query protocol.
+const PERSISTED_QUERY_VERSION: u32 = 1;
+
+/// LRU cache of parsed GraphQL documents, keyed by the SHA-256 hash of the query text.
+///
+/// The cache also holds the registry of persisted queries: Clients can send the hash of a query
+/// instead of the query text. If the hash is unknown, the request fails with a
+/// `PersistedQueryNotFound` error, and the client registers the query by sending the text along
+/// with the hash. Registered queries are kept in their own LRU cache, independently of the
+/// documents: documents evicted from the cache are parsed again from the registered text, and
+/// clients register evicted queries again.
+///
+/// Only documents are cached, not validated operations: Validation evaluates the `@default`
+/// generators of the arguments, such as `cuid()` or `now()`, which must run for every request.
+pub struct DocumentCache {
+    documents: Mutex<LruCache<String, Arc<Document>>>,
+
+    /// The text of the registered persisted queries, by hash.
+    persisted_queries: Mutex<LruCache<String, Arc<str>>>,
+}
+
+impl DocumentCache {
+    /// Creates a cache holding at most `capacity` documents and `persisted_queries_capacity`
+    /// registered persisted queries. A capacity of 0 disables caching, respectively persisted
+    /// queries.
+    pub fn new(capacity: usize, persisted_queries_capacity: usize) -> Self {
+        Self {
+            documents: Mutex::new(LruCache::new(capacity)),
+            persisted_queries: Mutex::new(LruCache::new(persisted_queries_capacity)),
+        }
+    }
+
+    /// Converts the query into an operation, parsing the query only if it isn't cached yet.
+    pub fn operation(&self, query: SingleQuery) -> PrismaResult<Operation> {
+        let document = self.document(&query)?;
+
+        GraphQLProtocolAdapter::convert((*document).clone(), query.operation_name, &query.variables)
+    }
+
+    /// Converts the query into an operation and validates it against the query schema.
+    pub fn validated_operation(
+        &self,
+        query: SingleQuery,
+        query_schema: &QuerySchemaRef,
+    ) -> PrismaResult<ValidatedOperation> {
+        let operation = self.operation(query)?;
+
+        Ok(QueryGraphBuilder::new(Arc::clone(query_schema))
+            .validate(operation)
+            .map_err(CoreError::from)?)
+    }
+
+    /// Converts the query into the root field of its subscription, parsing the query only if it isn't cached yet.
+    pub fn subscription(&self, query: SingleQuery) -> PrismaResult<Selection> {
+        let document = self.document(&query)?;
+
+        GraphQLProtocolAdapter::convert_subscription((*document).clone(), query.operation_name, &query.variables)
+    }
+
+    /// Looks up the parsed document for the query, registering it on a miss.
+    fn document(&self, query: &SingleQuery) -> PrismaResult<Arc<Document>> {
+        let hash = match (&query.query, &query.extensions.persisted_query) {
+            (_, Some(persisted)) if persisted.version != PERSISTED_QUERY_VERSION => {
+                return Err(PrismaError::UnsupportedFeatureError(
+                    "Persisted query version",
+                    format!(
+                        "Version {} is not supported, expected version {}.",
+                        persisted.version, PERSISTED_QUERY_VERSION
+                    ),
+                ))
+            }
+            (Some(text), Some(persisted)) => {
+                let hash = Self::hash(text);
+
+                if hash != persisted.sha256_hash.to_lowercase() {
+                    return Err(PrismaError::QueryConversionError(format!(
+                        "The provided sha256Hash '{}' does not match the query.",
+                        persisted.sha256_hash
+                    )));
+                }
+
+                hash
+            }
+            (Some(text), None) => Self::hash(text),
+            (None, Some(persisted)) => persisted.sha256_hash.to_lowercase(),
+            (None, None) => {
+                return Err(PrismaError::QueryConversionError(
+                    "The request contains neither a query nor a persisted query hash.".into(),
+                ))
+            }
+        };
+
+        if let Some(document) = self.documents.lock().unwrap().get_mut(&hash) {
+            return Ok(Arc::clone(document));
+        }
+
+        let text: Arc<str> = match &query.query {
+            Some(text) => Arc::from(text.as_str()),
+            None => self
+                .persisted_queries
+                .lock()
+                .unwrap()
+                .get_mut(&hash)
+                .cloned()
+                .ok_or_else(|| PrismaError::PersistedQueryNotFound(hash.clone()))?,
+        };
+
+        let document = Arc::new(gql::parse_query(&text)?);
+
+        if query.extensions.persisted_query.is_some() {
+            self.persisted_queries.lock().unwrap().insert(hash.clone(), text);
+        }
+
+        self.documents.lock().unwrap().insert(hash, Arc::clone(&document));
+
+        Ok(document)
+    }
+
+    fn hash(query: &str) -> String {
+        format!("{:x}", Sha256::digest(query.as_bytes()))
+    }
+}
@@ -1,21 +1,37 @@
-use super::protocol_adapter::GraphQLProtocolAdapter;
-use crate::{context::PrismaContext, PrismaError, PrismaRequest, PrismaResponse, PrismaResult, RequestHandler};
-use async_trait::async_trait;
-use futures::{future, FutureExt};
-use graphql_parser as gql;
-use indexmap::IndexMap;
-use query_core::{
-    response_ir, BatchDocument, CompactedDocument, CoreError, Item, Operation, QueryDocument, QueryValue, Responses,
+use super::document_cache::DocumentCache;
+use crate::{
+    context::PrismaContext,
+    request_handlers::execution::{error_response, handle_batch_document, handle_single_query},
+    PrismaRequest, PrismaResponse, PrismaResult, RequestHandler,
 };
+use async_trait::async_trait;
+use query_core::{BatchDocument, Operation};
 use serde::{Deserialize, Serialize};
-use std::{collections::HashMap, convert::TryFrom, panic::AssertUnwindSafe, sync::Arc};
+use std::{collections::HashMap, sync::Arc};
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase")]
 pub struct SingleQuery {
-    query: String,
-    operation_name: Option<String>,
-    variables: HashMap<String, String>,
+    /// The query text. Can be omitted if the hash of an already registered persisted query is given.
+    pub(super) query: Option<String>,
+    pub(super) operation_name: Option<String>,
+    #[serde(default)]
+    pub(super) variables: HashMap<String, serde_json::Value>,
+    #[serde(default)]
+    pub(super) extensions: QueryExtensions,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct QueryExtensions {
+    pub(super) persisted_query: Option<PersistedQuery>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PersistedQuery {
+    pub(super) version: u32,
+    pub(super) sha256_hash: String,
 }
 
 #[derive(Clone, Debug, Serialize, Deserialize)]
@@ -24,19 +40,22 @@ pub struct MultiQuery {
     batch: Vec<SingleQuery>,
 }
 
+// Multi has to come first: All fields of a single query are optional, which means that every
+// batch would deserialize into a single query otherwise.
 #[derive(Clone, Debug, Serialize, Deserialize)]
 #[serde(rename_all = "camelCase", untagged)]
 pub enum GraphQlBody {
-    Single(SingleQuery),
     Multi(MultiQuery),
+    Single(SingleQuery),
 }
 
 impl From<String> for SingleQuery {
     fn from(query: String) -> Self {
         SingleQuery {
-            query,
+            query: Some(query),
             operation_name: None,
             variables: HashMap::new(),
+            extensions: QueryExtensions::default(),
         }
     }
 }
@@ -47,30 +66,13 @@ impl From<&str> for SingleQuery {
     }
 }
 
-impl TryFrom<GraphQlBody> for QueryDocument {
-    type Error = PrismaError;
-
-    fn try_from(body: GraphQlBody) -> PrismaResult<Self> {
-        match body {
-            GraphQlBody::Single(body) => {
-                let gql_doc = gql::parse_query(&body.query)?;
-                let operation = GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)?;
+impl MultiQuery {
+    /// Converts all queries of the batch into operations, using the document cache for parsing.
+    fn into_batch(self, cache: &DocumentCache) -> PrismaResult<BatchDocument> {
+        let operations: PrismaResult<Vec<Operation>> =
+            self.batch.into_iter().map(|query| cache.operation(query)).collect();
 
-                Ok(QueryDocument::Single(operation))
-            }
-            GraphQlBody::Multi(bodies) => {
-                let operations: PrismaResult<Vec<Operation>> = bodies
-                    .batch
-                    .into_iter()
-                    .map(|body| {
-                        let gql_doc = gql::parse_query(&body.query)?;
-                        GraphQLProtocolAdapter::convert(gql_doc, body.operation_name)
-                    })
-                    .collect();
-
-                Ok(QueryDocument::Multi(BatchDocument::new(operations?)))
-            }
-        }
+        Ok(BatchDocument::new(operations?))
     }
 }
 
@@ -88,142 +90,15 @@ impl RequestHandler for GraphQlRequestHandler {
         let request = req.into();
         debug!("Incoming GraphQL query: {:?}", request.body);
 
-        match QueryDocument::try_from(request.body) {
-            Ok(QueryDocument::Single(query)) => handle_single_query(query, ctx.clone()).await,
-            Ok(QueryDocument::Multi(batch)) => match batch.compact() {
-                BatchDocument::Multi(batch) => handle_batch(batch, ctx).await,
-                BatchDocument::Compact(compacted) => handle_compacted(compacted, ctx).await,
+        match request.body {
+            GraphQlBody::Single(query) => match ctx.document_cache().validated_operation(query, ctx.query_schema()) {
+                Ok(operation) => handle_single_query(operation, ctx.clone()).await,
+                Err(err) => error_response(err),
+            },
+            GraphQlBody::Multi(batch) => match batch.into_batch(ctx.document_cache()) {
+                Ok(batch) => handle_batch_document(batch, ctx).await,
+                Err(err) => error_response(err),
             },
-            Err(err) => {
-                let mut responses = response_ir::Responses::default();
-                responses.insert_error(err);
-
-                PrismaResponse::Single(responses)
-            }
-        }
-    }
-}
-
-async fn handle_single_query(query: Operation, ctx: Arc<PrismaContext>) -> PrismaResponse {
-    use user_facing_errors::Error;
-
-    let responses = match AssertUnwindSafe(handle_graphql_query(query, &*ctx))
-        .catch_unwind()
-        .await
-    {
-        Ok(Ok(responses)) => responses,
-        Ok(Err(err)) => {
-            let mut responses = response_ir::Responses::default();
-            responses.insert_error(err);
-            responses
-        }
-        // panicked
-        Err(err) => {
-            let mut responses = response_ir::Responses::default();
-            let error = Error::from_panic_payload(&err);
-
-            responses.insert_error(error);
-            responses
-        }
-    };
-
-    PrismaResponse::Single(responses)
-}
-
-async fn handle_batch(queries: Vec<Operation>, ctx: &Arc<PrismaContext>) -> PrismaResponse {
-    let mut futures = Vec::with_capacity(queries.len());
-
-    for operation in queries.into_iter() {
-        futures.push(tokio::spawn(handle_single_query(operation, ctx.clone())));
-    }
-
-    let responses = future::join_all(futures)
-        .await
-        .into_iter()
-        .map(|res| res.expect("IO Error in tokio::spawn"))
-        .collect();
-
-    PrismaResponse::Multi(responses)
-}
-
-async fn handle_compacted(document: CompactedDocument, ctx: &Arc<PrismaContext>) -> PrismaResponse {
-    use user_facing_errors::Error;
-
-    let plural_name = document.plural_name();
-    let singular_name = document.single_name();
-    let keys = document.keys;
-    let arguments = document.arguments;
-    let nested_selection = document.nested_selection;
-
-    match AssertUnwindSafe(handle_graphql_query(document.operation, ctx))
-        .catch_unwind()
-        .await
-    {
-        Ok(Ok(mut responses)) => {
-            // We find the response data and make a hash from the given unique
-            // keys.
-            let data = responses
-                .take_data(plural_name)
-                .unwrap()
-                .into_list()
-                .unwrap()
-                .index_by(keys.as_slice());
-
-            let results = arguments
-                .into_iter()
-                .map(|args| {
-                    let vals: Vec<QueryValue> = args.into_iter().map(|(_, v)| v).collect();
-                    let mut responses = Responses::with_capacity(1);
-
-                    // Copying here is mandatory due to some of the queries
-                    // might be repeated with the same arguments in the original
-                    // batch. We need to give the same answer for both of them.
-                    match data.get(&vals) {
-                        Some(result) => {
-                            // Filter out all the keys not selected in the
-                            // original query.
-                            let result: IndexMap<String, Item> = result
-                                .clone()
-                                .into_iter()
-                                .filter(|(k, _)| nested_selection.contains(k))
-                                .collect();
-
-                            responses.insert_data(&singular_name, Item::Map(result));
-                        }
-                        _ => {
-                            responses.insert_data(&singular_name, Item::null());
-                        }
-                    }
-
-                    PrismaResponse::Single(responses)
-                })
-                .collect();
-
-            PrismaResponse::Multi(results)
-        }
-        Ok(Err(err)) => {
-            let mut responses = response_ir::Responses::default();
-            responses.insert_error(err);
-            PrismaResponse::Single(responses)
-        }
-        // panicked
-        Err(err) => {
-            let mut responses = response_ir::Responses::default();
-            let error = Error::from_panic_payload(&err);
-
-            responses.insert_error(error);
-            PrismaResponse::Single(responses)
         }
     }
 }
-
-async fn handle_graphql_query(query_doc: Operation, ctx: &PrismaContext) -> PrismaResult<response_ir::Responses> {
-    ctx.executor
-        .execute(query_doc, Arc::clone(ctx.query_schema()))
-        .await
-        .map_err(|err| {
-            debug!("{}", err);
-            let ce: CoreError = err.into();
-            ce.into()
-        })
-}
@@ -1,10 +1,23 @@
-use crate::{error::PrismaError, PrismaResult};
+use crate::{error::PrismaError, request_handlers::JsonProtocolAdapter, PrismaResult};
 use graphql_parser::query::{
-    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Value,
+    Definition, Document, OperationDefinition, Selection as GqlSelection, SelectionSet, Type, Value, VariableDefinition,
 };
 use query_core::query_document::*;
 use rust_decimal::Decimal;
-use std::{collections::BTreeMap, str::FromStr};
+use std::{
+    collections::{BTreeMap, HashMap},
+    str::FromStr,
+};
+
+/// Variables of a single operation, resolved against its variable definitions.
+/// A `None` value designates a declared nullable variable that has not been provided.
+type Variables = HashMap<String, Option<QueryValue>>;
+
+/// GraphQL scalars that are checked when validating variables.
+/// Enums and input objects are validated by the query document parser.
+const BUILTIN_SCALARS: &[&str] = &[
+    "Int", "Float", "Boolean", "String", "ID", "DateTime", "UUID", "Json", "Decimal", "BigInt", "Bytes",
+];
 
 /// Protocol adapter for GraphQL -> Query Document.
 ///
@@ -14,16 +27,24 @@ use std::{collections::BTreeMap, str::FromStr};
 /// - If the JSON payload specifies an operation name, only that specific operation is picked and the rest ignored.
 /// - Fields on the queries are mapped to `Field`s, including arguments.
 /// - Concrete values (e.g. in arguments) are mapped to `QueryValue`s.
+/// - Variables (`$name`) are resolved against the JSON variables of the request, validated against
+///   the declared variable types of the operation. Declared nullable variables that are not provided
+///   are treated as absent arguments.
+///
+/// Subscriptions are not operations and are converted separately by `convert_subscription`,
+/// yielding the single root field of the subscription.
 ///
 /// Currently unsupported features:
 /// - Fragments in any form.
-/// - Variables.
-/// - Subscription queries.
 /// - Query names are ignored
 pub struct GraphQLProtocolAdapter;
 
 impl GraphQLProtocolAdapter {
-    pub fn convert(gql_doc: Document, operation: Option<String>) -> PrismaResult<Operation> {
+    pub fn convert(
+        gql_doc: Document,
+        operation: Option<String>,
+        variables: &HashMap<String, serde_json::Value>,
+    ) -> PrismaResult<Operation> {
         let mut operations: Vec<Operation> = match operation {
             Some(ref op) => gql_doc
                 .definitions
@@ -32,12 +53,12 @@ impl GraphQLProtocolAdapter {
                 .ok_or_else(|| {
                     PrismaError::QueryConversionError(format!("Operation '{}' does not match any query.", op))
                 })
-                .and_then(Self::convert_definition),
+                .and_then(|def| Self::convert_definition(def, variables)),
 
             None => gql_doc
                 .definitions
                 .into_iter()
-                .map(Self::convert_definition)
+                .map(|def| Self::convert_definition(def, variables))
                 .collect::<PrismaResult<Vec<Vec<Operation>>>>()
                 .map(|r| r.into_iter().flatten().collect::<Vec<Operation>>()),
         }?;
@@ -50,7 +71,41 @@ impl GraphQLProtocolAdapter {
         Ok(operation)
     }
 
-    fn convert_definition(def: Definition) -> PrismaResult<Vec<Operation>> {
+    /// Converts the subscription of the document into the selection of its single root field.
+    pub fn convert_subscription(
+        gql_doc: Document,
+        operation: Option<String>,
+        variables: &HashMap<String, serde_json::Value>,
+    ) -> PrismaResult<Selection> {
+        let subscription = gql_doc
+            .definitions
+            .into_iter()
+            .filter(|def| match operation {
+                Some(ref op) => Self::matches_operation(def, op),
+                None => true,
+            })
+            .find_map(|def| match def {
+                Definition::Operation(OperationDefinition::Subscription(s)) => Some(s),
+                _ => None,
+            })
+            .ok_or_else(|| PrismaError::QueryConversionError("Document contained no subscription.".into()))?;
+
+        let variables = Self::resolve_variables(&subscription.variable_definitions, variables)?;
+        let mut selections = Self::convert_selection_set(subscription.selection_set, &variables)?;
+
+        match selections.len() {
+            1 => Ok(selections.pop().unwrap()),
+            n => Err(PrismaError::QueryConversionError(format!(
+                "A subscription must select exactly one root field, found {} at position {}.",
+                n, subscription.position
+            ))),
+        }
+    }
+
+    fn convert_definition(
+        def: Definition,
+        variables: &HashMap<String, serde_json::Value>,
+    ) -> PrismaResult<Vec<Operation>> {
         match def {
             Definition::Fragment(f) => Err(PrismaError::UnsupportedFeatureError(
                 "Fragment definition",
@@ -59,22 +114,31 @@ impl GraphQLProtocolAdapter {
             Definition::Operation(op) => match op {
                 OperationDefinition::Subscription(s) => Err(PrismaError::UnsupportedFeatureError(
                     "Subscription query",
-                    format!("At position {}.", s.position),
+                    format!(
+                        "At position {}. Subscriptions are only served over WebSocket connections.",
+                        s.position
+                    ),
                 )),
-                OperationDefinition::SelectionSet(s) => Self::convert_query(s),
-                OperationDefinition::Query(q) => Self::convert_query(q.selection_set),
-                OperationDefinition::Mutation(m) => Self::convert_mutation(m.selection_set),
+                OperationDefinition::SelectionSet(s) => Self::convert_query(s, &Variables::new()),
+                OperationDefinition::Query(q) => {
+                    let variables = Self::resolve_variables(&q.variable_definitions, variables)?;
+                    Self::convert_query(q.selection_set, &variables)
+                }
+                OperationDefinition::Mutation(m) => {
+                    let variables = Self::resolve_variables(&m.variable_definitions, variables)?;
+                    Self::convert_mutation(m.selection_set, &variables)
+                }
             },
         }
     }
 
-    fn convert_query(selection_set: SelectionSet) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set)
+    fn convert_query(selection_set: SelectionSet, variables: &Variables) -> PrismaResult<Vec<Operation>> {
+        Self::convert_selection_set(selection_set, variables)
             .map(|fields| fields.into_iter().map(|field| Operation::Read(field)).collect())
     }
 
-    fn convert_mutation(selection_set: SelectionSet) -> PrismaResult<Vec<Operation>> {
-        Self::convert_selection_set(selection_set).map(|fields| {
+    fn convert_mutation(selection_set: SelectionSet, variables: &Variables) -> PrismaResult<Vec<Operation>> {
+        Self::convert_selection_set(selection_set, variables).map(|fields| {
             fields
                 .into_iter()
                 .map(|selection| Operation::Write(selection))
@@ -82,7 +146,7 @@ impl GraphQLProtocolAdapter {
         })
     }
 
-    fn convert_selection_set(selection_set: SelectionSet) -> PrismaResult<Vec<Selection>> {
+    fn convert_selection_set(selection_set: SelectionSet, variables: &Variables) -> PrismaResult<Vec<Selection>> {
         selection_set
             .items
             .into_iter()
@@ -91,12 +155,13 @@ impl GraphQLProtocolAdapter {
                     let arguments: Vec<(String, QueryValue)> = f
                         .arguments
                         .into_iter()
-                        .map(|(k, v)| Ok((k, Self::convert_value(v)?)))
+                        .filter(|(_, v)| !Self::is_unset_variable(v, variables))
+                        .map(|(k, v)| Ok((k, Self::convert_value(v, variables)?)))
                         .collect::<PrismaResult<Vec<_>>>()?;
 
                     let mut builder = Selection::builder(f.name);
                     builder.set_arguments(arguments);
-                    builder.nested_selections(Self::convert_selection_set(f.selection_set)?);
+                    builder.nested_selections(Self::convert_selection_set(f.selection_set, variables)?);
 
                     if let Some(alias) = f.alias {
                         builder.alias(alias);
@@ -132,12 +197,16 @@ impl GraphQLProtocolAdapter {
         }
     }
 
-    fn convert_value(value: Value) -> PrismaResult<QueryValue> {
+    fn convert_value(value: Value, variables: &Variables) -> PrismaResult<QueryValue> {
         match value {
-            Value::Variable(name) => Err(PrismaError::UnsupportedFeatureError(
-                "Variable usage",
-                format!("Variable '{}'.", name),
-            )),
+            Value::Variable(name) => match variables.get(&name) {
+                Some(Some(value)) => Ok(value.clone()),
+                Some(None) => Ok(QueryValue::Null),
+                None => Err(PrismaError::QueryValidationError(
+                    format!("Variable '${}' is not defined by the operation.", name),
+                    format!("${}", name),
+                )),
+            },
             Value::Int(i) => match i.as_i64() {
                 Some(i) => Ok(QueryValue::Int(i)),
                 None => Err(PrismaError::QueryConversionError(format!(
@@ -161,7 +230,7 @@ impl GraphQLProtocolAdapter {
             Value::List(values) => {
                 let values: Vec<QueryValue> = values
                     .into_iter()
-                    .map(Self::convert_value)
+                    .map(|value| Self::convert_value(value, variables))
                     .collect::<PrismaResult<Vec<QueryValue>>>()?;
 
                 Ok(QueryValue::List(values))
@@ -169,11 +238,106 @@ impl GraphQLProtocolAdapter {
             Value::Object(map) => {
                 let values = map
                     .into_iter()
-                    .map(|(k, v)| Self::convert_value(v).map(|v| (k, v)))
+                    .filter(|(_, v)| !Self::is_unset_variable(v, variables))
+                    .map(|(k, v)| Self::convert_value(v, variables).map(|v| (k, v)))
                     .collect::<PrismaResult<BTreeMap<String, QueryValue>>>()?;
 
                 Ok(QueryValue::Object(values))
             }
         }
     }
+
+    /// Checks if the given value references a declared variable that has not been provided.
+    /// Such values are treated as if they were not given at all.
+    fn is_unset_variable(value: &Value, variables: &Variables) -> bool {
+        match value {
+            Value::Variable(name) => variables.get(name).map(Option::is_none).unwrap_or(false),
+            _ => false,
+        }
+    }
+
+    /// Resolves the given JSON variables against the variable definitions of an operation.
+    /// Variables that are provided but not declared by the operation are ignored.
+    fn resolve_variables(
+        definitions: &[VariableDefinition],
+        variables: &HashMap<String, serde_json::Value>,
+    ) -> PrismaResult<Variables> {
+        definitions
+            .iter()
+            .map(|def| {
+                let value = match (variables.get(&def.name), &def.default_value) {
+                    (Some(value), _) => Some(
+                        Self::convert_json_value(value, &def.var_type)
+                            .map_err(|reason| Self::variable_error(def, reason))?,
+                    ),
+                    (None, Some(default)) => Some(Self::convert_value(default.clone(), &Variables::new())?),
+                    (None, None) => match def.var_type {
+                        Type::NonNullType(_) => {
+                            return Err(Self::variable_error(def, "A value is required but not set.".into()))
+                        }
+                        _ => None,
+                    },
+                };
+
+                Ok((def.name.clone(), value))
+            })
+            .collect()
+    }
+
+    fn variable_error(def: &VariableDefinition, reason: String) -> PrismaError {
+        PrismaError::QueryValidationError(
+            format!(
+                "Invalid value for variable '${}' of type '{}': {}",
+                def.name, def.var_type, reason
+            ),
+            format!("{}", def.position),
+        )
+    }
+
+    /// Converts a JSON variable value into a `QueryValue`, validating it against the declared type.
+    fn convert_json_value(value: &serde_json::Value, typ: &Type) -> Result<QueryValue, String> {
+        match (typ, value) {
+            (Type::NonNullType(_), serde_json::Value::Null) => Err("A value is required but not set.".into()),
+            (Type::NonNullType(inner), _) => Self::convert_json_value(value, inner),
+            (_, serde_json::Value::Null) => Ok(QueryValue::Null),
+            (Type::ListType(inner), serde_json::Value::Array(values)) => values
+                .iter()
+                .map(|value| Self::convert_json_value(value, inner))
+                .collect::<Result<Vec<_>, _>>()
+                .map(QueryValue::List),
+
+            // Input coercion: a single value is accepted in place of a list with one element.
+            (Type::ListType(inner), _) => {
+                Self::convert_json_value(value, inner).map(|value| QueryValue::List(vec![value]))
+            }
+            (Type::NamedType(name), _) => Self::convert_json_named(value, name),
+        }
+    }
+
+    #[rustfmt::skip]
+    fn convert_json_named(value: &serde_json::Value, type_name: &str) -> Result<QueryValue, String> {
+        use serde_json::Value as Json;
+
+        match (type_name, value) {
+            ("Int", Json::Number(n))                                 => n.as_i64().map(QueryValue::Int).ok_or_else(|| format!("Invalid 64 bit integer: {}", n)),
+            ("Float", Json::Number(n))                               => JsonProtocolAdapter::convert_number(n).map(QueryValue::Float),
+            ("Decimal", Json::Number(n))                             => JsonProtocolAdapter::convert_number(n).map(QueryValue::Float),
+            ("BigInt", Json::Number(n))                              => n.as_i64().map(QueryValue::Int).ok_or_else(|| format!("Invalid 64 bit integer: {}", n)),
+            ("Boolean", Json::Bool(b))                               => Ok(QueryValue::Boolean(*b)),
+            ("ID", Json::Number(n))                                  => Ok(QueryValue::String(n.to_string())),
+            ("Json", Json::String(s))                                => Ok(QueryValue::String(s.clone())),
+            ("Json", json)                                           => Ok(QueryValue::String(json.to_string())),
+            ("String", Json::String(s))
+            | ("ID", Json::String(s))
+            | ("DateTime", Json::String(s))
+            | ("UUID", Json::String(s))
+            | ("Decimal", Json::String(s))
+            | ("BigInt", Json::String(s))
+            | ("Bytes", Json::String(s))                             => Ok(QueryValue::String(s.clone())),
+            (name, json) if BUILTIN_SCALARS.contains(&name)          => Err(format!("Expected a value of type {}, got {}", name, json)),
+
+            // Enums and input objects are validated against the query schema by the query document parser.
+            (_, json)                                                => JsonProtocolAdapter::convert_value(json),
+        }
+    }
 }
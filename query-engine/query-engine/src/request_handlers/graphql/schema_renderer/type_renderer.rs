@@ -56,6 +56,9 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::Json => "DateTime",
                     ScalarType::UUID => "UUID",
                     ScalarType::JsonList => "Json",
+                    ScalarType::Decimal => "Decimal",
+                    ScalarType::BigInt => "BigInt",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
 
@@ -97,6 +100,9 @@ impl<'a> GqlTypeRenderer<'a> {
                     ScalarType::Json => "Json",
                     ScalarType::UUID => "UUID",
                     ScalarType::JsonList => "Json",
+                    ScalarType::Decimal => "Decimal",
+                    ScalarType::BigInt => "BigInt",
+                    ScalarType::Bytes => "Bytes",
                     ScalarType::Enum(_) => unreachable!(), // Handled separately above.
                 };
 
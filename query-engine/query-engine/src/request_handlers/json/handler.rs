@@ -0,0 +1,105 @@
+use super::protocol_adapter::JsonProtocolAdapter;
+use crate::{
+    context::PrismaContext,
+    request_handlers::execution::{error_response, handle_batch_document, handle_single_query},
+    PrismaRequest, PrismaResponse, PrismaResult, RequestHandler,
+};
+use async_trait::async_trait;
+use indexmap::IndexMap;
+use query_core::{BatchDocument, CoreError, Operation, QueryGraphBuilder, QuerySchemaRef, ValidatedOperation};
+use serde::{Deserialize, Serialize};
+use std::sync::Arc;
+
+/// Content type designating requests of the JSON protocol.
+pub const JSON_PROTOCOL_CONTENT_TYPE: &str = "application/vnd.prisma.json";
+
+/// Selections of a field, by field name.
+pub type JsonSelectionSet = IndexMap<String, JsonSelection>;
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonSingleQuery {
+    /// Name of the model the action is executed on. Omitted for operations not tied to a model.
+    pub(super) model_name: Option<String>,
+    pub(super) action: String,
+    #[serde(default)]
+    pub(super) query: JsonFieldQuery,
+}
+
+#[derive(Clone, Debug, Default, Serialize, Deserialize)]
+pub struct JsonFieldQuery {
+    #[serde(default)]
+    pub(super) arguments: serde_json::Map<String, serde_json::Value>,
+    #[serde(default)]
+    pub(super) selection: JsonSelectionSet,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(untagged)]
+pub enum JsonSelection {
+    Flag(bool),
+    Nested(JsonFieldQuery),
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct JsonBatchQuery {
+    batch: Vec<JsonSingleQuery>,
+}
+
+#[derive(Clone, Debug, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase", untagged)]
+pub enum JsonBody {
+    Single(JsonSingleQuery),
+    Multi(JsonBatchQuery),
+}
+
+impl JsonSingleQuery {
+    fn into_validated(self, query_schema: &QuerySchemaRef) -> PrismaResult<ValidatedOperation> {
+        let operation = JsonProtocolAdapter::convert(self, query_schema)?;
+
+        let operation = QueryGraphBuilder::new(Arc::clone(query_schema))
+            .validate(operation)
+            .map_err(CoreError::from)?;
+
+        Ok(operation)
+    }
+}
+
+impl JsonBatchQuery {
+    fn into_batch(self, query_schema: &QuerySchemaRef) -> PrismaResult<BatchDocument> {
+        let operations: PrismaResult<Vec<Operation>> = self
+            .batch
+            .into_iter()
+            .map(|query| JsonProtocolAdapter::convert(query, query_schema))
+            .collect();
+
+        Ok(BatchDocument::new(operations?))
+    }
+}
+
+pub struct JsonRequestHandler;
+
+#[async_trait]
+impl RequestHandler for JsonRequestHandler {
+    type Body = JsonBody;
+
+    async fn handle<S>(&self, req: S, ctx: &Arc<PrismaContext>) -> PrismaResponse
+    where
+        S: Into<PrismaRequest<Self::Body>> + Send + Sync + 'static,
+    {
+        let request = req.into();
+        debug!("Incoming JSON query: {:?}", request.body);
+
+        match request.body {
+            JsonBody::Single(query) => match query.into_validated(ctx.query_schema()) {
+                Ok(operation) => handle_single_query(operation, ctx.clone()).await,
+                Err(err) => error_response(err),
+            },
+            JsonBody::Multi(batch) => match batch.into_batch(ctx.query_schema()) {
+                Ok(batch) => handle_batch_document(batch, ctx).await,
+                Err(err) => error_response(err),
+            },
+        }
+    }
+}
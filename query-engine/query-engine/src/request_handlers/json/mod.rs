@@ -0,0 +1,5 @@
+mod handler;
+mod protocol_adapter;
+
+pub use handler::*;
+pub use protocol_adapter::*;
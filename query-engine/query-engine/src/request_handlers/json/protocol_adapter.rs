@@ -0,0 +1,177 @@
+use super::{JsonFieldQuery, JsonSelection, JsonSelectionSet, JsonSingleQuery};
+use crate::{error::PrismaError, PrismaResult};
+use query_core::{query_document::*, FieldRef, ObjectTypeStrongRef, QuerySchemaRef, SchemaQueryBuilder};
+use rust_decimal::Decimal;
+use std::{collections::BTreeMap, str::FromStr, sync::Arc};
+
+/// Selection key that selects all scalar fields of an object.
+pub const SCALARS_SELECTION: &str = "$scalars";
+
+/// Protocol adapter for JSON -> Query Document.
+///
+/// The JSON protocol is mapped as following:
+/// - A model name and an action (e.g. `User` and `findMany`) designate the root field of the operation,
+///   as listed in the mappings of the DMMF. Operations not tied to a model (e.g. `executeRaw`) are
+///   designated by the root field name as action, without a model name.
+/// - Root fields of the query type are mapped to `Operation::Read`, of the mutation type to `Operation::Write`.
+/// - Arguments are mapped to `QueryValue`s. Strings are accepted for enum values.
+/// - Selections are mapped to `Selection`s: `true` selects a field, an object with `arguments` and
+///   `selection` selects a field with arguments and nested selections, and `$scalars: true` selects all
+///   scalar fields of the enclosing object. Relation fields selected with `true` select all their scalars.
+///
+/// The resulting operation is validated against the query schema by the query document parser,
+/// exactly like operations originating from GraphQL.
+pub struct JsonProtocolAdapter;
+
+impl JsonProtocolAdapter {
+    pub fn convert(query: JsonSingleQuery, query_schema: &QuerySchemaRef) -> PrismaResult<Operation> {
+        let (field, is_write) = Self::find_root_field(query.model_name.as_deref(), &query.action, query_schema)?;
+        let selection = Self::convert_field(field.name.clone(), query.query, Some(&field))?;
+
+        let operation = if is_write {
+            Operation::Write(selection)
+        } else {
+            Operation::Read(selection)
+        };
+
+        Ok(operation.dedup_selections())
+    }
+
+    /// Finds the root field for the action, returning it alongside whether or not it is a mutation field.
+    pub(crate) fn find_root_field(
+        model_name: Option<&str>,
+        action: &str,
+        query_schema: &QuerySchemaRef,
+    ) -> PrismaResult<(FieldRef, bool)> {
+        let root_objects = [(query_schema.query(), false), (query_schema.mutation(), true)];
+
+        root_objects
+            .iter()
+            .find_map(|(object, is_write)| {
+                object
+                    .get_fields()
+                    .iter()
+                    .find(|field| Self::matches_action(field, model_name, action))
+                    .map(|field| (Arc::clone(field), *is_write))
+            })
+            .ok_or_else(|| match model_name {
+                Some(model_name) => PrismaError::QueryConversionError(format!(
+                    "Action '{}' does not exist on model '{}'.",
+                    action, model_name
+                )),
+                None => PrismaError::QueryConversionError(format!("Operation '{}' does not exist.", action)),
+            })
+    }
+
+    fn matches_action(field: &FieldRef, model_name: Option<&str>, action: &str) -> bool {
+        match (model_name, &field.query_builder) {
+            (Some(model_name), Some(SchemaQueryBuilder::ModelQueryBuilder(builder))) => {
+                builder.model.name == model_name && builder.tag.to_string() == action
+            }
+            (Some(_), _) => false,
+            (None, _) => field.name == action,
+        }
+    }
+
+    fn convert_field(name: String, query: JsonFieldQuery, field: Option<&FieldRef>) -> PrismaResult<Selection> {
+        let arguments = query
+            .arguments
+            .iter()
+            .map(|(k, v)| Self::convert_value(v).map(|v| (k.clone(), v)))
+            .collect::<Result<Vec<_>, _>>()
+            .map_err(|err| PrismaError::QueryConversionError(format!("Argument of field '{}': {}", name, err)))?;
+
+        let object = field.and_then(|field| field.field_type.as_object_type());
+        let nested_selections = Self::convert_selection_set(query.selection, object.as_ref())?;
+
+        let mut builder = Selection::builder(name);
+        builder.set_arguments(arguments);
+        builder.nested_selections(nested_selections);
+
+        Ok(builder.build())
+    }
+
+    fn convert_selection_set(
+        selection_set: JsonSelectionSet,
+        object: Option<&ObjectTypeStrongRef>,
+    ) -> PrismaResult<Vec<Selection>> {
+        let mut selections = Vec::with_capacity(selection_set.len());
+
+        for (name, selection) in selection_set {
+            match (name.as_str(), selection) {
+                (_, JsonSelection::Flag(false)) => (),
+                (SCALARS_SELECTION, JsonSelection::Flag(true)) => {
+                    selections.extend(Self::scalar_selections(object, &name)?);
+                }
+                (_, JsonSelection::Flag(true)) => {
+                    let nested_object = object
+                        .and_then(|object| object.find_field(&name))
+                        .and_then(|field| field.field_type.as_object_type());
+
+                    let mut builder = Selection::builder(name.as_str());
+
+                    // Relations selected without a nested selection select all their scalars.
+                    if nested_object.is_some() {
+                        builder.nested_selections(Self::scalar_selections(nested_object.as_ref(), &name)?);
+                    }
+
+                    selections.push(builder.build());
+                }
+                (_, JsonSelection::Nested(query)) => {
+                    let field = object.and_then(|object| object.find_field(&name));
+                    selections.push(Self::convert_field(name, query, field.as_ref())?);
+                }
+            }
+        }
+
+        Ok(selections)
+    }
+
+    /// Selections of all fields of the object that don't return objects.
+    fn scalar_selections(object: Option<&ObjectTypeStrongRef>, field_name: &str) -> PrismaResult<Vec<Selection>> {
+        let object = object.ok_or_else(|| {
+            PrismaError::QueryConversionError(format!(
+                "Field '{}': '{}' can only be used on fields returning objects.",
+                field_name, SCALARS_SELECTION
+            ))
+        })?;
+
+        Ok(object
+            .get_fields()
+            .iter()
+            .filter(|field| field.field_type.as_object_type().is_none())
+            .map(|field| Selection::builder(field.name.as_str()).build())
+            .collect())
+    }
+
+    /// Untyped conversion of a JSON value into a `QueryValue`.
+    /// Type validation is left to the query document parser.
+    pub(crate) fn convert_value(value: &serde_json::Value) -> Result<QueryValue, String> {
+        use serde_json::Value as Json;
+
+        match value {
+            Json::Null => Ok(QueryValue::Null),
+            Json::Bool(b) => Ok(QueryValue::Boolean(*b)),
+            Json::Number(n) => match n.as_i64() {
+                Some(i) => Ok(QueryValue::Int(i)),
+                None => Self::convert_number(n).map(QueryValue::Float),
+            },
+            Json::String(s) => Ok(QueryValue::String(s.clone())),
+            Json::Array(values) => values
+                .iter()
+                .map(Self::convert_value)
+                .collect::<Result<Vec<_>, _>>()
+                .map(QueryValue::List),
+            Json::Object(map) => map
+                .iter()
+                .map(|(k, v)| Self::convert_value(v).map(|v| (k.clone(), v)))
+                .collect::<Result<BTreeMap<_, _>, _>>()
+                .map(QueryValue::Object),
+        }
+    }
+
+    // We can't use Decimal::from_f64 here due to a bug in rust_decimal.
+    pub(crate) fn convert_number(n: &serde_json::Number) -> Result<Decimal, String> {
+        Decimal::from_str(&n.to_string()).map_err(|_| format!("Invalid 64-bit float: {}", n))
+    }
+}
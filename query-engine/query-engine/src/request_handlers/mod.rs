@@ -1,6 +1,10 @@
+mod execution;
+
 pub mod graphql;
+pub mod json;
 
 pub use graphql::*;
+pub use json::*;
 pub use query_core::{response_ir, schema::QuerySchemaRenderer};
 
 use crate::context::PrismaContext;
@@ -1,20 +1,46 @@
 use super::dmmf;
 use crate::{
-    context::PrismaContext,
+    context::{PrismaContext, DEFAULT_PERSISTED_QUERY_CACHE_SIZE, DEFAULT_QUERY_CACHE_SIZE},
+    error::PrismaError,
     request_handlers::{
-        graphql::{GraphQLSchemaRenderer, GraphQlBody, GraphQlRequestHandler},
+        graphql::{GraphQLSchemaRenderer, GraphQlRequestHandler},
+        json::{JsonRequestHandler, JSON_PROTOCOL_CONTENT_TYPE},
         PrismaRequest, RequestHandler,
     },
+    subscriptions::{self, DEFAULT_POLL_INTERVAL},
     PrismaResult,
 };
 use datamodel::{Configuration, Datamodel};
+use futures::{
+    channel::oneshot,
+    future::{self, Either, FutureExt},
+    pin_mut, Future,
+};
 use hyper::header;
 use hyper::service::{make_service_fn, service_fn};
 use hyper::{Body, Error, Method, Request, Response, Server, StatusCode};
 use query_core::schema::QuerySchemaRenderer;
+use serde::de::DeserializeOwned;
 use serde_json::json;
 use std::net::SocketAddr;
-use std::{sync::Arc, time::Instant};
+use std::{
+    sync::{
+        atomic::{AtomicBool, Ordering},
+        Arc,
+    },
+    time::{Duration, Instant},
+};
+
+/// Time to wait for in-flight requests on shutdown, if not configured otherwise.
+pub const DEFAULT_SHUTDOWN_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// Time during which `/status` reports `draining` on shutdown while requests are still served, if not configured
+/// otherwise.
+pub const DEFAULT_DRAIN_GRACE_PERIOD: Duration = Duration::from_secs(5);
+
+/// Time given to the interrupted requests to respond once the shutdown timeout elapsed, before their connections are
+/// closed.
+const INTERRUPTED_REQUESTS_TIMEOUT: Duration = Duration::from_secs(1);
 
 #[derive(RustEmbed)]
 #[folder = "static_files"]
@@ -23,7 +49,10 @@ struct StaticFiles;
 pub(crate) struct RequestContext {
     context: Arc<PrismaContext>,
     graphql_request_handler: GraphQlRequestHandler,
+    json_request_handler: JsonRequestHandler,
     enable_playground: bool,
+    /// Set on shutdown, while in-flight requests are finishing.
+    draining: AtomicBool,
 }
 
 impl RequestContext {
@@ -38,6 +67,21 @@ pub struct HttpServerBuilder {
     legacy_mode: bool,
     enable_raw_queries: bool,
     enable_playground: bool,
+    query_cache_size: usize,
+    persisted_query_cache_size: usize,
+    subscription_poll_interval: Duration,
+    drain_grace_period: Duration,
+    shutdown_timeout: Duration,
+}
+
+/// When and how the server shuts down.
+pub(crate) struct Shutdown<F> {
+    /// Resolves when the server should shut down.
+    pub signal: F,
+    /// How long `/status` reports `draining` while new requests are still served.
+    pub drain_grace_period: Duration,
+    /// How long in-flight requests are waited for once no new connections are accepted.
+    pub timeout: Duration,
 }
 
 impl HttpServerBuilder {
@@ -56,14 +100,50 @@ impl HttpServerBuilder {
         self
     }
 
+    pub fn query_cache_size(mut self, val: usize) -> Self {
+        self.query_cache_size = val;
+        self
+    }
+
+    pub fn persisted_query_cache_size(mut self, val: usize) -> Self {
+        self.persisted_query_cache_size = val;
+        self
+    }
+
+    pub fn subscription_poll_interval(mut self, val: Duration) -> Self {
+        self.subscription_poll_interval = val;
+        self
+    }
+
+    pub fn drain_grace_period(mut self, val: Duration) -> Self {
+        self.drain_grace_period = val;
+        self
+    }
+
+    pub fn shutdown_timeout(mut self, val: Duration) -> Self {
+        self.shutdown_timeout = val;
+        self
+    }
+
     pub async fn build_and_run(self, address: SocketAddr) -> PrismaResult<()> {
         let ctx = PrismaContext::builder(self.config, self.datamodel)
             .legacy(self.legacy_mode)
             .enable_raw_queries(self.enable_raw_queries)
+            .query_cache_size(self.query_cache_size)
+            .persisted_query_cache_size(self.persisted_query_cache_size)
+            .subscription_poll_interval(self.subscription_poll_interval)
             .build()
             .await?;
 
-        HttpServer::run(address, ctx, self.enable_playground).await
+        let shutdown = Shutdown {
+            signal: HttpServer::shutdown_signal(),
+            drain_grace_period: self.drain_grace_period,
+            timeout: self.shutdown_timeout,
+        };
+
+        let listener = std::net::TcpListener::bind(address)?;
+
+        HttpServer::run(listener, Arc::new(ctx), self.enable_playground, shutdown).await
     }
 }
 
@@ -77,16 +157,34 @@ impl HttpServer {
             legacy_mode: false,
             enable_raw_queries: false,
             enable_playground: false,
+            query_cache_size: DEFAULT_QUERY_CACHE_SIZE,
+            persisted_query_cache_size: DEFAULT_PERSISTED_QUERY_CACHE_SIZE,
+            subscription_poll_interval: DEFAULT_POLL_INTERVAL,
+            drain_grace_period: DEFAULT_DRAIN_GRACE_PERIOD,
+            shutdown_timeout: DEFAULT_SHUTDOWN_TIMEOUT,
         }
     }
 
-    async fn run(address: SocketAddr, context: PrismaContext, enable_playground: bool) -> PrismaResult<()> {
+    /// Serves requests until the shutdown signal resolves. `/status` then reports `draining` for the grace period,
+    /// during which requests are still served, so load balancers stop routing to the server. The server then stops
+    /// accepting connections and waits up to the shutdown timeout for in-flight requests, before it interrupts the
+    /// remaining ones, rolling back their transactions, closes the database connections and cuts off the
+    /// connections still open.
+    pub(crate) async fn run(
+        listener: std::net::TcpListener,
+        context: Arc<PrismaContext>,
+        enable_playground: bool,
+        shutdown: Shutdown<impl Future<Output = ()>>,
+    ) -> PrismaResult<()> {
         let now = Instant::now();
+        let address = listener.local_addr()?;
 
         let ctx = Arc::new(RequestContext {
-            context: Arc::new(context),
+            context,
             graphql_request_handler: GraphQlRequestHandler,
+            json_request_handler: JsonRequestHandler,
             enable_playground,
+            draining: AtomicBool::new(false),
         });
 
         let service = make_service_fn(|_| {
@@ -95,49 +193,118 @@ impl HttpServer {
             async { Ok::<_, Error>(service_fn(move |req| Self::routes(ctx.clone(), req))) }
         });
 
-        let server = Server::bind(&address).serve(service);
+        let (stop_accepting_sender, stop_accepting) = oneshot::channel::<()>();
+
+        listener.set_nonblocking(true)?;
+
+        let server = Server::from_tcp(listener)
+            .map_err(|err| PrismaError::IOError(err.into()))?
+            .serve(service)
+            .with_graceful_shutdown(async {
+                stop_accepting.await.ok();
+            });
 
         trace!("Initialized in {}ms", now.elapsed().as_millis());
         info!("Started http server on {}:{}", address.ip(), address.port());
 
-        server.await.unwrap();
+        pin_mut!(server);
+
+        let signal = shutdown.signal;
+        pin_mut!(signal);
+
+        if let Either::Left((result, _)) = future::select(&mut server, signal).await {
+            result.unwrap();
+            return Ok(());
+        }
+
+        info!("Shutting down, draining for {}s", shutdown.drain_grace_period.as_secs());
+
+        ctx.draining.store(true, Ordering::SeqCst);
+
+        let grace_period = tokio::time::delay_for(shutdown.drain_grace_period);
+        pin_mut!(grace_period);
+
+        if let Either::Left((result, _)) = future::select(&mut server, grace_period).await {
+            result.unwrap();
+            return Ok(());
+        }
+
+        info!(
+            "Stopped accepting connections, waiting up to {}s for in-flight requests",
+            shutdown.timeout.as_secs()
+        );
+
+        stop_accepting_sender.send(()).ok();
+
+        let drained = tokio::time::timeout(shutdown.timeout, &mut server).await;
+
+        // Interrupts the requests still running and closes the connection pools once they're released.
+        ctx.context.executor.close();
+
+        match drained {
+            Ok(result) => result.unwrap(),
+            Err(_) => {
+                warn!("Shutdown timeout elapsed, rolling back the transactions of in-flight requests");
+
+                // Dropping the server closes the connections of the requests that did not respond in time.
+                if tokio::time::timeout(INTERRUPTED_REQUESTS_TIMEOUT, &mut server)
+                    .await
+                    .is_err()
+                {
+                    warn!("Closing the connections of the requests still running");
+                }
+            }
+        }
+
+        info!("Shut down http server");
 
         Ok(())
     }
 
+    /// Resolves on SIGTERM or SIGINT.
+    async fn shutdown_signal() {
+        #[cfg(unix)]
+        {
+            use tokio::signal::unix::{signal, SignalKind};
+
+            let mut terminate = signal(SignalKind::terminate()).expect("Failed to install the SIGTERM handler");
+            let interrupt = tokio::signal::ctrl_c();
+
+            pin_mut!(interrupt);
+            future::select(terminate.recv().boxed(), interrupt).await;
+        }
+
+        #[cfg(not(unix))]
+        tokio::signal::ctrl_c().await.ok();
+    }
+
     async fn routes(ctx: Arc<RequestContext>, req: Request<Body>) -> std::result::Result<Response<Body>, Error> {
         let start = Instant::now();
 
         let mut res = match (req.method(), req.uri().path()) {
             (&Method::POST, "/") => {
                 let (parts, body) = req.into_parts();
-
                 let bytes = hyper::body::to_bytes(body).await?;
 
-                match serde_json::from_slice(bytes.as_ref()) {
-                    Ok(body) => {
-                        let req = PrismaRequest {
-                            body,
-                            path: parts.uri.path().into(),
-                            headers: parts
-                                .headers
-                                .iter()
-                                .map(|(k, v)| (format!("{}", k), v.to_str().unwrap().into()))
-                                .collect(),
-                        };
-
-                        Self::http_handler(req, ctx).await
-                    }
-                    Err(_) => {
-                        let mut bad_request = Response::default();
-                        *bad_request.status_mut() = StatusCode::BAD_REQUEST;
-                        bad_request
-                    }
+                let is_json_protocol = parts
+                    .headers
+                    .get(header::CONTENT_TYPE)
+                    .and_then(|value| value.to_str().ok())
+                    .map(|value| value.starts_with(JSON_PROTOCOL_CONTENT_TYPE))
+                    .unwrap_or(false);
+
+                if is_json_protocol {
+                    Self::http_handler(&ctx.json_request_handler, parts, &bytes, &ctx).await
+                } else {
+                    Self::http_handler(&ctx.graphql_request_handler, parts, &bytes, &ctx).await
                 }
             }
 
+            (&Method::GET, "/") if subscriptions::is_websocket_upgrade(&req) => {
+                subscriptions::upgrade(req, Arc::clone(ctx.context()))
+            }
             (&Method::GET, "/") if ctx.enable_playground => Self::playground_handler(),
-            (&Method::GET, "/status") => Self::status_handler(),
+            (&Method::GET, "/status") => Self::status_handler(ctx),
 
             (&Method::GET, "/sdl") => Self::sdl_handler(ctx),
             (&Method::GET, "/dmmf") => Self::dmmf_handler(ctx),
@@ -156,8 +323,38 @@ impl HttpServer {
         Ok(res)
     }
 
-    async fn http_handler(req: PrismaRequest<GraphQlBody>, cx: Arc<RequestContext>) -> Response<Body> {
-        let result = cx.graphql_request_handler.handle(req, cx.context()).await;
+    /// Deserializes the request body into the body type of the given protocol handler and runs the request.
+    async fn http_handler<H>(
+        handler: &H,
+        parts: http::request::Parts,
+        bytes: &[u8],
+        cx: &Arc<RequestContext>,
+    ) -> Response<Body>
+    where
+        H: RequestHandler + Sync,
+        H::Body: DeserializeOwned + Send + Sync + 'static,
+    {
+        let body: H::Body = match serde_json::from_slice(bytes) {
+            Ok(body) => body,
+            Err(_) => {
+                let mut bad_request = Response::default();
+                *bad_request.status_mut() = StatusCode::BAD_REQUEST;
+
+                return bad_request;
+            }
+        };
+
+        let req = PrismaRequest {
+            body,
+            path: parts.uri.path().into(),
+            headers: parts
+                .headers
+                .iter()
+                .map(|(k, v)| (format!("{}", k), v.to_str().unwrap().into()))
+                .collect(),
+        };
+
+        let result = handler.handle(req, cx.context()).await;
         let bytes = serde_json::to_vec(&result).unwrap();
 
         Response::builder()
@@ -167,12 +364,18 @@ impl HttpServer {
             .unwrap()
     }
 
-    fn status_handler() -> Response<Body> {
-        let body_data = json!({"status": "ok"});
+    /// Reports `draining` with a 503 during shutdown, to get the server out of load balancers.
+    fn status_handler(cx: Arc<RequestContext>) -> Response<Body> {
+        let (status, body_data) = if cx.draining.load(Ordering::SeqCst) {
+            (StatusCode::SERVICE_UNAVAILABLE, json!({"status": "draining"}))
+        } else {
+            (StatusCode::OK, json!({"status": "ok"}))
+        };
+
         let bytes = serde_json::to_vec(&body_data).unwrap();
 
         Response::builder()
-            .status(StatusCode::OK)
+            .status(status)
             .header(header::CONTENT_TYPE, "application/json")
             .body(Body::from(bytes))
             .unwrap()
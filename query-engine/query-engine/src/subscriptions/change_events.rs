@@ -0,0 +1,132 @@
+use crate::{PrismaError, PrismaResult};
+use datamodel::{
+    configuration::{POSTGRES_SOURCE_NAME, SQLITE_SOURCE_NAME},
+    Source,
+};
+use serde::Deserialize;
+use std::{
+    sync::atomic::{AtomicBool, Ordering},
+    time::Duration,
+};
+use tokio::sync::broadcast;
+
+/// The channel the Postgres triggers installed by the migration engine notify.
+pub const CHANGE_EVENTS_CHANNEL: &str = "prisma_change_events";
+
+/// The table the SQLite triggers installed by the migration engine log changes into.
+pub const CHANGE_EVENTS_TABLE: &str = "_prisma_change_events";
+
+/// Interval in which sources without notifications are polled, if not configured otherwise.
+pub const DEFAULT_POLL_INTERVAL: Duration = Duration::from_millis(500);
+
+/// Delay before a failed source reconnects.
+pub(super) const RECONNECT_DELAY: Duration = Duration::from_secs(5);
+
+/// Number of events buffered per subscription. Slower subscriptions miss events.
+const CHANNEL_CAPACITY: usize = 1024;
+
+#[derive(Debug, Clone, Copy, PartialEq, Deserialize)]
+#[serde(rename_all = "UPPERCASE")]
+pub enum ChangeOperation {
+    Insert,
+    Update,
+    Delete,
+}
+
+/// A change of a single row, as reported by the triggers.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ChangeEvent {
+    pub table: String,
+    pub operation: ChangeOperation,
+    /// The primary key columns of the row and their values.
+    pub record: serde_json::Map<String, serde_json::Value>,
+}
+
+#[derive(Debug, Clone)]
+enum ChangeEventSource {
+    /// Listens for notifications of the triggers.
+    Postgres {
+        url: String,
+        schema: String,
+    },
+    /// Polls the table the triggers log changes into.
+    Sqlite {
+        url: String,
+        schema: String,
+        poll_interval: Duration,
+    },
+    Unsupported(String),
+}
+
+/// Broadcasts the record change events of the database to all subscriptions of the process.
+/// The source of the events is started with the first subscription and runs until the process exits.
+pub struct ChangeEvents {
+    source: ChangeEventSource,
+    sender: broadcast::Sender<ChangeEvent>,
+    started: AtomicBool,
+}
+
+impl ChangeEvents {
+    pub fn new(source: &(dyn Source + Send + Sync), db_name: &str, poll_interval: Duration) -> Self {
+        let url = source.url().value.clone();
+        let schema = db_name.to_owned();
+
+        let source = match source.connector_type() {
+            POSTGRES_SOURCE_NAME => ChangeEventSource::Postgres { url, schema },
+            SQLITE_SOURCE_NAME => ChangeEventSource::Sqlite {
+                url,
+                schema,
+                poll_interval,
+            },
+            connector => ChangeEventSource::Unsupported(connector.to_owned()),
+        };
+
+        let (sender, _) = broadcast::channel(CHANNEL_CAPACITY);
+
+        Self {
+            source,
+            sender,
+            started: AtomicBool::new(false),
+        }
+    }
+
+    /// Subscribes to all change events, starting the source if it isn't running yet.
+    pub fn subscribe(&self) -> PrismaResult<broadcast::Receiver<ChangeEvent>> {
+        if let ChangeEventSource::Unsupported(connector) = &self.source {
+            return Err(PrismaError::UnsupportedFeatureError(
+                "Subscriptions",
+                format!("Record change events are not supported on {}.", connector),
+            ));
+        }
+
+        let receiver = self.sender.subscribe();
+
+        if !self.started.swap(true, Ordering::SeqCst) {
+            self.start();
+        }
+
+        Ok(receiver)
+    }
+
+    #[cfg(feature = "sql")]
+    fn start(&self) {
+        let sender = self.sender.clone();
+
+        match self.source.clone() {
+            ChangeEventSource::Postgres { url, schema } => {
+                tokio::spawn(super::postgres::listen(url, schema, sender));
+            }
+            ChangeEventSource::Sqlite {
+                url,
+                schema,
+                poll_interval,
+            } => {
+                tokio::spawn(super::sqlite::poll(url, schema, poll_interval, sender));
+            }
+            ChangeEventSource::Unsupported(_) => unreachable!(),
+        }
+    }
+
+    #[cfg(not(feature = "sql"))]
+    fn start(&self) {}
+}
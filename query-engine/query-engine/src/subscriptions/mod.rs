@@ -0,0 +1,16 @@
+//! Subscriptions to record change events, served over WebSocket connections.
+//!
+//! Change events are produced by database triggers, which the migration engine installs with the
+//! `enableChangeEvents` command. On Postgres, the triggers notify a channel the query engine
+//! listens on. On SQLite, the triggers log the events into a table the query engine polls.
+mod change_events;
+#[cfg(feature = "sql")]
+mod postgres;
+#[cfg(feature = "sql")]
+mod sqlite;
+mod subscription;
+mod websocket;
+
+pub use change_events::*;
+pub use subscription::*;
+pub use websocket::*;
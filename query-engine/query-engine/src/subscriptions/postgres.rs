@@ -0,0 +1,113 @@
+use super::{ChangeEvent, CHANGE_EVENTS_CHANNEL, RECONNECT_DELAY};
+use crate::{PrismaError, PrismaResult};
+use futures::{channel::mpsc, stream, StreamExt};
+use native_tls::TlsConnector;
+use postgres_native_tls::MakeTlsConnector;
+use serde::Deserialize;
+use std::collections::HashMap;
+use tokio::sync::broadcast;
+use tokio_postgres::{config::SslMode, AsyncMessage, Config};
+use url::Url;
+
+/// Payload of the notifications sent by the triggers.
+#[derive(Debug, Deserialize)]
+struct Notification {
+    schema: String,
+    #[serde(flatten)]
+    event: ChangeEvent,
+}
+
+/// Listens for the notifications of the triggers on a dedicated connection, reconnecting on failures.
+/// Notifications of tables in other schemas are ignored.
+pub(super) async fn listen(url: String, schema: String, sender: broadcast::Sender<ChangeEvent>) {
+    loop {
+        if let Err(err) = listen_once(&url, &schema, &sender).await {
+            error!("Listening for change events failed: {}", err);
+        }
+
+        tokio::time::delay_for(RECONNECT_DELAY).await;
+    }
+}
+
+async fn listen_once(url: &str, schema: &str, sender: &broadcast::Sender<ChangeEvent>) -> PrismaResult<()> {
+    let (config, tls) = connection_config(url)?;
+    let (client, mut connection) = config.connect(tls).await.map_err(listen_error)?;
+
+    // Notifications are only received while the connection is driven, which is why it is driven
+    // by a separate task forwarding the messages, while the client issues the `LISTEN`.
+    let (message_sender, mut messages) = mpsc::unbounded();
+
+    tokio::spawn(async move {
+        let mut connection_messages = stream::poll_fn(move |cx| connection.poll_message(cx));
+
+        while let Some(message) = connection_messages.next().await {
+            if message_sender.unbounded_send(message).is_err() {
+                break;
+            }
+        }
+    });
+
+    client
+        .batch_execute(&format!("LISTEN {}", CHANGE_EVENTS_CHANNEL))
+        .await
+        .map_err(listen_error)?;
+
+    debug!("Listening for change events on channel '{}'.", CHANGE_EVENTS_CHANNEL);
+
+    while let Some(message) = messages.next().await {
+        if let AsyncMessage::Notification(notification) = message.map_err(listen_error)? {
+            match serde_json::from_str::<Notification>(notification.payload()) {
+                Ok(notification) if notification.schema == schema => {
+                    // Sending only fails if there are no subscriptions, in which case the event is dropped.
+                    sender.send(notification.event).ok();
+                }
+                Ok(_) => (),
+                Err(err) => warn!("Invalid change event '{}': {}", notification.payload(), err),
+            }
+        }
+    }
+
+    Err(PrismaError::ChangeEventsError(
+        "The connection was closed by the server.".into(),
+    ))
+}
+
+/// Translates the connection string into the configuration of a single connection. Prisma specific
+/// parameters are not accepted by the driver, which is why the configuration is built manually.
+fn connection_config(url: &str) -> PrismaResult<(Config, MakeTlsConnector)> {
+    let url = Url::parse(url)?;
+    let params: HashMap<String, String> = url.query_pairs().into_owned().collect();
+    let mut config = Config::new();
+
+    config.host(url.host_str().unwrap_or("localhost"));
+    config.port(url.port().unwrap_or(5432));
+    config.user(&percent_decode(url.username()));
+    config.dbname(url.path().trim_start_matches('/'));
+
+    if let Some(password) = url.password() {
+        config.password(percent_decode(password));
+    }
+
+    config.ssl_mode(match params.get("sslmode").map(String::as_str) {
+        Some("disable") => SslMode::Disable,
+        Some("require") => SslMode::Require,
+        _ => SslMode::Prefer,
+    });
+
+    let accept_invalid_certs = params.get("sslaccept").map(String::as_str) == Some("accept_invalid_certs");
+
+    let connector = TlsConnector::builder()
+        .danger_accept_invalid_certs(accept_invalid_certs)
+        .build()
+        .map_err(|err| PrismaError::ChangeEventsError(err.to_string()))?;
+
+    Ok((config, MakeTlsConnector::new(connector)))
+}
+
+fn percent_decode(s: &str) -> String {
+    percent_encoding::percent_decode_str(s).decode_utf8_lossy().into_owned()
+}
+
+fn listen_error(err: tokio_postgres::Error) -> PrismaError {
+    PrismaError::ChangeEventsError(err.to_string())
+}
@@ -0,0 +1,91 @@
+use super::{ChangeEvent, ChangeOperation, CHANGE_EVENTS_TABLE, RECONNECT_DELAY};
+use crate::{PrismaError, PrismaResult};
+use quaint::{ast::*, prelude::Queryable, single::Quaint};
+use std::time::Duration;
+use tokio::sync::broadcast;
+
+/// Polls the table the triggers log changes into, reconnecting on failures. Only changes logged
+/// after polling started are broadcast. The log is never truncated, as other processes may still
+/// be reading it, which is why polling is meant for development and tests.
+pub(super) async fn poll(url: String, schema: String, interval: Duration, sender: broadcast::Sender<ChangeEvent>) {
+    let mut last_id = None;
+
+    loop {
+        if let Err(err) = poll_once(&url, &schema, interval, &mut last_id, &sender).await {
+            error!("Polling for change events failed: {}", err);
+        }
+
+        tokio::time::delay_for(RECONNECT_DELAY).await;
+    }
+}
+
+async fn poll_once(
+    url: &str,
+    schema: &str,
+    interval: Duration,
+    last_id: &mut Option<i64>,
+    sender: &broadcast::Sender<ChangeEvent>,
+) -> PrismaResult<()> {
+    // The database file is attached under the schema name, like in the connector.
+    let separator = if url.contains('?') { '&' } else { '?' };
+    let url = format!("{}{}db_name={}", url, separator, schema);
+    let conn = Quaint::new(&url).await.map_err(poll_error)?;
+
+    let mut interval = tokio::time::interval(interval);
+
+    loop {
+        interval.tick().await;
+
+        let last = match *last_id {
+            Some(last) => last,
+            None => {
+                let sql = format!(
+                    r#"SELECT MAX("id") AS "id" FROM "{}"."{}""#,
+                    schema, CHANGE_EVENTS_TABLE
+                );
+                let result = conn.query_raw(&sql, &[]).await.map_err(poll_error)?;
+
+                result
+                    .first()
+                    .and_then(|row| row.get("id").and_then(|id| id.as_i64()))
+                    .unwrap_or(0)
+            }
+        };
+
+        *last_id = Some(last);
+
+        let select = Select::from_table((schema, CHANGE_EVENTS_TABLE))
+            .so_that("id".greater_than(last))
+            .order_by("id".ascend());
+
+        for row in conn.query(select.into()).await.map_err(poll_error)? {
+            if let Some(id) = row["id"].as_i64() {
+                *last_id = Some(id);
+            }
+
+            let event = (|| {
+                let table = row["table_name"].as_str()?.to_owned();
+                let operation = serde_json::from_value::<ChangeOperation>(row["operation"].as_str()?.into()).ok()?;
+                let record = serde_json::from_str(row["record"].as_str()?).ok()?;
+
+                Some(ChangeEvent {
+                    table,
+                    operation,
+                    record,
+                })
+            })();
+
+            match event {
+                // Sending only fails if there are no subscriptions, in which case the event is dropped.
+                Some(event) => {
+                    sender.send(event).ok();
+                }
+                None => warn!("Invalid change event with id {:?}.", last_id),
+            }
+        }
+    }
+}
+
+fn poll_error(err: quaint::error::Error) -> PrismaError {
+    PrismaError::ChangeEventsError(err.to_string())
+}
@@ -0,0 +1,277 @@
+use super::{ChangeEvent, ChangeOperation};
+use crate::{context::PrismaContext, request_handlers::JsonProtocolAdapter, PrismaError, PrismaResult};
+use prisma_models::ModelRef;
+use query_core::{query_document::*, CoreError, QueryGraphBuilder, QueryTag};
+use std::{collections::BTreeMap, sync::Arc};
+
+const WHERE_ARGUMENT: &str = "where";
+const MUTATION_IN_ARGUMENT: &str = "mutation_in";
+
+const MUTATION_SELECTION: &str = "mutation";
+const NODE_SELECTION: &str = "node";
+const IDENTIFIER_SELECTION: &str = "identifier";
+
+/// Mutation types of a subscription, corresponding to the operations of change events.
+const MUTATIONS: &[(&str, ChangeOperation)] = &[
+    ("CREATED", ChangeOperation::Insert),
+    ("UPDATED", ChangeOperation::Update),
+    ("DELETED", ChangeOperation::Delete),
+];
+
+/// A subscription to the changes of the records of a single model.
+///
+/// The root field of a subscription is named after the model with a lowercase first letter,
+/// e.g. `post` for the model `Post`:
+///
+/// ```graphql
+/// subscription {
+///   post(where: { published: true }, mutation_in: [CREATED, UPDATED]) {
+///     mutation
+///     identifier
+///     node { id title }
+///   }
+/// }
+/// ```
+///
+/// - `where` filters the changed records, using the `WhereInput` of the model.
+/// - `mutation_in` filters the mutation types, `CREATED`, `UPDATED` and `DELETED`. All by default.
+/// - `mutation` selects the mutation type of the change.
+/// - `identifier` selects the primary identifier fields of the record and their values.
+/// - `node` selects fields of the record, exactly like a `findMany` query.
+///
+/// Deleted records can't be read anymore: `node` is always `null` for deletions, and the `where`
+/// filter is not applied to them.
+#[derive(Debug)]
+pub struct Subscription {
+    model: ModelRef,
+    response_key: String,
+    mutations: Vec<ChangeOperation>,
+    filter: Option<QueryValue>,
+    selections: Vec<Selection>,
+    /// The `findMany` selection reading the changed record, without the record identifier.
+    node: Selection,
+}
+
+impl Subscription {
+    /// Creates a subscription from the root field of a subscription document, validating the node
+    /// selection against the query schema.
+    pub fn new(selection: Selection, ctx: &PrismaContext) -> PrismaResult<Self> {
+        let query_schema = ctx.query_schema();
+
+        let model = query_schema
+            .internal_data_model
+            .models()
+            .iter()
+            // Views never emit change events.
+            .find(|model| !model.is_view && Self::field_name(model) == selection.name())
+            .cloned()
+            .ok_or_else(|| {
+                PrismaError::QueryConversionError(format!(
+                    "Subscription field '{}' does not match any model.",
+                    selection.name()
+                ))
+            })?;
+
+        let mut mutations: Vec<ChangeOperation> = MUTATIONS.iter().map(|(_, operation)| *operation).collect();
+        let mut filter = None;
+
+        for (name, value) in selection.arguments() {
+            match (name.as_str(), value) {
+                (WHERE_ARGUMENT, value) => filter = Some(value.clone()),
+                (MUTATION_IN_ARGUMENT, QueryValue::List(values)) => {
+                    mutations = values.iter().map(Self::mutation).collect::<PrismaResult<_>>()?;
+                }
+                (MUTATION_IN_ARGUMENT, value) => mutations = vec![Self::mutation(value)?],
+                (name, _) => {
+                    return Err(PrismaError::QueryConversionError(format!(
+                        "Unknown argument '{}' on subscription field '{}'.",
+                        name,
+                        selection.name()
+                    )))
+                }
+            }
+        }
+
+        let mut node_selections = None;
+
+        for nested in selection.nested_selections() {
+            match nested.name() {
+                MUTATION_SELECTION | IDENTIFIER_SELECTION => (),
+                NODE_SELECTION => node_selections = Some(nested.nested_selections().to_vec()),
+                name => {
+                    return Err(PrismaError::QueryConversionError(format!(
+                        "Unknown field '{}' on subscription field '{}'.",
+                        name,
+                        selection.name()
+                    )))
+                }
+            }
+        }
+
+        // Without a node selection, the record is still read to apply the filter.
+        let node_selections = node_selections.unwrap_or_else(|| {
+            model
+                .primary_identifier()
+                .names()
+                .map(|name| Selection::builder(name).build())
+                .collect()
+        });
+
+        let (field, _) =
+            JsonProtocolAdapter::find_root_field(Some(&model.name), &QueryTag::FindMany.to_string(), query_schema)?;
+
+        let mut node = Selection::builder(field.name.clone());
+        node.nested_selections(node_selections);
+
+        let subscription = Self {
+            response_key: selection.alias().clone().unwrap_or_else(|| selection.name().to_owned()),
+            selections: selection.nested_selections().to_vec(),
+            model,
+            mutations,
+            filter,
+            node: node.build(),
+        };
+
+        // Validation errors are reported when subscribing rather than with the first event.
+        QueryGraphBuilder::new(Arc::clone(query_schema))
+            .validate(subscription.node_operation(None))
+            .map_err(CoreError::from)?;
+
+        Ok(subscription)
+    }
+
+    /// Resolves the subscription for a change event into a response. Returns `None` if the event
+    /// doesn't concern the subscription, or if the changed record doesn't match the filter.
+    pub async fn resolve(&self, event: &ChangeEvent, ctx: &PrismaContext) -> PrismaResult<Option<serde_json::Value>> {
+        if event.table != self.model.db_name() || !self.mutations.contains(&event.operation) {
+            return Ok(None);
+        }
+
+        let node = if event.operation == ChangeOperation::Delete {
+            serde_json::Value::Null
+        } else {
+            let identifier = self.identifier(event)?;
+
+            let mut responses = ctx
+                .executor
+                .execute(self.node_operation(Some(&identifier)), Arc::clone(ctx.query_schema()))
+                .await
+                .map_err(|err| PrismaError::from(CoreError::from(err)))?;
+
+            let nodes = responses
+                .take_data(self.node.name())
+                .map(serde_json::to_value)
+                .transpose()?;
+
+            match nodes {
+                Some(serde_json::Value::Array(mut nodes)) if !nodes.is_empty() => nodes.remove(0),
+                _ => return Ok(None),
+            }
+        };
+
+        let mutation = MUTATIONS
+            .iter()
+            .find(|(_, operation)| *operation == event.operation)
+            .map(|(name, _)| *name)
+            .unwrap();
+
+        let mut payload = serde_json::Map::new();
+
+        for selection in &self.selections {
+            let key = selection.alias().clone().unwrap_or_else(|| selection.name().to_owned());
+
+            let value = match selection.name() {
+                MUTATION_SELECTION => mutation.into(),
+                IDENTIFIER_SELECTION => self.identifier_json(event).into(),
+                _ => node.clone(),
+            };
+
+            payload.insert(key, value);
+        }
+
+        let mut data = serde_json::Map::new();
+        data.insert(self.response_key.clone(), payload.into());
+
+        Ok(Some(serde_json::json!({ "data": data })))
+    }
+
+    /// The `findMany` operation reading the changed record.
+    /// Without an identifier, only the filter of the subscription applies.
+    fn node_operation(&self, identifier: Option<&QueryValue>) -> Operation {
+        let filter = match (&self.filter, identifier) {
+            (Some(filter), Some(identifier)) => {
+                let mut and = BTreeMap::new();
+                and.insert(
+                    "AND".to_owned(),
+                    QueryValue::List(vec![filter.clone(), identifier.clone()]),
+                );
+
+                Some(QueryValue::Object(and))
+            }
+            (Some(filter), None) => Some(filter.clone()),
+            (None, identifier) => identifier.cloned(),
+        };
+
+        let mut builder = Selection::builder(self.node.name());
+        builder.nested_selections(self.node.nested_selections().to_vec());
+
+        if let Some(filter) = filter {
+            builder.push_argument(WHERE_ARGUMENT, filter);
+        }
+
+        Operation::Read(builder.build())
+    }
+
+    /// The primary key values of the event, by field name.
+    fn identifier_json(&self, event: &ChangeEvent) -> serde_json::Map<String, serde_json::Value> {
+        self.model
+            .fields()
+            .scalar()
+            .into_iter()
+            .filter_map(|field| {
+                event
+                    .record
+                    .get(field.db_name())
+                    .map(|value| (field.name.clone(), value.clone()))
+            })
+            .collect()
+    }
+
+    fn identifier(&self, event: &ChangeEvent) -> PrismaResult<QueryValue> {
+        let identifier = self
+            .identifier_json(event)
+            .iter()
+            .map(|(field, value)| Ok((field.clone(), JsonProtocolAdapter::convert_value(value)?)))
+            .collect::<Result<BTreeMap<_, _>, String>>()
+            .map_err(PrismaError::ChangeEventsError)?;
+
+        Ok(QueryValue::Object(identifier))
+    }
+
+    fn mutation(value: &QueryValue) -> PrismaResult<ChangeOperation> {
+        let name = match value {
+            QueryValue::Enum(name) | QueryValue::String(name) => Some(name.as_str()),
+            _ => None,
+        };
+
+        MUTATIONS
+            .iter()
+            .find(|(mutation, _)| Some(*mutation) == name)
+            .map(|(_, operation)| *operation)
+            .ok_or_else(|| {
+                PrismaError::QueryConversionError(format!(
+                    "Invalid value for '{}', expected CREATED, UPDATED or DELETED.",
+                    MUTATION_IN_ARGUMENT
+                ))
+            })
+    }
+
+    fn field_name(model: &ModelRef) -> String {
+        let mut chars = model.name.chars();
+
+        match chars.next() {
+            Some(first) => first.to_lowercase().chain(chars).collect(),
+            None => String::new(),
+        }
+    }
+}
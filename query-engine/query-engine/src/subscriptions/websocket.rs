@@ -0,0 +1,211 @@
+use super::Subscription;
+use crate::{context::PrismaContext, request_handlers::SingleQuery, PrismaError, PrismaResult};
+use futures::{
+    future::{AbortHandle, Abortable},
+    SinkExt, StreamExt,
+};
+use hyper::{header, Body, Request, Response, StatusCode};
+use query_core::response_ir::ResponseError;
+use serde::{Deserialize, Serialize};
+use serde_json::json;
+use sha1::{Digest, Sha1};
+use std::{collections::HashMap, sync::Arc};
+use tokio::{
+    io::{AsyncRead, AsyncWrite},
+    sync::{broadcast::RecvError, mpsc},
+};
+use tokio_tungstenite::{
+    tungstenite::{protocol::Role, Message},
+    WebSocketStream,
+};
+
+/// The WebSocket subprotocol for GraphQL subscriptions, as defined by `subscriptions-transport-ws`.
+pub const GRAPHQL_WS_PROTOCOL: &str = "graphql-ws";
+
+/// Appended to the key of the client to compute the accept key of the handshake (RFC 6455).
+const WEBSOCKET_GUID: &str = "258EAFA5-E914-47DA-95CA-C5AB0DC85B11";
+
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ClientMessage {
+    ConnectionInit,
+    Start { id: String, payload: SingleQuery },
+    Stop { id: String },
+    ConnectionTerminate,
+}
+
+#[derive(Debug, Serialize)]
+#[serde(tag = "type", rename_all = "snake_case")]
+enum ServerMessage {
+    ConnectionAck,
+    ConnectionError { payload: serde_json::Value },
+    Data { id: String, payload: serde_json::Value },
+    Error { id: String, payload: serde_json::Value },
+    Complete { id: String },
+}
+
+/// Checks if the request asks to upgrade the connection to a WebSocket connection.
+pub fn is_websocket_upgrade(req: &Request<Body>) -> bool {
+    req.headers()
+        .get(header::UPGRADE)
+        .and_then(|value| value.to_str().ok())
+        .map(|value| value.eq_ignore_ascii_case("websocket"))
+        .unwrap_or(false)
+}
+
+/// Completes the WebSocket handshake of the request and serves subscriptions on the connection
+/// once it is upgraded.
+pub fn upgrade(req: Request<Body>, ctx: Arc<PrismaContext>) -> Response<Body> {
+    let accept_key = match req.headers().get(header::SEC_WEBSOCKET_KEY) {
+        Some(key) => {
+            let mut hasher = Sha1::new();
+            hasher.input(key.as_bytes());
+            hasher.input(WEBSOCKET_GUID.as_bytes());
+
+            base64::encode(&hasher.result())
+        }
+        None => {
+            let mut bad_request = Response::default();
+            *bad_request.status_mut() = StatusCode::BAD_REQUEST;
+
+            return bad_request;
+        }
+    };
+
+    tokio::spawn(async move {
+        match req.into_body().on_upgrade().await {
+            Ok(upgraded) => {
+                let socket = WebSocketStream::from_raw_socket(upgraded, Role::Server, None).await;
+                serve(socket, ctx).await;
+            }
+            Err(err) => error!("Upgrading to a WebSocket connection failed: {}", err),
+        }
+    });
+
+    Response::builder()
+        .status(StatusCode::SWITCHING_PROTOCOLS)
+        .header(header::UPGRADE, "websocket")
+        .header(header::CONNECTION, "Upgrade")
+        .header(header::SEC_WEBSOCKET_ACCEPT, accept_key)
+        .header(header::SEC_WEBSOCKET_PROTOCOL, GRAPHQL_WS_PROTOCOL)
+        .body(Body::empty())
+        .unwrap()
+}
+
+/// Serves the subscriptions of a single connection until the client closes it.
+async fn serve<S>(socket: WebSocketStream<S>, ctx: Arc<PrismaContext>)
+where
+    S: AsyncRead + AsyncWrite + Unpin + Send + 'static,
+{
+    let (mut sink, mut stream) = socket.split();
+    let (sender, mut outgoing) = mpsc::unbounded_channel::<ServerMessage>();
+
+    // All subscriptions of the connection send their messages through a single writer.
+    let writer = tokio::spawn(async move {
+        while let Some(message) = outgoing.recv().await {
+            let text = serde_json::to_string(&message).unwrap();
+
+            if sink.send(Message::Text(text)).await.is_err() {
+                break;
+            }
+        }
+    });
+
+    let mut subscriptions: HashMap<String, AbortHandle> = HashMap::new();
+
+    while let Some(message) = stream.next().await {
+        let text = match message {
+            Ok(Message::Text(text)) => text,
+            Ok(Message::Close(_)) | Err(_) => break,
+            Ok(_) => continue,
+        };
+
+        let reply = match serde_json::from_str::<ClientMessage>(&text) {
+            Ok(ClientMessage::ConnectionInit) => Some(ServerMessage::ConnectionAck),
+            Ok(ClientMessage::Start { id, payload }) => match start(id.clone(), payload, &ctx, sender.clone()) {
+                Ok(handle) => {
+                    if let Some(previous) = subscriptions.insert(id, handle) {
+                        previous.abort();
+                    }
+
+                    None
+                }
+                Err(err) => Some(ServerMessage::Error {
+                    id,
+                    payload: error_payload(err),
+                }),
+            },
+            Ok(ClientMessage::Stop { id }) => subscriptions.remove(&id).map(|handle| {
+                handle.abort();
+                ServerMessage::Complete { id }
+            }),
+            Ok(ClientMessage::ConnectionTerminate) => break,
+            Err(err) => Some(ServerMessage::ConnectionError {
+                payload: json!({ "message": err.to_string() }),
+            }),
+        };
+
+        if let Some(reply) = reply {
+            sender.send(reply).ok();
+        }
+    }
+
+    for handle in subscriptions.values() {
+        handle.abort();
+    }
+
+    // The writer finishes as soon as the aborted subscriptions dropped their senders.
+    drop(sender);
+    writer.await.ok();
+}
+
+/// Starts a subscription, streaming its responses to the sender until it is aborted.
+fn start(
+    id: String,
+    query: SingleQuery,
+    ctx: &Arc<PrismaContext>,
+    sender: mpsc::UnboundedSender<ServerMessage>,
+) -> PrismaResult<AbortHandle> {
+    let selection = ctx.document_cache().subscription(query)?;
+    let subscription = Subscription::new(selection, ctx)?;
+    let mut events = ctx.change_events().subscribe()?;
+
+    let ctx = Arc::clone(ctx);
+    let (handle, registration) = AbortHandle::new_pair();
+
+    let task = async move {
+        loop {
+            let event = match events.recv().await {
+                Ok(event) => event,
+                Err(RecvError::Lagged(skipped)) => {
+                    warn!("Subscription '{}' missed {} change events.", id, skipped);
+                    continue;
+                }
+                Err(RecvError::Closed) => break,
+            };
+
+            let payload = match subscription.resolve(&event, &ctx).await {
+                Ok(Some(payload)) => payload,
+                Ok(None) => continue,
+                Err(err) => error_payload(err),
+            };
+
+            let message = ServerMessage::Data {
+                id: id.clone(),
+                payload,
+            };
+
+            if sender.send(message).is_err() {
+                break;
+            }
+        }
+    };
+
+    tokio::spawn(Abortable::new(task, registration));
+
+    Ok(handle)
+}
+
+fn error_payload(err: PrismaError) -> serde_json::Value {
+    json!({ "errors": [ResponseError::from(err)] })
+}
@@ -1,4 +1,11 @@
+mod composite_filters;
 mod dmmf;
 mod execute_raw;
+mod json_protocol;
+mod persisted_queries;
+mod schema_files;
+mod shutdown;
+mod subscriptions;
 mod test_api;
 mod type_mappings;
+mod variables;
@@ -0,0 +1,116 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static USER: &str = indoc! {"
+    model User {
+        id      Int     @id
+        profile Profile
+    }
+
+    type Profile {
+        name     String
+        age      Int
+        score    Float
+        verified Boolean
+    }
+"};
+
+async fn create_users(query_engine: &QueryEngine) {
+    let users = [
+        (1, "Alice", 9, 2.5, true),
+        (2, "Bob", 10, 10.25, false),
+        (3, "Carol", 100, 100.0, true),
+    ];
+
+    for (id, name, age, score, verified) in users.iter() {
+        let mutation = format!(
+            r#"mutation {{
+                createOneUser(data: {{ id: {}, profile: {{ name: "{}", age: {}, score: {}, verified: {} }} }}) {{ id }}
+            }}"#,
+            id, name, age, score, verified
+        );
+
+        query_engine.request(mutation.as_str()).await;
+    }
+}
+
+async fn find_ids(query_engine: &QueryEngine, filter: &str) -> serde_json::Value {
+    let query = format!(
+        "query {{ findManyUser(where: {{ profile: {} }}, orderBy: id_ASC) {{ id }} }}",
+        filter
+    );
+
+    query_engine.request(query.as_str()).await["data"]["findManyUser"].clone()
+}
+
+#[test_each_connector(capabilities("json"), ignore("mysql_5_6"))]
+async fn composite_filters_compare_ints_as_numbers(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USER).await?;
+    create_users(&query_engine).await;
+
+    assert_eq!(
+        json!([{ "id": 2 }, { "id": 3 }]),
+        find_ids(&query_engine, "{ age_gt: 9 }").await
+    );
+    assert_eq!(
+        json!([{ "id": 1 }, { "id": 2 }]),
+        find_ids(&query_engine, "{ age_lt: 100 }").await
+    );
+    assert_eq!(json!([{ "id": 2 }]), find_ids(&query_engine, "{ age: 10 }").await);
+    assert_eq!(
+        json!([{ "id": 1 }, { "id": 3 }]),
+        find_ids(&query_engine, "{ age_in: [9, 100] }").await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(capabilities("json"), ignore("mysql_5_6"))]
+async fn composite_filters_compare_floats_as_numbers(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USER).await?;
+    create_users(&query_engine).await;
+
+    assert_eq!(
+        json!([{ "id": 2 }, { "id": 3 }]),
+        find_ids(&query_engine, "{ score_gte: 10 }").await
+    );
+    assert_eq!(json!([{ "id": 1 }]), find_ids(&query_engine, "{ score_lt: 10 }").await);
+
+    Ok(())
+}
+
+#[test_each_connector(capabilities("json"), ignore("mysql_5_6"))]
+async fn composite_filters_match_booleans(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USER).await?;
+    create_users(&query_engine).await;
+
+    assert_eq!(
+        json!([{ "id": 1 }, { "id": 3 }]),
+        find_ids(&query_engine, "{ verified: true }").await
+    );
+    assert_eq!(
+        json!([{ "id": 2 }]),
+        find_ids(&query_engine, "{ verified_not: true }").await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(capabilities("json"), ignore("mysql_5_6"))]
+async fn composite_filters_match_strings(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&USER).await?;
+    create_users(&query_engine).await;
+
+    assert_eq!(
+        json!([{ "id": 2 }]),
+        find_ids(&query_engine, r#"{ name: "Bob" }"#).await
+    );
+    assert_eq!(
+        json!([{ "id": 3 }]),
+        find_ids(&query_engine, r#"{ name_starts_with: "Ca" }"#).await
+    );
+
+    Ok(())
+}
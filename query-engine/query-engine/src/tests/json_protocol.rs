@@ -0,0 +1,166 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static BLOG: &str = indoc! {"
+    model Author {
+        id String @id @default(cuid())
+        name String
+        posts Post[]
+    }
+
+    model Post {
+        id String @id @default(cuid())
+        title String
+        published Boolean @default(false)
+        author Author
+    }
+"};
+
+#[test_each_connector]
+async fn json_protocol_create_and_find_many(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    let create = json!({
+        "modelName": "Author",
+        "action": "createOne",
+        "query": {
+            "arguments": {
+                "data": { "name": "Ada", "posts": { "create": [{ "title": "Notes" }] } }
+            },
+            "selection": { "name": true }
+        }
+    });
+
+    assert_eq!(
+        json!({ "data": { "createOneAuthor": { "name": "Ada" } } }),
+        query_engine.request_json(create).await
+    );
+
+    let find_many = json!({
+        "modelName": "Post",
+        "action": "findMany",
+        "query": {
+            "arguments": { "where": { "published": false } },
+            "selection": {
+                "title": true,
+                "published": true,
+                "author": { "selection": { "name": true } }
+            }
+        }
+    });
+
+    assert_eq!(
+        json!({
+            "data": {
+                "findManyPost": [{ "title": "Notes", "published": false, "author": { "name": "Ada" } }]
+            }
+        }),
+        query_engine.request_json(find_many).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn json_protocol_scalars_selection(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    let create = json!({
+        "modelName": "Author",
+        "action": "createOne",
+        "query": {
+            "arguments": { "data": { "id": "ada", "name": "Ada" } },
+            "selection": { "$scalars": true }
+        }
+    });
+
+    assert_eq!(
+        json!({ "data": { "createOneAuthor": { "id": "ada", "name": "Ada" } } }),
+        query_engine.request_json(create).await
+    );
+
+    let create = json!({
+        "modelName": "Post",
+        "action": "createOne",
+        "query": {
+            "arguments": {
+                "data": { "id": "notes", "title": "Notes", "author": { "connect": { "id": "ada" } } }
+            },
+            "selection": { "$scalars": true, "author": true }
+        }
+    });
+
+    assert_eq!(
+        json!({
+            "data": {
+                "createOnePost": {
+                    "id": "notes",
+                    "title": "Notes",
+                    "published": false,
+                    "author": { "id": "ada", "name": "Ada" }
+                }
+            }
+        }),
+        query_engine.request_json(create).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn json_protocol_batches(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    let create = json!({
+        "modelName": "Author",
+        "action": "createOne",
+        "query": { "arguments": { "data": { "id": "ada", "name": "Ada" } }, "selection": { "id": true } }
+    });
+
+    query_engine.request_json(create).await;
+
+    let batch = json!({
+        "batch": [
+            {
+                "modelName": "Author",
+                "action": "findOne",
+                "query": { "arguments": { "where": { "id": "ada" } }, "selection": { "name": true } }
+            },
+            {
+                "modelName": "Author",
+                "action": "findOne",
+                "query": { "arguments": { "where": { "id": "grace" } }, "selection": { "name": true } }
+            }
+        ]
+    });
+
+    assert_eq!(
+        json!([
+            { "data": { "findOneAuthor": { "name": "Ada" } } },
+            { "data": { "findOneAuthor": null } }
+        ]),
+        query_engine.request_json(batch).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn json_protocol_unknown_actions_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&BLOG).await?;
+
+    let query = json!({
+        "modelName": "Author",
+        "action": "findSome",
+        "query": { "selection": { "$scalars": true } }
+    });
+
+    let response = query_engine.request_json(query).await;
+    let message = response["errors"][0]["error"].as_str().unwrap();
+
+    assert!(message.contains("Action 'findSome' does not exist on model 'Author'."));
+
+    Ok(())
+}
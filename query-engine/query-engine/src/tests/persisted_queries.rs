@@ -0,0 +1,147 @@
+use super::test_api::*;
+use crate::{
+    error::PrismaError,
+    request_handlers::{DocumentCache, SingleQuery},
+};
+use indoc::indoc;
+use serde_json::json;
+use sha2::{Digest, Sha256};
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id String @id @default(cuid())
+        title String
+    }
+"};
+
+fn persisted_query(query: Option<&str>, sha256_hash: &str) -> SingleQuery {
+    serde_json::from_value(json!({
+        "query": query,
+        "extensions": { "persistedQuery": { "version": 1, "sha256Hash": sha256_hash } }
+    }))
+    .unwrap()
+}
+
+#[test_each_connector]
+async fn persisted_queries_are_registered_on_first_miss(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { title: "title1" }) { id } }"#)
+        .await;
+
+    let query = "query { findManyTodo { title } }";
+    let hash = format!("{:x}", Sha256::digest(query.as_bytes()));
+
+    let result = query_engine.request(persisted_query(None, &hash)).await;
+
+    assert_eq!(
+        Some("P2021"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    let expected = json!({ "data": { "findManyTodo": [{ "title": "title1" }] } });
+
+    assert_eq!(
+        expected,
+        query_engine.request(persisted_query(Some(query), &hash)).await
+    );
+    assert_eq!(expected, query_engine.request(persisted_query(None, &hash)).await);
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn persisted_queries_with_mismatching_hashes_are_rejected(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = "query { findManyTodo { title } }";
+    let hash = format!("{:x}", Sha256::digest(b"query { findManyTodo { id } }"));
+
+    let result = query_engine.request(persisted_query(Some(query), &hash)).await;
+
+    assert!(result["errors"][0]["error"]
+        .as_str()
+        .unwrap()
+        .contains("does not match the query"));
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn cached_documents_yield_the_same_results(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let mutation = r#"mutation { createOneTodo(data: { title: "title1" }) { id title } }"#;
+
+    let first = query_engine.request(mutation).await;
+    let second = query_engine.request(mutation).await;
+
+    assert_eq!("title1", second["data"]["createOneTodo"]["title"]);
+
+    // The default ids are generated for each execution of the cached document.
+    assert_ne!(
+        first["data"]["createOneTodo"]["id"],
+        second["data"]["createOneTodo"]["id"]
+    );
+
+    let result = query_engine.request("query { findManyTodo { title } }").await;
+
+    assert_eq!(2, result["data"]["findManyTodo"].as_array().unwrap().len());
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn persisted_queries_are_registered_without_document_cache(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine_with_query_cache_size(&TODO, 0).await?;
+
+    let query = "query { findManyTodo { title } }";
+    let hash = format!("{:x}", Sha256::digest(query.as_bytes()));
+    let expected = json!({ "data": { "findManyTodo": [] } });
+
+    assert_eq!(
+        expected,
+        query_engine.request(persisted_query(Some(query), &hash)).await
+    );
+    assert_eq!(expected, query_engine.request(persisted_query(None, &hash)).await);
+
+    Ok(())
+}
+
+#[test]
+fn the_least_recently_used_persisted_queries_are_evicted() {
+    let document_cache = DocumentCache::new(0, 1);
+
+    let cats = "query { findManyCat { name } }";
+    let cats_hash = format!("{:x}", Sha256::digest(cats.as_bytes()));
+    let dogs = "query { findManyDog { name } }";
+    let dogs_hash = format!("{:x}", Sha256::digest(dogs.as_bytes()));
+
+    document_cache
+        .operation(persisted_query(Some(cats), &cats_hash))
+        .unwrap();
+    document_cache
+        .operation(persisted_query(Some(dogs), &dogs_hash))
+        .unwrap();
+
+    match document_cache.operation(persisted_query(None, &cats_hash)) {
+        Err(PrismaError::PersistedQueryNotFound(hash)) => assert_eq!(cats_hash, hash),
+        other => panic!("Expected the persisted query to be evicted, got {:?}", other.map(drop)),
+    }
+
+    assert!(document_cache.operation(persisted_query(None, &dogs_hash)).is_ok());
+}
+
+#[test]
+fn persisted_queries_are_not_registered_with_a_size_of_zero() {
+    let document_cache = DocumentCache::new(0, 0);
+
+    let query = "query { findManyCat { name } }";
+    let hash = format!("{:x}", Sha256::digest(query.as_bytes()));
+
+    document_cache.operation(persisted_query(Some(query), &hash)).unwrap();
+
+    assert!(document_cache.operation(persisted_query(None, &hash)).is_err());
+}
@@ -0,0 +1,82 @@
+use crate::{error::PrismaError, opt::PrismaOpt};
+use std::{ffi::OsStr, path::Path};
+use structopt::StructOpt;
+
+fn opts_for_directory(path: &Path) -> PrismaOpt {
+    let args = vec![
+        OsStr::new("query-engine"),
+        OsStr::new("--datamodel-path"),
+        path.as_os_str(),
+    ];
+
+    PrismaOpt::from_iter_safe(args).unwrap()
+}
+
+#[test]
+fn datamodel_path_can_be_a_directory_of_schema_files() {
+    let schema_directory = tempfile::tempdir().unwrap();
+
+    std::fs::write(
+        schema_directory.path().join("users.prisma"),
+        r#"
+            model User {
+                id    Int    @id
+                posts Post[]
+            }
+        "#,
+    )
+    .unwrap();
+    std::fs::write(schema_directory.path().join("empty.prisma"), "").unwrap();
+    std::fs::write(
+        schema_directory.path().join("posts.prisma"),
+        r#"
+            model Post {
+                id       Int  @id
+                authorId Int
+                author   User @relation(fields: [authorId], references: [id])
+            }
+        "#,
+    )
+    .unwrap();
+    // Only `.prisma` files are part of the schema.
+    std::fs::write(schema_directory.path().join("README.md"), "# Schema").unwrap();
+
+    let datamodel = opts_for_directory(schema_directory.path()).datamodel(true).unwrap();
+
+    assert!(datamodel.has_model("User"));
+    assert!(datamodel.has_model("Post"));
+}
+
+#[test]
+fn datamodel_errors_point_at_the_schema_file_they_originate_from() {
+    let schema_directory = tempfile::tempdir().unwrap();
+
+    std::fs::write(
+        schema_directory.path().join("a_users.prisma"),
+        r#"
+            model User {
+                id Int @id
+            }
+        "#,
+    )
+    .unwrap();
+    std::fs::write(
+        schema_directory.path().join("b_posts.prisma"),
+        r#"
+            model Post {
+                id     Int @id
+                author Author
+            }
+        "#,
+    )
+    .unwrap();
+
+    match opts_for_directory(schema_directory.path()).datamodel(true) {
+        Err(PrismaError::ConversionError(errors, schema_files)) => {
+            let rendered = schema_files.to_pretty_string(&errors);
+
+            assert!(rendered.contains("b_posts.prisma:4"), "{}", rendered);
+        }
+        other => panic!("Expected a conversion error, got {:?}", other.map(drop)),
+    }
+}
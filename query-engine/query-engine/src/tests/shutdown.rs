@@ -0,0 +1,153 @@
+use super::test_api::*;
+use crate::server::{HttpServer, Shutdown};
+use futures::{channel::oneshot, future};
+use hyper::{Body, Client, Request, StatusCode};
+use indoc::indoc;
+use serde_json::json;
+use std::{
+    io::{Read, Write},
+    net::{SocketAddr, TcpListener, TcpStream},
+    time::Duration,
+};
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id String @id @default(cuid())
+        title String
+    }
+"};
+
+#[test_each_connector]
+async fn requests_fail_after_the_executor_is_closed(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let result = query_engine
+        .request(r#"mutation { createOneTodo(data: { title: "Drain" }) { title } }"#)
+        .await;
+
+    assert_eq!("Drain", result["data"]["createOneTodo"]["title"]);
+
+    query_engine.close();
+
+    let result = query_engine.request("query { findManyTodo { title } }").await;
+    let message = result["errors"][0]["error"].as_str().unwrap();
+
+    assert!(message.contains("shutting down"), "{}", message);
+
+    Ok(())
+}
+
+async fn send(request: Request<Body>) -> anyhow::Result<(StatusCode, serde_json::Value)> {
+    let response = Client::new().request(request).await?;
+    let status = response.status();
+    let body = hyper::body::to_bytes(response.into_body()).await?;
+
+    Ok((status, serde_json::from_slice(&body)?))
+}
+
+fn status_request(address: SocketAddr) -> Request<Body> {
+    Request::get(format!("http://{}/status", address))
+        .body(Body::empty())
+        .unwrap()
+}
+
+fn query_request(address: SocketAddr, query: &str) -> Request<Body> {
+    Request::post(format!("http://{}/", address))
+        .body(Body::from(json!({ "query": query }).to_string()))
+        .unwrap()
+}
+
+#[test_each_connector]
+async fn status_reports_draining_during_the_grace_period(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+    let (signal_sender, signal) = oneshot::channel::<()>();
+
+    let shutdown = Shutdown {
+        signal: async {
+            signal.await.ok();
+        },
+        drain_grace_period: Duration::from_secs(2),
+        timeout: Duration::from_secs(1),
+    };
+
+    let server = HttpServer::run(listener, query_engine.context().clone(), false, shutdown);
+
+    let client = async {
+        let (status, body) = send(status_request(address)).await?;
+
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(json!({ "status": "ok" }), body);
+
+        signal_sender.send(()).ok();
+        tokio::time::delay_for(Duration::from_millis(200)).await;
+
+        let (status, body) = send(status_request(address)).await?;
+
+        assert_eq!(StatusCode::SERVICE_UNAVAILABLE, status);
+        assert_eq!(json!({ "status": "draining" }), body);
+
+        // Requests are still served while draining.
+        let (status, body) = send(query_request(address, "query { findManyTodo { title } }")).await?;
+
+        assert_eq!(StatusCode::OK, status);
+        assert_eq!(json!([]), body["data"]["findManyTodo"]);
+
+        Ok::<_, anyhow::Error>(())
+    };
+
+    let (server_result, client_result) =
+        tokio::time::timeout(Duration::from_secs(10), future::join(server, client)).await?;
+
+    client_result?;
+    server_result.unwrap();
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn in_flight_requests_are_cut_off_after_the_shutdown_timeout(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    let listener = TcpListener::bind("127.0.0.1:0")?;
+    let address = listener.local_addr()?;
+    let (signal_sender, signal) = oneshot::channel::<()>();
+
+    let shutdown = Shutdown {
+        signal: async {
+            signal.await.ok();
+        },
+        drain_grace_period: Duration::from_secs(0),
+        timeout: Duration::from_millis(200),
+    };
+
+    let server = HttpServer::run(listener, query_engine.context().clone(), false, shutdown);
+
+    let client = async {
+        // The body of this request never arrives, so it stays in flight.
+        let mut stream = TcpStream::connect(address)?;
+        stream.write_all(b"POST / HTTP/1.1\r\nhost: localhost\r\ncontent-length: 100\r\n\r\n{")?;
+
+        tokio::time::delay_for(Duration::from_millis(200)).await;
+        signal_sender.send(()).ok();
+
+        Ok::<_, anyhow::Error>(stream)
+    };
+
+    let (server_result, client_result) =
+        tokio::time::timeout(Duration::from_secs(10), future::join(server, client)).await?;
+
+    server_result.unwrap();
+
+    // The server closed the connection without responding.
+    let mut stream = client_result?;
+    let mut response = Vec::new();
+
+    stream.set_read_timeout(Some(Duration::from_secs(1)))?;
+    stream.read_to_end(&mut response)?;
+
+    assert!(response.is_empty(), "{}", String::from_utf8_lossy(&response));
+
+    Ok(())
+}
@@ -0,0 +1,84 @@
+use super::test_api::*;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id Int @id
+        title String
+        done Boolean @default(false)
+    }
+"};
+
+#[test_each_connector(ignore("mysql"))]
+async fn subscriptions_resolve_created_and_updated_records(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    api.enable_change_events().await?;
+
+    let (subscription, mut events) = query_engine.subscribe(
+        "subscription { todo(mutation_in: [CREATED, UPDATED]) { mutation identifier node { title done } } }",
+    );
+
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "Write tests" }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        Some(json!({
+            "data": {
+                "todo": {
+                    "mutation": "CREATED",
+                    "identifier": { "id": 1 },
+                    "node": { "title": "Write tests", "done": false }
+                }
+            }
+        })),
+        query_engine.next_change(&subscription, &mut events).await
+    );
+
+    query_engine
+        .request(r#"mutation { updateOneTodo(where: { id: 1 }, data: { done: true }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        Some(json!({
+            "data": {
+                "todo": {
+                    "mutation": "UPDATED",
+                    "identifier": { "id": 1 },
+                    "node": { "title": "Write tests", "done": true }
+                }
+            }
+        })),
+        query_engine.next_change(&subscription, &mut events).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector(ignore("mysql"))]
+async fn subscriptions_filter_records_and_mutations(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+    api.enable_change_events().await?;
+
+    let (subscription, mut events) =
+        query_engine.subscribe("subscription { todo(where: { done: true }) { mutation identifier } }");
+
+    query_engine
+        .request(r#"mutation { createOneTodo(data: { id: 1, title: "Open" }) { id } }"#)
+        .await;
+
+    assert_eq!(None, query_engine.next_change(&subscription, &mut events).await);
+
+    query_engine
+        .request(r#"mutation { deleteOneTodo(where: { id: 1 }) { id } }"#)
+        .await;
+
+    assert_eq!(
+        Some(json!({ "data": { "todo": { "mutation": "DELETED", "identifier": { "id": 1 } } } })),
+        query_engine.next_change(&subscription, &mut events).await
+    );
+
+    Ok(())
+}
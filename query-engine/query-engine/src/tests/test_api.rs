@@ -1,12 +1,13 @@
 use crate::{
-    context::PrismaContext,
-    request_handlers::{GraphQlBody, GraphQlRequestHandler, RequestHandler, SingleQuery},
+    context::{PrismaContext, DEFAULT_QUERY_CACHE_SIZE},
+    request_handlers::{GraphQlBody, GraphQlRequestHandler, JsonRequestHandler, RequestHandler, SingleQuery},
+    subscriptions::{ChangeEvent, Subscription},
     PrismaRequest, PrismaResponse,
 };
 use migration_connector::*;
 use migration_core::{
     api::{GenericApi, MigrationApi},
-    commands::{ApplyMigrationInput, InferMigrationStepsInput, ResetCommand},
+    commands::{ApplyMigrationInput, EnableChangeEventsInput, InferMigrationStepsInput, ResetCommand},
 };
 use quaint::{
     ast::*,
@@ -14,8 +15,9 @@ use quaint::{
     visitor::{self, Visitor},
 };
 use sql_migration_connector::SqlMigrationConnector;
-use std::{collections::HashMap, sync::Arc};
+use std::{collections::HashMap, sync::Arc, time::Duration};
 use test_setup::*;
+use tokio::sync::broadcast;
 
 pub struct QueryEngine {
     context: Arc<PrismaContext>,
@@ -38,6 +40,50 @@ impl QueryEngine {
             _ => unreachable!(),
         }
     }
+
+    /// Sends a request of the JSON protocol, single or batched, and returns the whole response.
+    pub async fn request_json(&self, body: serde_json::Value) -> serde_json::Value {
+        let request = PrismaRequest {
+            body: serde_json::from_value(body).unwrap(),
+            headers: HashMap::new(),
+            path: String::new(),
+        };
+
+        let response = JsonRequestHandler.handle(request, &self.context).await;
+        serde_json::to_value(response).unwrap()
+    }
+
+    pub fn context(&self) -> &Arc<PrismaContext> {
+        &self.context
+    }
+
+    /// Closes the executor, as on shutdown.
+    pub fn close(&self) {
+        self.context.executor.close();
+    }
+
+    /// Starts a GraphQL subscription, returning it with the receiver of the change events it resolves.
+    pub fn subscribe(&self, body: impl Into<SingleQuery>) -> (Subscription, broadcast::Receiver<ChangeEvent>) {
+        let selection = self.context.document_cache().subscription(body.into()).unwrap();
+        let subscription = Subscription::new(selection, &self.context).unwrap();
+        let events = self.context.change_events().subscribe().unwrap();
+
+        (subscription, events)
+    }
+
+    /// Waits for the next change event and resolves the subscription for it.
+    pub async fn next_change(
+        &self,
+        subscription: &Subscription,
+        events: &mut broadcast::Receiver<ChangeEvent>,
+    ) -> Option<serde_json::Value> {
+        let event = tokio::time::timeout(Duration::from_secs(10), events.recv())
+            .await
+            .expect("Timed out waiting for a change event")
+            .unwrap();
+
+        subscription.resolve(&event, &self.context).await.unwrap()
+    }
 }
 
 pub struct TestApi {
@@ -48,6 +94,15 @@ pub struct TestApi {
 
 impl TestApi {
     pub async fn create_engine(&self, datamodel: &str) -> anyhow::Result<QueryEngine> {
+        self.create_engine_with_query_cache_size(datamodel, DEFAULT_QUERY_CACHE_SIZE)
+            .await
+    }
+
+    pub async fn create_engine_with_query_cache_size(
+        &self,
+        datamodel: &str,
+        query_cache_size: usize,
+    ) -> anyhow::Result<QueryEngine> {
         let datamodel_string = format!("{}\n\n{}", self.config, datamodel);
         let migration_id = "test-cli-migration".to_owned();
 
@@ -56,6 +111,7 @@ impl TestApi {
             assume_to_be_applied: Some(Vec::new()),
             datamodel: datamodel_string.clone(),
             migration_id: migration_id.clone(),
+            accepted_renames: Vec::new(),
         };
 
         self.migration_api.reset(&serde_json::Value::Null).await?;
@@ -65,6 +121,9 @@ impl TestApi {
             force: Some(true),
             migration_id,
             steps: result.datamodel_steps,
+            data_migration_steps: Vec::new(),
+            accepted_renames: Vec::new(),
+            dry_run: false,
         };
 
         self.migration_api.apply_migration(&apply_input).await?;
@@ -74,6 +133,8 @@ impl TestApi {
 
         let context = PrismaContext::builder(config, dml)
             .enable_raw_queries(true)
+            .query_cache_size(query_cache_size)
+            .subscription_poll_interval(Duration::from_millis(50))
             .build()
             .await
             .unwrap();
@@ -83,6 +144,14 @@ impl TestApi {
         })
     }
 
+    /// Installs the change event triggers on all tables of the test database.
+    pub async fn enable_change_events(&self) -> anyhow::Result<()> {
+        let input = EnableChangeEventsInput { tables: Vec::new() };
+        self.migration_api.enable_change_events(&input).await?;
+
+        Ok(())
+    }
+
     pub fn connection_info(&self) -> &ConnectionInfo {
         &self.connection_info
     }
@@ -83,7 +83,7 @@ const CREATE_ONE_TYPES_QUERY: &str = indoc! {
                 string_text: "to yeet or not to yeet"
                 binary_bits: "0101110"
                 binary_bits_varying: "0101110"
-                # binary_bytea: "test"
+                binary_bytea: "dGVzdA=="
                 binary_uuid: "111142ec-880b-4062-913d-8eac479ab957"
                 time_timestamp: "2020-03-02T08:00:00.000"
                 time_timestamptz: "2020-03-02T08:00:00.000"
@@ -121,7 +121,7 @@ const CREATE_ONE_TYPES_QUERY: &str = indoc! {
             string_char
             string_varchar
             string_text
-            # binary_bytea
+            binary_bytea
             binary_bits
             binary_bits_varying
             binary_uuid
@@ -168,6 +168,7 @@ fn create_one_types_response() -> serde_json::Value {
                 "string_char": "yeet    ",
                 "string_varchar": "yeet variable",
                 "string_text": "to yeet or not to yeet",
+                "binary_bytea": "dGVzdA==",
                 "binary_bits": "0101110",
                 "binary_bits_varying": "0101110",
                 "binary_uuid": "111142ec-880b-4062-913d-8eac479ab957",
@@ -207,7 +208,7 @@ async fn postgres_types_roundtrip(api: &TestApi) -> TestResult {
             .assert_field_type("string_char", ScalarType::String)?
             .assert_field_type("string_varchar", ScalarType::String)?
             .assert_field_type("string_text", ScalarType::String)?
-            // .assert_field_type("binary_bytea", ScalarType::String)?
+            .assert_field_type("binary_bytea", ScalarType::Bytes)?
             .assert_field_type("binary_bits", ScalarType::String)?
             .assert_field_type("binary_bits_varying", ScalarType::String)?
             .assert_field_type("binary_uuid", ScalarType::String)?
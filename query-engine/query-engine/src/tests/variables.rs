@@ -0,0 +1,126 @@
+use super::test_api::*;
+use crate::request_handlers::SingleQuery;
+use indoc::indoc;
+use serde_json::json;
+use test_macros::*;
+
+static TODO: &str = indoc! {"
+    model Todo {
+        id String @id @default(cuid())
+        title String
+        priority Int
+        done Boolean @default(false)
+    }
+"};
+
+fn query_with_variables(query: &str, variables: serde_json::Value) -> SingleQuery {
+    serde_json::from_value(json!({ "query": query, "variables": variables })).unwrap()
+}
+
+#[test_each_connector]
+async fn variables_are_resolved_in_arguments(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let mutation = indoc! {r#"
+        mutation CreateTodo($data: TodoCreateInput!) {
+            createOneTodo(data: $data) { title priority done }
+        }
+    "#};
+
+    let variables = json!({ "data": { "title": "escape \"me\"", "priority": 2, "done": true } });
+
+    assert_eq!(
+        json!({
+            "data": {
+                "createOneTodo": { "title": "escape \"me\"", "priority": 2, "done": true }
+            }
+        }),
+        query_engine.request(query_with_variables(mutation, variables)).await
+    );
+
+    let query = indoc! {r#"
+        query FindTodos($priority: Int!, $titles: [String!]) {
+            findManyTodo(where: { priority: $priority, title_in: $titles }) { title }
+        }
+    "#};
+
+    let variables = json!({ "priority": 2, "titles": ["escape \"me\"", "other"] });
+
+    assert_eq!(
+        json!({ "data": { "findManyTodo": [{ "title": "escape \"me\"" }] } }),
+        query_engine.request(query_with_variables(query, variables)).await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn unset_nullable_variables_are_ignored(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let mutation = indoc! {r#"
+        mutation CreateTodo($title: String!, $done: Boolean) {
+            createOneTodo(data: { title: $title, priority: 1, done: $done }) { title done }
+        }
+    "#};
+
+    assert_eq!(
+        json!({ "data": { "createOneTodo": { "title": "a", "done": false } } }),
+        query_engine
+            .request(query_with_variables(mutation, json!({ "title": "a" })))
+            .await
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn variables_with_mismatching_types_fail_validation(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        query FindTodos($priority: Int!) {
+            findManyTodo(where: { priority: $priority }) { title }
+        }
+    "#};
+
+    let result = query_engine
+        .request(query_with_variables(query, json!({ "priority": "high" })))
+        .await;
+
+    assert_eq!(
+        Some("P2009"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    let result = query_engine.request(query_with_variables(query, json!({}))).await;
+
+    assert_eq!(
+        Some("P2009"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    Ok(())
+}
+
+#[test_each_connector]
+async fn undeclared_variables_fail_validation(api: &TestApi) -> anyhow::Result<()> {
+    let query_engine = api.create_engine(&TODO).await?;
+
+    let query = indoc! {r#"
+        query {
+            findManyTodo(where: { priority: $priority }) { title }
+        }
+    "#};
+
+    let result = query_engine
+        .request(query_with_variables(query, json!({ "priority": 1 })))
+        .await;
+
+    assert_eq!(
+        Some("P2009"),
+        result["errors"][0]["user_facing_error"]["error_code"].as_str()
+    );
+
+    Ok(())
+}